@@ -7,14 +7,18 @@ use anyhow::Result;
 use base64::Engine;
 use chrono::{DateTime, Utc};
 use cosmos::{
-    messages::{MsgExecHelper, MsgGrantHelper},
+    messages::{Authorization, MsgExecHelper, MsgGrantHelper},
     proto::{
         cosmos::authz::v1beta1::MsgGrant, cosmwasm::wasm::v1::MsgExecuteContract, traits::Message,
     },
     Address, Cosmos, HasAddress, HasAddressHrp, ParsedCoin, TxBuilder, TxMessage,
 };
+use futures::TryStreamExt;
 
-use crate::{cli::TxOpt, my_duration::MyDuration};
+use crate::{
+    cli::{ConfigLocation, TxOpt},
+    my_duration::MyDuration,
+};
 
 #[derive(clap::Parser)]
 pub(crate) struct Opt {
@@ -27,7 +31,7 @@ enum Subcommand {
     /// Give the grantee permissions
     Grant {
         grantee: Address,
-        /// Type of grant to allow
+        /// Type of grant to allow: send, execute-contract, store-code, or a message type URL
         grant_type: GrantType,
         #[clap(flatten)]
         tx_opt: TxOpt,
@@ -43,7 +47,7 @@ enum Subcommand {
         /// Address allowed to perform actions
         #[clap(long)]
         grantee: Address,
-        /// Type of grant to allow
+        /// Type of grant to allow: send, execute-contract, store-code, or a message type URL
         grant_type: GrantType,
         /// How long, in seconds, the grant lasts
         #[clap(long)]
@@ -51,6 +55,8 @@ enum Subcommand {
     },
     /// Query grants by the granter
     GranterGrants { granter: Address },
+    /// Query grants received by the grantee
+    GranteeGrants { grantee: Address },
     /// Exec a store-code via a grant
     StoreCode {
         /// Filepath containing the code
@@ -82,7 +88,7 @@ enum Subcommand {
     },
 }
 
-pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt) -> Result<()> {
+pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt, config: &ConfigLocation) -> Result<()> {
     match sub {
         Subcommand::Grant {
             grantee,
@@ -91,7 +97,7 @@ pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt) -> Result<()> {
             grant_type,
         } => {
             let expiration = Utc::now() + duration.into_chrono_duration()?;
-            grant(cosmos, grantee, tx_opt, expiration, grant_type).await?;
+            grant(cosmos, grantee, tx_opt, expiration, grant_type, config).await?;
         }
         Subcommand::Cw3Grant {
             granter,
@@ -104,18 +110,19 @@ pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt) -> Result<()> {
             cw3_grant(granter, grantee, expiration, grant_type)?;
         }
         Subcommand::GranterGrants { granter } => granter_grants(cosmos, granter).await?,
+        Subcommand::GranteeGrants { grantee } => grantee_grants(cosmos, grantee).await?,
         Subcommand::StoreCode {
             path,
             granter,
             tx_opt,
-        } => store_code(cosmos, tx_opt, &path, granter).await?,
+        } => store_code(cosmos, tx_opt, &path, granter, config).await?,
         Subcommand::ExecuteContract {
             tx_opt,
             address,
             msg,
             funds,
             granter,
-        } => execute_contract(cosmos, tx_opt, address, msg, funds, granter).await?,
+        } => execute_contract(cosmos, tx_opt, address, msg, funds, granter, config).await?,
         Subcommand::ParseGrant { grant } => {
             let grant = base64::engine::general_purpose::STANDARD_NO_PAD.decode(grant)?;
             let grant = MsgGrant::decode(&*grant)?;
@@ -126,11 +133,12 @@ pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt) -> Result<()> {
     Ok(())
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 enum GrantType {
     Send,
     ExecuteContract,
     StoreCode,
+    Custom(String),
 }
 
 impl FromStr for GrantType {
@@ -141,20 +149,23 @@ impl FromStr for GrantType {
             "send" => Ok(Self::Send),
             "execute-contract" => Ok(Self::ExecuteContract),
             "store-code" => Ok(Self::StoreCode),
+            _ if s.starts_with('/') => Ok(Self::Custom(s.to_owned())),
             _ => Err(anyhow::anyhow!(
-                "Invalid grant type, use one of: send, execute-contract, store-code"
+                "Invalid grant type, use one of: send, execute-contract, store-code, or a message type URL starting with /"
             )),
         }
     }
 }
 
 impl GrantType {
-    fn as_url(self) -> &'static str {
-        match self {
-            GrantType::Send => "/cosmos.bank.v1beta1.MsgSend",
-            GrantType::ExecuteContract => "/cosmwasm.wasm.v1.MsgExecuteContract",
-            GrantType::StoreCode => "/cosmwasm.wasm.v1.MsgStoreCode",
-        }
+    fn into_authorization(self) -> Authorization {
+        let msg_type_url = match self {
+            GrantType::Send => "/cosmos.bank.v1beta1.MsgSend".to_owned(),
+            GrantType::ExecuteContract => "/cosmwasm.wasm.v1.MsgExecuteContract".to_owned(),
+            GrantType::StoreCode => "/cosmwasm.wasm.v1.MsgStoreCode".to_owned(),
+            GrantType::Custom(url) => url,
+        };
+        Authorization::Generic { msg_type_url }
     }
 }
 
@@ -164,13 +175,14 @@ async fn grant(
     tx_opt: TxOpt,
     expiration: DateTime<Utc>,
     grant_type: GrantType,
+    config: &ConfigLocation,
 ) -> Result<()> {
-    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp())?;
+    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
     let mut txbuilder = TxBuilder::default();
     txbuilder.try_add_message(MsgGrantHelper {
         granter: wallet.get_address(),
         grantee,
-        authorization: grant_type.as_url().to_owned(),
+        authorization: grant_type.into_authorization(),
         expiration: Some(expiration),
     })?;
     let res = txbuilder.sign_and_broadcast(&cosmos, &wallet).await?;
@@ -187,7 +199,7 @@ fn cw3_grant(
     let any = TxMessage::from(MsgGrantHelper {
         granter,
         grantee,
-        authorization: grant_type.as_url().to_owned(),
+        authorization: grant_type.into_authorization(),
         expiration: Some(expiration),
     })
     .into_protobuf()
@@ -221,8 +233,22 @@ async fn granter_grants(cosmos: Cosmos, granter: Address) -> Result<()> {
     Ok(())
 }
 
-async fn store_code(cosmos: Cosmos, tx_opt: TxOpt, path: &Path, granter: Address) -> Result<()> {
-    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp())?;
+async fn grantee_grants(cosmos: Cosmos, grantee: Address) -> Result<()> {
+    let mut grants = std::pin::pin!(cosmos.query_grantee_grants(grantee));
+    while let Some(x) = grants.try_next().await? {
+        tracing::info!("{x:?}");
+    }
+    Ok(())
+}
+
+async fn store_code(
+    cosmos: Cosmos,
+    tx_opt: TxOpt,
+    path: &Path,
+    granter: Address,
+    config: &ConfigLocation,
+) -> Result<()> {
+    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
     let (res, code_id) = cosmos.store_code_path_authz(&wallet, path, granter).await?;
     tracing::info!("Executed in {}", res.txhash);
     tracing::info!("Code ID: {}", code_id);
@@ -236,6 +262,7 @@ async fn execute_contract(
     msg: String,
     funds: Option<String>,
     granter: Address,
+    config: &ConfigLocation,
 ) -> Result<()> {
     let contract = cosmos.make_contract(address);
     let amount = match funds {
@@ -245,7 +272,7 @@ async fn execute_contract(
         }
         None => vec![],
     };
-    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp())?;
+    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
 
     let msg_exec_contract = MsgExecuteContract {
         sender: granter.get_address_string(),
@@ -1,10 +1,14 @@
-use anyhow::Result;
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::{Context, Result};
 use cosmos::{
-    proto::cosmos::bank::v1beta1::MsgSend, Address, Coin, Cosmos, HasAddress, HasAddressHrp,
-    ParsedCoin, TxBuilder,
+    messages::MsgMultiSendHelper,
+    normalize_event_attributes,
+    proto::cosmos::{bank::v1beta1::MsgSend, base::abci::v1beta1::TxResponse},
+    Address, Coin, Cosmos, HasAddress, HasAddressHrp, NormalizedEvent, ParsedCoin, TxBuilder,
 };
 
-use crate::cli::TxOpt;
+use crate::cli::{ConfigLocation, TxOpt};
 
 #[derive(clap::Parser)]
 pub(crate) struct Opt {
@@ -21,6 +25,9 @@ enum Subcommand {
         /// Optional height to do the query at
         #[clap(long)]
         height: Option<u64>,
+        /// Only print balances which can be sent, excluding locked vesting funds
+        #[clap(long)]
+        spendable: bool,
     },
     /// Send coins to the given address
     Send {
@@ -31,12 +38,60 @@ enum Subcommand {
         /// Coins to send
         coins: Vec<ParsedCoin>,
     },
+    /// Send coins to many recipients in a single message
+    MultiSend {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// CSV file with recipient and amount columns, e.g. osmo1...,100uosmo
+        csv: PathBuf,
+    },
+    /// Print the transfer history of a single denom for an address, with running balances
+    History {
+        /// Address to print history for
+        address: Address,
+        /// Denom to track
+        #[clap(long)]
+        denom: String,
+        /// Only include transactions at or after this height
+        ///
+        /// The starting balance is queried at the preceding height.
+        #[clap(long)]
+        from_height: Option<u64>,
+        /// Number of transactions to request per page
+        #[clap(long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+        page_size: u64,
+    },
+    /// Print the metadata registered for a denom
+    DenomMetadata {
+        /// Denom to look up
+        denom: String,
+    },
+    /// Print the total supply of a denom, or of all denoms if omitted
+    Supply {
+        /// Denom to look up
+        denom: Option<String>,
+    },
 }
 
-pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt) -> Result<()> {
+#[derive(serde::Deserialize)]
+struct MultiSendRecord {
+    recipient: Address,
+    amount: String,
+}
+
+pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt, config: &ConfigLocation) -> Result<()> {
     match sub {
-        Subcommand::PrintBalances { address, height } => {
-            let balances = cosmos.at_height(height).all_balances(address).await?;
+        Subcommand::PrintBalances {
+            address,
+            height,
+            spendable,
+        } => {
+            let cosmos = cosmos.at_height(height);
+            let balances = if spendable {
+                cosmos.spendable_balances(address).await?
+            } else {
+                cosmos.all_balances(address).await?
+            };
             for Coin { denom, amount } in &balances {
                 println!("{amount}{denom}");
             }
@@ -50,7 +105,7 @@ pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt) -> Result<()> {
             coins,
         } => {
             let address_type = cosmos.get_address_hrp();
-            let wallet = tx_opt.get_wallet(address_type)?;
+            let wallet = tx_opt.get_wallet(address_type, config)?;
             let mut builder = TxBuilder::default();
             builder.add_message(MsgSend {
                 from_address: wallet.get_address_string(),
@@ -62,6 +117,185 @@ pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt) -> Result<()> {
 
             println!("{}", txres.txhash);
         }
+        Subcommand::MultiSend { tx_opt, csv } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let mut outputs = vec![];
+            for record in csv::Reader::from_path(&csv)?.into_deserialize() {
+                let MultiSendRecord { recipient, amount } = record?;
+                let amount = amount
+                    .parse::<ParsedCoin>()
+                    .with_context(|| format!("Invalid amount for {recipient}"))?;
+                outputs.push((recipient, vec![amount.into()]));
+            }
+            let mut builder = TxBuilder::default();
+            builder.try_add_message(MsgMultiSendHelper {
+                sender: wallet.get_address(),
+                outputs,
+            })?;
+            builder.set_optional_memo(tx_opt.memo);
+            let txres = builder.sign_and_broadcast(&cosmos, &wallet).await?;
+            println!("{}", txres.txhash);
+        }
+        Subcommand::History {
+            address,
+            denom,
+            from_height,
+            page_size,
+        } => history(cosmos, address, &denom, from_height, page_size).await?,
+        Subcommand::DenomMetadata { denom } => {
+            let metadata = cosmos.denom_metadata(denom).await?;
+            println!("Base: {}", metadata.base);
+            println!("Display: {}", metadata.display);
+            println!("Name: {}", metadata.name);
+            println!("Symbol: {}", metadata.symbol);
+            if !metadata.description.is_empty() {
+                println!("Description: {}", metadata.description);
+            }
+            for unit in metadata.denom_units {
+                println!("Unit: {} (exponent {})", unit.denom, unit.exponent);
+            }
+        }
+        Subcommand::Supply { denom: Some(denom) } => {
+            let Coin { denom, amount } = cosmos.supply_of(denom).await?;
+            println!("{amount}{denom}");
+        }
+        Subcommand::Supply { denom: None } => {
+            for Coin { denom, amount } in cosmos.total_supply().await? {
+                println!("{amount}{denom}");
+            }
+        }
     }
     Ok(())
 }
+
+async fn history(
+    cosmos: Cosmos,
+    address: Address,
+    denom: &str,
+    from_height: Option<u64>,
+    page_size: u64,
+) -> Result<()> {
+    let mut balance = match from_height {
+        Some(height) if height > 1 => cosmos
+            .at_height(Some(height - 1))
+            .all_balances(address)
+            .await?
+            .into_iter()
+            .find(|coin| coin.denom == denom)
+            .map(|coin| coin.amount.parse::<i128>())
+            .transpose()
+            .context("Invalid starting balance")?
+            .unwrap_or_default(),
+        _ => 0,
+    };
+
+    // Transactions can match both queries (e.g. sending to yourself), so key by hash.
+    let mut txs = BTreeMap::new();
+    for attribute in ["sender", "recipient"] {
+        let mut query = format!("transfer.{attribute}='{address}'");
+        if let Some(height) = from_height {
+            query.push_str(&format!(" AND tx.height>={height}"));
+        }
+        let mut seen = 0;
+        for page in 1.. {
+            let res = cosmos
+                .query_transactions(&query, Some(page_size), Some(page))
+                .await?;
+            let count = res.transactions.len() as u64;
+            seen += count;
+            for tx in res.transactions {
+                let tx = tx.response;
+                txs.insert((tx.height, tx.txhash.clone()), tx);
+            }
+            if count == 0 || seen >= res.total {
+                break;
+            }
+        }
+    }
+
+    println!("Starting balance: {balance}{denom}");
+    for tx in txs.values() {
+        let change = transfer_events(tx)
+            .iter()
+            .flat_map(|event| {
+                event
+                    .get_all("sender")
+                    .zip(event.get_all("recipient"))
+                    .zip(event.get_all("amount"))
+            })
+            .map(|((sender, recipient), amount)| {
+                let amount = parse_denom_amount(amount, denom)
+                    .with_context(|| format!("Invalid transfer amount in {}", tx.txhash))?;
+                let sender = sender == address.get_address_string();
+                let recipient = recipient == address.get_address_string();
+                Ok(match (sender, recipient) {
+                    (true, false) => -amount,
+                    (false, true) => amount,
+                    _ => 0,
+                })
+            })
+            .sum::<Result<i128>>()?;
+        if change == 0 {
+            continue;
+        }
+        balance += change;
+        println!(
+            "{} {} {} {change:+}{denom} balance: {balance}{denom}",
+            tx.timestamp, tx.height, tx.txhash
+        );
+    }
+    println!("Final balance: {balance}{denom}");
+    Ok(())
+}
+
+/// Get the transfer events from a transaction.
+///
+/// Newer chains include all events in both the logs and the events, so only
+/// fall back to the logs when there are no events.
+fn transfer_events(tx: &TxResponse) -> Vec<NormalizedEvent> {
+    let events = if tx.events.is_empty() {
+        tx.logs
+            .iter()
+            .flat_map(|log| &log.events)
+            .filter(|event| event.r#type == "transfer")
+            .map(|event| {
+                normalize_event_attributes(
+                    event
+                        .attributes
+                        .iter()
+                        .map(|attr| (attr.key.as_str(), attr.value.as_str())),
+                )
+            })
+            .collect::<Vec<_>>()
+    } else {
+        tx.events
+            .iter()
+            .filter(|event| event.r#type == "transfer")
+            .map(|event| {
+                normalize_event_attributes(
+                    event
+                        .attributes
+                        .iter()
+                        .map(|attr| (attr.key.as_str(), attr.value.as_str())),
+                )
+            })
+            .collect()
+    };
+    events
+        .into_iter()
+        .map(|attributes| NormalizedEvent {
+            r#type: "transfer".to_owned(),
+            attributes,
+        })
+        .collect()
+}
+
+/// Sum the amounts of the given denom in a comma-separated list of coins, e.g. `5uosmo,3uatom`.
+fn parse_denom_amount(coins: &str, denom: &str) -> Result<i128> {
+    coins
+        .split(',')
+        .filter_map(|coin| coin.trim().strip_suffix(denom))
+        .filter(|amount| !amount.is_empty() && amount.bytes().all(|b| b.is_ascii_digit()))
+        .map(|amount| Ok(amount.parse::<i128>()?))
+        .sum()
+}
@@ -10,7 +10,7 @@ use cosmos::{
         },
         traits::Message,
     },
-    Address, BlockInfo, Cosmos, TxResponseExt,
+    Address, BlockInfo, Cosmos, CosmosTxResponse, NodeInfo, TxResponseExt,
 };
 
 #[derive(clap::Parser)]
@@ -19,6 +19,14 @@ pub(crate) struct Opt {
     sub: Subcommand,
 }
 
+#[derive(Clone, Copy, clap::ValueEnum)]
+pub(crate) enum ParamsModule {
+    Auth,
+    Bank,
+    Staking,
+    Gov,
+}
+
 #[derive(clap::Parser)]
 pub(crate) enum Subcommand {
     /// Find the first block after the given timestamp
@@ -78,11 +86,57 @@ pub(crate) enum Subcommand {
         #[clap(long)]
         offset: Option<u64>,
     },
+    /// Search for transactions matching an event query
+    SearchTxs {
+        /// Event query, e.g. `transfer.recipient='osmo1...'`
+        query: String,
+        /// Maximum number of transactions to return
+        #[clap(long)]
+        limit: Option<u64>,
+        /// Page number, starting at 1
+        #[clap(long)]
+        page: Option<u64>,
+    },
+    /// Show events emitted while executing a block, including those outside of transactions
+    BlockResults {
+        /// Height of the block
+        height: i64,
+    },
+    /// Show version and sync status of the node answering queries
+    NodeInfo {
+        /// Query every configured node, including fallbacks
+        #[clap(long)]
+        all: bool,
+    },
+    /// Show the current parameters of a chain module
+    Params { module: ParamsModule },
+    /// Perform a raw ABCI store query
+    AbciQuery {
+        /// Query path, e.g. `/store/bank/key`
+        path: String,
+        /// Query data, hex encoded
+        #[clap(default_value = "")]
+        data: String,
+        /// Height to query at, latest if omitted
+        #[clap(long, default_value_t = 0)]
+        height: i64,
+        /// Request a Merkle proof
+        #[clap(long)]
+        prove: bool,
+    },
+    /// Show the validator set and voting power
+    ValidatorSet {
+        /// Height to load the validator set for, latest if omitted
+        #[clap(long)]
+        height: Option<i64>,
+    },
     /// Show block metadata and transaction hashes within the block
     ShowBlock {
         /// Height of the block to show
         height: i64,
     },
+    /// Show version and feature information for this client
+    ClientInfo {},
 }
 
 pub(crate) async fn go(Opt { sub }: Opt, opt: crate::cli::Opt) -> Result<()> {
@@ -207,6 +261,111 @@ pub(crate) async fn go(Opt { sub }: Opt, opt: crate::cli::Opt) -> Result<()> {
                 println!("{txhash}");
             }
         }
+        Subcommand::SearchTxs { query, limit, page } => {
+            let cosmos = opt.network_opt.build().await?;
+            let page = cosmos.query_transactions(query, limit, page).await?;
+            println!("Total matches: {}", page.total);
+            for CosmosTxResponse { response, tx } in page.transactions {
+                let fee = tx
+                    .auth_info
+                    .and_then(|auth_info| auth_info.fee)
+                    .map(|fee| {
+                        fee.amount
+                            .iter()
+                            .map(|coin| format!("{}{}", coin.amount, coin.denom))
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    })
+                    .unwrap_or_default();
+                println!(
+                    "{} {} code {} fee {fee}",
+                    response.height, response.txhash, response.code
+                );
+            }
+        }
+        Subcommand::BlockResults { height } => {
+            let cosmos = opt.network_opt.build().await?;
+            let results = cosmos.get_block_results(height).await?;
+            for event in results.block_events() {
+                println!("Block event: {event:?}");
+            }
+            for (idx, events) in results.tx_events.iter().enumerate() {
+                for event in events {
+                    println!("Transaction #{idx} event: {event:?}");
+                }
+            }
+        }
+        Subcommand::NodeInfo { all } => {
+            let cosmos = opt.network_opt.build().await?;
+            let print = |info: NodeInfo| {
+                println!("{}", info.grpc_url);
+                println!("  Moniker: {}", info.moniker);
+                println!("  Network: {}", info.network);
+                println!("  App: {} {}", info.app_name, info.app_version);
+                println!("  Cosmos SDK: {}", info.cosmos_sdk_version);
+                println!("  CometBFT: {}", info.tendermint_version);
+                println!("  Syncing: {}", info.syncing);
+                println!("  Latency: {:?}", info.latency);
+            };
+            if all {
+                for (grpc_url, res) in cosmos.get_all_node_info().await {
+                    match res {
+                        Ok(info) => print(info),
+                        Err(err) => println!("{grpc_url}\n  Error: {err}"),
+                    }
+                }
+            } else {
+                print(cosmos.get_node_info().await?);
+            }
+        }
+        Subcommand::Params { module } => {
+            let cosmos = opt.network_opt.build().await?;
+            match module {
+                ParamsModule::Auth => println!("{:#?}", cosmos.auth_params().await?),
+                ParamsModule::Bank => println!("{:#?}", cosmos.bank_params().await?),
+                ParamsModule::Staking => println!("{:#?}", cosmos.staking_params().await?),
+                ParamsModule::Gov => println!("{:#?}", cosmos.gov_params().await?),
+            }
+        }
+        Subcommand::AbciQuery {
+            path,
+            data,
+            height,
+            prove,
+        } => {
+            let cosmos = opt.network_opt.build().await?;
+            let data = hex::decode(&data).context("Invalid hex data")?;
+            let res = cosmos.abci_query(path, data, height, prove).await?;
+            println!("Height: {}", res.height);
+            println!("Key: {}", hex::encode(&res.key));
+            println!("Value: {}", hex::encode(&res.value));
+            if let Some(proof_ops) = res.proof_ops {
+                for op in proof_ops.ops {
+                    println!("Proof op {} for key {}", op.r#type, hex::encode(&op.key));
+                }
+            }
+        }
+        Subcommand::ValidatorSet { height } => {
+            let cosmos = opt.network_opt.build().await?;
+            let set = match height {
+                Some(height) => cosmos.get_validator_set(height).await?,
+                None => cosmos.get_latest_validator_set().await?,
+            };
+            let total = set.total_voting_power();
+            println!("Height: {}", set.height);
+            println!("Total voting power: {total}");
+            for validator in &set.validators {
+                let share = if total == 0 {
+                    0.0
+                } else {
+                    validator.voting_power as f64 / total as f64 * 100.0
+                };
+                println!(
+                    "{} {} ({share:.2}%)",
+                    validator.address, validator.voting_power
+                );
+            }
+        }
         Subcommand::ShowBlock { height } => {
             let cosmos = opt.network_opt.build().await?;
             let BlockInfo {
@@ -224,6 +383,21 @@ pub(crate) async fn go(Opt { sub }: Opt, opt: crate::cli::Opt) -> Result<()> {
                 println!("Transaction #{}: {txhash}", idx + 1);
             }
         }
+        Subcommand::ClientInfo {} => {
+            let cosmos::BuildInfo {
+                version,
+                features,
+                proto_versions,
+                git_hash,
+            } = cosmos::build_info();
+            println!("cosmos-bin version: {}", env!("CARGO_PKG_VERSION"));
+            println!("cosmos library version: {version}");
+            println!("Git hash: {}", git_hash.unwrap_or("unknown"));
+            println!("Enabled features: {}", features.join(", "));
+            for (name, version) in proto_versions {
+                println!("Proto version {name}: {version}");
+            }
+        }
     }
 
     Ok(())
@@ -1,5 +1,7 @@
+use std::{path::PathBuf, str::FromStr};
+
 use anyhow::Result;
-use cosmos::{clap::CosmosOpt, error::WalletError, AddressHrp, SeedPhrase, Wallet};
+use cosmos::{clap::CosmosOpt, error::WalletError, AddressHrp, CosmosConfig, SeedPhrase, Wallet};
 use tracing::Level;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
 
@@ -45,17 +47,152 @@ impl Opt {
 
 #[derive(clap::Parser)]
 pub(crate) struct TxOpt {
-    /// Mnemonic phrase
+    /// Mnemonic phrase or hex-encoded private key
+    ///
+    /// Not needed for dry runs from a watch-only --sender address.
     #[clap(long, env = "COSMOS_WALLET")]
-    pub(crate) wallet: SeedPhrase,
+    pub(crate) wallet: Option<WalletSource>,
+    /// Name of a seed phrase stored in the OS keyring, takes precedence over --wallet
+    #[cfg(feature = "keyring")]
+    #[clap(long, env = "COSMOS_KEYRING_WALLET")]
+    pub(crate) keyring_wallet: Option<String>,
+    /// Named wallet from the config file, takes precedence over --keyring-wallet
+    ///
+    /// If the named wallet has no keyring entry or keystore file, --wallet is
+    /// used as its seed phrase.
+    #[clap(long, env = "COSMOS_WALLET_NAME")]
+    pub(crate) wallet_name: Option<String>,
+    /// Passphrase for a named wallet's keystore file, read from stdin if needed and omitted
+    #[clap(long, env = "COSMOS_KEYSTORE_PASSPHRASE")]
+    pub(crate) keystore_passphrase: Option<String>,
+    /// BIP39 passphrase (25th word) to use with the seed phrase
+    #[clap(long, env = "COSMOS_BIP39_PASSPHRASE")]
+    pub(crate) bip39_passphrase: Option<String>,
     /// Memo to put on transaction
     #[clap(long)]
     pub(crate) memo: Option<String>,
 }
 
 impl TxOpt {
-    pub(crate) fn get_wallet(&self, hrp: AddressHrp) -> Result<Wallet, WalletError> {
-        self.wallet.with_hrp(hrp)
+    /// Resolve the wallet, loading named wallets from the given config file.
+    pub(crate) fn get_wallet(&self, hrp: AddressHrp, config: &ConfigLocation) -> Result<Wallet> {
+        let source = match &self.wallet_name {
+            Some(name) => WalletSource::Phrase(self.load_named_wallet(name, hrp, config)?),
+            None => match (self.load_keyring_wallet()?, &self.wallet) {
+                (Some(phrase), _) => WalletSource::Phrase(phrase),
+                (None, Some(wallet)) => wallet.clone(),
+                (None, None) => {
+                    anyhow::bail!(
+                        "Must provide one of --wallet, --keyring-wallet, or --wallet-name"
+                    )
+                }
+            },
+        };
+        match (source, &self.bip39_passphrase) {
+            (WalletSource::Phrase(phrase), Some(passphrase)) => Ok(phrase
+                .with_bip39_passphrase(Some(passphrase.clone()))
+                .with_hrp(hrp)?),
+            (WalletSource::PrivateKey(_), Some(_)) => {
+                anyhow::bail!("--bip39-passphrase cannot be used with a private key")
+            }
+            (source, None) => Ok(source.with_hrp(hrp)?),
+        }
+    }
+
+    #[cfg(feature = "keyring")]
+    fn load_keyring_wallet(&self) -> Result<Option<SeedPhrase>> {
+        Ok(self
+            .keyring_wallet
+            .as_deref()
+            .map(SeedPhrase::load_from_keyring)
+            .transpose()?)
+    }
+
+    #[cfg(not(feature = "keyring"))]
+    fn load_keyring_wallet(&self) -> Result<Option<SeedPhrase>> {
+        Ok(None)
+    }
+
+    /// Resolve a named wallet through the config file.
+    ///
+    /// Uses the same config file as the network, see [ConfigLocation].
+    fn load_named_wallet(
+        &self,
+        name: &str,
+        hrp: AddressHrp,
+        config: &ConfigLocation,
+    ) -> Result<SeedPhrase> {
+        let config = config.load()?;
+        let base = match &self.wallet {
+            Some(WalletSource::Phrase(phrase)) => Some(phrase.clone()),
+            Some(WalletSource::PrivateKey(_)) | None => None,
+        };
+        let wallet = config.get_wallet(name)?;
+        let passphrase = match &self.keystore_passphrase {
+            None if wallet.keyring.is_none() && wallet.keystore.is_some() => {
+                Some(crate::wallet::read_stdin_line("keystore passphrase")?)
+            }
+            passphrase => passphrase.clone(),
+        };
+        Ok(config.load_wallet_phrase(name, hrp, base, passphrase.as_deref())?)
+    }
+}
+
+/// Config file selected by the global `--config` and `--config-disable` options.
+///
+/// Passed to [TxOpt::get_wallet] so that named wallets are loaded from the
+/// same config file as the network.
+#[derive(Clone)]
+pub(crate) struct ConfigLocation {
+    path: Option<PathBuf>,
+    disabled: bool,
+}
+
+impl ConfigLocation {
+    pub(crate) fn from_opt(opt: &CosmosOpt) -> Self {
+        ConfigLocation {
+            path: opt.config.clone(),
+            disabled: opt.config_disable,
+        }
+    }
+
+    fn load(&self) -> Result<CosmosConfig> {
+        if self.disabled {
+            anyhow::bail!("--wallet-name requires the config file, but config usage is disabled");
+        }
+        Ok(match &self.path {
+            Some(path) => CosmosConfig::load_from(path, true)?,
+            None => CosmosConfig::load()?,
+        })
+    }
+}
+
+/// A wallet provided on the command line, either as a seed phrase or a raw private key.
+#[derive(Clone)]
+pub(crate) enum WalletSource {
+    Phrase(SeedPhrase),
+    PrivateKey(String),
+}
+
+impl WalletSource {
+    pub(crate) fn with_hrp(&self, hrp: AddressHrp) -> Result<Wallet, WalletError> {
+        match self {
+            WalletSource::Phrase(phrase) => phrase.with_hrp(hrp),
+            WalletSource::PrivateKey(key) => Wallet::from_privkey_hex(key, hrp),
+        }
+    }
+}
+
+impl FromStr for WalletSource {
+    type Err = WalletError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix("0x").unwrap_or(s);
+        if hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            Ok(WalletSource::PrivateKey(s.to_owned()))
+        } else {
+            s.parse().map(WalletSource::Phrase)
+        }
     }
 }
 
@@ -95,9 +232,9 @@ pub(crate) enum Subcommand {
 
     /// Tokenfactory operations
     TokenFactory {
-        /// Mnemonic phrase
+        /// Mnemonic phrase or hex-encoded private key
         #[clap(long, env = "COSMOS_WALLET")]
-        wallet: SeedPhrase,
+        wallet: WalletSource,
 
         #[clap(subcommand)]
         cmd: tokenfactory::Command,
@@ -107,6 +244,31 @@ pub(crate) enum Subcommand {
         #[clap(flatten)]
         opt: authz::Opt,
     },
+    /// Fee allowance operations
+    Feegrant {
+        #[clap(flatten)]
+        opt: crate::feegrant::Opt,
+    },
+    /// IBC transfers, queries and interchain accounts
+    Ibc {
+        #[clap(flatten)]
+        opt: crate::ibc::Opt,
+    },
+    /// Governance proposals and voting
+    Gov {
+        #[clap(flatten)]
+        opt: crate::gov::Opt,
+    },
+    /// Staking operations
+    Staking {
+        #[clap(flatten)]
+        opt: crate::staking::Opt,
+    },
+    /// CW20 token operations
+    Cw20 {
+        #[clap(flatten)]
+        opt: crate::cw20::Opt,
+    },
     /// CW3 multisig operations
     Cw3 {
         #[clap(flatten)]
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use anyhow::Result;
-use cosmos::{AddressHrp, CosmosConfig, CosmosConfigError};
+use cosmos::{error::BuilderError, AddressHrp, CosmosConfig, CosmosConfigError};
 
 #[derive(clap::Parser)]
 pub(crate) enum Opt {
@@ -39,6 +39,30 @@ pub(crate) enum Opt {
         /// Value
         value: String,
     },
+    /// Set the default network and profile, used when neither is specified
+    SetDefault {
+        /// Default network name, unset if omitted
+        #[clap(long)]
+        network: Option<String>,
+        /// Default profile name, unset if omitted
+        #[clap(long)]
+        profile: Option<String>,
+    },
+    /// Configure a network using data from the Cosmos chain registry
+    ImportChain {
+        /// Chain registry path, e.g. juno or testnets/junotestnet
+        chain: String,
+        /// Network name to save as, defaults to the last component of the chain path
+        #[clap(long)]
+        name: Option<String>,
+    },
+    /// Connect to each configured network and report on the health of every endpoint
+    ///
+    /// Checks all networks in the config file unless one is specified.
+    Check {
+        /// Network name
+        network: Option<String>,
+    },
     /// Add a gRPC fallback
     AddFallback {
         /// Network name
@@ -75,7 +99,7 @@ fn load(opt: &crate::cli::Opt) -> Result<CosmosConfig, CosmosConfigError> {
         })
 }
 
-pub(crate) fn go(opt: crate::cli::Opt, inner: Opt) -> Result<()> {
+pub(crate) async fn go(opt: crate::cli::Opt, inner: Opt) -> Result<()> {
     match inner {
         Opt::File {} => {
             match opt.network_opt.config {
@@ -121,6 +145,49 @@ pub(crate) fn go(opt: crate::cli::Opt, inner: Opt) -> Result<()> {
             println!("Changes saved");
             Ok(())
         }
+        Opt::SetDefault { network, profile } => {
+            let mut config = load(&opt)?;
+            config.set_default_network(network);
+            config.set_default_profile(profile);
+            config.save()?;
+            println!("Changes saved");
+            Ok(())
+        }
+        Opt::ImportChain { chain, name } => {
+            let mut config = load(&opt)?;
+            let name = name.unwrap_or_else(|| {
+                chain
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or(chain.as_str())
+                    .to_owned()
+            });
+            config.import_from_registry(name.clone(), &chain).await?;
+            config.save()?;
+            println!("Imported {chain} as network {name}, changes saved");
+            Ok(())
+        }
+        Opt::Check { network } => {
+            let config = load(&opt)?;
+            let networks = match &network {
+                Some(network) => vec![network.as_str()],
+                None => config.network_names(),
+            };
+            if networks.is_empty() {
+                println!("No networks configured");
+                return Ok(());
+            }
+            let mut problems = 0;
+            for name in networks {
+                println!("{name}");
+                problems += check_network(&config, name).await;
+            }
+            if problems > 0 {
+                anyhow::bail!("Found {problems} problem(s) while checking the config");
+            }
+            println!("All endpoints healthy");
+            Ok(())
+        }
         Opt::AddFallback { name, url } => {
             let mut config = load(&opt)?;
             config.add_grpc_fallback(name, url);
@@ -130,3 +197,50 @@ pub(crate) fn go(opt: crate::cli::Opt, inner: Opt) -> Result<()> {
         }
     }
 }
+
+/// Check every endpoint of a single network, returning the number of problems found.
+async fn check_network(config: &CosmosConfig, name: &str) -> usize {
+    let builder = match config.builder_for(Some(name), None).await {
+        Ok(builder) => builder,
+        Err(e) => {
+            println!("  Error: {e}");
+            return 1;
+        }
+    };
+    let chain_id = builder.chain_id().to_owned();
+    let cosmos = match builder.build() {
+        Ok(cosmos) => cosmos,
+        Err(e) => {
+            println!("  Error: {e}");
+            return 1;
+        }
+    };
+    let mut problems = 0;
+    for (grpc_url, res) in cosmos.get_all_node_info().await {
+        match res {
+            Ok(info) if info.network != chain_id => {
+                problems += 1;
+                let e = BuilderError::MismatchedChainIds {
+                    grpc_url: grpc_url.to_string(),
+                    expected: chain_id.clone(),
+                    actual: Some(info.network),
+                };
+                println!("  {grpc_url}: {e}");
+            }
+            Ok(info) => {
+                println!(
+                    "  {grpc_url}: OK in {:?}, {} {}{}",
+                    info.latency,
+                    info.app_name,
+                    info.app_version,
+                    if info.syncing { " (syncing)" } else { "" }
+                );
+            }
+            Err(e) => {
+                problems += 1;
+                println!("  {grpc_url}: {e}");
+            }
+        }
+    }
+    problems
+}
@@ -2,14 +2,14 @@ use std::{io::Write, path::PathBuf, str::FromStr};
 
 use anyhow::Result;
 use cosmos::{
-    proto::cosmwasm::wasm::v1::{
-        ContractCodeHistoryEntry, ContractInfo, MsgExecuteContract, QueryContractHistoryResponse,
-    },
-    Address, ContractAdmin, Cosmos, HasAddress, HasAddressHrp, ParsedCoin, RawAddress, TxBuilder,
+    proto::cosmwasm::wasm::v1::{ContractInfo, Model, MsgExecuteContract},
+    Address, ContractAdmin, ContractStateSnapshot, Cosmos, HasAddress, HasAddressHrp,
+    InstantiatePermission, ParsedCoin, RawAddress, TxBuilder, UpgradeProgress, WatchWallet,
 };
 use cosmwasm_std::storage_keys::namespace_with_key;
+use futures::TryStreamExt;
 
-use crate::cli::TxOpt;
+use crate::cli::{ConfigLocation, TxOpt};
 
 #[derive(clap::Parser)]
 pub(crate) struct Opt {
@@ -35,7 +35,7 @@ enum Subcommand {
         #[clap(long, env = "CONTRACT")]
         contract: Address,
         #[clap(long, env = "COSMOS_SENDER")]
-        sender: Address,
+        sender: WatchWallet,
         /// Memo to put on transaction
         #[clap(long)]
         memo: Option<String>,
@@ -64,6 +64,41 @@ enum Subcommand {
         /// Administrator set on this contract
         #[clap(long, default_value = "sender")]
         admin: ContractAdmin,
+        /// Print a pre-flight report instead of instantiating
+        #[clap(long)]
+        dry_run: bool,
+        /// Address to run the pre-flight report for, instead of the wallet's
+        #[clap(long, requires = "dry_run")]
+        sender: Option<WatchWallet>,
+    },
+    /// Instantiate contract at a predictable address
+    Instantiate2 {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Code to deploy
+        code_id: u64,
+        /// Label to display
+        label: String,
+        /// Instantiate message (JSON)
+        msg: String,
+        /// Salt used to derive the address
+        #[clap(long)]
+        salt: String,
+        /// Administrator set on this contract
+        #[clap(long, default_value = "sender")]
+        admin: ContractAdmin,
+    },
+    /// Print the address an instantiate2 call would produce
+    PredictAddress {
+        /// Code to deploy
+        #[clap(long)]
+        code_id: u64,
+        /// Address which will send the instantiate2 message
+        #[clap(long)]
+        creator: Address,
+        /// Salt used to derive the address
+        #[clap(long)]
+        salt: String,
     },
     /// Query contract
     Query {
@@ -84,6 +119,48 @@ enum Subcommand {
         #[clap(long)]
         height: Option<u64>,
     },
+    /// Print all raw key/value pairs in the contract's storage
+    AllState {
+        /// Contract address
+        address: Address,
+        /// Only print keys starting with this prefix
+        #[clap(long)]
+        prefix: Option<String>,
+        /// Optional Height. Use latest if not passed.
+        #[clap(long)]
+        height: Option<u64>,
+    },
+    /// Export all raw contract storage to a JSON file
+    ExportState {
+        /// Contract address
+        address: Address,
+        /// File to write the snapshot to
+        dest: PathBuf,
+        /// Optional Height. Use latest if not passed.
+        #[clap(long)]
+        height: Option<u64>,
+    },
+    /// Restore a state snapshot into a contract via a state loader contract
+    RestoreState {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Snapshot file written by export-state
+        snapshot: PathBuf,
+        /// Contract to restore into, must be administered by the sender
+        address: Address,
+        /// Code ID of the state loader contract
+        #[clap(long)]
+        loader_code_id: u64,
+        /// Code ID to migrate back to afterwards, defaults to the snapshot's code ID
+        #[clap(long)]
+        code_id: Option<u64>,
+        /// Migrate message (JSON) used when migrating back
+        #[clap(long, default_value = "{}")]
+        migrate_msg: String,
+        /// Number of entries to write per message
+        #[clap(long, default_value_t = 100)]
+        chunk_size: usize,
+    },
     /// Migrate contract
     Migrate {
         #[clap(flatten)]
@@ -95,6 +172,17 @@ enum Subcommand {
         /// Migrate message (JSON)
         msg: String,
     },
+    /// Upload new code and migrate a contract to it
+    Upgrade {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Contract address
+        address: Address,
+        /// WASM file to upload
+        file: PathBuf,
+        /// Migrate message (JSON)
+        msg: String,
+    },
     /// Execute contract
     Execute {
         #[clap(flatten)]
@@ -127,7 +215,12 @@ enum Subcommand {
     /// Get contract metadata
     Info { contract: Address },
     /// Get the contract history
-    History { contract: Address },
+    History {
+        contract: Address,
+        /// Also search transactions for admin changes, requires a node with tx indexing
+        #[clap(long)]
+        admin: bool,
+    },
     /// Download the code for a given code ID
     Download {
         #[clap(long)]
@@ -135,16 +228,48 @@ enum Subcommand {
         #[clap(long)]
         dest: PathBuf,
     },
+    /// List all contracts instantiated from a code ID
+    ListContracts {
+        #[clap(long)]
+        code_id: u64,
+    },
+    /// List all code uploaded to the chain
+    ListCodes {},
+    /// Show who may instantiate a code ID
+    InstantiatePermission {
+        #[clap(long)]
+        code_id: u64,
+    },
+    /// Change who may instantiate a code ID
+    UpdateInstantiatePermission {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        #[clap(long)]
+        code_id: u64,
+        /// `nobody`, `everybody`, or a comma-separated list of addresses
+        permission: InstantiatePermission,
+    },
+    /// Check that a local WASM file matches the code stored on chain
+    Verify {
+        #[clap(long)]
+        code_id: u64,
+        /// WASM file, optionally gzipped
+        file: PathBuf,
+    },
 }
 
-pub(crate) async fn go(Opt { subcommand }: Opt, cosmos: Cosmos) -> Result<()> {
+pub(crate) async fn go(
+    Opt { subcommand }: Opt,
+    cosmos: Cosmos,
+    config: &ConfigLocation,
+) -> Result<()> {
     match subcommand {
         Subcommand::UpdateAdmin {
             new_admin,
             tx_opt,
             contract,
         } => {
-            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp())?;
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
             TxBuilder::default()
                 .add_update_contract_admin(contract, &wallet, new_admin)
                 .sign_and_broadcast(&cosmos, &wallet)
@@ -162,7 +287,7 @@ pub(crate) async fn go(Opt { subcommand }: Opt, cosmos: Cosmos) -> Result<()> {
                 txbuilder.set_memo(memo);
             }
             let msg: serde_json::Value = serde_json::from_str(&msg)?;
-            txbuilder.add_migrate_message(contract, sender, code_id, msg)?;
+            txbuilder.add_migrate_message(contract, sender.get_address(), code_id, msg)?;
             let simres = txbuilder.simulate(&cosmos, &[sender]).await?;
             println!("{simres:?}");
         }
@@ -171,9 +296,63 @@ pub(crate) async fn go(Opt { subcommand }: Opt, cosmos: Cosmos) -> Result<()> {
             let bytes = code.download().await?;
             fs_err::write(&dest, bytes)?;
         }
+        Subcommand::ListContracts { code_id } => {
+            for contract in cosmos.make_code_id(code_id).list_contracts().await? {
+                println!("{contract}");
+            }
+        }
+        Subcommand::ListCodes {} => {
+            for code in cosmos.list_codes().await? {
+                println!(
+                    "{}: creator {}, checksum {}",
+                    code.code_id,
+                    code.creator,
+                    hex::encode(&code.data_hash)
+                );
+            }
+        }
+        Subcommand::InstantiatePermission { code_id } => {
+            let permission = cosmos
+                .make_code_id(code_id)
+                .instantiate_permission()
+                .await?;
+            println!("{permission}");
+        }
+        Subcommand::UpdateInstantiatePermission {
+            tx_opt,
+            code_id,
+            permission,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let tx = cosmos
+                .make_code_id(code_id)
+                .update_instantiate_permission(&wallet, permission)
+                .await?;
+            println!("Transaction hash: {}", tx.txhash);
+        }
+        Subcommand::Verify { code_id, file } => {
+            let verification = cosmos
+                .make_code_id(code_id)
+                .verify_local_wasm(&file)
+                .await?;
+            println!(
+                "Local checksum: {}",
+                hex::encode(&verification.local_checksum)
+            );
+            println!(
+                "Chain checksum: {}",
+                hex::encode(&verification.chain_checksum)
+            );
+            anyhow::ensure!(
+                verification.is_match(),
+                "{} does not match code ID {code_id}",
+                file.display()
+            );
+            println!("Checksums match");
+        }
         Subcommand::StoreCode { tx_opt, file } => {
             let address_type = cosmos.get_address_hrp();
-            let wallet = tx_opt.get_wallet(address_type)?;
+            let wallet = tx_opt.get_wallet(address_type, config)?;
             let codeid = cosmos.store_code_path(&wallet, &file).await?;
             println!("Code ID: {codeid}");
         }
@@ -183,14 +362,67 @@ pub(crate) async fn go(Opt { subcommand }: Opt, cosmos: Cosmos) -> Result<()> {
             label,
             msg,
             admin,
+            dry_run,
+            sender,
         } => {
             let address_type = cosmos.get_address_hrp();
+            if dry_run {
+                let sender = match sender {
+                    Some(sender) => sender,
+                    None => WatchWallet::from(&tx_opt.get_wallet(address_type, config)?),
+                };
+                let msg: serde_json::Value = serde_json::from_str(&msg)?;
+                let plan = cosmos
+                    .make_code_id(code_id)
+                    .plan_instantiate(sender, label, vec![], msg, admin)
+                    .await?;
+                println!("Code ID: {}", plan.code_id);
+                println!("Sender: {}", plan.sender);
+                match plan.admin {
+                    Some(admin) => println!("Admin: {admin}"),
+                    None => println!("Admin: none"),
+                }
+                println!("Label: {}", plan.label);
+                println!("Simulated gas used: {}", plan.gas_used);
+                for warning in &plan.warnings {
+                    println!("WARNING: {warning}");
+                }
+            } else {
+                let wallet = tx_opt.get_wallet(address_type, config)?;
+                let contract = cosmos
+                    .make_code_id(code_id)
+                    .instantiate_rendered(&wallet, label, vec![], msg, admin)
+                    .await?;
+                println!("Contract: {contract}");
+            }
+        }
+        Subcommand::Instantiate2 {
+            tx_opt,
+            code_id,
+            label,
+            msg,
+            salt,
+            admin,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let msg: serde_json::Value = serde_json::from_str(&msg)?;
             let contract = cosmos
                 .make_code_id(code_id)
-                .instantiate_rendered(&tx_opt.get_wallet(address_type)?, label, vec![], msg, admin)
+                .instantiate2(&wallet, label, vec![], msg, admin, salt)
                 .await?;
             println!("Contract: {contract}");
         }
+        Subcommand::PredictAddress {
+            code_id,
+            creator,
+            salt,
+        } => {
+            let address = cosmos
+                .make_code_id(code_id)
+                .predict_address(creator, salt.as_bytes())
+                .await?;
+            println!("{address}");
+        }
         Subcommand::Query {
             address,
             query,
@@ -224,6 +456,61 @@ pub(crate) async fn go(Opt { subcommand }: Opt, cosmos: Cosmos) -> Result<()> {
             stdout.write_all(&x)?;
             stdout.write_all(b"\n")?;
         }
+        Subcommand::AllState {
+            address,
+            prefix,
+            height,
+        } => {
+            let contract = cosmos.at_height(height).make_contract(address);
+            let mut state = std::pin::pin!(contract.all_state(prefix.map(String::into_bytes)));
+            while let Some(Model { key, value }) = state.try_next().await? {
+                println!("{}\t{}", hex::encode(key), String::from_utf8_lossy(&value));
+            }
+        }
+        Subcommand::ExportState {
+            address,
+            dest,
+            height,
+        } => {
+            let snapshot = cosmos
+                .at_height(height)
+                .make_contract(address)
+                .export_state(&dest)
+                .await?;
+            println!(
+                "Wrote {} entries to {}",
+                snapshot.entries.len(),
+                dest.display()
+            );
+        }
+        Subcommand::RestoreState {
+            tx_opt,
+            snapshot,
+            address,
+            loader_code_id,
+            code_id,
+            migrate_msg,
+            chunk_size,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let snapshot = ContractStateSnapshot::load(&snapshot)?;
+            let migrate_msg: serde_json::Value = serde_json::from_str(&migrate_msg)?;
+            let msgs = snapshot.restore_messages(
+                &wallet,
+                address,
+                loader_code_id,
+                code_id.unwrap_or(snapshot.code_id),
+                migrate_msg,
+                chunk_size,
+            )?;
+            for msg in msgs {
+                let tx = TxBuilder::default()
+                    .add_message(msg)
+                    .sign_and_broadcast(&cosmos, &wallet)
+                    .await?;
+                println!("Transaction hash: {}", tx.txhash);
+            }
+        }
         Subcommand::Migrate {
             tx_opt,
             address,
@@ -233,10 +520,31 @@ pub(crate) async fn go(Opt { subcommand }: Opt, cosmos: Cosmos) -> Result<()> {
             let address_type = cosmos.get_address_hrp();
             let contract = cosmos.make_contract(address);
             let tx = contract
-                .migrate_binary(&tx_opt.get_wallet(address_type)?, code_id, msg)
+                .migrate_binary(&tx_opt.get_wallet(address_type, config)?, code_id, msg)
                 .await?;
             println!("Transaction hash: {}", tx.txhash);
         }
+        Subcommand::Upgrade {
+            tx_opt,
+            address,
+            file,
+            msg,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let msg: serde_json::Value = serde_json::from_str(&msg)?;
+            cosmos
+                .make_contract(address)
+                .upgrade_from_file(&wallet, &file, msg, |progress| match progress {
+                    UpgradeProgress::Stored { code_id } => println!("Code ID: {code_id}"),
+                    UpgradeProgress::Migrated { txhash } => {
+                        println!("Transaction hash: {txhash}")
+                    }
+                    UpgradeProgress::Verified { code_id } => {
+                        println!("Contract {address} now running code ID {code_id}")
+                    }
+                })
+                .await?;
+        }
         Subcommand::Execute {
             tx_opt,
             address,
@@ -253,7 +561,7 @@ pub(crate) async fn go(Opt { subcommand }: Opt, cosmos: Cosmos) -> Result<()> {
                 }
                 None => vec![],
             };
-            let wallet = tx_opt.get_wallet(address_type)?;
+            let wallet = tx_opt.get_wallet(address_type, config)?;
 
             let mut tx_builder = TxBuilder::default();
             tx_builder.add_message(MsgExecuteContract {
@@ -277,6 +585,7 @@ pub(crate) async fn go(Opt { subcommand }: Opt, cosmos: Cosmos) -> Result<()> {
             tracing::debug!("{tx:?}");
         }
         Subcommand::Info { contract } => {
+            let contract = cosmos.make_contract(contract);
             let ContractInfo {
                 code_id,
                 creator,
@@ -285,25 +594,48 @@ pub(crate) async fn go(Opt { subcommand }: Opt, cosmos: Cosmos) -> Result<()> {
                 created: _,
                 ibc_port_id: _,
                 extension: _,
-            } = cosmos.make_contract(contract).info().await?;
+            } = contract.info().await?;
             println!("code_id: {code_id}");
             println!("creator: {creator}");
             println!("admin: {admin}");
             println!("label: {label}");
+            if let Some(version) = contract.version().await? {
+                println!("cw2: {} {}", version.contract, version.version);
+            }
         }
-        Subcommand::History { contract } => {
-            let QueryContractHistoryResponse {
-                entries,
-                pagination: _,
-            } = cosmos.make_contract(contract).history().await?;
-            for ContractCodeHistoryEntry {
-                operation,
-                code_id,
-                updated,
-                msg,
-            } in entries
-            {
-                println!("Operation: {operation}. Code ID: {code_id}. Updated: {updated:?}. Message: {:?}", String::from_utf8(msg))
+        Subcommand::History { contract, admin } => {
+            let contract = cosmos.make_contract(contract);
+            let entries = contract.history_entries().await?;
+            for entry in &entries {
+                println!(
+                    "Operation: {}. Code ID: {}. Height: {}. Message: {}",
+                    entry.operation.as_str_name(),
+                    entry.code_id,
+                    entry
+                        .block_height
+                        .map_or_else(|| "unknown".to_owned(), |height| height.to_string()),
+                    entry.msg
+                );
+            }
+            for change in cosmos::code_changes(&entries) {
+                match change.from {
+                    Some(from) => println!("Code changed from {from} to {}", change.to),
+                    None => println!("Instantiated with code ID {}", change.to),
+                }
+            }
+            if admin {
+                for change in contract.admin_changes().await? {
+                    match change.new_admin {
+                        Some(new_admin) => println!(
+                            "Admin set to {new_admin} at height {} in {}",
+                            change.height, change.txhash
+                        ),
+                        None => println!(
+                            "Admin cleared at height {} in {}",
+                            change.height, change.txhash
+                        ),
+                    }
+                }
             }
         }
         Subcommand::Simulate {
@@ -0,0 +1,105 @@
+use anyhow::Result;
+use cosmos::{Address, Cosmos, HasAddressHrp};
+
+use crate::cli::{ConfigLocation, TxOpt};
+
+#[derive(clap::Parser)]
+pub(crate) struct Opt {
+    /// CW20 token contract address
+    #[clap(long, env = "CW20_CONTRACT")]
+    token: Address,
+    #[clap(subcommand)]
+    sub: Subcommand,
+}
+
+#[derive(clap::Parser)]
+enum Subcommand {
+    /// Print the token balance of an address
+    Balance {
+        /// Address to check
+        address: Address,
+    },
+    /// Print the token's name, symbol, decimals and total supply
+    TokenInfo {},
+    /// Transfer tokens to another address
+    Transfer {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Recipient address
+        recipient: Address,
+        /// Amount in the smallest unit
+        amount: u128,
+    },
+    /// Send tokens to a contract along with a message for its receive hook
+    Send {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Receiving contract
+        contract: Address,
+        /// Amount in the smallest unit
+        amount: u128,
+        /// Message for the receiving contract (JSON)
+        msg: String,
+    },
+    /// Allow another address to spend tokens on your behalf
+    IncreaseAllowance {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Spender address
+        spender: Address,
+        /// Additional amount in the smallest unit
+        amount: u128,
+    },
+}
+
+pub(crate) async fn go(
+    cosmos: Cosmos,
+    Opt { token, sub }: Opt,
+    config: &ConfigLocation,
+) -> Result<()> {
+    let token = cosmos.make_cw20(token);
+    match sub {
+        Subcommand::Balance { address } => {
+            println!("{}", token.balance(address).await?);
+        }
+        Subcommand::TokenInfo {} => {
+            let info = token.token_info().await?;
+            println!("Name: {}", info.name);
+            println!("Symbol: {}", info.symbol);
+            println!("Decimals: {}", info.decimals);
+            println!("Total supply: {}", info.total_supply);
+        }
+        Subcommand::Transfer {
+            tx_opt,
+            recipient,
+            amount,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let res = token.transfer(&wallet, recipient, amount.into()).await?;
+            println!("Transaction hash: {}", res.txhash);
+        }
+        Subcommand::Send {
+            tx_opt,
+            contract,
+            amount,
+            msg,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let msg: serde_json::Value = serde_json::from_str(&msg)?;
+            let res = token.send(&wallet, contract, amount.into(), msg).await?;
+            println!("Transaction hash: {}", res.txhash);
+        }
+        Subcommand::IncreaseAllowance {
+            tx_opt,
+            spender,
+            amount,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let res = token
+                .increase_allowance(&wallet, spender, amount.into())
+                .await?;
+            println!("Transaction hash: {}", res.txhash);
+        }
+    }
+    Ok(())
+}
@@ -1,14 +1,17 @@
 use anyhow::{Context, Result};
 use cosmos::{
     proto::cosmos::bank::v1beta1::MsgSend, Address, ContractAdmin, Cosmos, HasAddress,
-    HasAddressHrp, ParsedCoin, TxBuilder,
+    HasAddressHrp, ParsedCoin, TxBuilder, WatchWallet,
 };
 use cosmwasm_std::{to_json_binary, CosmosMsg, Decimal, Empty, WasmMsg};
 use cw3::{ProposalListResponse, ProposalResponse};
 use cw4::Member;
 use cw_utils::Threshold;
 
-use crate::{cli::TxOpt, my_duration::MyDuration};
+use crate::{
+    cli::{ConfigLocation, TxOpt},
+    my_duration::MyDuration,
+};
 
 #[derive(Clone, Copy, Debug)]
 enum ContractType {
@@ -89,14 +92,14 @@ enum Subcommand {
     },
 }
 
-pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt) -> Result<()> {
+pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt, config: &ConfigLocation) -> Result<()> {
     match sub {
-        Subcommand::NewFlex { inner } => new_flex(cosmos, inner).await,
+        Subcommand::NewFlex { inner } => new_flex(cosmos, inner, config).await,
         Subcommand::UpdateMembersMessage { inner } => update_members_message(inner).await,
-        Subcommand::Propose { inner } => propose(cosmos, inner).await,
+        Subcommand::Propose { inner } => propose(cosmos, inner, config).await,
         Subcommand::List { inner } => list(cosmos, inner).await,
-        Subcommand::Vote { inner } => vote(cosmos, inner).await,
-        Subcommand::Execute { inner } => execute(cosmos, inner).await,
+        Subcommand::Vote { inner } => vote(cosmos, inner, config).await,
+        Subcommand::Execute { inner } => execute(cosmos, inner, config).await,
         Subcommand::WasmExecuteMessage { inner } => wasm_execute_message(&cosmos, inner).await,
         Subcommand::MigrateContractMessage { inner } => {
             migrate_contract_message(&cosmos, inner).await
@@ -136,9 +139,10 @@ async fn new_flex(
         weight_needed,
         duration,
     }: NewFlexOpt,
+    config: &ConfigLocation,
 ) -> Result<()> {
     let chain_id = cosmos.get_cosmos_builder().chain_id();
-    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp())?;
+    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
     let cw3 = cosmos.make_code_id(get_code_id(chain_id, ContractType::Cw3Flex)?);
     let cw4 = cosmos.make_code_id(get_code_id(chain_id, ContractType::Cw4Group)?);
 
@@ -267,8 +271,9 @@ async fn propose(
         description,
         msg,
     }: ProposeOpt,
+    config: &ConfigLocation,
 ) -> Result<()> {
-    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp())?;
+    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
     let cw3 = cosmos.make_contract(cw3);
     let res = cw3
         .execute(
@@ -353,8 +358,9 @@ async fn vote(
         proposal,
         vote,
     }: VoteOpt,
+    config: &ConfigLocation,
 ) -> Result<()> {
-    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp())?;
+    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
     let cw3 = cosmos.make_contract(cw3);
     let res = cw3
         .execute(
@@ -389,8 +395,9 @@ async fn execute(
         cw3,
         proposal,
     }: ExecuteOpt,
+    config: &ConfigLocation,
 ) -> Result<()> {
-    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp())?;
+    let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
     let cw3 = cosmos.make_contract(cw3);
     let res = cw3
         .execute(
@@ -415,7 +422,7 @@ struct WasmExecuteMessageOpt {
     message: String,
     /// Should we simulate? If so, what address should we simulate from?
     #[clap(long)]
-    simulate: Option<Address>,
+    simulate: Option<WatchWallet>,
     /// Coins to attach as funds for the message
     #[clap(long)]
     funds: Vec<ParsedCoin>,
@@ -442,11 +449,11 @@ async fn wasm_execute_message(
         let mut tx = TxBuilder::default();
         tx.add_execute_message_bytes(
             contract,
-            sender,
+            sender.get_address(),
             funds.iter().map(Into::into).collect(),
             message,
         )?;
-        let res = tx.simulate(cosmos, &[sender.get_address()]).await?;
+        let res = tx.simulate(cosmos, &[sender]).await?;
         println!("Simulation successful. Gas used: {}", res.gas_used);
     }
     Ok(())
@@ -465,7 +472,7 @@ struct MigrateContractOpt {
     message: String,
     /// Should we simulate? If so, what address should we simulate from?
     #[clap(long)]
-    simulate: Option<Address>,
+    simulate: Option<WatchWallet>,
 }
 
 async fn migrate_contract_message(
@@ -486,8 +493,8 @@ async fn migrate_contract_message(
     println!("{}", serde_json::to_string(&msg)?);
     if let Some(sender) = simulate {
         let mut tx = TxBuilder::default();
-        tx.add_migrate_message(contract, sender, code_id, &message)?;
-        let res = tx.simulate(cosmos, &[sender.get_address()]).await?;
+        tx.add_migrate_message(contract, sender.get_address(), code_id, &message)?;
+        let res = tx.simulate(cosmos, &[sender]).await?;
         println!("Simulation successful. Gas used: {}", res.gas_used);
     }
     Ok(())
@@ -525,7 +532,10 @@ async fn send_coins_message(
         to_address: recipient.get_address_string(),
         amount: coins.into_iter().map(|x| x.into()).collect(),
     });
-    match tx.simulate(cosmos, &[cw3.get_address()]).await {
+    match tx
+        .simulate(cosmos, &[WatchWallet::new(cw3.get_address())])
+        .await
+    {
         Ok(res) => {
             tracing::info!("Simulation was successful");
             tracing::debug!("{:?}", res);
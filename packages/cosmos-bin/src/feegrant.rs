@@ -0,0 +1,128 @@
+use anyhow::Result;
+use chrono::Utc;
+use cosmos::{
+    messages::{FeeAllowance, MsgGrantAllowanceHelper},
+    proto::cosmos::feegrant::v1beta1::{Grant, MsgRevokeAllowance},
+    Address, Cosmos, HasAddress, HasAddressHrp, ParsedCoin, TxBuilder,
+};
+
+use crate::{
+    cli::{ConfigLocation, TxOpt},
+    my_duration::MyDuration,
+};
+
+#[derive(clap::Parser)]
+pub(crate) struct Opt {
+    #[clap(subcommand)]
+    sub: Subcommand,
+}
+
+#[derive(clap::Parser)]
+enum Subcommand {
+    /// Pay for the grantee's transaction fees
+    Grant {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Address whose fees will be paid
+        grantee: Address,
+        /// Total amount which may be spent, unlimited if omitted
+        #[clap(long)]
+        spend_limit: Vec<ParsedCoin>,
+        /// Length of each period, for a periodic allowance
+        #[clap(long, requires = "period_limit")]
+        period: Option<MyDuration>,
+        /// Amount which may be spent within each period
+        #[clap(long, requires = "period")]
+        period_limit: Vec<ParsedCoin>,
+        /// How long the allowance lasts, forever if omitted
+        #[clap(long)]
+        duration: Option<MyDuration>,
+    },
+    /// Revoke a previously granted fee allowance
+    Revoke {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Address whose allowance is revoked
+        grantee: Address,
+    },
+    /// List fee allowances granted to an address
+    Allowances {
+        /// Grantee address
+        grantee: Address,
+    },
+    /// List fee allowances granted by an address
+    GrantedBy {
+        /// Granter address
+        granter: Address,
+    },
+}
+
+pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt, config: &ConfigLocation) -> Result<()> {
+    match sub {
+        Subcommand::Grant {
+            tx_opt,
+            grantee,
+            spend_limit,
+            period,
+            period_limit,
+            duration,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let spend_limit = spend_limit.into_iter().map(Into::into).collect();
+            let allowance = match period {
+                Some(period) => FeeAllowance::Periodic {
+                    spend_limit,
+                    period: period.into_std_duration(),
+                    period_spend_limit: period_limit.into_iter().map(Into::into).collect(),
+                },
+                None => FeeAllowance::Basic { spend_limit },
+            };
+            let expiration = duration
+                .map(|duration| duration.into_chrono_duration())
+                .transpose()?
+                .map(|duration| Utc::now() + duration);
+            let mut builder = TxBuilder::default();
+            builder.add_message(MsgGrantAllowanceHelper {
+                granter: wallet.get_address(),
+                grantee,
+                allowance,
+                expiration,
+            });
+            builder.set_optional_memo(tx_opt.memo);
+            let txres = builder.sign_and_broadcast(&cosmos, &wallet).await?;
+            println!("{}", txres.txhash);
+        }
+        Subcommand::Revoke { tx_opt, grantee } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let mut builder = TxBuilder::default();
+            builder.add_message(MsgRevokeAllowance {
+                granter: wallet.get_address_string(),
+                grantee: grantee.get_address_string(),
+            });
+            builder.set_optional_memo(tx_opt.memo);
+            let txres = builder.sign_and_broadcast(&cosmos, &wallet).await?;
+            println!("{}", txres.txhash);
+        }
+        Subcommand::Allowances { grantee } => {
+            print_grants(cosmos.fee_allowances(grantee).await?);
+        }
+        Subcommand::GrantedBy { granter } => {
+            print_grants(cosmos.fee_allowances_by_granter(granter).await?);
+        }
+    }
+    Ok(())
+}
+
+fn print_grants(grants: Vec<Grant>) {
+    for Grant {
+        granter,
+        grantee,
+        allowance,
+    } in grants
+    {
+        let allowance = allowance
+            .map(|allowance| allowance.type_url)
+            .unwrap_or_default();
+        println!("{granter} -> {grantee}: {allowance}");
+    }
+}
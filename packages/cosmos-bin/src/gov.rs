@@ -0,0 +1,288 @@
+use anyhow::Result;
+use cosmos::{
+    proto::cosmos::gov::{v1, v1beta1},
+    Address, Cosmos, HasAddress, HasAddressHrp, ParsedCoin, TxBuilder, TxMessage, WasmAuthority,
+};
+
+use crate::cli::{ConfigLocation, TxOpt};
+
+#[derive(clap::Parser)]
+pub(crate) struct Opt {
+    #[clap(subcommand)]
+    sub: Subcommand,
+}
+
+#[derive(clap::Parser)]
+enum Subcommand {
+    /// List governance proposals
+    List {
+        /// Only include proposals currently in their voting period
+        #[clap(long)]
+        voting: bool,
+        /// Use the legacy v1beta1 API, for chains older than Cosmos SDK 0.46
+        #[clap(long)]
+        legacy: bool,
+    },
+    /// Print a single proposal
+    Show {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Use the legacy v1beta1 API, for chains older than Cosmos SDK 0.46
+        #[clap(long)]
+        legacy: bool,
+    },
+    /// Print the current vote tally for a proposal
+    Tally {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Use the legacy v1beta1 API, for chains older than Cosmos SDK 0.46
+        #[clap(long)]
+        legacy: bool,
+    },
+    /// Vote on a proposal
+    Vote {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Proposal ID
+        proposal_id: u64,
+        /// How to vote
+        #[clap(value_enum)]
+        option: Vote,
+        /// Use the legacy v1beta1 message, for chains older than Cosmos SDK 0.46
+        #[clap(long)]
+        legacy: bool,
+    },
+    /// Deposit on a proposal
+    Deposit {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Proposal ID
+        proposal_id: u64,
+        /// Coins to deposit
+        amount: Vec<ParsedCoin>,
+    },
+    /// Submit a proposal migrating a contract administered by governance
+    ProposeMigrate {
+        #[clap(flatten)]
+        proposal: WasmProposalOpt,
+        /// Contract to migrate
+        contract: Address,
+        /// New code ID
+        code_id: u64,
+        /// Migrate message (JSON)
+        msg: String,
+    },
+    /// Submit a proposal calling a contract's sudo entry point
+    ProposeSudo {
+        #[clap(flatten)]
+        proposal: WasmProposalOpt,
+        /// Contract to call
+        contract: Address,
+        /// Sudo message (JSON)
+        msg: String,
+    },
+}
+
+#[derive(clap::Parser)]
+struct WasmProposalOpt {
+    #[clap(flatten)]
+    tx_opt: TxOpt,
+    /// Proposal title
+    #[clap(long)]
+    title: String,
+    /// Proposal summary
+    #[clap(long)]
+    summary: String,
+    /// Initial deposit
+    #[clap(long)]
+    deposit: Vec<ParsedCoin>,
+    /// Authority executing the message, defaults to the governance module
+    #[clap(long)]
+    authority: Option<Address>,
+}
+
+impl WasmProposalOpt {
+    async fn submit(
+        self,
+        cosmos: &Cosmos,
+        config: &ConfigLocation,
+        make_msg: impl FnOnce(WasmAuthority) -> Result<TxMessage>,
+    ) -> Result<()> {
+        let wallet = self.tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+        let authority = self.authority.map_or_else(
+            || WasmAuthority::gov(cosmos.get_address_hrp()),
+            WasmAuthority::new,
+        );
+        let mut builder = TxBuilder::default();
+        builder.add_submit_proposal(
+            &wallet,
+            vec![make_msg(authority)?],
+            self.deposit.into_iter().map(Into::into).collect(),
+            self.title,
+            self.summary,
+        );
+        builder.set_optional_memo(self.tx_opt.memo);
+        let txres = builder.sign_and_broadcast(cosmos, &wallet).await?;
+        println!("{}", txres.txhash);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum Vote {
+    Yes,
+    No,
+    Abstain,
+    NoWithVeto,
+}
+
+impl From<Vote> for v1::VoteOption {
+    fn from(vote: Vote) -> Self {
+        match vote {
+            Vote::Yes => v1::VoteOption::Yes,
+            Vote::No => v1::VoteOption::No,
+            Vote::Abstain => v1::VoteOption::Abstain,
+            Vote::NoWithVeto => v1::VoteOption::NoWithVeto,
+        }
+    }
+}
+
+pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt, config: &ConfigLocation) -> Result<()> {
+    match sub {
+        Subcommand::List { voting, legacy } => {
+            if legacy {
+                let status = voting.then_some(v1beta1::ProposalStatus::VotingPeriod);
+                for proposal in cosmos.proposals_v1beta1(status).await? {
+                    println!(
+                        "{} [{}] {}",
+                        proposal.proposal_id,
+                        status_name_v1beta1(proposal.status),
+                        proposal
+                            .content
+                            .map(|content| content.type_url)
+                            .unwrap_or_default()
+                    );
+                }
+            } else {
+                let status = voting.then_some(v1::ProposalStatus::VotingPeriod);
+                for proposal in cosmos.proposals(status).await? {
+                    println!(
+                        "{} [{}] {}",
+                        proposal.id,
+                        status_name(proposal.status),
+                        proposal.title
+                    );
+                }
+            }
+        }
+        Subcommand::Show {
+            proposal_id,
+            legacy,
+        } => {
+            if legacy {
+                let proposal = cosmos.proposal_v1beta1(proposal_id).await?;
+                println!("{proposal:#?}");
+            } else {
+                let proposal = cosmos.proposal(proposal_id).await?;
+                println!("ID: {}", proposal.id);
+                println!("Title: {}", proposal.title);
+                println!("Status: {}", status_name(proposal.status));
+                println!("Proposer: {}", proposal.proposer);
+                println!("Summary: {}", proposal.summary);
+                for msg in &proposal.messages {
+                    println!("Message: {}", msg.type_url);
+                }
+            }
+        }
+        Subcommand::Tally {
+            proposal_id,
+            legacy,
+        } => {
+            if legacy {
+                let tally = cosmos.tally_result_v1beta1(proposal_id).await?;
+                println!("Yes: {}", tally.yes);
+                println!("No: {}", tally.no);
+                println!("Abstain: {}", tally.abstain);
+                println!("No with veto: {}", tally.no_with_veto);
+            } else {
+                let tally = cosmos.tally_result(proposal_id).await?;
+                println!("Yes: {}", tally.yes_count);
+                println!("No: {}", tally.no_count);
+                println!("Abstain: {}", tally.abstain_count);
+                println!("No with veto: {}", tally.no_with_veto_count);
+            }
+        }
+        Subcommand::Vote {
+            tx_opt,
+            proposal_id,
+            option,
+            legacy,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let option = v1::VoteOption::from(option);
+            let mut builder = TxBuilder::default();
+            if legacy {
+                builder.add_message(v1beta1::MsgVote {
+                    proposal_id,
+                    voter: wallet.get_address_string(),
+                    option: option as i32,
+                });
+            } else {
+                builder.add_vote(&wallet, proposal_id, option);
+            }
+            builder.set_optional_memo(tx_opt.memo);
+            let txres = builder.sign_and_broadcast(&cosmos, &wallet).await?;
+            println!("{}", txres.txhash);
+        }
+        Subcommand::Deposit {
+            tx_opt,
+            proposal_id,
+            amount,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let mut builder = TxBuilder::default();
+            builder.add_deposit(
+                &wallet,
+                proposal_id,
+                amount.into_iter().map(Into::into).collect(),
+            );
+            builder.set_optional_memo(tx_opt.memo);
+            let txres = builder.sign_and_broadcast(&cosmos, &wallet).await?;
+            println!("{}", txres.txhash);
+        }
+        Subcommand::ProposeMigrate {
+            proposal,
+            contract,
+            code_id,
+            msg,
+        } => {
+            let msg: serde_json::Value = serde_json::from_str(&msg)?;
+            proposal
+                .submit(&cosmos, config, |authority| {
+                    Ok(authority.migrate(contract, code_id, msg)?.into())
+                })
+                .await?;
+        }
+        Subcommand::ProposeSudo {
+            proposal,
+            contract,
+            msg,
+        } => {
+            let msg: serde_json::Value = serde_json::from_str(&msg)?;
+            proposal
+                .submit(&cosmos, config, |authority| {
+                    Ok(authority.sudo(contract, msg)?.into())
+                })
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+fn status_name(status: i32) -> &'static str {
+    v1::ProposalStatus::try_from(status).map_or("UNKNOWN", |status| status.as_str_name())
+}
+
+fn status_name_v1beta1(status: i32) -> &'static str {
+    v1beta1::ProposalStatus::try_from(status).map_or("UNKNOWN", |status| status.as_str_name())
+}
@@ -0,0 +1,220 @@
+use anyhow::Result;
+use cosmos::{
+    ibc::{
+        ChannelState, IdentifiedChannel, InterchainAccountPacketData, MsgRegisterInterchainAccount,
+        MsgSendTx,
+    },
+    messages::MsgTransfer,
+    proto::cosmos::bank::v1beta1::MsgSend,
+    Address, Cosmos, HasAddress, HasAddressHrp, ParsedCoin, TxBuilder,
+};
+
+use crate::{
+    cli::{ConfigLocation, TxOpt},
+    my_duration::MyDuration,
+};
+
+#[derive(clap::Parser)]
+pub(crate) struct Opt {
+    #[clap(subcommand)]
+    sub: Subcommand,
+}
+
+#[derive(clap::Parser)]
+enum Subcommand {
+    /// Send tokens to another chain over an IBC channel
+    Transfer {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Source channel on this chain, e.g. channel-0
+        channel: String,
+        /// Recipient address on the destination chain
+        receiver: String,
+        /// Amount to send
+        amount: ParsedCoin,
+        /// How long before the transfer times out and is refunded
+        #[clap(long, default_value = "10m")]
+        timeout: MyDuration,
+        /// Memo to include in the IBC packet, distinct from the transaction memo
+        #[clap(long)]
+        ibc_memo: Option<String>,
+    },
+    /// Show the path and base denom of an IBC token
+    DenomTrace {
+        /// Either the full ibc/<hash> denom or just the hash
+        denom: String,
+    },
+    /// List the paths and base denoms of all IBC tokens
+    DenomTraces {},
+    /// List IBC channels on this chain
+    Channels {
+        /// Include channels which are not open
+        #[clap(long)]
+        all: bool,
+    },
+    /// List IBC light clients on this chain along with their status
+    Clients {},
+    /// List IBC connections on this chain
+    Connections {},
+    /// List unrelayed packets sent on a channel
+    PacketCommitments {
+        /// Channel on this chain, e.g. channel-0
+        channel: String,
+        /// Port the channel is bound to
+        #[clap(long, default_value = "transfer")]
+        port: String,
+    },
+    /// Register an interchain account on the chain at the other end of a connection
+    IcaRegister {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Connection on this chain, e.g. connection-0
+        connection: String,
+    },
+    /// Show the address of an interchain account
+    IcaAddress {
+        /// Owner of the account on this chain
+        owner: Address,
+        /// Connection on this chain, e.g. connection-0
+        connection: String,
+    },
+    /// Send tokens from an interchain account on the host chain
+    IcaSend {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Connection on this chain, e.g. connection-0
+        connection: String,
+        /// Recipient address on the host chain
+        recipient: String,
+        /// Amount to send, in host chain denoms
+        #[clap(required = true)]
+        amount: Vec<ParsedCoin>,
+        /// How long before the packet times out
+        #[clap(long, default_value = "10m")]
+        timeout: MyDuration,
+    },
+}
+
+pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt, config: &ConfigLocation) -> Result<()> {
+    match sub {
+        Subcommand::Transfer {
+            tx_opt,
+            channel,
+            receiver,
+            amount,
+            timeout,
+            ibc_memo,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let mut msg = MsgTransfer::new(channel, amount.into(), &wallet, receiver)
+                .with_timeout_after(timeout.into_std_duration());
+            if let Some(ibc_memo) = ibc_memo {
+                msg = msg.with_memo(ibc_memo);
+            }
+            let mut builder = TxBuilder::default();
+            builder.add_message(msg);
+            builder.set_optional_memo(tx_opt.memo);
+            let txres = builder.sign_and_broadcast(&cosmos, &wallet).await?;
+            println!("{}", txres.txhash);
+        }
+        Subcommand::DenomTrace { denom } => {
+            let trace = cosmos.ibc_denom_trace(&denom).await?;
+            println!("Path: {}", trace.path);
+            println!("Base denom: {}", trace.base_denom);
+        }
+        Subcommand::DenomTraces {} => {
+            for trace in cosmos.ibc_denom_traces().await? {
+                println!("{}: {}/{}", trace.ibc_denom(), trace.path, trace.base_denom);
+            }
+        }
+        Subcommand::Channels { all } => {
+            for channel in cosmos.ibc_channels().await? {
+                if all || channel.state() == ChannelState::Open {
+                    print_channel(&channel);
+                }
+            }
+        }
+        Subcommand::Clients {} => {
+            for client in cosmos.ibc_client_states().await? {
+                let status = cosmos.ibc_client_status(&client.client_id).await?;
+                let client_type = client
+                    .client_state
+                    .map(|state| state.type_url)
+                    .unwrap_or_default();
+                println!("{}: {status} ({client_type})", client.client_id);
+            }
+        }
+        Subcommand::Connections {} => {
+            for connection in cosmos.ibc_connections().await? {
+                let counterparty = connection
+                    .counterparty
+                    .as_ref()
+                    .map(|c| format!("{}/{}", c.client_id, c.connection_id))
+                    .unwrap_or_default();
+                println!(
+                    "{} ({}) -> {counterparty} ({:?})",
+                    connection.id,
+                    connection.client_id,
+                    connection.state(),
+                );
+            }
+        }
+        Subcommand::PacketCommitments { channel, port } => {
+            for packet in cosmos.ibc_packet_commitments(port, channel).await? {
+                println!("{}", packet.sequence);
+            }
+        }
+        Subcommand::IcaRegister { tx_opt, connection } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let mut builder = TxBuilder::default();
+            builder.add_message(MsgRegisterInterchainAccount::new(&wallet, connection));
+            builder.set_optional_memo(tx_opt.memo);
+            let txres = builder.sign_and_broadcast(&cosmos, &wallet).await?;
+            println!("{}", txres.txhash);
+        }
+        Subcommand::IcaAddress { owner, connection } => {
+            println!("{}", cosmos.interchain_account(owner, connection).await?);
+        }
+        Subcommand::IcaSend {
+            tx_opt,
+            connection,
+            recipient,
+            amount,
+            timeout,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let ica = cosmos.interchain_account(&wallet, &connection).await?;
+            let packet = InterchainAccountPacketData::execute_tx([MsgSend {
+                from_address: ica.get_address_string(),
+                to_address: recipient,
+                amount: amount.into_iter().map(Into::into).collect(),
+            }]);
+            let mut builder = TxBuilder::default();
+            builder.add_message(MsgSendTx::new(
+                &wallet,
+                connection,
+                packet,
+                timeout.into_std_duration(),
+            ));
+            builder.set_optional_memo(tx_opt.memo);
+            let txres = builder.sign_and_broadcast(&cosmos, &wallet).await?;
+            println!("{}", txres.txhash);
+        }
+    }
+    Ok(())
+}
+
+fn print_channel(channel: &IdentifiedChannel) {
+    let counterparty = channel
+        .counterparty
+        .as_ref()
+        .map(|c| format!("{}/{}", c.port_id, c.channel_id))
+        .unwrap_or_default();
+    println!(
+        "{}/{} -> {counterparty} ({:?}, {})",
+        channel.port_id,
+        channel.channel_id,
+        channel.state(),
+        channel.connection_hops.join(","),
+    );
+}
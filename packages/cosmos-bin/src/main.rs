@@ -4,10 +4,15 @@ mod chain;
 mod cli;
 mod config;
 mod contract;
+mod cw20;
 mod cw3;
+mod feegrant;
+mod gov;
+mod ibc;
 mod my_duration;
 mod nft;
 mod rujira;
+mod staking;
 mod tokenfactory;
 mod wallet;
 
@@ -28,10 +33,11 @@ async fn main() -> Result<()> {
 
 impl Subcommand {
     pub(crate) async fn go(self, opt: cli::Opt) -> Result<()> {
+        let config = cli::ConfigLocation::from_opt(&opt.network_opt);
         match self {
             Subcommand::Bank { opt: bank_opt } => {
                 let cosmos = opt.network_opt.build().await?;
-                bank::go(cosmos, bank_opt).await?;
+                bank::go(cosmos, bank_opt, &config).await?;
             }
             Subcommand::Wallet { opt } => {
                 wallet::go(opt).await?;
@@ -46,11 +52,11 @@ impl Subcommand {
             }
             Subcommand::Nft { subcommand } => {
                 let cosmos = opt.network_opt.build().await?;
-                nft::go(subcommand, cosmos).await?;
+                nft::go(subcommand, cosmos, &config).await?;
             }
             Subcommand::Contract { opt: inner } => {
                 let cosmos = opt.network_opt.build().await?;
-                contract::go(inner, cosmos).await?;
+                contract::go(inner, cosmos, &config).await?;
             }
             Subcommand::Chain { opt: inner } => {
                 chain::go(inner, opt).await?;
@@ -61,13 +67,33 @@ impl Subcommand {
             }
             Subcommand::Authz { opt: inner } => {
                 let cosmos = opt.network_opt.build().await?;
-                authz::go(cosmos, inner).await?;
+                authz::go(cosmos, inner, &config).await?;
+            }
+            Subcommand::Feegrant { opt: inner } => {
+                let cosmos = opt.network_opt.build().await?;
+                feegrant::go(cosmos, inner, &config).await?;
+            }
+            Subcommand::Gov { opt: inner } => {
+                let cosmos = opt.network_opt.build().await?;
+                gov::go(cosmos, inner, &config).await?;
+            }
+            Subcommand::Ibc { opt: inner } => {
+                let cosmos = opt.network_opt.build().await?;
+                ibc::go(cosmos, inner, &config).await?;
+            }
+            Subcommand::Staking { opt: inner } => {
+                let cosmos = opt.network_opt.build().await?;
+                staking::go(cosmos, inner, &config).await?;
+            }
+            Subcommand::Cw20 { opt: inner } => {
+                let cosmos = opt.network_opt.build().await?;
+                cw20::go(cosmos, inner, &config).await?;
             }
             Subcommand::Cw3 { opt: inner } => {
                 let cosmos = opt.network_opt.build().await?;
-                cw3::go(cosmos, inner).await?;
+                cw3::go(cosmos, inner, &config).await?;
             }
-            Subcommand::Config { opt: inner } => config::go(opt, inner)?,
+            Subcommand::Config { opt: inner } => config::go(opt, inner).await?,
             Subcommand::Rujira { opt: inner } => rujira::go(opt, inner).await?,
         }
 
@@ -13,6 +13,10 @@ impl MyDuration {
     pub(crate) fn into_chrono_duration(self) -> Result<chrono::Duration> {
         Ok(chrono::Duration::seconds(self.0.try_into()?))
     }
+
+    pub(crate) fn into_std_duration(self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.0)
+    }
 }
 
 impl FromStr for MyDuration {
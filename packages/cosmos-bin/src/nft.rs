@@ -2,12 +2,11 @@ use std::{collections::BTreeMap, fs::File, path::PathBuf, sync::Arc};
 
 use anyhow::Result;
 use async_channel::RecvError;
-use cosmos::{Address, Contract, Cosmos, HasAddress, HasAddressHrp, TxBuilder};
-use cosmwasm_std::Uint64;
+use cosmos::{Address, Cosmos, Cw721Contract, HasAddress, HasAddressHrp, TxBuilder};
 use parking_lot::Mutex;
 use tokio::task::JoinSet;
 
-use crate::cli::TxOpt;
+use crate::cli::{ConfigLocation, TxOpt};
 
 #[derive(clap::Parser)]
 pub(crate) enum Subcommand {
@@ -35,22 +34,17 @@ pub(crate) enum Subcommand {
     },
 }
 
-pub(super) async fn go(sub: Subcommand, cosmos: Cosmos) -> Result<()> {
+pub(super) async fn go(sub: Subcommand, cosmos: Cosmos, config: &ConfigLocation) -> Result<()> {
     match sub {
         Subcommand::TransferAll {
             nft_contract,
             dest,
             tx_opt,
         } => {
-            let contract = cosmos.make_contract(nft_contract);
-            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp())?;
+            let contract = cosmos.make_cw721(nft_contract);
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
             loop {
-                let Tokens { tokens } = contract
-                    .query(&NftQuery::Tokens {
-                        owner: wallet.get_address(),
-                        limit: 30,
-                    })
-                    .await?;
+                let tokens = contract.tokens(&wallet, None, Some(30)).await?;
                 if tokens.is_empty() {
                     tracing::info!("No more tokens remaining");
                     break;
@@ -59,15 +53,7 @@ pub(super) async fn go(sub: Subcommand, cosmos: Cosmos) -> Result<()> {
 
                 let mut builder = TxBuilder::default();
                 for token_id in tokens {
-                    builder.add_execute_message(
-                        &contract,
-                        &wallet,
-                        vec![],
-                        NftExec::TransferNft {
-                            token_id,
-                            recipient: dest,
-                        },
-                    )?;
+                    contract.add_transfer_message(&mut builder, &wallet, dest, token_id)?;
                 }
                 let res = builder.sign_and_broadcast(&cosmos, &wallet).await?;
                 tracing::info!(
@@ -90,12 +76,12 @@ pub(super) async fn go(sub: Subcommand, cosmos: Cosmos) -> Result<()> {
 
 enum WorkItem {
     GetTokens {
-        nft_contract: Contract,
+        nft_contract: Cw721Contract,
         start_after: Option<u64>,
         tx: async_channel::Sender<WorkItem>,
     },
     GetOwner {
-        nft_contract: Contract,
+        nft_contract: Cw721Contract,
         token_id: u64,
     },
 }
@@ -126,25 +112,27 @@ async fn run_worker(
                         println!("Getting tokens after ID {token_id} for contract {nft_contract}")
                     }
                 }
-                let AllTokensResp { tokens } = nft_contract
-                    .query(NftQuery::AllTokens {
-                        start_after: start_after.map(Uint64::new),
-                    })
-                    .await?;
+                let start_after = start_after.map(|token_id| token_id.to_string());
+                let tokens = nft_contract
+                    .all_tokens(start_after.as_deref(), None)
+                    .await?
+                    .into_iter()
+                    .map(|token_id| token_id.parse::<u64>())
+                    .collect::<Result<Vec<_>, _>>()?;
                 let Some(last) = tokens.last().copied() else {
                     continue;
                 };
-                for token in tokens {
+                for token_id in tokens {
                     tx.send(WorkItem::GetOwner {
                         nft_contract: nft_contract.clone(),
-                        token_id: token.u64(),
+                        token_id,
                     })
                     .await?;
                 }
                 tx.clone()
                     .send(WorkItem::GetTokens {
                         nft_contract,
-                        start_after: Some(last.u64()),
+                        start_after: Some(last),
                         tx,
                     })
                     .await?;
@@ -153,11 +141,7 @@ async fn run_worker(
                 nft_contract,
                 token_id,
             }) => {
-                let OwnerOfResp { owner } = nft_contract
-                    .query(NftQuery::OwnerOf {
-                        token_id: Uint64::new(token_id),
-                    })
-                    .await?;
+                let owner = nft_contract.owner_of(&token_id.to_string()).await?;
                 let mut csv = csv.lock();
                 csv.serialize(&OwnerRecord {
                     contract: nft_contract.get_address(),
@@ -224,7 +208,7 @@ async fn owners_csv(
         }
 
         tx.send(WorkItem::GetTokens {
-            nft_contract: cosmos.make_contract(nft_contract),
+            nft_contract: cosmos.make_cw721(nft_contract),
             start_after,
             tx: tx.clone(),
         })
@@ -249,35 +233,3 @@ async fn owners_csv(
 
     Ok(())
 }
-
-#[derive(serde::Serialize)]
-#[serde(rename_all = "snake_case")]
-enum NftQuery {
-    Tokens { owner: Address, limit: u32 },
-    AllTokens { start_after: Option<Uint64> },
-    OwnerOf { token_id: Uint64 },
-}
-
-#[derive(serde::Serialize)]
-#[serde(rename_all = "snake_case")]
-enum NftExec {
-    TransferNft {
-        token_id: String,
-        recipient: Address,
-    },
-}
-
-#[derive(serde::Deserialize)]
-struct Tokens {
-    tokens: Vec<String>,
-}
-
-#[derive(serde::Deserialize)]
-struct AllTokensResp {
-    tokens: Vec<Uint64>,
-}
-
-#[derive(serde::Deserialize)]
-struct OwnerOfResp {
-    owner: Address,
-}
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use cosmos::rujira::{Asset, QueryQuoteSwapRequest};
 
 #[derive(clap::Parser)]
 pub(crate) enum Subcommand {
@@ -9,6 +10,18 @@ pub(crate) enum Subcommand {
     },
     /// Print information about all pools
     Pools {},
+    /// Print a quote for swapping between two assets
+    QuoteSwap {
+        /// Asset to swap from, e.g. THOR.RUNE
+        from: Asset,
+        /// Asset to swap to, e.g. BTC.BTC
+        to: Asset,
+        /// Amount to swap, in 1e8 base units
+        amount: u128,
+        /// Address to receive the output
+        #[clap(long)]
+        destination: Option<String>,
+    },
 }
 
 pub(crate) async fn go(opt: crate::cli::Opt, inner: Subcommand) -> Result<()> {
@@ -23,6 +36,18 @@ pub(crate) async fn go(opt: crate::cli::Opt, inner: Subcommand) -> Result<()> {
             let x = cosmos.rujira_pools().await?;
             println!("{x:#?}");
         }
+        Subcommand::QuoteSwap {
+            from,
+            to,
+            amount,
+            destination,
+        } => {
+            let cosmos = opt.network_opt.build().await?;
+            let mut req = QueryQuoteSwapRequest::new(&from, &to, amount);
+            req.destination = destination.unwrap_or_default();
+            let x = cosmos.rujira_quote_swap(req).await?;
+            println!("{x:#?}");
+        }
     }
 
     Ok(())
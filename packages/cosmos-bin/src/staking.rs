@@ -0,0 +1,215 @@
+use anyhow::Result;
+use cosmos::{
+    proto::cosmos::staking::v1beta1::{BondStatus, MsgBeginRedelegate, MsgDelegate, MsgUndelegate},
+    Address, Cosmos, HasAddress, HasAddressHrp, ParsedCoin, TxBuilder, TxMessage, Wallet,
+};
+
+use crate::cli::{ConfigLocation, TxOpt};
+
+#[derive(clap::Parser)]
+pub(crate) struct Opt {
+    #[clap(subcommand)]
+    sub: Subcommand,
+}
+
+#[derive(clap::Parser)]
+enum Subcommand {
+    /// List delegations made by an address
+    Delegations {
+        /// Delegator address
+        address: Address,
+    },
+    /// List in-progress unbonding delegations for an address
+    Unbonding {
+        /// Delegator address
+        address: Address,
+    },
+    /// Print pending staking rewards for an address
+    Rewards {
+        /// Delegator address
+        address: Address,
+    },
+    /// Withdraw pending staking rewards
+    WithdrawRewards {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Validators to withdraw from, defaults to all validators delegated to
+        validators: Vec<Address>,
+    },
+    /// List validators
+    Validators {
+        /// Only include bonded validators
+        #[clap(long)]
+        bonded: bool,
+    },
+    /// Delegate tokens to a validator
+    Delegate {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Validator operator address
+        validator: Address,
+        /// Amount to delegate
+        amount: ParsedCoin,
+    },
+    /// Undelegate tokens from a validator
+    Undelegate {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Validator operator address
+        validator: Address,
+        /// Amount to undelegate
+        amount: ParsedCoin,
+    },
+    /// Move a delegation from one validator to another
+    Redelegate {
+        #[clap(flatten)]
+        tx_opt: TxOpt,
+        /// Validator to move the delegation from
+        src_validator: Address,
+        /// Validator to move the delegation to
+        dst_validator: Address,
+        /// Amount to redelegate
+        amount: ParsedCoin,
+    },
+}
+
+pub(crate) async fn go(cosmos: Cosmos, Opt { sub }: Opt, config: &ConfigLocation) -> Result<()> {
+    match sub {
+        Subcommand::Delegations { address } => {
+            for delegation in cosmos.delegations(address).await? {
+                let validator = delegation
+                    .delegation
+                    .map(|delegation| delegation.validator_address)
+                    .unwrap_or_default();
+                let balance = delegation
+                    .balance
+                    .map(|coin| format!("{}{}", coin.amount, coin.denom))
+                    .unwrap_or_default();
+                println!("{validator}: {balance}");
+            }
+        }
+        Subcommand::Unbonding { address } => {
+            for unbonding in cosmos.unbonding_delegations(address).await? {
+                for entry in unbonding.entries {
+                    let completion = entry
+                        .completion_time
+                        .and_then(|time| {
+                            chrono::DateTime::from_timestamp(time.seconds, time.nanos as u32)
+                        })
+                        .map(|time| time.to_string())
+                        .unwrap_or_default();
+                    println!(
+                        "{}: {} completes at {completion}",
+                        unbonding.validator_address, entry.balance
+                    );
+                }
+            }
+        }
+        Subcommand::Rewards { address } => {
+            let rewards = cosmos.delegation_rewards(address).await?;
+            for reward in rewards.rewards {
+                for coin in reward.reward {
+                    println!(
+                        "{}: {}{}",
+                        reward.validator_address, coin.amount, coin.denom
+                    );
+                }
+            }
+            for coin in rewards.total {
+                println!("Total: {}{}", coin.amount, coin.denom);
+            }
+        }
+        Subcommand::WithdrawRewards { tx_opt, validators } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let validators = if validators.is_empty() {
+                cosmos
+                    .delegations(&wallet)
+                    .await?
+                    .into_iter()
+                    .filter_map(|delegation| delegation.delegation)
+                    .map(|delegation| delegation.validator_address.parse())
+                    .collect::<Result<Vec<Address>, _>>()?
+            } else {
+                validators
+            };
+            anyhow::ensure!(!validators.is_empty(), "No delegations to withdraw from");
+            let mut builder = TxBuilder::default();
+            for validator in validators {
+                builder.add_withdraw_delegator_reward(&wallet, validator);
+            }
+            builder.set_optional_memo(tx_opt.memo);
+            let txres = builder.sign_and_broadcast(&cosmos, &wallet).await?;
+            println!("{}", txres.txhash);
+        }
+        Subcommand::Validators { bonded } => {
+            let status = bonded.then_some(BondStatus::Bonded);
+            for validator in cosmos.validators(status).await? {
+                let moniker = validator
+                    .description
+                    .map(|description| description.moniker)
+                    .unwrap_or_default();
+                let jailed = if validator.jailed { " (jailed)" } else { "" };
+                println!(
+                    "{} {moniker}: {} tokens{jailed}",
+                    validator.operator_address, validator.tokens
+                );
+            }
+        }
+        Subcommand::Delegate {
+            tx_opt,
+            validator,
+            amount,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let msg = MsgDelegate {
+                delegator_address: wallet.get_address_string(),
+                validator_address: validator.get_address_string(),
+                amount: Some(amount.into()),
+            };
+            broadcast(&cosmos, &wallet, tx_opt.memo, msg).await?;
+        }
+        Subcommand::Undelegate {
+            tx_opt,
+            validator,
+            amount,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let msg = MsgUndelegate {
+                delegator_address: wallet.get_address_string(),
+                validator_address: validator.get_address_string(),
+                amount: Some(amount.into()),
+            };
+            broadcast(&cosmos, &wallet, tx_opt.memo, msg).await?;
+        }
+        Subcommand::Redelegate {
+            tx_opt,
+            src_validator,
+            dst_validator,
+            amount,
+        } => {
+            let wallet = tx_opt.get_wallet(cosmos.get_address_hrp(), config)?;
+            let msg = MsgBeginRedelegate {
+                delegator_address: wallet.get_address_string(),
+                validator_src_address: src_validator.get_address_string(),
+                validator_dst_address: dst_validator.get_address_string(),
+                amount: Some(amount.into()),
+            };
+            broadcast(&cosmos, &wallet, tx_opt.memo, msg).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn broadcast(
+    cosmos: &Cosmos,
+    wallet: &Wallet,
+    memo: Option<String>,
+    msg: impl Into<TxMessage>,
+) -> Result<()> {
+    let mut builder = TxBuilder::default();
+    builder.add_message(msg);
+    builder.set_optional_memo(memo);
+    let txres = builder.sign_and_broadcast(cosmos, wallet).await?;
+    println!("{}", txres.txhash);
+    Ok(())
+}
@@ -1,20 +1,67 @@
 use anyhow::Result;
-use cosmos::{Cosmos, HasAddressHrp, SeedPhrase};
+use cosmos::{Address, Cosmos, HasAddressHrp, TokenFactory};
+
+use crate::cli::WalletSource;
 
 #[derive(clap::Parser)]
 pub enum Command {
-    Create { subdenom: String },
+    Create {
+        subdenom: String,
+    },
+
+    Mint {
+        denom: String,
+        amount: u128,
+    },
+
+    Burn {
+        denom: String,
+        amount: u128,
+        /// Burn from this address instead of the wallet, requires admin rights
+        #[clap(long)]
+        from: Option<Address>,
+    },
 
-    Mint { denom: String, amount: u128 },
+    /// Move tokens between addresses as the token admin
+    ForceTransfer {
+        denom: String,
+        amount: u128,
+        /// Address to take the tokens from
+        from: Address,
+        /// Address to send the tokens to
+        to: Address,
+    },
 
-    Burn { denom: String, amount: u128 },
+    ChangeAdmin {
+        denom: String,
+        addr: String,
+    },
 
-    ChangeAdmin { denom: String, addr: String },
+    /// Set the bank metadata shown by wallets for a token
+    SetMetadata {
+        /// Full denom, e.g. factory/osmo1.../mytoken
+        denom: String,
+        /// Display unit, e.g. mytoken
+        #[clap(long)]
+        display: String,
+        /// Ticker symbol, e.g. MYT
+        #[clap(long)]
+        symbol: String,
+        /// Decimal places between the base denom and the display unit
+        #[clap(long, default_value_t = 6)]
+        exponent: u32,
+        /// Human readable name, defaults to the symbol
+        #[clap(long)]
+        name: Option<String>,
+        /// Description of the token
+        #[clap(long)]
+        description: Option<String>,
+    },
 }
 
-pub(crate) async fn go(cosmos: Cosmos, raw_wallet: SeedPhrase, cmd: Command) -> Result<()> {
+pub(crate) async fn go(cosmos: Cosmos, raw_wallet: WalletSource, cmd: Command) -> Result<()> {
     let wallet = raw_wallet.with_hrp(cosmos.get_address_hrp())?;
-    let tokenfactory = cosmos.token_factory()?;
+    let tokenfactory = cosmos.detect_token_factory().await?;
 
     match cmd {
         Command::Create { subdenom } => {
@@ -27,11 +74,37 @@ pub(crate) async fn go(cosmos: Cosmos, raw_wallet: SeedPhrase, cmd: Command) ->
             tracing::info!("MINTED {amount} {denom}, tx hash: {}", resp.txhash);
         }
 
-        Command::Burn { denom, amount } => {
-            let resp = tokenfactory.burn(&wallet, denom.clone(), amount).await?;
+        Command::Burn {
+            denom,
+            amount,
+            from,
+        } => {
+            let resp = match from {
+                None => tokenfactory.burn(&wallet, denom.clone(), amount).await?,
+                Some(from) => {
+                    tokenfactory
+                        .burn_from(&wallet, denom.clone(), amount, from)
+                        .await?
+                }
+            };
             tracing::info!("BURNED {amount} {denom}, tx hash: {}", resp.txhash);
         }
 
+        Command::ForceTransfer {
+            denom,
+            amount,
+            from,
+            to,
+        } => {
+            let resp = tokenfactory
+                .force_transfer(&wallet, denom.clone(), amount, from, to)
+                .await?;
+            tracing::info!(
+                "FORCE TRANSFERRED {amount} {denom} from {from} to {to}, tx hash: {}",
+                resp.txhash
+            );
+        }
+
         Command::ChangeAdmin { denom, addr } => {
             let resp = tokenfactory
                 .change_admin(&wallet, denom.clone(), addr.clone())
@@ -41,6 +114,26 @@ pub(crate) async fn go(cosmos: Cosmos, raw_wallet: SeedPhrase, cmd: Command) ->
                 resp.txhash
             );
         }
+
+        Command::SetMetadata {
+            denom,
+            display,
+            symbol,
+            exponent,
+            name,
+            description,
+        } => {
+            let mut metadata =
+                TokenFactory::denom_metadata(denom.clone(), display, symbol, exponent);
+            if let Some(name) = name {
+                metadata.name = name;
+            }
+            if let Some(description) = description {
+                metadata.description = description;
+            }
+            let resp = tokenfactory.set_denom_metadata(&wallet, metadata).await?;
+            tracing::info!("SET METADATA FOR {denom}, tx hash: {}", resp.txhash);
+        }
     }
     Ok(())
 }
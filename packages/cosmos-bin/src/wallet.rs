@@ -1,7 +1,9 @@
-use anyhow::Result;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
 use cosmos::{AddressHrp, RawAddress, SeedPhrase};
 
-use crate::gen_wallet;
+use crate::{cli::WalletSource, gen_wallet};
 
 #[derive(clap::Parser)]
 pub(crate) struct Opt {
@@ -16,12 +18,26 @@ enum Subcommand {
         /// Address type, supports any valid Human Readable Part like cosmos, osmo, or juno
         address_type: AddressHrp,
     },
-    /// Print the address for the given phrase
+    /// Generate a wallet whose address matches the given prefix and/or suffix
+    Vanity {
+        /// Address type, supports any valid Human Readable Part like cosmos, osmo, or juno
+        address_type: AddressHrp,
+        /// Characters the address should start with, after the HRP
+        #[clap(long, default_value = "")]
+        prefix: String,
+        /// Characters the address should end with
+        #[clap(long, default_value = "")]
+        suffix: String,
+        /// Number of threads to search with, defaults to the number of CPUs
+        #[clap(long)]
+        threads: Option<usize>,
+    },
+    /// Print the address for the given phrase or hex-encoded private key
     PrintAddress {
         /// HRP (human readable part) of the address, e.g. osmo, inj
         hrp: AddressHrp,
-        /// Phrase
-        phrase: SeedPhrase,
+        /// Phrase or private key
+        phrase: WalletSource,
     },
     /// Print the address for a different chain
     ChangeAddressType {
@@ -30,11 +46,61 @@ enum Subcommand {
         /// Destination address HRP (human-readable part)
         hrp: AddressHrp,
     },
+    /// Store a seed phrase in the OS keyring
+    #[cfg(feature = "keyring")]
+    KeyringStore {
+        /// Name to store the phrase under
+        name: String,
+        /// Phrase, read from stdin if omitted
+        #[clap(long, env = "COSMOS_WALLET")]
+        phrase: Option<SeedPhrase>,
+    },
+    /// Remove a seed phrase from the OS keyring
+    #[cfg(feature = "keyring")]
+    KeyringDelete {
+        /// Name the phrase was stored under
+        name: String,
+    },
+    /// Import a seed phrase into an encrypted keystore file
+    Import {
+        /// Keystore file to create
+        path: PathBuf,
+        /// Phrase, read from stdin if omitted
+        #[clap(long, env = "COSMOS_WALLET")]
+        phrase: Option<SeedPhrase>,
+        /// Passphrase for encrypting the file, read from stdin if omitted
+        #[clap(long, env = "COSMOS_KEYSTORE_PASSPHRASE")]
+        passphrase: Option<String>,
+        /// Replace the keystore file if it already exists
+        #[clap(long)]
+        force: bool,
+    },
+    /// Print the seed phrase stored in an encrypted keystore file
+    Export {
+        /// Keystore file to read
+        path: PathBuf,
+        /// Passphrase for decrypting the file, read from stdin if omitted
+        #[clap(long, env = "COSMOS_KEYSTORE_PASSPHRASE")]
+        passphrase: Option<String>,
+    },
 }
 
 pub(crate) async fn go(Opt { sub }: Opt) -> Result<()> {
     match sub {
         Subcommand::GenWallet { address_type } => gen_wallet(address_type)?,
+        Subcommand::Vanity {
+            address_type,
+            prefix,
+            suffix,
+            threads,
+        } => {
+            let threads = threads.unwrap_or_else(|| {
+                std::thread::available_parallelism().map_or(1, |threads| threads.get())
+            });
+            let phrase = SeedPhrase::generate_vanity(address_type, &prefix, &suffix, threads)?;
+            println!("Mnemonic: {}", phrase.phrase());
+            println!("Address: {}", phrase.with_hrp(address_type)?);
+        }
         Subcommand::PrintAddress { hrp, phrase } => {
             println!("{}", phrase.with_hrp(hrp)?);
         }
@@ -44,6 +110,58 @@ pub(crate) async fn go(Opt { sub }: Opt) -> Result<()> {
         } => {
             println!("{}", orig.with_hrp(address_type));
         }
+        #[cfg(feature = "keyring")]
+        Subcommand::KeyringStore { name, phrase } => {
+            let phrase = match phrase {
+                Some(phrase) => phrase,
+                None => read_stdin_line("seed phrase")?.parse()?,
+            };
+            phrase.store_in_keyring(&name)?;
+            println!("Stored seed phrase as {name}");
+        }
+        #[cfg(feature = "keyring")]
+        Subcommand::KeyringDelete { name } => {
+            SeedPhrase::delete_from_keyring(&name)?;
+            println!("Deleted seed phrase {name}");
+        }
+        Subcommand::Import {
+            path,
+            phrase,
+            passphrase,
+            force,
+        } => {
+            let phrase = match phrase {
+                Some(phrase) => phrase,
+                None => read_stdin_line("seed phrase")?.parse()?,
+            };
+            let passphrase = match passphrase {
+                Some(passphrase) => passphrase,
+                None => read_stdin_line("passphrase")?,
+            };
+            if force {
+                phrase.overwrite_encrypted(&path, &passphrase)?;
+            } else {
+                phrase.save_encrypted(&path, &passphrase)?;
+            }
+            println!("Wrote encrypted keystore to {}", path.display());
+        }
+        Subcommand::Export { path, passphrase } => {
+            let passphrase = match passphrase {
+                Some(passphrase) => passphrase,
+                None => read_stdin_line("passphrase")?,
+            };
+            let phrase = SeedPhrase::load_encrypted(&path, &passphrase)?;
+            println!("{}", phrase.phrase());
+        }
     }
     Ok(())
 }
+
+pub(crate) fn read_stdin_line(what: &str) -> Result<String> {
+    eprintln!("Enter {what}:");
+    let mut line = String::new();
+    std::io::stdin()
+        .read_line(&mut line)
+        .with_context(|| format!("Unable to read {what} from stdin"))?;
+    Ok(line.trim().to_owned())
+}
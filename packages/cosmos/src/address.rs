@@ -12,7 +12,12 @@ use parking_lot::RwLock;
 use serde::de::Visitor;
 
 use crate::{
-    error::AddressError, wallet::DerivationPathConfig, Cosmos, CosmosBuilder, CosmosNetwork,
+    error::AddressError,
+    wallet::{
+        cosmos_address_from_public_key, ed25519_address_from_public_key,
+        eth_address_from_public_key, DerivationPathConfig,
+    },
+    Cosmos, CosmosBuilder, CosmosNetwork,
 };
 
 /// A raw address value not connected to a specific blockchain.
@@ -143,6 +148,35 @@ impl Address {
     pub fn hrp(self) -> AddressHrp {
         self.hrp
     }
+
+    /// Derive the address for a public key.
+    ///
+    /// secp256k1 public keys may be in compressed or uncompressed form, and
+    /// are converted as needed by the given [PublicKeyMethod].
+    pub fn from_public_key(
+        public_key: &[u8],
+        method: PublicKeyMethod,
+        hrp: AddressHrp,
+    ) -> Result<Address, AddressError> {
+        let secp256k1 = || {
+            bitcoin::secp256k1::PublicKey::from_slice(public_key)
+                .map_err(|source| AddressError::InvalidPublicKey { source })
+        };
+        let raw_address = match method {
+            PublicKeyMethod::Cosmos => cosmos_address_from_public_key(&secp256k1()?.serialize()),
+            PublicKeyMethod::Ethereum => {
+                eth_address_from_public_key(&secp256k1()?.serialize_uncompressed())
+            }
+            PublicKeyMethod::Ed25519 => {
+                ed25519_address_from_public_key(public_key.try_into().map_err(|_| {
+                    AddressError::InvalidEd25519PublicKey {
+                        actual: public_key.len(),
+                    }
+                })?)
+            }
+        };
+        Ok(RawAddress::from(raw_address).with_hrp(hrp))
+    }
 }
 
 /// The method used for hashing public keys into a byte representation.
@@ -153,6 +187,12 @@ pub enum PublicKeyMethod {
     Cosmos,
     /// Ethereum, and some Cosmos chains like Injective, use keccak3.
     Ethereum,
+    /// ed25519 keys, using a truncated SHA2 256 hash.
+    ///
+    /// Unlike the other methods, this also changes the key type. When
+    /// deriving from a [crate::SeedPhrase], SLIP-10 is used, which requires
+    /// every component of the derivation path to be hardened.
+    Ed25519,
 }
 
 impl Display for Address {
@@ -1,12 +1,15 @@
+use chrono::{DateTime, Utc};
 use cosmos_sdk_proto::cosmos::{
     authz::v1beta1::{
-        GrantAuthorization, MsgGrant, QueryGranterGrantsRequest, QueryGranterGrantsResponse,
+        GrantAuthorization, MsgGrant, QueryGranteeGrantsRequest, QueryGranterGrantsRequest,
+        QueryGranterGrantsResponse,
     },
     base::query::v1beta1::{PageRequest, PageResponse},
 };
+use futures::{Stream, TryStreamExt};
 use prost::Message;
 
-use crate::{error::Action, Cosmos, HasAddress, TxMessage};
+use crate::{client::next_page_request, error::Action, Cosmos, HasAddress, TxMessage};
 
 impl From<MsgGrant> for TxMessage {
     fn from(msg: MsgGrant) -> Self {
@@ -27,39 +30,145 @@ impl Cosmos {
         &self,
         granter: impl HasAddress,
     ) -> Result<Vec<GrantAuthorization>, crate::Error> {
-        let mut res = vec![];
-        let mut pagination = None;
-
-        loop {
-            let req = QueryGranterGrantsRequest {
-                granter: granter.get_address_string(),
-                pagination: pagination.take(),
-            };
-
-            let QueryGranterGrantsResponse {
-                mut grants,
-                pagination: pag_res,
-            } = self
-                .perform_query(req, Action::QueryGranterGrants(granter.get_address()))
+        let granter = granter.get_address();
+        collect_pages(|pagination| async move {
+            let QueryGranterGrantsResponse { grants, pagination } = self
+                .perform_query(
+                    QueryGranterGrantsRequest {
+                        granter: granter.get_address_string(),
+                        pagination,
+                    },
+                    Action::QueryGranterGrants(granter),
+                )
                 .run()
                 .await?
                 .into_inner();
-            println!("{grants:?}");
-            if grants.is_empty() {
-                break Ok(res);
+            Ok((grants, pagination))
+        })
+        .await
+    }
+
+    /// Stream all grants the given address has received.
+    ///
+    /// Pages are requested lazily as the stream is consumed.
+    pub fn query_grantee_grants(
+        &self,
+        grantee: impl HasAddress,
+    ) -> impl Stream<Item = Result<GrantAuthorization, crate::Error>> + Send + 'static {
+        let cosmos = self.clone();
+        let grantee = grantee.get_address();
+        futures::stream::try_unfold(Some(None), move |pagination| {
+            let cosmos = cosmos.clone();
+            async move {
+                let Some(pagination) = pagination else {
+                    return Ok(None);
+                };
+                let res = cosmos
+                    .perform_query(
+                        QueryGranteeGrantsRequest {
+                            grantee: grantee.get_address_string(),
+                            pagination,
+                        },
+                        Action::QueryGranteeGrants(grantee),
+                    )
+                    .run()
+                    .await?
+                    .into_inner();
+                let next = next_page_request(res.pagination).map(Some);
+                Ok::<_, crate::Error>(Some((res.grants, next)))
             }
+        })
+        .map_ok(|grants| futures::stream::iter(grants.into_iter().map(Ok)))
+        .try_flatten()
+    }
 
-            res.append(&mut grants);
+    /// Find grants from the given address which expire before the deadline.
+    ///
+    /// Grants without an expiration are never included. Results are sorted
+    /// with the soonest expiration first.
+    pub async fn authz_grants_expiring_before(
+        &self,
+        granter: impl HasAddress,
+        deadline: DateTime<Utc>,
+    ) -> Result<Vec<GrantAuthorization>, crate::Error> {
+        let mut grants = self
+            .query_granter_grants(granter)
+            .await?
+            .into_iter()
+            .filter_map(|grant| {
+                grant_expiration(&grant)
+                    .filter(|expiration| *expiration < deadline)
+                    .map(|expiration| (expiration, grant))
+            })
+            .collect::<Vec<_>>();
+        grants.sort_by_key(|(expiration, _)| *expiration);
+        Ok(grants.into_iter().map(|(_, grant)| grant).collect())
+    }
+}
+
+/// When the grant expires, if ever.
+fn grant_expiration(grant: &GrantAuthorization) -> Option<DateTime<Utc>> {
+    let expiration = grant.expiration.as_ref()?;
+    DateTime::from_timestamp(
+        expiration.seconds,
+        u32::try_from(expiration.nanos).unwrap_or_default(),
+    )
+}
 
-            pagination = pag_res.map(|PageResponse { next_key, total: _ }| PageRequest {
-                key: next_key,
-                // Ideally we'd just leave this out so we use next_key
-                // instead, but the Rust types don't allow this
-                offset: res.len().try_into().unwrap_or(u64::MAX),
-                limit: 10,
-                count_total: false,
-                reverse: false,
-            });
+/// Fetch every page of a paginated query, following `next_key`.
+///
+/// Only the key is sent on follow-up pages, since the SDK rejects requests
+/// which set both a key and an offset.
+async fn collect_pages<T, F, Fut>(mut fetch: F) -> Result<Vec<T>, crate::Error>
+where
+    F: FnMut(Option<PageRequest>) -> Fut,
+    Fut: std::future::Future<Output = Result<(Vec<T>, Option<PageResponse>), crate::Error>>,
+{
+    let mut res = vec![];
+    let mut pagination = None;
+    loop {
+        let (mut items, page) = fetch(pagination).await?;
+        res.append(&mut items);
+        match next_page_request(page) {
+            Some(next) => pagination = Some(next),
+            None => break Ok(res),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn multiple_pages() {
+        let pages = collect_pages(|pagination: Option<PageRequest>| async move {
+            let page = match pagination {
+                None => 0,
+                Some(req) => {
+                    assert_eq!(req.offset, 0, "key and offset must not both be set");
+                    req.key[0]
+                }
+            };
+            let next_key = if page < 2 { vec![page + 1] } else { vec![] };
+            Ok((vec![page; 2], Some(PageResponse { next_key, total: 0 })))
+        })
+        .await
+        .unwrap();
+        assert_eq!(pages, vec![0, 0, 1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn expiration() {
+        let mut grant = GrantAuthorization::default();
+        assert_eq!(grant_expiration(&grant), None);
+        grant.expiration = Some(cosmos_sdk_proto::Timestamp {
+            seconds: 1_700_000_000,
+            nanos: 0,
+        });
+        assert_eq!(
+            grant_expiration(&grant),
+            DateTime::from_timestamp(1_700_000_000, 0)
+        );
+    }
+}
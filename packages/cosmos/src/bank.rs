@@ -0,0 +1,111 @@
+use cosmos_sdk_proto::cosmos::bank::v1beta1::{
+    Metadata, QueryDenomMetadataRequest, QueryDenomsMetadataRequest, QuerySpendableBalancesRequest,
+    QuerySupplyOfRequest, QueryTotalSupplyRequest,
+};
+
+use crate::{client::next_page_request, error::Action, Address, Coin, Cosmos, HasAddress};
+
+impl Cosmos {
+    /// Get the coin balances the given address is able to send.
+    ///
+    /// Unlike [Cosmos::all_balances], this excludes funds which are still
+    /// locked in a vesting schedule.
+    pub async fn spendable_balances(&self, address: Address) -> Result<Vec<Coin>, crate::Error> {
+        let mut coins = Vec::new();
+        let mut pagination = None;
+        loop {
+            let mut res = self
+                .perform_query(
+                    QuerySpendableBalancesRequest {
+                        address: address.get_address_string(),
+                        pagination: pagination.take(),
+                    },
+                    Action::QuerySpendableBalances(address),
+                )
+                .run()
+                .await?
+                .into_inner();
+            coins.append(&mut res.balances);
+            match next_page_request(res.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(coins),
+            }
+        }
+    }
+
+    /// Get the metadata registered for a denom, such as its display name and decimals.
+    pub async fn denom_metadata(&self, denom: impl Into<String>) -> Result<Metadata, crate::Error> {
+        let denom = denom.into();
+        let action = Action::QueryDenomMetadata(denom.clone());
+        self.perform_query(QueryDenomMetadataRequest { denom }, action.clone())
+            .run()
+            .await?
+            .into_inner()
+            .metadata
+            .ok_or_else(|| crate::Error::InvalidChainResponse {
+                message: "No denom metadata returned".to_owned(),
+                action: action.into(),
+            })
+    }
+
+    /// Get the metadata of all denoms which have any registered.
+    pub async fn denoms_metadata(&self) -> Result<Vec<Metadata>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    QueryDenomsMetadataRequest {
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryDenomsMetadata,
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.metadatas);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get the total supply of every denom on the chain.
+    pub async fn total_supply(&self) -> Result<Vec<Coin>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    QueryTotalSupplyRequest {
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryTotalSupply,
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.supply);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get the total supply of a single denom.
+    pub async fn supply_of(&self, denom: impl Into<String>) -> Result<Coin, crate::Error> {
+        let denom = denom.into();
+        let action = Action::QuerySupplyOf(denom.clone());
+        self.perform_query(QuerySupplyOfRequest { denom }, action.clone())
+            .run()
+            .await?
+            .into_inner()
+            .amount
+            .ok_or_else(|| crate::Error::InvalidChainResponse {
+                message: "No supply amount returned".to_owned(),
+                action: action.into(),
+            })
+    }
+}
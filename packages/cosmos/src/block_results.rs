@@ -0,0 +1,187 @@
+use serde::Deserialize;
+
+use crate::{error::Action, ext::normalize_event_attributes, Cosmos, NormalizedEvent};
+
+/// Events emitted while executing a block, as returned by the CometBFT `block_results` endpoint.
+///
+/// This includes events which are not part of any transaction, such as staking
+/// reward distribution, epoch processing, and IBC client updates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockResults {
+    /// Block height
+    pub height: i64,
+    /// Events for each transaction in the block, in transaction order
+    pub tx_events: Vec<Vec<NormalizedEvent>>,
+    /// Begin block events, only emitted by chains before CometBFT 0.38
+    pub begin_block_events: Vec<NormalizedEvent>,
+    /// End block events, only emitted by chains before CometBFT 0.38
+    pub end_block_events: Vec<NormalizedEvent>,
+    /// Finalize block events, only emitted by chains on CometBFT 0.38 and later
+    pub finalize_block_events: Vec<NormalizedEvent>,
+}
+
+impl BlockResults {
+    /// All events emitted outside of transactions.
+    pub fn block_events(&self) -> impl Iterator<Item = &NormalizedEvent> {
+        self.begin_block_events
+            .iter()
+            .chain(&self.end_block_events)
+            .chain(&self.finalize_block_events)
+    }
+}
+
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: RpcBlockResults,
+}
+
+#[derive(Deserialize)]
+struct RpcBlockResults {
+    height: String,
+    txs_results: Option<Vec<RpcTxResult>>,
+    begin_block_events: Option<Vec<RpcEvent>>,
+    end_block_events: Option<Vec<RpcEvent>>,
+    finalize_block_events: Option<Vec<RpcEvent>>,
+}
+
+#[derive(Deserialize)]
+struct RpcTxResult {
+    events: Option<Vec<RpcEvent>>,
+}
+
+#[derive(Deserialize)]
+struct RpcEvent {
+    r#type: String,
+    #[serde(default)]
+    attributes: Vec<RpcAttribute>,
+}
+
+#[derive(Deserialize)]
+struct RpcAttribute {
+    #[serde(default)]
+    key: Option<String>,
+    #[serde(default)]
+    value: Option<String>,
+}
+
+fn normalize_events(events: Option<Vec<RpcEvent>>) -> Vec<NormalizedEvent> {
+    events
+        .unwrap_or_default()
+        .into_iter()
+        .map(|event| NormalizedEvent {
+            r#type: event.r#type,
+            attributes: normalize_event_attributes(event.attributes.iter().map(|attr| {
+                (
+                    attr.key.as_deref().unwrap_or_default(),
+                    attr.value.as_deref().unwrap_or_default(),
+                )
+            })),
+        })
+        .collect()
+}
+
+impl RpcBlockResults {
+    fn into_block_results(self, action: &Action) -> Result<BlockResults, crate::Error> {
+        Ok(BlockResults {
+            height: self
+                .height
+                .parse()
+                .map_err(|_| crate::Error::InvalidChainResponse {
+                    message: format!("Invalid block height {:?}", self.height),
+                    action: action.clone().into(),
+                })?,
+            tx_events: self
+                .txs_results
+                .unwrap_or_default()
+                .into_iter()
+                .map(|tx| normalize_events(tx.events))
+                .collect(),
+            begin_block_events: normalize_events(self.begin_block_events),
+            end_block_events: normalize_events(self.end_block_events),
+            finalize_block_events: normalize_events(self.finalize_block_events),
+        })
+    }
+}
+
+impl Cosmos {
+    /// Get the events emitted while executing the given block.
+    ///
+    /// This is not available over gRPC, so it requires a CometBFT RPC
+    /// endpoint to be configured with [crate::CosmosBuilder::set_rpc_url].
+    pub async fn get_block_results(&self, height: i64) -> Result<BlockResults, crate::Error> {
+        let action = Action::GetBlockResults(height);
+        let rpc_url =
+            self.get_cosmos_builder()
+                .rpc_url()
+                .ok_or_else(|| crate::Error::NoRpcUrl {
+                    action: action.clone().into(),
+                })?;
+        let url = format!(
+            "{}/block_results?height={height}",
+            rpc_url.trim_end_matches('/')
+        );
+        let make_error = |source| crate::Error::RpcRequest {
+            url: url.clone(),
+            source,
+            action: action.clone().into(),
+        };
+        let res: RpcResponse = reqwest::Client::new()
+            .get(&url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(make_error)?
+            .json()
+            .await
+            .map_err(make_error)?;
+        res.result.into_block_results(&action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_finalize_block() {
+        let json = r#"{
+            "jsonrpc": "2.0",
+            "id": -1,
+            "result": {
+                "height": "42",
+                "txs_results": [{"code": 0, "events": [{"type": "message", "attributes": [{"key": "action", "value": "send", "index": true}]}]}],
+                "finalize_block_events": [{"type": "rewards", "attributes": [{"key": "amount", "value": "5uosmo"}, {"key": "mode", "value": "BeginBlock"}]}],
+                "validator_updates": [],
+                "app_hash": "AA=="
+            }
+        }"#;
+        let res: RpcResponse = serde_json::from_str(json).unwrap();
+        let res = res
+            .result
+            .into_block_results(&Action::GetBlockResults(42))
+            .unwrap();
+        assert_eq!(res.height, 42);
+        assert_eq!(res.tx_events.len(), 1);
+        assert!(res.begin_block_events.is_empty());
+        let events = res.block_events().collect::<Vec<_>>();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].get("amount"), Some("5uosmo"));
+    }
+
+    #[test]
+    fn parse_legacy_base64() {
+        let json = r#"{"result": {
+            "height": "7",
+            "txs_results": null,
+            "begin_block_events": [{"type": "commission", "attributes": [{"key": "YW1vdW50", "value": "NXVvc21v"}]}],
+            "end_block_events": null
+        }}"#;
+        let res: RpcResponse = serde_json::from_str(json).unwrap();
+        let res = res
+            .result
+            .into_block_results(&Action::GetBlockResults(7))
+            .unwrap();
+        assert_eq!(res.begin_block_events[0].get("amount"), Some("5uosmo"));
+        assert!(res.finalize_block_events.is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+/// Information about how this crate was built, for diagnostics.
+///
+/// See [build_info].
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct BuildInfo {
+    /// Version of this crate
+    pub version: &'static str,
+    /// Cargo features enabled at compile time
+    pub features: Vec<&'static str>,
+    /// Upstream versions of the protobuf definitions compiled into this crate
+    pub proto_versions: Vec<(&'static str, &'static str)>,
+    /// Git commit this crate was built from.
+    ///
+    /// Only available if the `COSMOS_RS_GIT_HASH` environment variable was set at build time.
+    pub git_hash: Option<&'static str>,
+}
+
+/// Get information about the version and features of this crate.
+///
+/// Useful when debugging differences in behavior between deployed services.
+pub fn build_info() -> BuildInfo {
+    let mut features = vec![];
+    if cfg!(feature = "clap") {
+        features.push("clap");
+    }
+    if cfg!(feature = "config") {
+        features.push("config");
+    }
+    if cfg!(feature = "compression") {
+        features.push("compression");
+    }
+    if cfg!(feature = "rustls-tls") {
+        features.push("rustls-tls");
+    }
+    if cfg!(feature = "native-tls") {
+        features.push("native-tls");
+    }
+    if cfg!(feature = "directories") {
+        features.push("directories");
+    }
+    if cfg!(feature = "deterministic-tests") {
+        features.push("deterministic-tests");
+    }
+    if cfg!(feature = "toml") {
+        features.push("toml");
+    }
+    if cfg!(feature = "keyring") {
+        features.push("keyring");
+    }
+    if cfg!(feature = "injective") {
+        features.push("injective");
+    }
+
+    // Each module with vendored protobuf code keeps its own list, next to the code.
+    let mut proto_versions = vec![("cosmos-sdk", cosmos_sdk_proto::COSMOS_SDK_VERSION.trim())];
+    proto_versions.extend_from_slice(crate::osmosis::PROTO_VERSIONS);
+    #[cfg(feature = "injective")]
+    proto_versions.extend_from_slice(crate::injective::PROTO_VERSIONS);
+    proto_versions.extend_from_slice(crate::rujira::PROTO_VERSIONS);
+
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        features,
+        proto_versions,
+        git_hash: option_env!("COSMOS_RS_GIT_HASH"),
+    }
+}
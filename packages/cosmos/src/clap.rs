@@ -18,6 +18,10 @@ pub struct CosmosOpt {
     /// Disable usage of config file overrides
     #[clap(long, env = "COSMOS_CONFIG_DISABLE", global = true)]
     pub config_disable: bool,
+    #[cfg(feature = "config")]
+    /// Named profile from the config file, selecting a network and overrides
+    #[clap(long, env = "COSMOS_PROFILE", global = true)]
+    pub profile: Option<String>,
     /// Optional gRPC endpoint override
     #[clap(long, env = "COSMOS_GRPC", global = true)]
     pub cosmos_grpc: Option<String>,
@@ -29,6 +33,9 @@ pub struct CosmosOpt {
         value_delimiter = ','
     )]
     pub cosmos_grpc_fallbacks: Vec<String>,
+    /// Optional CometBFT RPC endpoint, needed for block results
+    #[clap(long, env = "COSMOS_RPC", global = true)]
+    pub cosmos_rpc: Option<String>,
     /// Optional chain ID override
     #[clap(long, env = "COSMOS_CHAIN_ID", global = true)]
     pub chain_id: Option<String>,
@@ -53,7 +60,7 @@ pub struct CosmosOpt {
 #[derive(thiserror::Error, Debug)]
 #[allow(missing_docs)]
 pub enum CosmosOptError {
-    #[error("No network specified, either provide the COSMOS_NETWORK env var or --network option, or provide the following settings: {missing}")]
+    #[error("No network specified, either provide the COSMOS_NETWORK env var or --network option, set a default network or profile in the config file, or provide the following settings: {missing}")]
     NoNetworkProvided { missing: String },
     #[error("{source}")]
     CosmosBuilderError { source: BuilderError },
@@ -77,6 +84,7 @@ impl CosmosOpt {
             network,
             cosmos_grpc,
             cosmos_grpc_fallbacks,
+            cosmos_rpc,
             chain_id,
             gas_multiplier,
             referer_header,
@@ -86,62 +94,60 @@ impl CosmosOpt {
             config,
             #[cfg(feature = "config")]
             config_disable,
+            #[cfg(feature = "config")]
+            profile,
             query_timeout_seconds,
         } = self;
 
-        // Do the error checking here instead of in clap so that the field can
-        // be global.
-        let mut builder = match network {
-            Some(network) => {
-                async fn builder_without_config(
-                    network: &str,
-                ) -> Result<CosmosBuilder, CosmosOptError> {
-                    CosmosNetwork::from_str(network)
-                        .map_err(|source| CosmosOptError::NetworkParseError { source })?
-                        .builder()
-                        .await
-                        .map_err(|source| CosmosOptError::CosmosBuilderError { source })
+        async fn builder_without_config(network: &str) -> Result<CosmosBuilder, CosmosOptError> {
+            CosmosNetwork::from_str(network)
+                .map_err(|source| CosmosOptError::NetworkParseError { source })?
+                .builder()
+                .await
+                .map_err(|source| CosmosOptError::CosmosBuilderError { source })
+        }
+
+        #[cfg(feature = "config")]
+        let config_builder = {
+            use crate::{CosmosConfig, CosmosConfigError};
+            let loaded = if config_disable {
+                if profile.is_some() {
+                    tracing::warn!("Config file usage is disabled, ignoring the provided profile");
                 }
-                #[cfg(feature = "config")]
-                let mut builder = {
-                    use crate::{CosmosConfig, CosmosConfigError};
-                    if config_disable {
-                        builder_without_config(&network).await?
-                    } else {
-                        match &config {
-                            Some(config) => {
-                                CosmosConfig::load_from(config, true)?
-                                    .builder_for(&network)
-                                    .await?
-                            }
-                            None => match CosmosConfig::load() {
-                                Ok(config) => config.builder_for(&network).await?,
-                                Err(e @ CosmosConfigError::ProjectDirsNotFound) => {
-                                    tracing::warn!("{e}");
-                                    builder_without_config(&network).await?
-                                }
-                                Err(e) => return Err(e.into()),
-                            },
+                None
+            } else {
+                match &config {
+                    Some(config) => Some(CosmosConfig::load_from(config, true)?),
+                    None => match CosmosConfig::load() {
+                        Ok(config) => Some(config),
+                        Err(e @ CosmosConfigError::ProjectDirsNotFound) => {
+                            tracing::warn!("{e}");
+                            None
                         }
-                    }
-                };
-                #[cfg(not(feature = "config"))]
-                let mut builder = builder_without_config(&network).await?;
-                if let Some(grpc) = cosmos_grpc {
-                    builder.set_grpc_url(grpc);
-                }
-                if let Some(chain_id) = chain_id {
-                    builder.set_chain_id(chain_id);
+                        Err(e) => return Err(e.into()),
+                    },
                 }
-                if let Some(gas_coin) = gas_coin {
-                    builder.set_gas_coin(gas_coin);
+            };
+            match loaded {
+                Some(loaded) if network.is_some() || profile.is_some() || loaded.has_default() => {
+                    Some(
+                        loaded
+                            .builder_for(network.as_deref(), profile.as_deref())
+                            .await?,
+                    )
                 }
-                if let Some(hrp) = hrp {
-                    builder.set_hrp(hrp)
-                }
-                builder
+                _ => None,
             }
-            None => {
+        };
+        #[cfg(not(feature = "config"))]
+        let config_builder: Option<CosmosBuilder> = None;
+
+        // Do the error checking here instead of in clap so that the field can
+        // be global.
+        let mut builder = match (config_builder, network) {
+            (Some(builder), _) => builder,
+            (None, Some(network)) => builder_without_config(&network).await?,
+            (None, None) => {
                 #[cfg(feature = "config")]
                 if config.is_some() {
                     tracing::warn!(
@@ -161,7 +167,7 @@ impl CosmosOpt {
                 if hrp.is_none() {
                     missing.push("COSMOS_HRP");
                 }
-                match (cosmos_grpc, chain_id, gas_coin, hrp) {
+                match (&cosmos_grpc, &chain_id, &gas_coin, hrp) {
                     (Some(grpc), Some(chain_id), Some(gas_coin), Some(hrp)) => {
                         assert!(missing.is_empty());
                         CosmosBuilder::new(chain_id, gas_coin, hrp, grpc)
@@ -175,6 +181,18 @@ impl CosmosOpt {
                 }
             }
         };
+        if let Some(grpc) = cosmos_grpc {
+            builder.set_grpc_url(grpc);
+        }
+        if let Some(chain_id) = chain_id {
+            builder.set_chain_id(chain_id);
+        }
+        if let Some(gas_coin) = gas_coin {
+            builder.set_gas_coin(gas_coin);
+        }
+        if let Some(hrp) = hrp {
+            builder.set_hrp(hrp)
+        }
         for fallback in cosmos_grpc_fallbacks {
             builder.add_grpc_fallback_url(fallback);
         }
@@ -182,8 +200,15 @@ impl CosmosOpt {
         if let Some(gas_multiplier) = gas_multiplier {
             builder.set_gas_estimate_multiplier(gas_multiplier);
         }
-        builder.set_referer_header(referer_header);
-        builder.set_query_timeout_seconds(query_timeout_seconds);
+        if cosmos_rpc.is_some() {
+            builder.set_rpc_url(cosmos_rpc);
+        }
+        if referer_header.is_some() {
+            builder.set_referer_header(referer_header);
+        }
+        if query_timeout_seconds.is_some() {
+            builder.set_query_timeout_seconds(query_timeout_seconds);
+        }
 
         Ok(builder)
     }
@@ -4,7 +4,6 @@ mod pool;
 pub(crate) mod query;
 
 use std::{
-    collections::HashMap,
     str::FromStr,
     sync::{Arc, Weak},
 };
@@ -16,40 +15,49 @@ use cosmos_sdk_proto::{
         bank::v1beta1::QueryAllBalancesRequest,
         base::{
             abci::v1beta1::TxResponse,
-            query::v1beta1::PageRequest,
-            tendermint::v1beta1::{GetBlockByHeightRequest, GetLatestBlockRequest},
+            query::v1beta1::{PageRequest, PageResponse},
+            reflection::v1beta1::ListImplementationsRequest,
+            tendermint::v1beta1::{
+                AbciQueryRequest, AbciQueryResponse, GetBlockByHeightRequest,
+                GetLatestBlockRequest, GetNodeInfoRequest, GetSyncingRequest,
+            },
             v1beta1::Coin,
         },
         tx::v1beta1::{
-            AuthInfo, BroadcastMode, BroadcastTxRequest, BroadcastTxResponse, Fee, GetTxRequest,
-            GetTxResponse, GetTxsEventRequest, ModeInfo, OrderBy, SignDoc, SignerInfo,
-            SimulateRequest, SimulateResponse, Tx, TxBody,
+            AuthInfo, BroadcastTxRequest, BroadcastTxResponse, Fee, GetTxRequest, GetTxResponse,
+            GetTxsEventRequest, ModeInfo, OrderBy, SignDoc, SignerInfo, SimulateRequest,
+            SimulateResponse, Tx, TxBody, TxRaw,
         },
     },
     cosmwasm::wasm::v1::QueryCodeRequest,
     traits::Message,
 };
-use parking_lot::{Mutex, RwLock};
+use parking_lot::Mutex;
 use tokio::{sync::mpsc::Receiver, task::JoinSet, time::Instant};
 use tonic::{service::Interceptor, Status};
 
 use crate::{
     address::HasAddressHrp,
     error::{
-        Action, BuilderError, ConnectionError, CosmosSdkError, FirstBlockAfterError,
-        NodeHealthReport, QueryError, QueryErrorCategory, QueryErrorDetails,
+        Action, ActionCategory, BuilderError, ConnectionError, CosmosSdkError,
+        DynamicGasStateError, FirstBlockAfterError, NodeHealthReport, QueryError,
+        QueryErrorCategory, QueryErrorDetails,
     },
-    gas_multiplier::{GasMultiplier, GasMultiplierConfig},
+    ext::normalize_event_attributes,
+    fee_budget::FeeCharge,
+    gas_multiplier::{DynamicGasState, GasMultiplier, GasMultiplierConfig},
     gas_price::{CurrentGasPrice, DEFAULT_GAS_PRICE},
+    gas_statistics::{GasStatistics, GasStatisticsTracker},
     osmosis::ChainPausedStatus,
-    wallet::WalletPublicKey,
-    Address, CosmosBuilder, DynamicGasMultiplier, Error, HasAddress, TxBuilder,
+    sequence_store::{InMemorySequenceStore, SequenceKind, SequenceStore},
+    simulation_cache::{SimulationCache, SimulationKey},
+    wallet::{WalletPublicKey, WatchWallet},
+    Address, BroadcastMode, CosmosBuilder, DynamicGasMultiplier, Error, HasAddress,
+    NormalizedEvent, Signer, TxBuilder, Wallet,
 };
 
 use self::{node::Node, node_chooser::QueryResult, pool::Pool, query::GrpcRequest};
 
-use super::Wallet;
-
 /// A connection to a gRPC endpoint to communicate with a Cosmos chain.
 ///
 /// Behind the scenes, this uses a [Pool] of connections. Cloning this value is
@@ -62,16 +70,19 @@ pub struct Cosmos {
     pool: Pool,
     height: Option<u64>,
     pub(crate) chain_paused_status: ChainPausedStatus,
-    gas_multiplier: GasMultiplier,
+    pub(crate) gas_multiplier: GasMultiplier,
     /// Maximum gas price
     pub(crate) max_price: f64,
+    /// Cross-check query results against a second node?
+    cross_check: bool,
     tracking: Arc<Tracking>,
 }
 
 struct Tracking {
     block_height: Mutex<BlockHeightTracking>,
-    simulate_sequences: RwLock<HashMap<Address, SequenceInformation>>,
-    broadcast_sequences: RwLock<HashMap<Address, SequenceInformation>>,
+    sequence_store: Arc<dyn SequenceStore>,
+    gas_statistics: Mutex<GasStatisticsTracker>,
+    simulation_cache: Mutex<SimulationCache>,
 }
 
 pub(crate) struct WeakCosmos {
@@ -81,6 +92,7 @@ pub(crate) struct WeakCosmos {
     chain_paused_status: ChainPausedStatus,
     gas_multiplier: GasMultiplier,
     max_price: f64,
+    cross_check: bool,
 }
 
 /// Type encapsulating both the [TxResponse] as well the actual [Tx]
@@ -93,6 +105,14 @@ pub struct CosmosTxResponse {
     pub tx: Tx,
 }
 
+/// A single page of results from [Cosmos::query_transactions].
+pub struct TransactionsPage {
+    /// Matching transactions along with their responses
+    pub transactions: Vec<CosmosTxResponse>,
+    /// Total number of matching transactions across all pages
+    pub total: u64,
+}
+
 impl From<&Cosmos> for WeakCosmos {
     fn from(
         Cosmos {
@@ -102,6 +122,7 @@ impl From<&Cosmos> for WeakCosmos {
             chain_paused_status,
             gas_multiplier,
             max_price,
+            cross_check,
         }: &Cosmos,
     ) -> Self {
         WeakCosmos {
@@ -111,6 +132,7 @@ impl From<&Cosmos> for WeakCosmos {
             chain_paused_status: chain_paused_status.clone(),
             gas_multiplier: gas_multiplier.clone(),
             max_price: *max_price,
+            cross_check: *cross_check,
         }
     }
 }
@@ -124,6 +146,7 @@ impl WeakCosmos {
             chain_paused_status,
             gas_multiplier,
             max_price,
+            cross_check,
         } = self;
         tracking.upgrade().map(|tracking| Cosmos {
             pool: pool.clone(),
@@ -132,6 +155,7 @@ impl WeakCosmos {
             chain_paused_status: chain_paused_status.clone(),
             gas_multiplier: gas_multiplier.clone(),
             max_price: *max_price,
+            cross_check: *cross_check,
         })
     }
 }
@@ -239,7 +263,133 @@ impl<'a, Request: GrpcRequest> PerformQueryBuilder<'a, Request> {
     }
 
     pub(crate) async fn run(self) -> Result<PerformQueryWrapper<Request::Response>, QueryError> {
-        self.run_with(|_pqr, res| res).await
+        if self.should_cross_check() {
+            self.run_cross_check().await
+        } else {
+            self.run_with(|_pqr, res| res).await
+        }
+    }
+
+    fn should_cross_check(&self) -> bool {
+        if self.all_nodes {
+            return false;
+        }
+        // Blocks, transactions, and simulations are either expected to
+        // differ between nodes or aren't state reads, so never cross-check them.
+        match self.action.category() {
+            ActionCategory::Account | ActionCategory::ContractQuery | ActionCategory::Other => {
+                self.cosmos.cross_check
+                    || self.cosmos.get_cosmos_builder().get_cross_check_queries()
+            }
+            ActionCategory::Simulate
+            | ActionCategory::Broadcast
+            | ActionCategory::GetTx
+            | ActionCategory::Block => false,
+        }
+    }
+
+    /// Run the query against two distinct nodes and compare the results.
+    ///
+    /// The second node is queried at the height reported by the first, so
+    /// that nodes at different heights aren't reported as disagreeing.
+    async fn run_cross_check(self) -> Result<PerformQueryWrapper<Request::Response>, QueryError> {
+        let PerformQueryBuilder {
+            cosmos,
+            req,
+            action,
+            should_retry: _,
+            all_nodes: _,
+        } = self;
+        let make_error = |query, grpc_url| QueryError {
+            action: action.clone(),
+            builder: cosmos.pool.builder.clone(),
+            height: cosmos.height,
+            query,
+            grpc_url,
+            node_health: cosmos.pool.node_chooser.health_report(),
+        };
+        let timeout = cosmos.pool.builder.get_action_timeout(action.category());
+        let nodes = cosmos.pool.node_chooser.choose_nodes();
+        let mut pinned = cosmos.clone();
+        let mut first: Option<(Arc<String>, tonic::Response<Request::Response>)> = None;
+        let mut last_error = None;
+
+        if cosmos.pool.builder.get_log_requests() {
+            tracing::info!("{action} (cross-checked)");
+        }
+
+        for node in &nodes {
+            let res = {
+                let _permit = cosmos.pool.get_node_permit().await;
+                pinned.perform_query_inner(req.clone(), node, timeout).await
+            };
+            let res = match res {
+                Ok(res) => {
+                    node.log_query_result(QueryResult::Success);
+                    res
+                }
+                Err((err, can_retry)) => {
+                    node.log_query_result(if can_retry {
+                        QueryResult::NetworkError {
+                            err: err.clone(),
+                            action: action.clone(),
+                        }
+                    } else {
+                        QueryResult::OtherError
+                    });
+                    let err = make_error(err, node.grpc_url().clone());
+                    if !can_retry {
+                        return Err(err);
+                    }
+                    tracing::debug!("Error during cross-checked query, trying next node: {err}");
+                    last_error = Some(err);
+                    continue;
+                }
+            };
+            match first.take() {
+                None => {
+                    if pinned.height.is_none() {
+                        pinned.height = res
+                            .metadata()
+                            .get("x-cosmos-block-height")
+                            .and_then(|height| height.to_str().ok())
+                            .and_then(|height| height.parse().ok());
+                    }
+                    first = Some((node.grpc_url().clone(), res));
+                }
+                Some((grpc_url, first_res)) => {
+                    return if first_res.get_ref().encode_to_vec() == res.get_ref().encode_to_vec() {
+                        Ok(PerformQueryWrapper {
+                            grpc_url,
+                            tonic: first_res,
+                        })
+                    } else {
+                        Err(QueryError {
+                            height: pinned.height,
+                            ..make_error(
+                                QueryErrorDetails::NodesDisagree {
+                                    height: pinned.height,
+                                    first_grpc_url: grpc_url.clone(),
+                                    first: format!("{:?}", first_res.get_ref()),
+                                    second_grpc_url: node.grpc_url().clone(),
+                                    second: format!("{:?}", res.get_ref()),
+                                },
+                                grpc_url,
+                            )
+                        })
+                    };
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            make_error(
+                QueryErrorDetails::CrossCheckUnavailable {
+                    healthy_nodes: nodes.len(),
+                },
+                cosmos.get_cosmos_builder().grpc_url_arc().clone(),
+            )
+        }))
     }
 
     pub(crate) fn no_retry(mut self) -> Self {
@@ -314,128 +464,64 @@ impl Cosmos {
         &self,
         address: Address,
     ) -> Result<BaseAccount, Error> {
-        let sequence = {
-            let guard = self.tracking.simulate_sequences.read();
-            let result = guard.get(&address);
-            result.cloned()
-        };
+        self.get_and_update_sequence(SequenceKind::Simulate, address)
+            .await
+    }
+
+    /// Get the base account, using the larger of the on-chain and locally tracked sequence numbers.
+    ///
+    /// Locally tracked values older than 30 seconds are ignored. Broadcast
+    /// sequence numbers are reserved atomically in the [SequenceStore], so
+    /// concurrent callers sharing a store receive distinct values.
+    async fn get_and_update_sequence(
+        &self,
+        kind: SequenceKind,
+        address: Address,
+    ) -> Result<BaseAccount, Error> {
         let mut base_account = self.get_base_account(address).await?;
-        if let Some(SequenceInformation {
-            sequence,
-            timestamp,
-        }) = sequence
-        {
-            let diff = Instant::now().duration_since(timestamp);
-            if diff.as_secs() <= 30 {
-                let max_sequence = std::cmp::max(sequence, base_account.sequence);
-                if max_sequence != sequence {
-                    let sequence_info = SequenceInformation {
-                        sequence: max_sequence,
-                        timestamp: Instant::now(),
-                    };
-                    {
-                        let mut seq_info = self.tracking.simulate_sequences.write();
-                        let seq_info = seq_info
-                            .entry(address)
-                            .or_insert_with(|| sequence_info.clone());
-                        *seq_info = sequence_info;
-                    }
-                }
-                base_account.sequence = max_sequence;
-                return Ok(base_account);
-            }
-        }
-        let mut seq_info = self.tracking.simulate_sequences.write();
-        let sequence_info = SequenceInformation {
-            sequence: base_account.sequence,
-            timestamp: Instant::now(),
-        };
-        let seq_info = seq_info
-            .entry(address)
-            .or_insert_with(|| sequence_info.clone());
-        *seq_info = sequence_info;
+        base_account.sequence = self
+            .tracking
+            .sequence_store
+            .reserve(kind, address, base_account.sequence)
+            .await
+            .map_err(|source| Error::SequenceStore { address, source })?;
         Ok(base_account)
     }
 
-    async fn update_broadcast_sequence(
+    /// Give back sequence numbers reserved with [Self::get_and_update_sequence]
+    /// for a transaction which never reached the chain.
+    ///
+    /// Failures are only logged, the reservation then expires with the record.
+    async fn release_sequences(
         &self,
-        address: Address,
-        tx: &Tx,
-        hash: &str,
-    ) -> Result<(), Error> {
-        let auth_info = &tx.auth_info;
-        if let Some(auth_info) = auth_info {
-            // This only works since we allow a single signer per
-            // transaction. This needs to be updated to check with
-            // public key when multiple signers exist.
-            let sequence = &auth_info
-                .signer_infos
-                .iter()
-                .map(|item| item.sequence)
-                .max();
-            match sequence {
-                Some(sequence) => {
-                    let mut sequences = self.tracking.broadcast_sequences.write();
-                    sequences
-                        .entry(address)
-                        .and_modify(|item| item.sequence = *sequence);
-                }
-                None => {
-                    tracing::warn!("No sequence number found in Tx {hash} from signer_infos");
-                }
+        kind: SequenceKind,
+        reserved: impl IntoIterator<Item = (Address, u64)>,
+    ) {
+        for (address, sequence) in reserved {
+            if let Err(e) = self
+                .tracking
+                .sequence_store
+                .release(kind, address, sequence)
+                .await
+            {
+                tracing::warn!("Unable to release sequence {sequence} for {address}: {e}");
             }
-        } else {
-            tracing::warn!("No sequence number found in Tx {hash} from auth_info");
         }
-
-        Ok(())
     }
 
-    async fn get_and_update_broadcast_sequence(
-        &self,
-        address: Address,
-    ) -> Result<BaseAccount, Error> {
-        let sequence = {
-            let guard = self.tracking.broadcast_sequences.read();
-            let result = guard.get(&address);
-            result.cloned()
-        };
-        let mut base_account = self.get_base_account(address).await?;
-        if let Some(SequenceInformation {
-            sequence,
-            timestamp,
-        }) = sequence
+    /// Record a corrected sequence number which the chain accepted.
+    ///
+    /// Failures are only logged, the next reservation then relies on the
+    /// on-chain value once the record expires.
+    async fn record_used_sequence(&self, kind: SequenceKind, address: Address, sequence: u64) {
+        if let Err(e) = self
+            .tracking
+            .sequence_store
+            .record_used(kind, address, sequence)
+            .await
         {
-            let diff = Instant::now().duration_since(timestamp);
-            if diff.as_secs() <= 30 {
-                let max_sequence = std::cmp::max(sequence, base_account.sequence);
-                if max_sequence != sequence {
-                    let sequence_info = SequenceInformation {
-                        sequence: max_sequence,
-                        timestamp: Instant::now(),
-                    };
-                    {
-                        let mut seq_info = self.tracking.broadcast_sequences.write();
-                        let seq_info = seq_info
-                            .entry(address)
-                            .or_insert_with(|| sequence_info.clone());
-                        *seq_info = sequence_info;
-                    }
-                }
-                base_account.sequence = max_sequence;
-                return Ok(base_account);
-            }
+            tracing::warn!("Unable to record used sequence {sequence} for {address}: {e}");
         }
-        let mut seq_info = self.tracking.broadcast_sequences.write();
-        let sequence_info = SequenceInformation {
-            sequence: base_account.sequence,
-            timestamp: Instant::now(),
-        };
-        let seq_info = seq_info
-            .entry(address)
-            .or_insert_with(|| sequence_info.clone());
-        *seq_info = sequence_info;
-        Ok(base_account)
     }
 
     pub(crate) fn perform_query<Request: GrpcRequest>(
@@ -487,6 +573,7 @@ async fn run_query<Request: GrpcRequest>(
     let all_nodes_broadcast = all_nodes && cosmos.get_cosmos_builder().get_all_nodes_broadcast();
     let delay = cosmos.get_cosmos_builder().get_delay_before_fallback();
     let total_attempts = cosmos.pool.builder.query_retries();
+    let timeout = cosmos.pool.builder.get_action_timeout(action.category());
 
     // Get the set of nodes we should run against.
     let nodes = if all_nodes_broadcast {
@@ -523,7 +610,7 @@ async fn run_query<Request: GrpcRequest>(
             }
                 for attempt in 1..=total_attempts {
                     let _permit = cosmos.pool.get_node_permit().await;
-                    match cosmos.perform_query_inner(req.clone(), &node).await {
+                    match cosmos.perform_query_inner(req.clone(), &node, timeout).await {
                         Ok(tonic) => {
                             node.log_query_result(QueryResult::Success);
                             tx
@@ -569,9 +656,8 @@ impl Cosmos {
         &self,
         req: Request,
         cosmos_inner: &Node,
+        duration: tokio::time::Duration,
     ) -> Result<tonic::Response<Request::Response>, (QueryErrorDetails, bool)> {
-        let duration =
-            tokio::time::Duration::from_secs(self.pool.builder.query_timeout_seconds().into());
         let mut req = tonic::Request::new(req.clone());
         if let Some(height) = self.height {
             // https://docs.cosmos.network/v0.47/run-node/interact-node#query-for-historical-state-using-rest
@@ -643,6 +729,35 @@ impl Cosmos {
         &self.pool.builder
     }
 
+    /// Create a [TxBuilder] with this network's default transaction options.
+    ///
+    /// Applies the default memo, fee granter and broadcast mode from the
+    /// [CosmosBuilder]. If a timeout height offset is configured, the latest
+    /// block height is queried and the timeout is fixed relative to it, so
+    /// the builder should be broadcast soon after creation.
+    pub async fn tx_builder(&self) -> Result<TxBuilder, crate::Error> {
+        let builder = self.get_cosmos_builder();
+        let mut txbuilder = TxBuilder::default();
+        txbuilder
+            .set_optional_memo(builder.get_default_memo().map(ToOwned::to_owned))
+            .set_fee_granter(builder.get_default_fee_granter())
+            .set_broadcast_mode(builder.get_default_broadcast_mode());
+        if let Some(offset) = builder.get_timeout_height_offset() {
+            let height = self.get_latest_block_info().await?.height;
+            let timeout_height = u64::try_from(height)
+                .ok()
+                .and_then(|height| height.checked_add(offset))
+                .ok_or_else(|| crate::Error::InvalidChainResponse {
+                    message: format!(
+                        "Cannot apply timeout height offset {offset} to latest block height {height}"
+                    ),
+                    action: Action::GetLatestBlock.into(),
+                })?;
+            txbuilder.set_timeout_height(timeout_height);
+        }
+        Ok(txbuilder)
+    }
+
     fn check_block_height(
         &self,
         new_height: Option<&tonic::metadata::MetadataValue<tonic::metadata::Ascii>>,
@@ -739,36 +854,45 @@ impl Cosmos {
 }
 
 #[derive(Clone)]
-pub struct CosmosInterceptor(Option<Arc<String>>);
+pub struct CosmosInterceptor {
+    pub(crate) referer: Option<Arc<String>>,
+    pub(crate) query_auth: Option<Arc<Wallet>>,
+}
 
 impl Interceptor for CosmosInterceptor {
     fn call(&mut self, mut request: tonic::Request<()>) -> Result<tonic::Request<()>, Status> {
         let req = request.metadata_mut();
-        if let Some(value) = &self.0 {
+        if let Some(value) = &self.referer {
             let value = FromStr::from_str(value);
             if let Ok(header_value) = value {
                 req.insert("referer", header_value);
             }
         }
+        if let Some(wallet) = &self.query_auth {
+            for (key, value) in wallet.query_auth_headers(Utc::now().timestamp()) {
+                if let Ok(value) = FromStr::from_str(&value) {
+                    req.insert(key, value);
+                }
+            }
+        }
         Ok(request)
     }
 }
 
-#[derive(Debug, Clone)]
-pub(crate) struct SequenceInformation {
-    sequence: u64,
-    timestamp: Instant,
-}
-
 impl CosmosBuilder {
     /// Create a new [Cosmos] but do not perform any sanity checks.
     ///
     /// Can fail if parsing the gRPC URLs fails.
-    pub fn build(self) -> Result<Cosmos, BuilderError> {
+    pub fn build(mut self) -> Result<Cosmos, BuilderError> {
+        self.resolve_gas_price_method();
         let builder = Arc::new(self);
         let chain_paused_status = builder.chain_paused_method.into();
         let gas_multiplier = builder.build_gas_multiplier();
         let max_price = builder.get_init_max_gas_price();
+        let sequence_store = builder
+            .get_sequence_store()
+            .cloned()
+            .unwrap_or_else(|| Arc::new(InMemorySequenceStore::default()));
         let cosmos = Cosmos {
             pool: Pool::new(builder)?,
             height: None,
@@ -777,12 +901,14 @@ impl CosmosBuilder {
                     when: Instant::now(),
                     height: 0,
                 }),
-                simulate_sequences: RwLock::new(HashMap::new()),
-                broadcast_sequences: RwLock::new(HashMap::new()),
+                sequence_store,
+                gas_statistics: Mutex::new(GasStatisticsTracker::default()),
+                simulation_cache: Mutex::new(SimulationCache::default()),
             }),
             chain_paused_status,
             gas_multiplier,
             max_price,
+            cross_check: false,
         };
         cosmos.launch_chain_paused_tracker();
 
@@ -805,6 +931,19 @@ impl Cosmos {
         self
     }
 
+    /// Return a modified version of this [Cosmos] that cross-checks query results.
+    ///
+    /// When enabled, account, contract, and other state queries are run
+    /// against two distinct healthy nodes at the same block height, and a
+    /// [QueryErrorDetails::NodesDisagree] error is returned if the results
+    /// differ. Use this for critical reads, such as checking balances before
+    /// large sends. See [CosmosBuilder::set_cross_check_queries] to enable
+    /// this globally.
+    pub fn with_cross_check(mut self, cross_check: bool) -> Self {
+        self.cross_check = cross_check;
+        self
+    }
+
     /// Return a modified version of this [Cosmos] with a separate dynamic gas value.
     ///
     /// This is useful for being able to share connections across an application, but allow different pieces of the application to calculate the gas multiplier separately. For example, send-coin heavy workloads will likely need a higher multiplier.
@@ -813,11 +952,68 @@ impl Cosmos {
         self
     }
 
+    /// Use a dynamic gas multiplier restored from a previously exported state.
+    ///
+    /// Like [Self::with_dynamic_gas], but starts from the learned multiplier
+    /// instead of [DynamicGasMultiplier::initial]. See [Self::get_dynamic_gas_state].
+    ///
+    /// Fails if the state is corrupt, e.g. with inverted bounds or NaN values.
+    pub fn with_dynamic_gas_state(
+        mut self,
+        state: &DynamicGasState,
+    ) -> Result<Self, DynamicGasStateError> {
+        self.gas_multiplier = state.build()?;
+        Ok(self)
+    }
+
+    /// Export the learned state of the dynamic gas multiplier.
+    ///
+    /// Returns [None] if a static multiplier is in use.
+    pub fn get_dynamic_gas_state(&self) -> Option<DynamicGasState> {
+        self.gas_multiplier.get_state()
+    }
+
     /// Return the currently used gas multiplier.
     pub fn get_current_gas_multiplier(&self) -> f64 {
         self.gas_multiplier.get_current()
     }
 
+    /// Get statistics on requested versus used gas for recent broadcasts.
+    ///
+    /// Statistics are shared by all clones of this [Cosmos].
+    pub fn gas_statistics(&self) -> GasStatistics {
+        self.tracking.gas_statistics.lock().statistics()
+    }
+
+    fn forget_simulation_on_out_of_gas(
+        &self,
+        key: Option<SimulationKey>,
+        res: &Result<CosmosTxResponse, crate::Error>,
+    ) {
+        if let (
+            Some(key),
+            Err(crate::Error::TransactionFailed {
+                code: CosmosSdkError::OutOfGas,
+                ..
+            }),
+        ) = (key, res)
+        {
+            self.tracking.simulation_cache.lock().remove(&key);
+        }
+    }
+
+    fn record_gas_statistics(
+        &self,
+        simulated: u64,
+        multiplier: f64,
+        res: &Result<CosmosTxResponse, crate::Error>,
+    ) {
+        self.tracking
+            .gas_statistics
+            .lock()
+            .record(simulated, multiplier, res);
+    }
+
     /// Are we using a dynamic gas multiplier?
     pub fn is_gas_multiplier_dynamic(&self) -> bool {
         match self.gas_multiplier {
@@ -896,17 +1092,9 @@ impl Cosmos {
                 .await?
                 .into_inner();
             coins.append(&mut res.balances);
-            match res.pagination {
-                Some(x) if !x.next_key.is_empty() => {
-                    pagination = Some(PageRequest {
-                        key: x.next_key,
-                        offset: 0,
-                        limit: 0,
-                        count_total: false,
-                        reverse: false,
-                    })
-                }
-                _ => break Ok(coins),
+            match next_page_request(res.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(coins),
             }
         }
     }
@@ -1002,6 +1190,7 @@ impl Cosmos {
                                 hash: txhash.clone(),
                             },
                             node,
+                            self.pool.builder.get_action_timeout(action.category()),
                         )
                         .await
                     {
@@ -1023,7 +1212,7 @@ impl Cosmos {
         self.wait_for_transaction_with_action(txhash, None).await
     }
 
-    async fn wait_for_transaction_with_action(
+    pub(crate) async fn wait_for_transaction_with_action(
         &self,
         txhash: impl Into<String>,
         action: Option<Action>,
@@ -1108,8 +1297,81 @@ impl Cosmos {
             })
     }
 
+    /// Search for transactions matching the given event query.
+    ///
+    /// The query uses the CometBFT syntax, e.g. `transfer.recipient='osmo1...' AND tx.height>=1000`.
+    /// Results are returned in ascending height order. `page` starts at 1.
+    pub async fn search_transactions(
+        &self,
+        query: impl Into<String>,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> Result<Vec<TxResponse>, QueryError> {
+        let query = query.into();
+        #[allow(deprecated)]
+        let req = GetTxsEventRequest {
+            events: vec![],
+            pagination: None,
+            order_by: OrderBy::Asc as i32,
+            page: page.unwrap_or(1),
+            limit: limit.unwrap_or(10),
+            query: query.clone(),
+        };
+        self.perform_query(req, Action::SearchTransactions(query))
+            .run()
+            .await
+            .map(|x| x.into_inner().tx_responses)
+    }
+
+    /// Like [Self::search_transactions], but include the decoded transactions and match count.
+    ///
+    /// Avoids a follow-up request per txhash when the fee, signers or
+    /// messages of each transaction are needed. `page` starts at 1.
+    pub async fn query_transactions(
+        &self,
+        query: impl Into<String>,
+        limit: Option<u64>,
+        page: Option<u64>,
+    ) -> Result<TransactionsPage, crate::Error> {
+        let query = query.into();
+        let action = Action::SearchTransactions(query.clone());
+        #[allow(deprecated)]
+        let req = GetTxsEventRequest {
+            events: vec![],
+            pagination: None,
+            order_by: OrderBy::Asc as i32,
+            page: page.unwrap_or(1),
+            limit: limit.unwrap_or(10),
+            query,
+        };
+        let res = self
+            .perform_query(req, action.clone())
+            .run()
+            .await?
+            .into_inner();
+        if res.txs.len() != res.tx_responses.len() {
+            return Err(crate::Error::InvalidChainResponse {
+                message: format!(
+                    "Mismatched counts: {} transactions, {} responses",
+                    res.txs.len(),
+                    res.tx_responses.len()
+                ),
+                action: action.into(),
+            });
+        }
+        Ok(TransactionsPage {
+            transactions: res
+                .txs
+                .into_iter()
+                .zip(res.tx_responses)
+                .map(|(tx, response)| CosmosTxResponse { response, tx })
+                .collect(),
+            total: res.total,
+        })
+    }
+
     /// attempt_number starts at 0
-    async fn gas_to_coins(&self, gas: u64, attempt_number: u64) -> u64 {
+    pub(crate) async fn gas_to_coins(&self, gas: u64, attempt_number: u64) -> u64 {
         let CurrentGasPrice { low, high, base: _ } = self.current_gas_price().await;
         let attempts = self.pool.builder.gas_price_retry_attempts();
 
@@ -1152,7 +1414,11 @@ impl Cosmos {
                 for node in self.pool.node_chooser.all_nodes() {
                     let _permit = self.pool.get_node_permit().await;
                     if let Ok(res) = self
-                        .perform_query_inner(GetBlockByHeightRequest { height }, node)
+                        .perform_query_inner(
+                            GetBlockByHeightRequest { height },
+                            node,
+                            self.pool.builder.get_action_timeout(action.category()),
+                        )
                         .await
                     {
                         let res = res.into_inner();
@@ -1196,6 +1462,102 @@ impl Cosmos {
         BlockInfo::new(action, res.block_id, res.sdk_block, res.block, None)
     }
 
+    /// Get the Cosmos SDK version reported by the node, e.g. `v0.50.9`.
+    pub async fn get_cosmos_sdk_version(&self) -> Result<String, crate::Error> {
+        let action = Action::GetNodeInfo;
+        let res = self
+            .perform_query(GetNodeInfoRequest {}, action.clone())
+            .run()
+            .await?
+            .into_inner();
+        res.application_version
+            .map(|version| version.cosmos_sdk_version)
+            .ok_or_else(|| crate::Error::InvalidChainResponse {
+                message: "Missing application_version in GetNodeInfoResponse".to_owned(),
+                action: action.into(),
+            })
+    }
+
+    /// Perform a raw ABCI query against the application store.
+    ///
+    /// This is an escape hatch for paths not covered by the typed gRPC
+    /// clients, e.g. `/store/bank/key` with `prove` set to get Merkle proofs
+    /// for light client verification. A height of 0 means the latest block.
+    /// Responses with a non-zero code are returned as errors.
+    pub async fn abci_query(
+        &self,
+        path: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        height: i64,
+        prove: bool,
+    ) -> Result<AbciQueryResponse, crate::Error> {
+        let path = path.into();
+        let action = Action::AbciQuery {
+            path: path.clone(),
+            height,
+        };
+        let res = self
+            .perform_query(
+                AbciQueryRequest {
+                    data: data.into(),
+                    path,
+                    height,
+                    prove,
+                },
+                action.clone(),
+            )
+            .run()
+            .await?
+            .into_inner();
+        if res.code != 0 {
+            return Err(crate::Error::InvalidChainResponse {
+                message: format!(
+                    "ABCI query failed with code {} in codespace {:?}: {}",
+                    res.code, res.codespace, res.log
+                ),
+                action: action.into(),
+            });
+        }
+        Ok(res)
+    }
+
+    /// Does this chain support unordered transactions?
+    ///
+    /// Checks that the chain is running Cosmos SDK 0.53 or later. See [TxBuilder::set_unordered].
+    pub async fn supports_unordered_transactions(&self) -> Result<bool, crate::Error> {
+        let version = self.get_cosmos_sdk_version().await?;
+        Ok(parse_sdk_version(&version).is_some_and(|version| version >= (0, 53)))
+    }
+
+    /// Get the type URLs of all messages registered with the chain.
+    ///
+    /// Uses the reflection service, which lists every implementation of the
+    /// `sdk.Msg` interface, e.g. `/cosmos.bank.v1beta1.MsgSend`.
+    pub async fn get_message_type_urls(&self) -> Result<Vec<String>, crate::Error> {
+        Ok(self
+            .perform_query(
+                ListImplementationsRequest {
+                    interface_name: "cosmos.base.v1beta1.Msg".to_owned(),
+                },
+                Action::ListMessageTypes,
+            )
+            .run()
+            .await?
+            .into_inner()
+            .implementation_message_names)
+    }
+
+    /// Does the chain accept messages with the given type URL?
+    ///
+    /// See [Self::get_message_type_urls].
+    pub async fn supports_message_type(&self, type_url: &str) -> Result<bool, crate::Error> {
+        Ok(self
+            .get_message_type_urls()
+            .await?
+            .iter()
+            .any(|registered| registered == type_url))
+    }
+
     /// Get the most recently seen block height.
     ///
     /// If no queries have been made, this will return 0.
@@ -1212,8 +1574,9 @@ impl Cosmos {
 
     /// Get the base gas price.
     ///
-    /// On Osmosis mainnet, this will be the base gas fee reported by the chain.
-    /// On all other chains, it will be the low price value.
+    /// On Osmosis mainnet and chains using the feemarket module, this will be
+    /// the base gas fee reported by the chain. On all other chains, it will be
+    /// the low price value.
     pub async fn get_base_gas_price(&self) -> f64 {
         self.current_gas_price().await.base
     }
@@ -1230,6 +1593,107 @@ impl Cosmos {
         self.pool.node_chooser.health_report()
     }
 
+    /// Run a query against one specific node, bypassing node selection and retries.
+    async fn perform_query_on_node<Request: GrpcRequest>(
+        &self,
+        node: &Node,
+        req: Request,
+        action: Action,
+    ) -> Result<Request::Response, QueryError> {
+        let timeout = self.pool.builder.get_action_timeout(action.category());
+        let res = {
+            let _permit = self.pool.get_node_permit().await;
+            self.perform_query_inner(req, node, timeout).await
+        };
+        res.map(tonic::Response::into_inner)
+            .map_err(|(query, _can_retry)| QueryError {
+                action,
+                builder: self.pool.builder.clone(),
+                height: self.height,
+                query,
+                grpc_url: node.grpc_url().clone(),
+                node_health: self.pool.node_chooser.health_report(),
+            })
+    }
+
+    async fn get_node_info_from(&self, node: &Node) -> Result<NodeInfo, crate::Error> {
+        let action = Action::GetNodeInfo;
+        let start = Instant::now();
+        let info = self
+            .perform_query_on_node(node, GetNodeInfoRequest {}, action.clone())
+            .await?;
+        let syncing = self
+            .perform_query_on_node(node, GetSyncingRequest {}, Action::GetSyncing)
+            .await?
+            .syncing;
+        let latency = start.elapsed();
+        let missing = |field: &str| crate::Error::InvalidChainResponse {
+            message: format!("Missing {field} in GetNodeInfoResponse"),
+            action: action.clone().into(),
+        };
+        let default_node_info = info
+            .default_node_info
+            .ok_or_else(|| missing("default_node_info"))?;
+        let application_version = info
+            .application_version
+            .ok_or_else(|| missing("application_version"))?;
+        Ok(NodeInfo {
+            grpc_url: node.grpc_url().clone(),
+            moniker: default_node_info.moniker,
+            network: default_node_info.network,
+            tendermint_version: default_node_info.version,
+            app_name: application_version.app_name,
+            app_version: application_version.version,
+            cosmos_sdk_version: application_version.cosmos_sdk_version,
+            syncing,
+            latency,
+        })
+    }
+
+    /// Get version and sync status of the node currently answering queries.
+    ///
+    /// Both underlying queries go to the same node, trying the next healthiest
+    /// node on failure.
+    pub async fn get_node_info(&self) -> Result<NodeInfo, crate::Error> {
+        let mut last_error = None;
+        for node in self.pool.node_chooser.choose_nodes() {
+            match self.get_node_info_from(&node).await {
+                Ok(info) => return Ok(info),
+                Err(err) => {
+                    tracing::debug!("Unable to get node info, trying next node: {err}");
+                    last_error = Some(err);
+                }
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            QueryError {
+                action: Action::GetNodeInfo,
+                builder: self.pool.builder.clone(),
+                height: self.height,
+                query: QueryErrorDetails::ConnectionError(ConnectionError::NoHealthyFound),
+                grpc_url: self.pool.builder.grpc_url_arc().clone(),
+                node_health: self.pool.node_chooser.health_report(),
+            }
+            .into()
+        }))
+    }
+
+    /// Get version and sync status from every configured node, including unhealthy ones.
+    ///
+    /// Useful for finding out which backend is misbehaving when using fallbacks.
+    pub async fn get_all_node_info(&self) -> Vec<(Arc<String>, Result<NodeInfo, crate::Error>)> {
+        let nodes = self
+            .pool
+            .node_chooser
+            .all_nodes()
+            .cloned()
+            .collect::<Vec<_>>();
+        futures::future::join_all(nodes.iter().map(|node| async move {
+            (node.grpc_url().clone(), self.get_node_info_from(node).await)
+        }))
+        .await
+    }
+
     /// Get the first block with a timestamp greater than or equal to the given timestamp.
     ///
     /// Takes an optional earliest block to start checking from.
@@ -1306,12 +1770,16 @@ impl Cosmos {
     }
 
     /// Attempt to broadcast a fully formed [Tx]
-    pub async fn broadcast_tx_raw(&self, tx: Tx) -> Result<BroadcastTxResponse, QueryError> {
+    pub async fn broadcast_tx_raw(
+        &self,
+        tx: Tx,
+        mode: BroadcastMode,
+    ) -> Result<BroadcastTxResponse, QueryError> {
         let PerformQueryWrapper { grpc_url: _, tonic } = self
             .perform_query(
                 BroadcastTxRequest {
                     tx_bytes: tx.encode_to_vec(),
-                    mode: BroadcastMode::Sync as i32,
+                    mode: cosmos_sdk_proto::cosmos::tx::v1beta1::BroadcastMode::from(mode) as i32,
                 },
                 Action::BroadcastRaw,
             )
@@ -1320,14 +1788,249 @@ impl Cosmos {
             .await?;
         Ok(tonic.into_inner())
     }
-}
 
-/// Information on a block.
-#[derive(Debug)]
-pub struct BlockInfo {
-    /// Block height
-    pub height: i64,
-    /// Hash of the block
+    /// Broadcast a fully formed [Tx], wait for it to land, and confirm that it was successful.
+    ///
+    /// Unlike [Self::broadcast_tx_raw], this uses the same all-node broadcast,
+    /// "already in mempool" tolerance, and waiting logic as
+    /// [TxBuilder::sign_and_broadcast]. This is intended for externally signed
+    /// transactions. Since the fee is covered by the signatures, no fee
+    /// escalation is possible here.
+    pub async fn broadcast_and_wait_raw(
+        &self,
+        tx: Tx,
+        options: BroadcastRawOptions,
+    ) -> Result<CosmosTxResponse, crate::Error> {
+        let (grpc_url, res) = self
+            .perform_query(
+                BroadcastTxRequest {
+                    tx_bytes: tx.encode_to_vec(),
+                    mode: cosmos_sdk_proto::cosmos::tx::v1beta1::BroadcastMode::from(options.mode)
+                        as i32,
+                },
+                Action::BroadcastRaw,
+            )
+            .all_nodes()
+            .run_broadcast(options.skip_code_check)
+            .await?;
+
+        let action = Action::WaitForTransaction(res.txhash.clone());
+        let (_, _, res) = self
+            .wait_for_transaction_with_action(res.txhash, Some(action.clone()))
+            .await?;
+        if !options.skip_code_check && res.code != 0 {
+            return Err(crate::Error::TransactionFailed {
+                code: CosmosSdkError::from_code(res.code, &res.codespace),
+                txhash: res.txhash.clone(),
+                raw_log: res.raw_log,
+                action: action.into(),
+                grpc_url,
+                stage: crate::error::TransactionStage::Wait,
+            });
+        }
+
+        Ok(CosmosTxResponse { response: res, tx })
+    }
+
+    /// Re-sign and rebroadcast a stuck transaction with a higher fee.
+    ///
+    /// The body and sequence number of the original transaction are kept
+    /// as-is, and every fee coin is multiplied by `bump_factor`, rounding up.
+    /// Since both transactions share a sequence number, at most one of them
+    /// will land.
+    ///
+    /// The bumped fee goes through the same maximum fee check and
+    /// [crate::FeeBudget] as any other broadcast. The budget charges it in
+    /// full, even though the original fee will not be paid if it lands.
+    ///
+    /// When providing a transaction hash, the original transaction is looked
+    /// up with [Self::get_mempool_transaction], which requires a CometBFT RPC
+    /// endpoint. Transactions which already landed cannot be rebroadcast.
+    pub async fn rebroadcast_with_higher_fee(
+        &self,
+        wallet: &dyn Signer,
+        original: impl Into<RebroadcastTarget>,
+        bump_factor: cosmwasm_std::Decimal,
+    ) -> Result<CosmosTxResponse, crate::Error> {
+        let cannot_rebroadcast = |reason: &str| crate::Error::CannotRebroadcast {
+            reason: reason.to_owned(),
+        };
+        if bump_factor <= cosmwasm_std::Decimal::one() {
+            return Err(cannot_rebroadcast("bump factor must be greater than 1"));
+        }
+
+        let (body, auth_info) = match original.into() {
+            RebroadcastTarget::Tx(tx) => (
+                tx.body
+                    .ok_or_else(|| cannot_rebroadcast("transaction has no body"))?,
+                tx.auth_info
+                    .ok_or_else(|| cannot_rebroadcast("transaction has no auth info"))?,
+            ),
+            RebroadcastTarget::TxHash(txhash) => {
+                let tx = self
+                    .get_mempool_transaction(&txhash)
+                    .await?
+                    .ok_or_else(|| {
+                        cannot_rebroadcast(&format!(
+                            "transaction {txhash} not found in the mempool"
+                        ))
+                    })?;
+                (
+                    tx.body
+                        .ok_or_else(|| cannot_rebroadcast("transaction has no body"))?,
+                    tx.auth_info
+                        .ok_or_else(|| cannot_rebroadcast("transaction has no auth info"))?,
+                )
+            }
+        };
+        let mut fee = auth_info
+            .fee
+            .ok_or_else(|| cannot_rebroadcast("transaction has no fee"))?;
+        let sequence = match auth_info.signer_infos.as_slice() {
+            [signer_info] => signer_info.sequence,
+            _ => {
+                return Err(cannot_rebroadcast(
+                    "only single signer transactions are supported",
+                ))
+            }
+        };
+        for coin in &mut fee.amount {
+            let amount = coin
+                .amount
+                .parse::<cosmwasm_std::Uint128>()
+                .map_err(|_| cannot_rebroadcast("invalid fee amount"))?;
+            coin.amount = amount
+                .checked_mul_ceil(bump_factor)
+                .map_err(|_| cannot_rebroadcast("bumped fee amount overflows"))?
+                .to_string();
+        }
+
+        // A plain builder broadcasts in sync mode and checks the response code
+        let txbuilder = TxBuilder::default();
+        let payer = if fee.granter.is_empty() {
+            wallet.address()
+        } else {
+            fee.granter
+                .parse()
+                .map_err(|_| cannot_rebroadcast("invalid fee granter"))?
+        };
+        let charges =
+            txbuilder.charge_fee(self, Some(payer), &fee.amount, || Action::BroadcastRaw)?;
+
+        let base_account = self.get_base_account(wallet.address()).await?;
+        #[allow(deprecated)]
+        let auth_info = AuthInfo {
+            signer_infos: vec![txbuilder.make_signer_info(sequence, Some(wallet.public_key()))],
+            fee: Some(fee),
+            tip: None,
+        };
+        let sign_doc = SignDoc {
+            body_bytes: body.encode_to_vec(),
+            auth_info_bytes: auth_info.encode_to_vec(),
+            chain_id: self.pool.builder.chain_id().to_owned(),
+            account_number: base_account.account_number,
+        };
+        let signature = wallet.sign(&sign_doc.encode_to_vec()).await?;
+        let tx = Tx {
+            body: Some(body),
+            auth_info: Some(auth_info),
+            signatures: vec![signature],
+        };
+
+        let (grpc_url, res) = txbuilder
+            .broadcast_charged(self, tx.encode_to_vec(), charges, Action::BroadcastRaw)
+            .await?;
+        let action = Action::WaitForRebroadcast {
+            txhash: res.txhash.clone(),
+        };
+        let (_, _, res) = self
+            .wait_for_transaction_with_action(res.txhash, Some(action.clone()))
+            .await?;
+        if res.code != 0 {
+            return Err(crate::Error::TransactionFailed {
+                code: CosmosSdkError::from_code(res.code, &res.codespace),
+                txhash: res.txhash.clone(),
+                raw_log: res.raw_log,
+                action: action.into(),
+                grpc_url,
+                stage: crate::error::TransactionStage::Wait,
+            });
+        }
+        Ok(CosmosTxResponse { response: res, tx })
+    }
+}
+
+/// Options for [Cosmos::broadcast_and_wait_raw].
+#[derive(Debug, Clone, Default)]
+pub struct BroadcastRawOptions {
+    /// Broadcast mode to use.
+    pub mode: BroadcastMode,
+    /// Skip the check of whether the code is 0.
+    pub skip_code_check: bool,
+}
+
+/// A transaction to be rebroadcast by [Cosmos::rebroadcast_with_higher_fee].
+#[derive(Debug)]
+pub enum RebroadcastTarget {
+    /// The original signed transaction.
+    Tx(Tx),
+    /// Hash of the original transaction, looked up from the mempool.
+    TxHash(String),
+}
+
+impl From<Tx> for RebroadcastTarget {
+    fn from(tx: Tx) -> Self {
+        RebroadcastTarget::Tx(tx)
+    }
+}
+
+impl From<CosmosTxResponse> for RebroadcastTarget {
+    fn from(res: CosmosTxResponse) -> Self {
+        RebroadcastTarget::Tx(res.tx)
+    }
+}
+
+impl From<String> for RebroadcastTarget {
+    fn from(txhash: String) -> Self {
+        RebroadcastTarget::TxHash(txhash)
+    }
+}
+
+impl From<&str> for RebroadcastTarget {
+    fn from(txhash: &str) -> Self {
+        RebroadcastTarget::TxHash(txhash.to_owned())
+    }
+}
+
+/// Version and sync information reported by a single node.
+#[derive(Clone, Debug)]
+pub struct NodeInfo {
+    /// gRPC endpoint of the node which answered
+    pub grpc_url: Arc<String>,
+    /// Node moniker
+    pub moniker: String,
+    /// Chain ID the node is running
+    pub network: String,
+    /// CometBFT version
+    pub tendermint_version: String,
+    /// Application binary name, e.g. `osmosisd`
+    pub app_name: String,
+    /// Application version
+    pub app_version: String,
+    /// Cosmos SDK version, e.g. `v0.50.9`
+    pub cosmos_sdk_version: String,
+    /// Whether the node reports that it is still catching up
+    pub syncing: bool,
+    /// Round trip time for the node info and syncing queries together
+    pub latency: tokio::time::Duration,
+}
+
+/// Information on a block.
+#[derive(Debug)]
+pub struct BlockInfo {
+    /// Block height
+    pub height: i64,
+    /// Hash of the block
     pub block_hash: String,
     /// Timestamp of the block
     pub timestamp: DateTime<Utc>,
@@ -1407,14 +2110,21 @@ impl TxBuilder {
     /// Simulate the transaction with the given signer or signers.
     ///
     /// Note that for simulation purposes you do not need to provide valid
-    /// signatures, so only the signer addresses are needed.
-    pub async fn simulate(
+    /// signatures, so only the signer addresses are needed. Pass a
+    /// [WatchWallet] with a public key to simulate accounts which have not
+    /// yet signed anything on chain more accurately.
+    pub async fn simulate<W: Clone + Into<WatchWallet>>(
         &self,
         cosmos: &Cosmos,
-        wallets: &[Address],
+        wallets: &[W],
     ) -> Result<FullSimulateResponse, crate::Error> {
-        let mut sequences = vec![];
-        for wallet in wallets {
+        let wallets = wallets
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect::<Vec<WatchWallet>>();
+        let mut signers = vec![];
+        for wallet in &wallets {
             let base_account = cosmos
                 .get_and_update_simulation_sequence(wallet.get_address())
                 .await;
@@ -1431,10 +2141,10 @@ impl TxBuilder {
                     }
                 }
             };
-            sequences.push(sequence);
+            signers.push((sequence, wallet.public_key().cloned()));
         }
 
-        let result = self.simulate_inner(cosmos, &sequences).await;
+        let result = self.simulate_inner(cosmos, &signers).await;
         if let Err(err) = &result {
             if wallets.len() == 1 {
                 let err = err.get_sequence_mismatch_status();
@@ -1442,7 +2152,12 @@ impl TxBuilder {
                     let sequence = cosmos.get_expected_sequence(status.message());
                     match sequence {
                         Some(new_sequence_no) => {
-                            let result = self.simulate_inner(cosmos, &[new_sequence_no]).await;
+                            let result = self
+                                .simulate_inner(
+                                    cosmos,
+                                    &[(new_sequence_no, wallets[0].public_key().cloned())],
+                                )
+                                .await;
                             if result.is_ok() {
                                 tracing::info!("Retry of broadcast simulation failure succeeded with new sequence number of {new_sequence_no}");
                             } else {
@@ -1458,13 +2173,37 @@ impl TxBuilder {
         result
     }
 
+    /// Simulate the transaction without affecting locally tracked sequence numbers.
+    ///
+    /// Unlike [Self::simulate], this always uses the on-chain sequence
+    /// numbers and does not retry on sequence mismatch. Use the events and
+    /// message responses on the result to check what the transaction would
+    /// do before broadcasting it.
+    pub async fn dry_run<W: Clone + Into<WatchWallet>>(
+        &self,
+        cosmos: &Cosmos,
+        wallets: &[W],
+    ) -> Result<FullSimulateResponse, crate::Error> {
+        let mut signers = vec![];
+        for wallet in wallets {
+            let wallet: WatchWallet = wallet.clone().into();
+            let sequence = match cosmos.get_base_account(wallet.get_address()).await {
+                Ok(account) => account.sequence,
+                Err(err) if err.to_string().contains("not found") => 0,
+                Err(err) => return Err(err),
+            };
+            signers.push((sequence, wallet.public_key().cloned()));
+        }
+        self.simulate_inner(cosmos, &signers).await
+    }
+
     /// Sign transaction, broadcast, wait for it to complete, confirm that it was successful
     /// the gas amount is determined automatically by running a simulation first and padding by a multiplier
     /// the multiplier can by adjusted by calling [CosmosBuilder::set_gas_estimate_multiplier]
     pub async fn sign_and_broadcast(
         &self,
         cosmos: &Cosmos,
-        wallet: &Wallet,
+        wallet: &dyn Signer,
     ) -> Result<TxResponse, crate::Error> {
         self.sign_and_broadcast_cosmos_tx(cosmos, wallet)
             .await
@@ -1475,21 +2214,37 @@ impl TxBuilder {
     pub async fn sign_and_broadcast_cosmos_tx(
         &self,
         cosmos: &Cosmos,
-        wallet: &Wallet,
+        wallet: &dyn Signer,
     ) -> Result<CosmosTxResponse, crate::Error> {
+        if cosmos.get_cosmos_builder().get_use_static_gas_estimates() {
+            if let Some(gas) = self.estimate_gas_static(cosmos) {
+                return self
+                    .inner_sign_and_broadcast_cosmos(cosmos, wallet, self.make_tx_body(), gas)
+                    .await;
+            }
+        }
+
+        let message_multiplier = self.message_gas_multiplier(cosmos);
         let mut attempts = 0;
         loop {
-            let simres = self.simulate(cosmos, &[wallet.get_address()]).await?;
+            let (body, gas_used, key) = self.simulate_gas(cosmos, &[wallet.address()]).await?;
+            let multiplier =
+                message_multiplier.unwrap_or_else(|| cosmos.gas_multiplier.get_current());
             let res = self
                 .inner_sign_and_broadcast_cosmos(
                     cosmos,
                     wallet,
-                    simres.body,
+                    body,
                     // Gas estimation is not perfect, so we need to adjust it by a multiplier to account for drift
                     // Since we're already estimating and padding, the loss of precision from f64 to u64 is negligible
-                    (simres.gas_used as f64 * cosmos.gas_multiplier.get_current()) as u64,
+                    (gas_used as f64 * multiplier) as u64,
                 )
                 .await;
+            cosmos.record_gas_statistics(gas_used, multiplier, &res);
+            cosmos.forget_simulation_on_out_of_gas(key, &res);
+            if message_multiplier.is_some() {
+                break res;
+            }
             let did_update = cosmos.gas_multiplier.update(&res);
             if !did_update {
                 break res;
@@ -1514,12 +2269,63 @@ impl TxBuilder {
         }
     }
 
+    /// Simulate to get the gas used, or reuse a cached result.
+    ///
+    /// Returns the cache key if the simulation cache is enabled, see
+    /// [CosmosBuilder::set_simulation_cache_duration].
+    async fn simulate_gas(
+        &self,
+        cosmos: &Cosmos,
+        signers: &[Address],
+    ) -> Result<(TxBody, u64, Option<SimulationKey>), crate::Error> {
+        let Some(duration) = cosmos.get_cosmos_builder().get_simulation_cache_duration() else {
+            let simres = self.simulate(cosmos, signers).await?;
+            return Ok((simres.body, simres.gas_used, None));
+        };
+        let key = self.simulation_key(signers);
+        let cached = cosmos.tracking.simulation_cache.lock().get(&key, duration);
+        if let Some(gas_used) = cached {
+            return Ok((self.make_tx_body(), gas_used, Some(key)));
+        }
+        let simres = self.simulate(cosmos, signers).await?;
+        cosmos
+            .tracking
+            .simulation_cache
+            .lock()
+            .insert(key, simres.gas_used, duration);
+        Ok((simres.body, simres.gas_used, Some(key)))
+    }
+
+    /// Gas multiplier configured for this transaction's dominant message type, if any.
+    fn message_gas_multiplier(&self, cosmos: &Cosmos) -> Option<f64> {
+        cosmos
+            .get_cosmos_builder()
+            .get_message_gas_multiplier(self.dominant_type_url()?)
+    }
+
+    /// Estimate gas for this transaction without simulating it.
+    ///
+    /// Uses the table configured with [CosmosBuilder::set_static_gas_estimate],
+    /// plus [CosmosBuilder::get_static_gas_base] and 10 gas per memo byte.
+    /// Returns [None] if any message has no static estimate.
+    pub fn estimate_gas_static(&self, cosmos: &Cosmos) -> Option<u64> {
+        let builder = cosmos.get_cosmos_builder();
+        let memo_len = self.memo.as_deref().map_or(0, str::len) as u64;
+        self.messages
+            .iter()
+            .try_fold(builder.get_static_gas_base() + memo_len * 10, |gas, msg| {
+                builder
+                    .get_static_gas_estimate(msg.type_url())
+                    .map(|msg_gas| gas + msg_gas)
+            })
+    }
+
     /// Sign transaction, broadcast, wait for it to complete, confirm that it was successful
     /// unlike sign_and_broadcast(), the gas amount is explicit here and therefore no simulation is run
     pub async fn sign_and_broadcast_with_gas(
         &self,
         cosmos: &Cosmos,
-        wallet: &Wallet,
+        wallet: &dyn Signer,
         gas_to_request: u64,
     ) -> Result<TxResponse, crate::Error> {
         self.inner_sign_and_broadcast_cosmos(cosmos, wallet, self.make_tx_body(), gas_to_request)
@@ -1531,47 +2337,121 @@ impl TxBuilder {
     pub async fn sign_and_broadcast_with_cosmos_gas(
         &self,
         cosmos: &Cosmos,
-        wallet: &Wallet,
+        wallet: &dyn Signer,
         gas_to_request: u64,
     ) -> Result<CosmosTxResponse, crate::Error> {
         let base_account = cosmos
-            .get_and_update_broadcast_sequence(wallet.get_address())
+            .get_and_update_sequence(self.sequence_kind(), wallet.address())
             .await?;
         self.sign_and_broadcast_with_inner(
             cosmos,
-            wallet,
-            &base_account,
-            base_account.sequence,
+            &[SignerAccount {
+                signer: wallet,
+                account_number: base_account.account_number,
+                sequence: base_account.sequence,
+            }],
             self.make_tx_body(),
             gas_to_request,
         )
         .await
     }
 
+    /// Sign a transaction with multiple signers, broadcast, wait for it to complete, and confirm that it was successful.
+    ///
+    /// Gas is determined by simulation, the same as [Self::sign_and_broadcast].
+    /// Each signer's sequence number is tracked independently. The first
+    /// signer is used as the fee payer.
+    pub async fn sign_and_broadcast_multi<S: Signer>(
+        &self,
+        cosmos: &Cosmos,
+        signers: &[S],
+    ) -> Result<CosmosTxResponse, crate::Error> {
+        if signers.is_empty() {
+            return Err(crate::Error::NoSignersProvided);
+        }
+        let addresses = signers
+            .iter()
+            .map(|signer| signer.address())
+            .collect::<Vec<_>>();
+        let (body, gas_used, key) = self.simulate_gas(cosmos, &addresses).await?;
+
+        let mut accounts = Vec::with_capacity(signers.len());
+        for signer in signers {
+            let base_account = match cosmos
+                .get_and_update_sequence(self.sequence_kind(), signer.address())
+                .await
+            {
+                Ok(base_account) => base_account,
+                Err(e) => {
+                    cosmos
+                        .release_sequences(
+                            self.sequence_kind(),
+                            accounts
+                                .iter()
+                                .map(|account| (account.signer.address(), account.sequence)),
+                        )
+                        .await;
+                    return Err(e);
+                }
+            };
+            accounts.push(SignerAccount {
+                signer,
+                account_number: base_account.account_number,
+                sequence: base_account.sequence,
+            });
+        }
+
+        let message_multiplier = self.message_gas_multiplier(cosmos);
+        let multiplier = message_multiplier.unwrap_or_else(|| cosmos.gas_multiplier.get_current());
+        let res = self
+            .sign_and_broadcast_with_inner(
+                cosmos,
+                &accounts,
+                body,
+                (gas_used as f64 * multiplier) as u64,
+            )
+            .await;
+        cosmos.record_gas_statistics(gas_used, multiplier, &res);
+        cosmos.forget_simulation_on_out_of_gas(key, &res);
+        if message_multiplier.is_none() {
+            cosmos.gas_multiplier.update(&res);
+        }
+        res
+    }
+
     async fn inner_sign_and_broadcast_cosmos(
         &self,
         cosmos: &Cosmos,
-        wallet: &Wallet,
+        wallet: &dyn Signer,
         body: TxBody,
         gas_to_request: u64,
     ) -> Result<CosmosTxResponse, crate::Error> {
         let base_account = cosmos
-            .get_and_update_broadcast_sequence(wallet.get_address())
+            .get_and_update_sequence(self.sequence_kind(), wallet.address())
             .await?;
         self.sign_and_broadcast_with_cosmos_tx(
             cosmos,
             wallet,
             &base_account,
-            base_account.sequence,
             body.clone(),
             gas_to_request,
         )
         .await
     }
 
-    fn make_signer_info(&self, sequence: u64, wallet: Option<&Wallet>) -> SignerInfo {
+    fn make_signer_info(&self, sequence: u64, public_key: Option<WalletPublicKey>) -> SignerInfo {
+        // SIGN_MODE_DIRECT
+        self.make_signer_info_with_mode(sequence, public_key, 1)
+    }
+
+    pub(crate) fn make_signer_info_with_mode(
+        &self,
+        sequence: u64,
+        public_key: Option<WalletPublicKey>,
+        mode: i32,
+    ) -> SignerInfo {
         SignerInfo {
-            public_key: match wallet {
+            public_key: match public_key {
                 // No wallet/base account. We're simulating. Fill in a dummy value.
                 None => Some(cosmos_sdk_proto::Any {
                     type_url: "/cosmos.crypto.secp256k1.PubKey".to_owned(),
@@ -1582,8 +2462,8 @@ impl TxBuilder {
                     }
                     .encode_to_vec(),
                 }),
-                Some(wallet) => {
-                    match wallet.public_key {
+                Some(public_key) => {
+                    match public_key {
                         // Use the Cosmos method of public key
                         WalletPublicKey::Cosmos(public_key) => Some(cosmos_sdk_proto::Any {
                             type_url: "/cosmos.crypto.secp256k1.PubKey".to_owned(),
@@ -1608,13 +2488,20 @@ impl TxBuilder {
                             }
                             .encode_to_vec(),
                         }),
+                        WalletPublicKey::Ed25519(public_key) => Some(cosmos_sdk_proto::Any {
+                            type_url: "/cosmos.crypto.ed25519.PubKey".to_owned(),
+                            value: cosmos_sdk_proto::cosmos::crypto::ed25519::PubKey {
+                                key: public_key.to_vec(),
+                            }
+                            .encode_to_vec(),
+                        }),
                     }
                 }
             },
             mode_info: Some(ModeInfo {
                 sum: Some(
                     cosmos_sdk_proto::cosmos::tx::v1beta1::mode_info::Sum::Single(
-                        cosmos_sdk_proto::cosmos::tx::v1beta1::mode_info::Single { mode: 1 },
+                        cosmos_sdk_proto::cosmos::tx::v1beta1::mode_info::Single { mode },
                     ),
                 ),
             }),
@@ -1623,21 +2510,23 @@ impl TxBuilder {
     }
 
     /// Make a [TxBody] for this builder
-    fn make_tx_body(&self) -> TxBody {
+    pub(crate) fn make_tx_body(&self) -> TxBody {
         TxBody {
             messages: self.messages.iter().map(|msg| msg.get_protobuf()).collect(),
             memo: self.memo.as_deref().unwrap_or_default().to_owned(),
-            timeout_height: 0,
+            timeout_height: self.timeout_height.unwrap_or_default(),
             extension_options: vec![],
             non_critical_extension_options: vec![],
         }
     }
 
     /// Simulate to calculate the gas costs
+    ///
+    /// Each signer is given as its sequence number and, if known, public key.
     async fn simulate_inner(
         &self,
         cosmos: &Cosmos,
-        sequences: &[u64],
+        signers: &[(u64, Option<WalletPublicKey>)],
     ) -> Result<FullSimulateResponse, crate::Error> {
         let body = self.make_tx_body();
         let gas_coin = cosmos.pool.builder.gas_coin();
@@ -1649,7 +2538,10 @@ impl TxBuilder {
                 fee: Some(Fee {
                     amount: if cosmos.pool.builder.get_simulate_with_gas_coin() {
                         vec![Coin {
-                            denom: gas_coin.to_owned(),
+                            denom: self
+                                .effective_fee_denom(cosmos)
+                                .unwrap_or(gas_coin)
+                                .to_owned(),
                             amount: "1".to_owned(),
                         }]
                     } else {
@@ -1657,22 +2549,33 @@ impl TxBuilder {
                     },
                     gas_limit: 0,
                     payer: "".to_owned(),
-                    granter: "".to_owned(),
+                    granter: self.fee_granter_string(),
                 }),
-                signer_infos: sequences
+                signer_infos: signers
                     .iter()
-                    .map(|sequence| self.make_signer_info(*sequence, None))
+                    .map(|(sequence, public_key)| {
+                        self.make_signer_info(self.tx_sequence(*sequence), public_key.clone())
+                    })
                     .collect(),
-                tip: None,
+                tip: self.make_tip(),
             }),
-            signatures: sequences.iter().map(|_| vec![]).collect(),
+            signatures: signers.iter().map(|_| vec![]).collect(),
             body: Some(body.clone()),
         };
 
         #[allow(deprecated)]
         let simulate_req = SimulateRequest {
             tx: None,
-            tx_bytes: simulate_tx.encode_to_vec(),
+            tx_bytes: TxRaw {
+                body_bytes: self.encode_tx_body(&body),
+                auth_info_bytes: simulate_tx
+                    .auth_info
+                    .as_ref()
+                    .map(Message::encode_to_vec)
+                    .unwrap_or_default(),
+                signatures: simulate_tx.signatures,
+            }
+            .encode_to_vec(),
         };
 
         let action = Action::Simulate(self.clone());
@@ -1691,39 +2594,333 @@ impl TxBuilder {
             })?
             .gas_used;
 
-        Ok(FullSimulateResponse {
-            body,
-            simres,
-            gas_used,
-        })
+        Ok(FullSimulateResponse::new(body, simres, gas_used))
     }
 
     async fn sign_and_broadcast_with_cosmos_tx(
         &self,
         cosmos: &Cosmos,
-        wallet: &Wallet,
+        wallet: &dyn Signer,
         base_account: &BaseAccount,
-        sequence: u64,
         body: TxBody,
         gas_to_request: u64,
     ) -> Result<CosmosTxResponse, crate::Error> {
         self.sign_and_broadcast_with_inner(
             cosmos,
-            wallet,
-            base_account,
-            sequence,
+            &[SignerAccount {
+                signer: wallet,
+                account_number: base_account.account_number,
+                sequence: base_account.sequence,
+            }],
             body,
             gas_to_request,
         )
         .await
     }
 
+    /// Sign the transaction without broadcasting it.
+    ///
+    /// Returns the encoded transaction bytes, ready to be broadcast by any
+    /// node or relayer. The account and sequence numbers are determined the
+    /// same way as [Self::sign_and_broadcast], and the sequence number is
+    /// reserved in the [SequenceStore] so that transactions signed back to
+    /// back do not collide. If the signed transaction is never broadcast, the
+    /// reservation is discarded once the record is older than 30 seconds.
+    pub async fn sign(
+        &self,
+        cosmos: &Cosmos,
+        wallet: &dyn Signer,
+        gas_to_request: u64,
+        fee: Coin,
+    ) -> Result<Vec<u8>, crate::Error> {
+        let base_account = cosmos
+            .get_and_update_sequence(self.sequence_kind(), wallet.address())
+            .await?;
+        let (_, tx_bytes) = self
+            .make_signed_tx(
+                cosmos,
+                &[SignerAccount {
+                    signer: wallet,
+                    account_number: base_account.account_number,
+                    sequence: base_account.sequence,
+                }],
+                &self.make_tx_body(),
+                gas_to_request,
+                fee,
+            )
+            .await?;
+        Ok(tx_bytes)
+    }
+
+    /// Returns the signed transaction and its encoded bytes.
+    ///
+    /// The bytes are encoded directly from the signed body and auth info,
+    /// since [Tx] cannot represent the unordered transaction fields.
+    async fn make_signed_tx(
+        &self,
+        cosmos: &Cosmos,
+        signers: &[SignerAccount<'_>],
+        body: &TxBody,
+        gas_to_request: u64,
+        fee: Coin,
+    ) -> Result<(Tx, Vec<u8>), crate::Error> {
+        #[allow(deprecated)]
+        let auth_info = AuthInfo {
+            signer_infos: signers
+                .iter()
+                .map(|account| {
+                    self.make_signer_info(
+                        self.tx_sequence(account.sequence),
+                        Some(account.signer.public_key()),
+                    )
+                })
+                .collect(),
+            fee: Some(Fee {
+                amount: vec![fee],
+                gas_limit: gas_to_request,
+                payer: "".to_owned(),
+                granter: self.fee_granter_string(),
+            }),
+            tip: self.make_tip(),
+        };
+
+        let body_bytes = self.encode_tx_body(body);
+        let auth_info_bytes = auth_info.encode_to_vec();
+        let mut signatures = Vec::with_capacity(signers.len());
+        for account in signers {
+            let sign_doc = SignDoc {
+                body_bytes: body_bytes.clone(),
+                auth_info_bytes: auth_info_bytes.clone(),
+                chain_id: cosmos.pool.builder.chain_id().to_owned(),
+                account_number: account.account_number,
+            };
+            let sign_doc_bytes = sign_doc.encode_to_vec();
+            signatures.push(account.signer.sign(&sign_doc_bytes).await?);
+        }
+
+        let tx_bytes = TxRaw {
+            body_bytes,
+            auth_info_bytes,
+            signatures: signatures.clone(),
+        }
+        .encode_to_vec();
+        Ok((
+            Tx {
+                body: Some(body.clone()),
+                auth_info: Some(auth_info),
+                signatures,
+            },
+            tx_bytes,
+        ))
+    }
+
+    /// Check a fee against the maximum and charge it to the [crate::FeeBudget], if any.
+    ///
+    /// Every coin is charged to `payer` separately. The charges are refunded
+    /// when dropped, unless [Self::broadcast_charged] commits them.
+    pub(crate) fn charge_fee(
+        &self,
+        cosmos: &Cosmos,
+        payer: Option<Address>,
+        fee: &[Coin],
+        mk_action: impl Fn() -> Action,
+    ) -> Result<Vec<FeeCharge>, crate::Error> {
+        for coin in fee {
+            self.check_max_fee(cosmos, coin, &mk_action)?;
+        }
+        let (Some(budget), Some(payer)) = (cosmos.get_cosmos_builder().get_fee_budget(), payer)
+        else {
+            return Ok(vec![]);
+        };
+        fee.iter()
+            .map(|coin| {
+                budget
+                    .charge(payer, coin)
+                    .map_err(|spent| crate::Error::FeeBudgetExceeded {
+                        address: payer,
+                        spent,
+                        fee: coin.clone(),
+                        limit: Coin {
+                            denom: coin.denom.clone(),
+                            amount: budget.get_limit(&coin.denom).to_string(),
+                        },
+                        action: mk_action().into(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Broadcast a signed transaction to all nodes, without waiting for it to land.
+    ///
+    /// The fee charges are kept unless the transaction definitely never
+    /// reached the chain, since after e.g. a timeout it may still land and
+    /// pay its fee.
+    pub(crate) async fn broadcast_charged(
+        &self,
+        cosmos: &Cosmos,
+        tx_bytes: Vec<u8>,
+        charges: Vec<FeeCharge>,
+        action: Action,
+    ) -> Result<(Arc<String>, TxResponse), crate::Error> {
+        let res = cosmos
+            .perform_query(
+                BroadcastTxRequest {
+                    tx_bytes,
+                    mode: cosmos_sdk_proto::cosmos::tx::v1beta1::BroadcastMode::from(
+                        self.broadcast_mode,
+                    ) as i32,
+                },
+                action,
+            )
+            .all_nodes()
+            .run_broadcast(self.skip_code_check)
+            .await;
+        if !matches!(&res, Err(e) if e.is_definite_tx_failure()) {
+            charges.into_iter().for_each(FeeCharge::commit);
+        }
+        res
+    }
+
+    /// Sign and broadcast with the given signers and fee amount, without
+    /// waiting for the transaction to land.
+    pub(crate) async fn sign_and_broadcast_no_wait(
+        &self,
+        cosmos: &Cosmos,
+        signers: &[SignerAccount<'_>],
+        body: &TxBody,
+        gas_to_request: u64,
+        amount: u64,
+    ) -> Result<(Arc<String>, Tx, TxResponse), crate::Error> {
+        let amount = cosmos
+            .make_fee_coin(
+                self.effective_fee_denom(cosmos),
+                self.apply_priority_fee(amount),
+            )
+            .await?;
+        let mk_action = || Action::Broadcast {
+            txbuilder: self.clone(),
+            gas_wanted: gas_to_request,
+            fee: amount.clone(),
+        };
+        let payer = self
+            .fee_granter
+            .or_else(|| signers.first().map(|account| account.signer.address()));
+        let charges = self.charge_fee(cosmos, payer, std::slice::from_ref(&amount), mk_action)?;
+        let (tx, tx_bytes) = self
+            .make_signed_tx(cosmos, signers, body, gas_to_request, amount.clone())
+            .await?;
+        let (grpc_url, res) = self
+            .broadcast_charged(cosmos, tx_bytes, charges, mk_action())
+            .await?;
+        Ok((grpc_url, tx, res))
+    }
+
+    /// Sign and broadcast using sequence numbers reserved with [Self::sequence_kind].
+    ///
+    /// If the transaction definitely never reached the chain, the reserved
+    /// sequence numbers are given back to the [SequenceStore] so that later
+    /// transactions do not leave a gap.
     async fn sign_and_broadcast_with_inner(
         &self,
         cosmos: &Cosmos,
-        wallet: &Wallet,
-        base_account: &BaseAccount,
-        sequence: u64,
+        signers: &[SignerAccount<'_>],
+        body: TxBody,
+        gas_to_request: u64,
+    ) -> Result<CosmosTxResponse, crate::Error> {
+        let res = self
+            .sign_and_broadcast_with_retries(cosmos, signers, body, gas_to_request)
+            .await;
+        if matches!(&res, Err(e) if e.is_definite_tx_failure()) {
+            cosmos
+                .release_sequences(
+                    self.sequence_kind(),
+                    signers
+                        .iter()
+                        .map(|account| (account.signer.address(), account.sequence)),
+                )
+                .await;
+        }
+        res
+    }
+
+    /// Same as [Self::sign_and_broadcast_with_fee_ladder], but retries with a
+    /// corrected sequence number if the broadcast fails with a sequence mismatch.
+    ///
+    /// Only applies to single signer transactions, and only when
+    /// [CosmosBuilder::autofix_sequence_mismatch] is enabled.
+    async fn sign_and_broadcast_with_retries(
+        &self,
+        cosmos: &Cosmos,
+        signers: &[SignerAccount<'_>],
+        body: TxBody,
+        gas_to_request: u64,
+    ) -> Result<CosmosTxResponse, crate::Error> {
+        let builder = cosmos.get_cosmos_builder();
+        if !builder.autofix_sequence_mismatch() {
+            return self
+                .sign_and_broadcast_with_fee_ladder(cosmos, signers, body, gas_to_request)
+                .await;
+        }
+        let mut signers = signers.to_vec();
+        let allowed = builder.get_broadcast_sequence_mismatch_retries();
+        let mut attempts = 0;
+        loop {
+            let res = self
+                .sign_and_broadcast_with_fee_ladder(cosmos, &signers, body.clone(), gas_to_request)
+                .await;
+            let message = match &res {
+                Err(crate::Error::TransactionFailed {
+                    code: CosmosSdkError::IncorrectAccountSequence,
+                    raw_log,
+                    stage: crate::error::TransactionStage::Broadcast,
+                    ..
+                }) => Some(raw_log.clone()),
+                Err(e) => e
+                    .get_sequence_mismatch_status()
+                    .map(|status| status.message().to_owned()),
+                Ok(_) => None,
+            };
+            let new_sequence = match (message, signers.as_mut_slice()) {
+                (Some(message), [signer]) if attempts < allowed => {
+                    get_expected_sequence_inner(&message)
+                        .filter(|new_sequence| *new_sequence != signer.sequence)
+                        .map(|new_sequence| (signer, new_sequence))
+                }
+                _ => None,
+            };
+            match new_sequence {
+                Some((signer, new_sequence)) => {
+                    attempts += 1;
+                    tracing::warn!(
+                        "Sequence mismatch while broadcasting from {}, retrying with sequence {new_sequence} ({attempts}/{allowed})",
+                        signer.signer.address()
+                    );
+                    signer.sequence = new_sequence;
+                }
+                None => {
+                    // Write the corrected sequence back so the next
+                    // reservation doesn't repeat the mismatch.
+                    if let (Ok(_), [signer]) = (&res, signers.as_slice()) {
+                        if attempts > 0 {
+                            cosmos
+                                .record_used_sequence(
+                                    self.sequence_kind(),
+                                    signer.signer.address(),
+                                    signer.sequence,
+                                )
+                                .await;
+                        }
+                    }
+                    break res;
+                }
+            }
+        }
+    }
+
+    async fn sign_and_broadcast_with_fee_ladder(
+        &self,
+        cosmos: &Cosmos,
+        signers: &[SignerAccount<'_>],
         body: TxBody,
         gas_to_request: u64,
     ) -> Result<CosmosTxResponse, crate::Error> {
@@ -1738,53 +2935,8 @@ impl TxBuilder {
         // }
         let body_ref = &body;
         let retry_with_price = |amount| async move {
-            let amount = Coin {
-                denom: cosmos.pool.builder.gas_coin().to_owned(),
-                amount,
-            };
-            #[allow(deprecated)]
-            let auth_info = AuthInfo {
-                signer_infos: vec![self.make_signer_info(sequence, Some(wallet))],
-                fee: Some(Fee {
-                    amount: vec![amount.clone()],
-                    gas_limit: gas_to_request,
-                    payer: "".to_owned(),
-                    granter: "".to_owned(),
-                }),
-                tip: None,
-            };
-
-            let sign_doc = SignDoc {
-                body_bytes: body_ref.encode_to_vec(),
-                auth_info_bytes: auth_info.encode_to_vec(),
-                chain_id: cosmos.pool.builder.chain_id().to_owned(),
-                account_number: base_account.account_number,
-            };
-            let sign_doc_bytes = sign_doc.encode_to_vec();
-            let signature = wallet.sign_bytes(&sign_doc_bytes);
-
-            let tx = Tx {
-                body: Some(body_ref.clone()),
-                auth_info: Some(auth_info),
-                signatures: vec![signature.serialize_compact().to_vec()],
-            };
-
-            let mk_action = move || Action::Broadcast {
-                txbuilder: self.clone(),
-                gas_wanted: gas_to_request,
-                fee: amount.clone(),
-            };
-
-            let (grpc_url, res) = cosmos
-                .perform_query(
-                    BroadcastTxRequest {
-                        tx_bytes: tx.encode_to_vec(),
-                        mode: BroadcastMode::Sync as i32,
-                    },
-                    mk_action(),
-                )
-                .all_nodes()
-                .run_broadcast(self.skip_code_check)
+            let (grpc_url, tx, res) = self
+                .sign_and_broadcast_no_wait(cosmos, signers, body_ref, gas_to_request, amount)
                 .await?;
 
             let action = Action::WaitForBroadcast {
@@ -1807,19 +2959,13 @@ impl TxBuilder {
             };
 
             tracing::debug!("TxResponse: {res:?}");
-            cosmos
-                .update_broadcast_sequence(wallet.get_address(), &tx, &res.txhash)
-                .await?;
 
             Ok(CosmosTxResponse { response: res, tx })
         };
 
         let attempts = cosmos.get_cosmos_builder().gas_price_retry_attempts();
         for attempt_number in 0..attempts {
-            let amount = cosmos
-                .gas_to_coins(gas_to_request, attempt_number)
-                .await
-                .to_string();
+            let amount = cosmos.gas_to_coins(gas_to_request, attempt_number).await;
             match retry_with_price(amount).await {
                 Err(crate::Error::TransactionFailed {
                     code: CosmosSdkError::InsufficientFee,
@@ -1838,19 +2984,52 @@ impl TxBuilder {
             }
         }
 
-        let amount = cosmos
-            .gas_to_coins(gas_to_request, attempts)
-            .await
-            .to_string();
+        let amount = cosmos.gas_to_coins(gas_to_request, attempts).await;
         retry_with_price(amount).await
     }
 
+    /// The denom to pay fees in, if different from the gas coin.
+    pub(crate) fn effective_fee_denom<'a>(&'a self, cosmos: &'a Cosmos) -> Option<&'a str> {
+        self.fee_denom
+            .as_deref()
+            .or_else(|| cosmos.get_cosmos_builder().get_fee_denom())
+    }
+
+    /// Type URL of the most common message type in this transaction.
+    ///
+    /// Ties go to the type that appears first. Returns [None] if there are no messages.
+    pub fn dominant_type_url(&self) -> Option<&str> {
+        let mut counts: Vec<(&str, usize)> = vec![];
+        for msg in &self.messages {
+            match counts
+                .iter_mut()
+                .find(|(type_url, _)| *type_url == msg.type_url())
+            {
+                Some((_, count)) => *count += 1,
+                None => counts.push((msg.type_url(), 1)),
+            }
+        }
+        counts
+            .into_iter()
+            .rev()
+            .max_by_key(|(_, count)| *count)
+            .map(|(type_url, _)| type_url)
+    }
+
     /// Does this transaction have any messages already?
     pub fn has_messages(&self) -> bool {
         !self.messages.is_empty()
     }
 }
 
+/// A signer together with the account information needed to sign for it.
+#[derive(Clone, Copy)]
+pub(crate) struct SignerAccount<'a> {
+    pub(crate) signer: &'a dyn Signer,
+    pub(crate) account_number: u64,
+    pub(crate) sequence: u64,
+}
+
 /// Trait for any types that contain a [Cosmos] connection.
 pub trait HasCosmos: HasAddressHrp {
     /// Get the underlying connection
@@ -1883,7 +3062,7 @@ impl Cosmos {
     }
 }
 
-fn get_expected_sequence_inner(message: &str) -> Option<u64> {
+pub(crate) fn get_expected_sequence_inner(message: &str) -> Option<u64> {
     for line in message.lines() {
         if let Some(x) = get_expected_sequence_single(line) {
             return Some(x);
@@ -1898,12 +3077,56 @@ fn get_expected_sequence_single(message: &str) -> Option<u64> {
     s[..comma].parse().ok()
 }
 
+/// Parse the major and minor version from a version string like `v0.50.9-lsm`.
+fn parse_sdk_version(version: &str) -> Option<(u64, u64)> {
+    let mut parts = version.strip_prefix('v').unwrap_or(version).split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Build the request for the next page of a paginated query, if there is one.
+pub(crate) fn next_page_request(pagination: Option<PageResponse>) -> Option<PageRequest> {
+    match pagination {
+        Some(x) if !x.next_key.is_empty() => Some(PageRequest {
+            key: x.next_key,
+            offset: 0,
+            limit: 0,
+            count_total: false,
+            reverse: false,
+        }),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::CosmosNetwork;
+    use crate::{CosmosNetwork, TxMessage};
 
     use super::*;
 
+    #[test]
+    fn sdk_version() {
+        assert_eq!(parse_sdk_version("v0.50.9"), Some((0, 50)));
+        assert_eq!(parse_sdk_version("v0.53.0-rc.2"), Some((0, 53)));
+        assert_eq!(parse_sdk_version("0.47.13-ics-lsm"), Some((0, 47)));
+        assert_eq!(parse_sdk_version(""), None);
+    }
+
+    #[test]
+    fn ed25519_signer_info() {
+        let public_key = [7; 32];
+        let signer_info =
+            TxBuilder::default().make_signer_info(3, Some(WalletPublicKey::Ed25519(public_key)));
+        let any = signer_info.public_key.unwrap();
+        assert_eq!(any.type_url, "/cosmos.crypto.ed25519.PubKey");
+        let decoded =
+            cosmos_sdk_proto::cosmos::crypto::ed25519::PubKey::decode(any.value.as_slice())
+                .unwrap();
+        assert_eq!(decoded.key, public_key);
+        assert_eq!(signer_info.sequence, 3);
+    }
+
     #[test]
     fn gas_estimate_multiplier() {
         let mut cosmos = CosmosNetwork::OsmosisTestnet.builder_local();
@@ -1918,6 +3141,26 @@ mod tests {
         assert_eq!(multiply_estimated_gas(&cosmos, 1234), 5182);
     }
 
+    #[test]
+    fn message_gas_multiplier() {
+        let send = "/cosmos.bank.v1beta1.MsgSend";
+        let execute = "/cosmwasm.wasm.v1.MsgExecuteContract";
+        let mut builder = TxBuilder::default();
+        assert_eq!(builder.dominant_type_url(), None);
+        builder.add_message(TxMessage::new(send, vec![], "send"));
+        builder.add_message(TxMessage::new(execute, vec![], "execute"));
+        assert_eq!(builder.dominant_type_url(), Some(send));
+        builder.add_message(TxMessage::new(execute, vec![], "execute"));
+        assert_eq!(builder.dominant_type_url(), Some(execute));
+
+        let mut cosmos = CosmosNetwork::OsmosisTestnet.builder_local();
+        cosmos.set_message_gas_multiplier(execute, Some(2.0));
+        assert_eq!(cosmos.get_message_gas_multiplier(execute), Some(2.0));
+        assert_eq!(cosmos.get_message_gas_multiplier(send), None);
+        cosmos.set_message_gas_multiplier(execute, None);
+        assert_eq!(cosmos.get_message_gas_multiplier(execute), None);
+    }
+
     #[tokio::test]
     async fn lazy_load() {
         let mut builder = CosmosNetwork::OsmosisTestnet.builder().await.unwrap();
@@ -1997,9 +3240,78 @@ mod tests {
     }
 }
 
+/// The result of simulating a transaction.
 #[derive(Debug)]
 pub struct FullSimulateResponse {
+    /// The transaction body that was simulated
     pub body: TxBody,
+    /// Raw response from the node
     pub simres: SimulateResponse,
+    /// Gas used during simulation
     pub gas_used: u64,
+    /// Events the transaction would emit, with attributes normalized
+    pub events: Vec<NormalizedEvent>,
+    /// Responses for each message in the transaction
+    ///
+    /// Only populated on Cosmos SDK 0.46 and later.
+    pub msg_responses: Vec<cosmos_sdk_proto::Any>,
+    /// Log output from the simulation
+    pub log: String,
+}
+
+impl FullSimulateResponse {
+    fn new(body: TxBody, simres: SimulateResponse, gas_used: u64) -> Self {
+        let (events, msg_responses, log) = match &simres.result {
+            Some(result) => (
+                result
+                    .events
+                    .iter()
+                    .map(|event| NormalizedEvent {
+                        r#type: event.r#type.clone(),
+                        attributes: normalize_event_attributes(
+                            event
+                                .attributes
+                                .iter()
+                                .map(|attr| (attr.key.as_str(), attr.value.as_str())),
+                        ),
+                    })
+                    .collect(),
+                result.msg_responses.clone(),
+                result.log.clone(),
+            ),
+            None => (vec![], vec![], String::new()),
+        };
+        FullSimulateResponse {
+            body,
+            simres,
+            gas_used,
+            events,
+            msg_responses,
+            log,
+        }
+    }
+
+    /// Find all events of the given type, e.g. `wasm` or `transfer`.
+    pub fn events_of_type<'a>(
+        &'a self,
+        r#type: &'a str,
+    ) -> impl Iterator<Item = &'a NormalizedEvent> + 'a {
+        self.events
+            .iter()
+            .filter(move |event| event.r#type == r#type)
+    }
+
+    /// Decode the response for the message at the given index.
+    ///
+    /// Returns [None] if there is no response at that index or it has a different type URL.
+    pub fn decode_msg_response<T: Message + Default>(
+        &self,
+        index: usize,
+        type_url: &str,
+    ) -> Option<Result<T, prost::DecodeError>> {
+        self.msg_responses
+            .get(index)
+            .filter(|any| any.type_url == type_url)
+            .map(|any| T::decode(&*any.value))
+    }
 }
@@ -5,7 +5,8 @@ use std::{
 };
 
 use chrono::{DateTime, Utc};
-use parking_lot::RwLock;
+use cosmos_sdk_proto::cosmos::base::reflection::v1beta1::reflection_service_client::ReflectionServiceClient;
+use parking_lot::{Mutex, RwLock};
 use tonic::{
     codegen::InterceptedService,
     transport::{Channel, ClientTlsConfig, Endpoint, Uri},
@@ -32,11 +33,49 @@ struct NodeInner {
     grpc_url: Arc<String>,
     is_fallback: bool,
     last_error: RwLock<Option<LastError>>,
-    channel: InterceptedService<Channel, CosmosInterceptor>,
+    endpoint: Endpoint,
+    interceptor: CosmosInterceptor,
+    channel: Mutex<ChannelState>,
+    idle_timeout: Duration,
     query_count: RwLock<QueryCount>,
     max_decoding_message_size: usize,
 }
 
+/// The current gRPC channel along with information for recycling it.
+struct ChannelState {
+    channel: CosmosChannel,
+    last_used: Instant,
+    /// How many times in a row we've recycled due to transport errors.
+    recycle_count: u32,
+    /// Earliest time we're willing to recycle again after a transport error.
+    next_recycle_allowed: Instant,
+}
+
+impl ChannelState {
+    fn new(endpoint: &Endpoint, interceptor: &CosmosInterceptor) -> Self {
+        let now = Instant::now();
+        ChannelState {
+            channel: InterceptedService::new(endpoint.connect_lazy(), interceptor.clone()),
+            last_used: now,
+            recycle_count: 0,
+            next_recycle_allowed: now,
+        }
+    }
+}
+
+/// Delay before we allow another recycle after `recycle_count` consecutive recycles.
+///
+/// Exponential backoff with jitter, capped at 30 seconds.
+fn recycle_backoff(recycle_count: u32) -> Duration {
+    use rand::Rng;
+    const BASE_RECYCLE_BACKOFF: Duration = Duration::from_millis(250);
+    const MAX_RECYCLE_BACKOFF: Duration = Duration::from_secs(30);
+    let backoff = BASE_RECYCLE_BACKOFF
+        .saturating_mul(2u32.saturating_pow(recycle_count))
+        .min(MAX_RECYCLE_BACKOFF);
+    backoff.mul_f64(crate::rng::with_rng(|rng| rng.gen_range(0.5..=1.0)))
+}
+
 #[derive(Default)]
 pub(crate) struct QueryCount {
     pub(crate) first_request: Option<DateTime<Utc>>,
@@ -142,18 +181,22 @@ impl CosmosBuilder {
             grpc_endpoint
         };
 
-        let grpc_channel = grpc_endpoint.connect_lazy();
-
         let referer_header = self.referer_header().map(|x| x.to_owned());
 
-        let interceptor = CosmosInterceptor(referer_header.map(Arc::new));
-        let channel = InterceptedService::new(grpc_channel, interceptor);
+        let interceptor = CosmosInterceptor {
+            referer: referer_header.map(Arc::new),
+            query_auth: self.get_query_auth().cloned(),
+        };
+        let channel = ChannelState::new(&grpc_endpoint, &interceptor);
         let max_decoding_message_size = self.get_max_decoding_message_size();
 
         Ok(Node {
             node_inner: Arc::new(NodeInner {
                 is_fallback,
-                channel,
+                endpoint: grpc_endpoint,
+                interceptor,
+                channel: Mutex::new(channel),
+                idle_timeout: Duration::from_secs(self.idle_timeout_seconds().into()),
                 grpc_url: grpc_url.clone(),
                 last_error: RwLock::new(None),
                 query_count: RwLock::new(QueryCount::default()),
@@ -170,11 +213,58 @@ impl Node {
         &self.node_inner.grpc_url
     }
 
+    /// Get the current channel, rebuilding it first if it's been idle too long.
+    ///
+    /// Long-idle connections are frequently closed by load balancers without
+    /// us noticing, so we proactively replace them instead of letting the
+    /// next query fail.
+    fn channel(&self) -> CosmosChannel {
+        let mut guard = self.node_inner.channel.lock();
+        let now = Instant::now();
+        if now.duration_since(guard.last_used) > self.node_inner.idle_timeout {
+            tracing::debug!("Recycling idle channel to {}", self.node_inner.grpc_url);
+            guard.channel = InterceptedService::new(
+                self.node_inner.endpoint.connect_lazy(),
+                self.node_inner.interceptor.clone(),
+            );
+        }
+        guard.last_used = now;
+        guard.channel.clone()
+    }
+
+    /// Rebuild the channel after a transport-level failure.
+    ///
+    /// Uses jittered exponential backoff so that a node which is truly down
+    /// doesn't cause us to rebuild on every single query.
+    fn recycle_channel(&self) {
+        let mut guard = self.node_inner.channel.lock();
+        let now = Instant::now();
+        if now < guard.next_recycle_allowed {
+            return;
+        }
+        tracing::debug!(
+            "Recycling channel to {} after transport error",
+            self.node_inner.grpc_url
+        );
+        guard.channel = InterceptedService::new(
+            self.node_inner.endpoint.connect_lazy(),
+            self.node_inner.interceptor.clone(),
+        );
+        guard.next_recycle_allowed = now + recycle_backoff(guard.recycle_count);
+        guard.recycle_count = guard.recycle_count.saturating_add(1);
+    }
+
     pub(crate) fn set_broken(
         &self,
         err: impl FnOnce(Arc<String>) -> ConnectionError,
         details: &QueryErrorDetails,
     ) {
+        if matches!(
+            details,
+            QueryErrorDetails::TransportError { .. } | QueryErrorDetails::ConnectionError(_)
+        ) {
+            self.recycle_channel();
+        }
         let err = err(self.node_inner.grpc_url.clone());
         self.log_connection_error(err, details);
     }
@@ -203,6 +293,7 @@ impl Node {
                 if let Some(error) = guard.as_mut() {
                     error.error_count = 0;
                 }
+                self.node_inner.channel.lock().recycle_count = 0;
             }
             QueryResult::NetworkError { err, action } => {
                 let old_error_count = guard.as_ref().map_or(0, |x| x.error_count);
@@ -269,31 +360,58 @@ impl Node {
     pub(crate) fn auth_query_client(
         &self,
     ) -> cosmos_sdk_proto::cosmos::auth::v1beta1::query_client::QueryClient<CosmosChannel> {
-        let client = cosmos_sdk_proto::cosmos::auth::v1beta1::query_client::QueryClient::new(
-            self.node_inner.channel.clone(),
-        );
+        let client =
+            cosmos_sdk_proto::cosmos::auth::v1beta1::query_client::QueryClient::new(self.channel());
         client.max_decoding_message_size(self.node_inner.max_decoding_message_size)
     }
 
     pub(crate) fn rujira_query_client(&self) -> RujiraQueryClient<CosmosChannel> {
-        RujiraQueryClient::new(self.node_inner.channel.clone())
+        RujiraQueryClient::new(self.channel())
+    }
+
+    pub(crate) fn ibc_client_query_client(
+        &self,
+    ) -> crate::ibc::client::query_client::QueryClient<CosmosChannel> {
+        crate::ibc::client::query_client::QueryClient::new(self.channel())
+    }
+
+    pub(crate) fn ibc_connection_query_client(
+        &self,
+    ) -> crate::ibc::connection::query_client::QueryClient<CosmosChannel> {
+        crate::ibc::connection::query_client::QueryClient::new(self.channel())
+    }
+
+    pub(crate) fn ibc_channel_query_client(
+        &self,
+    ) -> crate::ibc::channel::query_client::QueryClient<CosmosChannel> {
+        crate::ibc::channel::query_client::QueryClient::new(self.channel())
+    }
+
+    pub(crate) fn ica_controller_query_client(
+        &self,
+    ) -> crate::ibc::ica_controller::query_client::QueryClient<CosmosChannel> {
+        crate::ibc::ica_controller::query_client::QueryClient::new(self.channel())
+    }
+
+    pub(crate) fn ibc_transfer_query_client(
+        &self,
+    ) -> crate::ibc::transfer::query_client::QueryClient<CosmosChannel> {
+        crate::ibc::transfer::query_client::QueryClient::new(self.channel())
     }
 
     pub(crate) fn bank_query_client(
         &self,
     ) -> cosmos_sdk_proto::cosmos::bank::v1beta1::query_client::QueryClient<CosmosChannel> {
-        let client = cosmos_sdk_proto::cosmos::bank::v1beta1::query_client::QueryClient::new(
-            self.node_inner.channel.clone(),
-        );
+        let client =
+            cosmos_sdk_proto::cosmos::bank::v1beta1::query_client::QueryClient::new(self.channel());
         client.max_decoding_message_size(self.node_inner.max_decoding_message_size)
     }
 
     pub(crate) fn wasm_query_client(
         &self,
     ) -> cosmos_sdk_proto::cosmwasm::wasm::v1::query_client::QueryClient<CosmosChannel> {
-        let client = cosmos_sdk_proto::cosmwasm::wasm::v1::query_client::QueryClient::new(
-            self.node_inner.channel.clone(),
-        );
+        let client =
+            cosmos_sdk_proto::cosmwasm::wasm::v1::query_client::QueryClient::new(self.channel());
         client.max_decoding_message_size(self.node_inner.max_decoding_message_size)
     }
 
@@ -301,7 +419,7 @@ impl Node {
         &self,
     ) -> cosmos_sdk_proto::cosmos::tx::v1beta1::service_client::ServiceClient<CosmosChannel> {
         let client = cosmos_sdk_proto::cosmos::tx::v1beta1::service_client::ServiceClient::new(
-            self.node_inner.channel.clone(),
+            self.channel(),
         );
         client.max_decoding_message_size(self.node_inner.max_decoding_message_size)
     }
@@ -313,16 +431,65 @@ impl Node {
     > {
         let client =
             cosmos_sdk_proto::cosmos::base::tendermint::v1beta1::service_client::ServiceClient::new(
-                self.node_inner.channel.clone(),
+                self.channel(),
             );
         client.max_decoding_message_size(self.node_inner.max_decoding_message_size)
     }
 
+    pub(crate) fn reflection_client(&self) -> ReflectionServiceClient<CosmosChannel> {
+        ReflectionServiceClient::new(self.channel())
+    }
+
     pub(crate) fn authz_query_client(
         &self,
     ) -> cosmos_sdk_proto::cosmos::authz::v1beta1::query_client::QueryClient<CosmosChannel> {
         let client = cosmos_sdk_proto::cosmos::authz::v1beta1::query_client::QueryClient::new(
-            self.node_inner.channel.clone(),
+            self.channel(),
+        );
+        client.max_decoding_message_size(self.node_inner.max_decoding_message_size)
+    }
+
+    pub(crate) fn staking_query_client(
+        &self,
+    ) -> cosmos_sdk_proto::cosmos::staking::v1beta1::query_client::QueryClient<CosmosChannel> {
+        let client = cosmos_sdk_proto::cosmos::staking::v1beta1::query_client::QueryClient::new(
+            self.channel(),
+        );
+        client.max_decoding_message_size(self.node_inner.max_decoding_message_size)
+    }
+
+    pub(crate) fn distribution_query_client(
+        &self,
+    ) -> cosmos_sdk_proto::cosmos::distribution::v1beta1::query_client::QueryClient<CosmosChannel>
+    {
+        let client =
+            cosmos_sdk_proto::cosmos::distribution::v1beta1::query_client::QueryClient::new(
+                self.channel(),
+            );
+        client.max_decoding_message_size(self.node_inner.max_decoding_message_size)
+    }
+
+    pub(crate) fn gov_query_client(
+        &self,
+    ) -> cosmos_sdk_proto::cosmos::gov::v1::query_client::QueryClient<CosmosChannel> {
+        let client =
+            cosmos_sdk_proto::cosmos::gov::v1::query_client::QueryClient::new(self.channel());
+        client.max_decoding_message_size(self.node_inner.max_decoding_message_size)
+    }
+
+    pub(crate) fn gov_v1beta1_query_client(
+        &self,
+    ) -> cosmos_sdk_proto::cosmos::gov::v1beta1::query_client::QueryClient<CosmosChannel> {
+        let client =
+            cosmos_sdk_proto::cosmos::gov::v1beta1::query_client::QueryClient::new(self.channel());
+        client.max_decoding_message_size(self.node_inner.max_decoding_message_size)
+    }
+
+    pub(crate) fn feegrant_query_client(
+        &self,
+    ) -> cosmos_sdk_proto::cosmos::feegrant::v1beta1::query_client::QueryClient<CosmosChannel> {
+        let client = cosmos_sdk_proto::cosmos::feegrant::v1beta1::query_client::QueryClient::new(
+            self.channel(),
         );
         client.max_decoding_message_size(self.node_inner.max_decoding_message_size)
     }
@@ -330,12 +497,50 @@ impl Node {
     pub(crate) fn epochs_query_client(
         &self,
     ) -> crate::osmosis::epochs::query_client::QueryClient<CosmosChannel> {
-        crate::osmosis::epochs::query_client::QueryClient::new(self.node_inner.channel.clone())
+        crate::osmosis::epochs::query_client::QueryClient::new(self.channel())
     }
 
     pub(crate) fn txfees_query_client(
         &self,
     ) -> crate::osmosis::txfees::query_client::QueryClient<CosmosChannel> {
-        crate::osmosis::txfees::query_client::QueryClient::new(self.node_inner.channel.clone())
+        crate::osmosis::txfees::query_client::QueryClient::new(self.channel())
+    }
+
+    pub(crate) fn twap_query_client(
+        &self,
+    ) -> crate::osmosis::twap::query_client::QueryClient<CosmosChannel> {
+        crate::osmosis::twap::query_client::QueryClient::new(self.channel())
+    }
+
+    pub(crate) fn poolmanager_query_client(
+        &self,
+    ) -> crate::osmosis::poolmanager::query_client::QueryClient<CosmosChannel> {
+        crate::osmosis::poolmanager::query_client::QueryClient::new(self.channel())
+    }
+
+    pub(crate) fn concentrated_liquidity_query_client(
+        &self,
+    ) -> crate::osmosis::concentrated_liquidity::query_client::QueryClient<CosmosChannel> {
+        crate::osmosis::concentrated_liquidity::query_client::QueryClient::new(self.channel())
+    }
+
+    #[cfg(feature = "injective")]
+    pub(crate) fn injective_exchange_query_client(
+        &self,
+    ) -> crate::injective::exchange::query_client::QueryClient<CosmosChannel> {
+        crate::injective::exchange::query_client::QueryClient::new(self.channel())
+    }
+
+    #[cfg(feature = "injective")]
+    pub(crate) fn injective_oracle_query_client(
+        &self,
+    ) -> crate::injective::oracle::query_client::QueryClient<CosmosChannel> {
+        crate::injective::oracle::query_client::QueryClient::new(self.channel())
+    }
+
+    pub(crate) fn feemarket_query_client(
+        &self,
+    ) -> crate::feemarket::query_client::QueryClient<CosmosChannel> {
+        crate::feemarket::query_client::QueryClient::new(self.channel())
     }
 }
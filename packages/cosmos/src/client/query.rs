@@ -1,14 +1,41 @@
 use cosmos_sdk_proto::{
     cosmos::{
-        auth::v1beta1::{QueryAccountRequest, QueryAccountResponse},
+        auth::v1beta1::{self as auth_v1beta1, QueryAccountRequest, QueryAccountResponse},
         authz::v1beta1::{
             QueryGranteeGrantsRequest, QueryGranteeGrantsResponse, QueryGranterGrantsRequest,
             QueryGranterGrantsResponse,
         },
-        bank::v1beta1::{QueryAllBalancesRequest, QueryAllBalancesResponse},
+        bank::v1beta1::{
+            self as bank_v1beta1, QueryAllBalancesRequest, QueryAllBalancesResponse,
+            QueryDenomMetadataRequest, QueryDenomMetadataResponse, QueryDenomsMetadataRequest,
+            QueryDenomsMetadataResponse, QuerySpendableBalancesRequest,
+            QuerySpendableBalancesResponse, QuerySupplyOfRequest, QuerySupplyOfResponse,
+            QueryTotalSupplyRequest, QueryTotalSupplyResponse,
+        },
+        base::reflection::v1beta1::{ListImplementationsRequest, ListImplementationsResponse},
         base::tendermint::v1beta1::{
-            GetBlockByHeightRequest, GetBlockByHeightResponse, GetLatestBlockRequest,
-            GetLatestBlockResponse,
+            AbciQueryRequest, AbciQueryResponse, GetBlockByHeightRequest, GetBlockByHeightResponse,
+            GetLatestBlockRequest, GetLatestBlockResponse, GetLatestValidatorSetRequest,
+            GetLatestValidatorSetResponse, GetNodeInfoRequest, GetNodeInfoResponse,
+            GetSyncingRequest, GetSyncingResponse, GetValidatorSetByHeightRequest,
+            GetValidatorSetByHeightResponse,
+        },
+        distribution::v1beta1::{
+            QueryDelegationTotalRewardsRequest, QueryDelegationTotalRewardsResponse,
+            QueryDelegatorWithdrawAddressRequest, QueryDelegatorWithdrawAddressResponse,
+            QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
+            QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
+        },
+        feegrant::v1beta1::{
+            QueryAllowanceRequest, QueryAllowanceResponse, QueryAllowancesByGranterRequest,
+            QueryAllowancesByGranterResponse, QueryAllowancesRequest, QueryAllowancesResponse,
+        },
+        gov::{v1 as gov_v1, v1beta1 as gov_v1beta1},
+        staking::v1beta1::{
+            self as staking_v1beta1, QueryDelegatorDelegationsRequest,
+            QueryDelegatorDelegationsResponse, QueryDelegatorUnbondingDelegationsRequest,
+            QueryDelegatorUnbondingDelegationsResponse, QueryValidatorRequest,
+            QueryValidatorResponse, QueryValidatorsRequest, QueryValidatorsResponse,
         },
         tx::v1beta1::{
             BroadcastTxRequest, BroadcastTxResponse, GetTxRequest, GetTxResponse,
@@ -16,25 +43,51 @@ use cosmos_sdk_proto::{
         },
     },
     cosmwasm::wasm::v1::{
-        QueryCodeRequest, QueryCodeResponse, QueryContractHistoryRequest,
+        QueryAllContractStateRequest, QueryAllContractStateResponse, QueryCodeRequest,
+        QueryCodeResponse, QueryCodesRequest, QueryCodesResponse, QueryContractHistoryRequest,
         QueryContractHistoryResponse, QueryContractInfoRequest, QueryContractInfoResponse,
-        QueryRawContractStateRequest, QueryRawContractStateResponse,
-        QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+        QueryContractsByCodeRequest, QueryContractsByCodeResponse, QueryRawContractStateRequest,
+        QueryRawContractStateResponse, QuerySmartContractStateRequest,
+        QuerySmartContractStateResponse,
     },
 };
 use tonic::async_trait;
 
-use crate::osmosis::{
-    epochs::{QueryEpochsInfoRequest, QueryEpochsInfoResponse},
-    txfees::QueryEipBaseFeeRequest,
-    QueryEipBaseFeeResponse,
+use crate::{
+    feemarket::{GasPriceRequest, GasPriceResponse},
+    ibc::{
+        channel as ibc_channel, client as ibc_client, connection as ibc_connection, ica_controller,
+        transfer as ibc_transfer,
+    },
+    osmosis::{
+        concentrated_liquidity::{
+            IncentiveRecordsRequest, IncentiveRecordsResponse, UserPositionsRequest,
+            UserPositionsResponse,
+        },
+        epochs::{QueryEpochsInfoRequest, QueryEpochsInfoResponse},
+        poolmanager::{
+            EstimateSwapExactAmountInRequest, EstimateSwapExactAmountInResponse,
+            EstimateSwapExactAmountOutRequest, EstimateSwapExactAmountOutResponse,
+            SpotPriceRequest, SpotPriceResponse,
+        },
+        twap::{
+            ArithmeticTwapRequest, ArithmeticTwapResponse, ArithmeticTwapToNowRequest,
+            ArithmeticTwapToNowResponse, GeometricTwapRequest, GeometricTwapResponse,
+            GeometricTwapToNowRequest, GeometricTwapToNowResponse,
+        },
+        txfees::{
+            QueryDenomSpotPriceRequest, QueryDenomSpotPriceResponse, QueryEipBaseFeeRequest,
+            QueryFeeTokensRequest, QueryFeeTokensResponse,
+        },
+        QueryEipBaseFeeResponse,
+    },
 };
 
 use super::node::Node;
 
 #[async_trait]
 pub(crate) trait GrpcRequest: Clone + Sized + Send + 'static {
-    type Response: Send;
+    type Response: Send + cosmos_sdk_proto::traits::Message;
 
     async fn perform(
         req: tonic::Request<Self>,
@@ -64,6 +117,61 @@ impl GrpcRequest for QueryAllBalancesRequest {
     }
 }
 
+#[async_trait]
+impl GrpcRequest for QuerySpendableBalancesRequest {
+    type Response = QuerySpendableBalancesResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.bank_query_client().spendable_balances(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryDenomMetadataRequest {
+    type Response = QueryDenomMetadataResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.bank_query_client().denom_metadata(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryDenomsMetadataRequest {
+    type Response = QueryDenomsMetadataResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.bank_query_client().denoms_metadata(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryTotalSupplyRequest {
+    type Response = QueryTotalSupplyResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.bank_query_client().total_supply(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QuerySupplyOfRequest {
+    type Response = QuerySupplyOfResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.bank_query_client().supply_of(req).await
+    }
+}
+
 #[async_trait]
 impl GrpcRequest for QuerySmartContractStateRequest {
     type Response = QuerySmartContractStateResponse;
@@ -141,6 +249,39 @@ impl GrpcRequest for QueryContractHistoryRequest {
     }
 }
 
+#[async_trait]
+impl GrpcRequest for QueryContractsByCodeRequest {
+    type Response = QueryContractsByCodeResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.wasm_query_client().contracts_by_code(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryCodesRequest {
+    type Response = QueryCodesResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.wasm_query_client().codes(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryAllContractStateRequest {
+    type Response = QueryAllContractStateResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.wasm_query_client().all_contract_state(req).await
+    }
+}
+
 #[async_trait]
 impl GrpcRequest for GetBlockByHeightRequest {
     type Response = GetBlockByHeightResponse;
@@ -163,6 +304,122 @@ impl GrpcRequest for GetLatestBlockRequest {
     }
 }
 
+#[async_trait]
+impl GrpcRequest for GetValidatorSetByHeightRequest {
+    type Response = GetValidatorSetByHeightResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .tendermint_client()
+            .get_validator_set_by_height(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for GetLatestValidatorSetRequest {
+    type Response = GetLatestValidatorSetResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .tendermint_client()
+            .get_latest_validator_set(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for AbciQueryRequest {
+    type Response = AbciQueryResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.tendermint_client().abci_query(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for auth_v1beta1::QueryParamsRequest {
+    type Response = auth_v1beta1::QueryParamsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.auth_query_client().params(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for bank_v1beta1::QueryParamsRequest {
+    type Response = bank_v1beta1::QueryParamsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.bank_query_client().params(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for staking_v1beta1::QueryParamsRequest {
+    type Response = staking_v1beta1::QueryParamsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.staking_query_client().params(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for gov_v1::QueryParamsRequest {
+    type Response = gov_v1::QueryParamsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.gov_query_client().params(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for GetSyncingRequest {
+    type Response = GetSyncingResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.tendermint_client().get_syncing(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for GetNodeInfoRequest {
+    type Response = GetNodeInfoResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.tendermint_client().get_node_info(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ListImplementationsRequest {
+    type Response = ListImplementationsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.reflection_client().list_implementations(req).await
+    }
+}
+
 #[async_trait]
 impl GrpcRequest for SimulateRequest {
     type Response = SimulateResponse;
@@ -207,6 +464,214 @@ impl GrpcRequest for QueryGranteeGrantsRequest {
     }
 }
 
+#[async_trait]
+impl GrpcRequest for QueryDelegatorDelegationsRequest {
+    type Response = QueryDelegatorDelegationsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .staking_query_client()
+            .delegator_delegations(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryDelegatorUnbondingDelegationsRequest {
+    type Response = QueryDelegatorUnbondingDelegationsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .staking_query_client()
+            .delegator_unbonding_delegations(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryValidatorsRequest {
+    type Response = QueryValidatorsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.staking_query_client().validators(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryValidatorRequest {
+    type Response = QueryValidatorResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.staking_query_client().validator(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryDelegationTotalRewardsRequest {
+    type Response = QueryDelegationTotalRewardsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .distribution_query_client()
+            .delegation_total_rewards(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryValidatorOutstandingRewardsRequest {
+    type Response = QueryValidatorOutstandingRewardsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .distribution_query_client()
+            .validator_outstanding_rewards(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryValidatorCommissionRequest {
+    type Response = QueryValidatorCommissionResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .distribution_query_client()
+            .validator_commission(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryDelegatorWithdrawAddressRequest {
+    type Response = QueryDelegatorWithdrawAddressResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .distribution_query_client()
+            .delegator_withdraw_address(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for gov_v1::QueryProposalsRequest {
+    type Response = gov_v1::QueryProposalsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.gov_query_client().proposals(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for gov_v1::QueryProposalRequest {
+    type Response = gov_v1::QueryProposalResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.gov_query_client().proposal(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for gov_v1::QueryTallyResultRequest {
+    type Response = gov_v1::QueryTallyResultResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.gov_query_client().tally_result(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for gov_v1beta1::QueryProposalsRequest {
+    type Response = gov_v1beta1::QueryProposalsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.gov_v1beta1_query_client().proposals(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for gov_v1beta1::QueryProposalRequest {
+    type Response = gov_v1beta1::QueryProposalResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.gov_v1beta1_query_client().proposal(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for gov_v1beta1::QueryTallyResultRequest {
+    type Response = gov_v1beta1::QueryTallyResultResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.gov_v1beta1_query_client().tally_result(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryAllowanceRequest {
+    type Response = QueryAllowanceResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.feegrant_query_client().allowance(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryAllowancesRequest {
+    type Response = QueryAllowancesResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.feegrant_query_client().allowances(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryAllowancesByGranterRequest {
+    type Response = QueryAllowancesByGranterResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .feegrant_query_client()
+            .allowances_by_granter(req)
+            .await
+    }
+}
+
 #[async_trait]
 impl GrpcRequest for QueryEpochsInfoRequest {
     type Response = QueryEpochsInfoResponse;
@@ -228,3 +693,274 @@ impl GrpcRequest for QueryEipBaseFeeRequest {
         inner.txfees_query_client().get_eip_base_fee(req).await
     }
 }
+
+#[async_trait]
+impl GrpcRequest for GasPriceRequest {
+    type Response = GasPriceResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.feemarket_query_client().gas_price(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryFeeTokensRequest {
+    type Response = QueryFeeTokensResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.txfees_query_client().fee_tokens(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryDenomSpotPriceRequest {
+    type Response = QueryDenomSpotPriceResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.txfees_query_client().denom_spot_price(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for SpotPriceRequest {
+    type Response = SpotPriceResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.poolmanager_query_client().spot_price(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for EstimateSwapExactAmountInRequest {
+    type Response = EstimateSwapExactAmountInResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .poolmanager_query_client()
+            .estimate_swap_exact_amount_in(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for EstimateSwapExactAmountOutRequest {
+    type Response = EstimateSwapExactAmountOutResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .poolmanager_query_client()
+            .estimate_swap_exact_amount_out(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ArithmeticTwapRequest {
+    type Response = ArithmeticTwapResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.twap_query_client().arithmetic_twap(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ArithmeticTwapToNowRequest {
+    type Response = ArithmeticTwapToNowResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.twap_query_client().arithmetic_twap_to_now(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for GeometricTwapRequest {
+    type Response = GeometricTwapResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.twap_query_client().geometric_twap(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for GeometricTwapToNowRequest {
+    type Response = GeometricTwapToNowResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.twap_query_client().geometric_twap_to_now(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for UserPositionsRequest {
+    type Response = UserPositionsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .concentrated_liquidity_query_client()
+            .user_positions(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for IncentiveRecordsRequest {
+    type Response = IncentiveRecordsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .concentrated_liquidity_query_client()
+            .incentive_records(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ibc_transfer::QueryDenomTraceRequest {
+    type Response = ibc_transfer::QueryDenomTraceResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.ibc_transfer_query_client().denom_trace(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ibc_transfer::QueryDenomTracesRequest {
+    type Response = ibc_transfer::QueryDenomTracesResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.ibc_transfer_query_client().denom_traces(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ibc_client::QueryClientStatesRequest {
+    type Response = ibc_client::QueryClientStatesResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.ibc_client_query_client().client_states(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ibc_client::QueryClientStateRequest {
+    type Response = ibc_client::QueryClientStateResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.ibc_client_query_client().client_state(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ibc_client::QueryClientStatusRequest {
+    type Response = ibc_client::QueryClientStatusResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.ibc_client_query_client().client_status(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ibc_connection::QueryConnectionsRequest {
+    type Response = ibc_connection::QueryConnectionsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.ibc_connection_query_client().connections(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ibc_connection::QueryConnectionRequest {
+    type Response = ibc_connection::QueryConnectionResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.ibc_connection_query_client().connection(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ibc_channel::QueryChannelsRequest {
+    type Response = ibc_channel::QueryChannelsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.ibc_channel_query_client().channels(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ibc_channel::QueryChannelRequest {
+    type Response = ibc_channel::QueryChannelResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.ibc_channel_query_client().channel(req).await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ibc_channel::QueryPacketCommitmentsRequest {
+    type Response = ibc_channel::QueryPacketCommitmentsResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .ibc_channel_query_client()
+            .packet_commitments(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for ica_controller::QueryInterchainAccountRequest {
+    type Response = ica_controller::QueryInterchainAccountResponse;
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .ica_controller_query_client()
+            .interchain_account(req)
+            .await
+    }
+}
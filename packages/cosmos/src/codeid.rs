@@ -1,17 +1,26 @@
 use std::{
     fmt::Display,
-    io::Write,
+    io::{Read, Write},
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
-use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
-use flate2::{write::GzEncoder, Compression};
+use cosmos_sdk_proto::{
+    cosmos::base::abci::v1beta1::TxResponse,
+    cosmwasm::wasm::v1::{
+        AccessConfig, AccessType, CodeInfoResponse, MsgUpdateInstantiateConfig, QueryCodeRequest,
+        QueryCodesRequest, QueryContractsByCodeRequest,
+    },
+};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use sha2::{Digest, Sha256};
 
 use crate::{
-    error::Action,
+    client::next_page_request,
+    error::{Action, AddressError},
     messages::{MsgExecHelper, MsgStoreCodeHelper},
-    Address, AddressHrp, Cosmos, HasAddress, HasAddressHrp, HasCosmos, TxBuilder, TxMessage,
-    TxResponseExt, Wallet,
+    Address, AddressHrp, Contract, Cosmos, HasAddress, HasAddressHrp, HasCosmos, TxBuilder,
+    TxMessage, TxResponseExt, Wallet,
 };
 
 /// Represents the uploaded code on a specific blockchain connection.
@@ -31,9 +40,269 @@ impl CodeId {
     pub async fn download(&self) -> Result<Vec<u8>, crate::Error> {
         self.client.code_info(self.code_id).await
     }
+
+    /// List all contracts instantiated from this code ID.
+    pub async fn list_contracts(&self) -> Result<Vec<Contract>, crate::Error> {
+        let action = Action::ListContractsByCode(self.code_id);
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let page = self
+                .client
+                .perform_query(
+                    QueryContractsByCodeRequest {
+                        code_id: self.code_id,
+                        pagination: pagination.take(),
+                    },
+                    action.clone(),
+                )
+                .run()
+                .await?
+                .into_inner();
+            for contract in page.contracts {
+                let address = contract
+                    .parse()
+                    .map_err(|_| crate::Error::InvalidChainResponse {
+                        message: format!("Invalid contract address: {contract}"),
+                        action: action.clone().into(),
+                    })?;
+                res.push(self.client.make_contract(address));
+            }
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get metadata on this code, including its checksum and instantiate permission.
+    pub async fn info(&self) -> Result<CodeInfoResponse, crate::Error> {
+        let action = Action::CodeInfo(self.code_id);
+        self.client
+            .perform_query(
+                QueryCodeRequest {
+                    code_id: self.code_id,
+                },
+                action.clone(),
+            )
+            .run()
+            .await?
+            .into_inner()
+            .code_info
+            .ok_or_else(|| crate::Error::InvalidChainResponse {
+                message: "Missing code_info field".to_owned(),
+                action: action.into(),
+            })
+    }
+
+    /// Get who is currently allowed to instantiate this code.
+    pub async fn instantiate_permission(&self) -> Result<InstantiatePermission, crate::Error> {
+        let config = self.info().await?.instantiate_permission;
+        InstantiatePermission::from_access_config(config.unwrap_or_default()).map_err(|message| {
+            crate::Error::InvalidChainResponse {
+                message,
+                action: Action::CodeInfo(self.code_id).into(),
+            }
+        })
+    }
+
+    /// Build a message changing who may instantiate this code.
+    ///
+    /// Useful when the sender is a multisig or other account this library cannot sign for.
+    pub fn update_instantiate_permission_msg(
+        &self,
+        sender: impl HasAddress,
+        permission: InstantiatePermission,
+    ) -> MsgUpdateInstantiateConfig {
+        MsgUpdateInstantiateConfig {
+            sender: sender.get_address_string(),
+            code_id: self.code_id,
+            new_instantiate_permission: Some(permission.into()),
+        }
+    }
+
+    /// Change who may instantiate this code. Must be sent by the code's creator.
+    pub async fn update_instantiate_permission(
+        &self,
+        wallet: &Wallet,
+        permission: InstantiatePermission,
+    ) -> Result<TxResponse, crate::Error> {
+        wallet
+            .broadcast_message(
+                &self.client,
+                self.update_instantiate_permission_msg(wallet, permission),
+            )
+            .await
+    }
+
+    /// Check whether a local WASM file matches the code stored on chain.
+    ///
+    /// Gzipped files are decompressed first, since the chain checksums the
+    /// uncompressed code.
+    pub async fn verify_local_wasm(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<WasmVerification, crate::Error> {
+        let path = path.as_ref();
+        let local_checksum = wasm_file_checksum(path)?;
+        let chain_checksum = self.checksum().await?;
+        Ok(WasmVerification {
+            local_checksum,
+            chain_checksum,
+        })
+    }
+}
+
+/// Result of comparing a local WASM file against on-chain code, see [CodeId::verify_local_wasm].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmVerification {
+    /// SHA-256 checksum of the uncompressed local file
+    pub local_checksum: Vec<u8>,
+    /// SHA-256 checksum reported by the chain
+    pub chain_checksum: Vec<u8>,
+}
+
+impl WasmVerification {
+    /// Do the checksums match?
+    pub fn is_match(&self) -> bool {
+        self.local_checksum == self.chain_checksum
+    }
+}
+
+fn wasm_file_checksum(path: &Path) -> Result<Vec<u8>, crate::Error> {
+    let make_error = |source| crate::Error::LoadingWasmFromFile {
+        path: path.to_owned(),
+        source,
+    };
+    let wasm_byte_code = fs_err::read(path).map_err(make_error)?;
+    Ok(Sha256::digest(decompress_wasm(wasm_byte_code).map_err(make_error)?).to_vec())
+}
+
+fn decompress_wasm(wasm_byte_code: Vec<u8>) -> std::io::Result<Vec<u8>> {
+    // Gzip magic bytes, see RFC 1952
+    if wasm_byte_code.starts_with(&[0x1f, 0x8b]) {
+        let mut decompressed = vec![];
+        GzDecoder::new(wasm_byte_code.as_slice()).read_to_end(&mut decompressed)?;
+        Ok(decompressed)
+    } else {
+        Ok(wasm_byte_code)
+    }
+}
+
+/// Who is allowed to instantiate a code ID.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InstantiatePermission {
+    /// No one may instantiate, except via governance
+    Nobody,
+    /// Anyone may instantiate
+    Everybody,
+    /// Only the listed addresses may instantiate
+    AnyOf(Vec<Address>),
+}
+
+impl From<InstantiatePermission> for AccessConfig {
+    fn from(permission: InstantiatePermission) -> Self {
+        let (permission, addresses) = match permission {
+            InstantiatePermission::Nobody => (AccessType::Nobody, vec![]),
+            InstantiatePermission::Everybody => (AccessType::Everybody, vec![]),
+            InstantiatePermission::AnyOf(addresses) => (
+                AccessType::AnyOfAddresses,
+                addresses
+                    .into_iter()
+                    .map(|addr| addr.get_address_string())
+                    .collect(),
+            ),
+        };
+        AccessConfig {
+            permission: permission as i32,
+            addresses,
+            ..Default::default()
+        }
+    }
+}
+
+impl InstantiatePermission {
+    fn from_access_config(config: AccessConfig) -> Result<Self, String> {
+        match AccessType::try_from(config.permission) {
+            Ok(AccessType::Nobody) => Ok(InstantiatePermission::Nobody),
+            Ok(AccessType::Everybody) => Ok(InstantiatePermission::Everybody),
+            Ok(AccessType::AnyOfAddresses) => config
+                .addresses
+                .iter()
+                .map(|addr| {
+                    addr.parse()
+                        .map_err(|e| format!("Invalid address in instantiate permission: {e}"))
+                })
+                .collect::<Result<_, _>>()
+                .map(InstantiatePermission::AnyOf),
+            _ => Err(format!(
+                "Unsupported instantiate permission type {}",
+                config.permission
+            )),
+        }
+    }
+}
+
+/// Parses `nobody`, `everybody`, or a comma-separated list of addresses.
+impl FromStr for InstantiatePermission {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "nobody" => Ok(InstantiatePermission::Nobody),
+            "everybody" => Ok(InstantiatePermission::Everybody),
+            _ => s
+                .split(',')
+                .map(|addr| addr.trim().parse())
+                .collect::<Result<_, _>>()
+                .map(InstantiatePermission::AnyOf),
+        }
+    }
+}
+
+impl Display for InstantiatePermission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstantiatePermission::Nobody => f.write_str("nobody"),
+            InstantiatePermission::Everybody => f.write_str("everybody"),
+            InstantiatePermission::AnyOf(addresses) => {
+                f.write_str("any of ")?;
+                for (idx, addr) in addresses.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{addr}")?;
+                }
+                Ok(())
+            }
+        }
+    }
 }
 
 impl Cosmos {
+    /// List information on all code uploaded to the chain.
+    pub async fn list_codes(&self) -> Result<Vec<CodeInfoResponse>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    QueryCodesRequest {
+                        pagination: pagination.take(),
+                    },
+                    Action::ListCodes,
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.code_infos);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
     pub(crate) fn compress_wasm_code(wasm_byte_code: &[u8]) -> Result<Vec<u8>, crate::Error> {
         // https://github.com/cosmos/cosmjs/blob/f944892fd337af1ae8b5b269d2b2f68cdf2ad6cb/packages/cosmwasm-stargate/src/signingcosmwasmclient.ts#L67
         let mut gzip_encoder = GzEncoder::new(Vec::new(), Compression::new(9));
@@ -151,3 +420,48 @@ impl HasAddressHrp for CodeId {
         self.client.get_address_hrp()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instantiate_permission_roundtrip() {
+        let multisig: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        for permission in [
+            InstantiatePermission::Nobody,
+            InstantiatePermission::Everybody,
+            InstantiatePermission::AnyOf(vec![multisig]),
+        ] {
+            let config = AccessConfig::from(permission.clone());
+            assert_eq!(
+                InstantiatePermission::from_access_config(config).unwrap(),
+                permission
+            );
+        }
+        assert!(InstantiatePermission::from_access_config(AccessConfig::default()).is_err());
+        assert_eq!(
+            format!("nobody,{multisig}")
+                .parse::<InstantiatePermission>()
+                .ok(),
+            None
+        );
+        assert_eq!(
+            multisig
+                .to_string()
+                .parse::<InstantiatePermission>()
+                .unwrap(),
+            InstantiatePermission::AnyOf(vec![multisig])
+        );
+    }
+
+    #[test]
+    fn decompress_gzipped_wasm() {
+        let wasm = b"\0asm\x01\0\0\0".to_vec();
+        let gzipped = Cosmos::compress_wasm_code(&wasm).unwrap();
+        assert_eq!(decompress_wasm(gzipped).unwrap(), wasm);
+        assert_eq!(decompress_wasm(wasm.clone()).unwrap(), wasm);
+    }
+}
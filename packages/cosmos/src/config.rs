@@ -2,6 +2,7 @@ use std::{
     collections::HashMap,
     path::{Path, PathBuf},
     str::FromStr,
+    time::Duration,
 };
 
 use figment::{
@@ -9,19 +10,81 @@ use figment::{
     Figment,
 };
 
-use crate::{AddressHrp, CosmosBuilder, CosmosNetwork};
+use crate::{
+    error::WalletError, Address, AddressHrp, BroadcastMode, CosmosBuilder, CosmosNetwork,
+    DynamicGasMultiplier, SeedPhrase,
+};
 
 /// Configuration overrides for individual network
 #[derive(Debug)]
 pub struct CosmosConfig {
+    /// The file changes are saved to
     path: PathBuf,
+    /// Every file which contributed to the merged values, lowest precedence first
+    sources: Vec<PathBuf>,
+    /// Merged values from all sources, used for lookups
     inner: CosmosConfigInner,
+    /// Values from [Self::path] alone, used for saving
+    file: CosmosConfigInner,
 }
 
-#[derive(serde::Deserialize, serde::Serialize, Debug)]
+/// Name of the project-local config file.
+///
+/// It is looked up in the current directory and its ancestors.
+pub const LOCAL_CONFIG_FILE: &str = "cosmos.toml";
+
+/// Prefix of environment variables overriding config values, see [CosmosConfig::load].
+pub const CONFIG_ENV_PREFIX: &str = "COSMOS_CONFIG_";
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Default)]
 struct CosmosConfigInner {
+    #[serde(default)]
+    default: DefaultConfig,
     #[serde(default)]
     network: HashMap<String, NetworkConfig>,
+    #[serde(default)]
+    profile: HashMap<String, ProfileConfig>,
+    #[serde(default)]
+    wallet: HashMap<String, WalletConfig>,
+}
+
+/// What to use when neither a network nor a profile is specified.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Default)]
+#[serde(rename_all = "kebab-case")]
+struct DefaultConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    network: Option<String>,
+    /// Takes precedence over the default network
+    #[serde(skip_serializing_if = "Option::is_none")]
+    profile: Option<String>,
+}
+
+/// A named wallet from the config file.
+///
+/// The seed phrase comes from the keyring entry if set, otherwise the keystore
+/// file, otherwise a base seed phrase supplied by the caller.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Default, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct WalletConfig {
+    /// Name of a seed phrase stored in the OS keyring
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyring: Option<String>,
+    /// Path to an encrypted keystore file, see [SeedPhrase::save_encrypted]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keystore: Option<PathBuf>,
+    /// Index within the chain's default derivation path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub derivation_index: Option<u64>,
+}
+
+/// A named network selection with its own overrides, e.g. `prod` or `staging`.
+#[derive(serde::Deserialize, serde::Serialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+struct ProfileConfig {
+    network: String,
+    /// Applied on top of the network's own config
+    #[serde(flatten)]
+    overrides: NetworkConfig,
 }
 
 #[derive(serde::Deserialize, serde::Serialize, Debug, Default)]
@@ -33,6 +96,40 @@ struct NetworkConfig {
     hrp: Option<AddressHrp>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     grpc_fallbacks: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rpc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_timeout_seconds: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_retries: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_lag_allowed: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connection_timeout_ms: Option<u64>,
+    /// Requests per second
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    referer_header: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    all_nodes_broadcast: Option<bool>,
+    /// Static gas multiplier, ignored if dynamic-gas is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gas_multiplier: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dynamic_gas: Option<DynamicGasMultiplier>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dynamic_gas_retries: Option<u32>,
+    /// Default memo for [crate::Cosmos::tx_builder]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memo: Option<String>,
+    /// Blocks after the latest block before transactions time out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout_height_offset: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fee_granter: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    broadcast_mode: Option<BroadcastMode>,
 }
 
 impl NetworkConfig {
@@ -54,6 +151,136 @@ impl NetworkConfig {
         for fallback in &self.grpc_fallbacks {
             builder.add_grpc_fallback_url(fallback);
         }
+        if let Some(rpc) = self.rpc.clone() {
+            builder.set_rpc_url(Some(rpc));
+        }
+        if let Some(secs) = self.query_timeout_seconds {
+            builder.set_query_timeout_seconds(Some(secs));
+        }
+        if let Some(retries) = self.query_retries {
+            builder.set_query_retries(Some(retries));
+        }
+        if let Some(lag) = self.block_lag_allowed {
+            builder.set_block_lag_allowed(Some(lag));
+        }
+        if let Some(ms) = self.connection_timeout_ms {
+            builder.set_connection_timeout(Some(Duration::from_millis(ms)));
+        }
+        if let Some(limit) = self.rate_limit {
+            builder.set_rate_limit(limit);
+        }
+        if let Some(referer_header) = self.referer_header.clone() {
+            builder.set_referer_header(Some(referer_header));
+        }
+        if let Some(all_nodes_broadcast) = self.all_nodes_broadcast {
+            builder.set_all_nodes_broadcast(all_nodes_broadcast);
+        }
+        if let Some(multiplier) = self.gas_multiplier {
+            builder.set_gas_estimate_multiplier(multiplier);
+        }
+        if let Some(dynamic_gas) = self.dynamic_gas.clone() {
+            builder.set_dynamic_gas_estimate_multiplier(dynamic_gas);
+        }
+        if let Some(retries) = self.dynamic_gas_retries {
+            builder.set_dynamic_gas_retries(Some(retries));
+        }
+        if let Some(memo) = self.memo.clone() {
+            builder.set_default_memo(Some(memo));
+        }
+        if let Some(offset) = self.timeout_height_offset {
+            builder.set_timeout_height_offset(Some(offset));
+        }
+        if let Some(granter) = self.fee_granter {
+            builder.set_default_fee_granter(Some(granter));
+        }
+        if let Some(mode) = self.broadcast_mode {
+            builder.set_default_broadcast_mode(Some(mode));
+        }
+    }
+
+    fn print(&self) {
+        let NetworkConfig {
+            grpc,
+            chain_id,
+            gas_coin,
+            hrp,
+            grpc_fallbacks,
+            rpc,
+            query_timeout_seconds,
+            query_retries,
+            block_lag_allowed,
+            connection_timeout_ms,
+            rate_limit,
+            referer_header,
+            all_nodes_broadcast,
+            gas_multiplier,
+            dynamic_gas,
+            dynamic_gas_retries,
+            memo,
+            timeout_height_offset,
+            fee_granter,
+            broadcast_mode,
+        } = self;
+        if let Some(grpc) = grpc {
+            println!("Primary endpoint: {grpc}");
+        }
+        for (idx, fallback) in grpc_fallbacks.iter().enumerate() {
+            println!("Fallback #{}: {fallback}", idx + 1);
+        }
+        if let Some(rpc) = rpc {
+            println!("RPC endpoint: {rpc}");
+        }
+        if let Some(chain_id) = chain_id {
+            println!("Chain ID: {chain_id}");
+        }
+        if let Some(gas_coin) = gas_coin {
+            println!("Gas coin: {gas_coin}");
+        }
+        if let Some(hrp) = hrp {
+            println!("Address prefix (HRP): {hrp}");
+        }
+        if let Some(secs) = query_timeout_seconds {
+            println!("Query timeout: {secs}s");
+        }
+        if let Some(retries) = query_retries {
+            println!("Query retries: {retries}");
+        }
+        if let Some(lag) = block_lag_allowed {
+            println!("Block lag allowed: {lag}");
+        }
+        if let Some(ms) = connection_timeout_ms {
+            println!("Connection timeout: {ms}ms");
+        }
+        if let Some(limit) = rate_limit {
+            println!("Rate limit: {limit} requests/second");
+        }
+        if let Some(referer_header) = referer_header {
+            println!("Referer header: {referer_header}");
+        }
+        if let Some(all_nodes_broadcast) = all_nodes_broadcast {
+            println!("Broadcast to all nodes: {all_nodes_broadcast}");
+        }
+        if let Some(multiplier) = gas_multiplier {
+            println!("Gas multiplier: {multiplier}");
+        }
+        if let Some(dynamic_gas) = dynamic_gas {
+            println!("Dynamic gas: {dynamic_gas:?}");
+        }
+        if let Some(retries) = dynamic_gas_retries {
+            println!("Dynamic gas retries: {retries}");
+        }
+        if let Some(memo) = memo {
+            println!("Default memo: {memo}");
+        }
+        if let Some(offset) = timeout_height_offset {
+            println!("Timeout height offset: {offset} blocks");
+        }
+        if let Some(granter) = fee_granter {
+            println!("Default fee granter: {granter}");
+        }
+        if let Some(mode) = broadcast_mode {
+            println!("Default broadcast mode: {mode:?}");
+        }
     }
 }
 
@@ -72,6 +299,24 @@ pub enum CosmosConfigError {
     },
     #[error("Unknown network {network:?} specified, not a known built-in network or found in config {}", config.display())]
     UnknownNetwork { network: String, config: PathBuf },
+    #[error("Unknown profile {profile:?} specified, not found in config {}", config.display())]
+    UnknownProfile { profile: String, config: PathBuf },
+    #[error("No network specified, and no default network or profile set in config {}", config.display())]
+    NoNetworkSelected { config: PathBuf },
+    #[error("Chain registry entry {chain:?} is missing {field}")]
+    RegistryMissingField { chain: String, field: &'static str },
+    #[error("Unknown wallet {name:?}, not found in config {}", config.display())]
+    UnknownWallet { name: String, config: PathBuf },
+    #[error(
+        "Wallet {name:?} has no keyring entry or keystore file, and no seed phrase was provided"
+    )]
+    WalletMissingSource { name: String },
+    #[error("Wallet {name:?} uses a keystore file, but no passphrase was provided")]
+    KeystorePassphraseRequired { name: String },
+    #[error("Wallet {name:?} uses the OS keyring, but keyring support is not enabled")]
+    KeyringUnavailable { name: String },
+    #[error("Unable to load wallet {name:?}: {source}")]
+    Wallet { name: String, source: WalletError },
     #[error(transparent)]
     Builder { source: crate::error::BuilderError },
     #[error("Missing required config values for network {network:?} in config file {}: {missing}", path.display())]
@@ -97,39 +342,149 @@ impl CosmosConfig {
         Ok(dirs.config_dir().join("config.toml"))
     }
 
-    /// Load the config values from the default config file location
+    /// Find the system-wide config file location, if the platform has one
+    pub fn system_file() -> Option<PathBuf> {
+        cfg!(unix).then(|| PathBuf::from("/etc/cosmos-rs/config.toml"))
+    }
+
+    /// Find the nearest project-local config file, see [LOCAL_CONFIG_FILE]
+    pub fn local_file() -> Option<PathBuf> {
+        std::env::current_dir()
+            .ok()?
+            .ancestors()
+            .map(|dir| dir.join(LOCAL_CONFIG_FILE))
+            .find(|path| path.is_file())
+    }
+
+    /// Load and merge the config values from all default locations
+    ///
+    /// Sources are merged in the following order, later sources taking precedence:
+    ///
+    /// 1. The system-wide file, see [Self::system_file]
+    /// 2. The user file, see [Self::default_file]
+    /// 3. The project-local file, see [Self::local_file]
+    /// 4. Environment variables prefixed with [CONFIG_ENV_PREFIX]
+    ///
+    /// In environment variable names, `__` separates nested keys and `_`
+    /// stands for `-`, since shells do not allow dashes. For example,
+    /// `COSMOS_CONFIG_NETWORK__OSMOSIS_MAINNET__GAS_COIN=uosmo` sets `gas-coin`
+    /// in the `[network.osmosis-mainnet]` table.
+    ///
+    /// Tables are merged key by key, so a project file can provide a network's
+    /// endpoints while API keys stay in the user file. Arrays, such as gRPC
+    /// fallbacks, are replaced instead of appended. Changes are always saved
+    /// to the user file.
     pub fn load() -> Result<CosmosConfig, CosmosConfigError> {
-        Self::load_from(&Self::default_file()?, false)
+        let user = Self::default_file()?;
+        let sources = Self::system_file()
+            .into_iter()
+            .chain(std::iter::once(user.clone()))
+            .chain(Self::local_file())
+            .collect();
+        Self::load_layered(user, sources)
     }
 
     /// Load the config values from the specified file
+    ///
+    /// No other files are merged in, but environment variables still apply.
     pub fn load_from(config: &Path, required: bool) -> Result<CosmosConfig, CosmosConfigError> {
         if required && !config.exists() {
             return Err(CosmosConfigError::FileNotFound {
                 path: config.to_owned(),
             });
         }
-        let inner = Figment::new()
-            .merge(Toml::file(config))
-            .merge(Env::prefixed("COSMOS_CONFIG_"))
-            .extract()
-            .map_err(|source| CosmosConfigError::ConfigLoadError {
-                source,
-                path: config.to_owned(),
-            })?;
+        Self::load_layered(config.to_owned(), vec![config.to_owned()])
+    }
+
+    fn load_layered(
+        path: PathBuf,
+        sources: Vec<PathBuf>,
+    ) -> Result<CosmosConfig, CosmosConfigError> {
+        let extract = |figment: Figment| {
+            figment
+                .extract()
+                .map_err(|source| CosmosConfigError::ConfigLoadError {
+                    source,
+                    path: path.clone(),
+                })
+        };
+        let inner = extract(
+            sources
+                .iter()
+                .fold(Figment::new(), |figment, source| {
+                    figment.merge(Toml::file_exact(source))
+                })
+                .merge(
+                    Env::prefixed(CONFIG_ENV_PREFIX)
+                        .split("__")
+                        .map(|key| key.as_str().replace('_', "-").into()),
+                ),
+        )?;
+        let file = extract(Figment::from(Toml::file_exact(&path)))?;
         Ok(CosmosConfig {
-            path: config.to_owned(),
+            sources: sources.into_iter().filter(|x| x.exists()).collect(),
+            path,
             inner,
+            file,
         })
     }
 
-    /// Generate a builder for the given network name
+    /// Apply a change to both the merged values and the values to be saved
+    fn edit(&mut self, f: impl Fn(&mut CosmosConfigInner)) {
+        f(&mut self.inner);
+        f(&mut self.file);
+    }
+
+    /// Does the config file select a network when none is given explicitly?
+    pub(crate) fn has_default(&self) -> bool {
+        self.inner.default.network.is_some() || self.inner.default.profile.is_some()
+    }
+
+    /// Generate a builder for the given network name and profile
     ///
-    /// If the network name is not a valid [CosmosNetwork], and there are insufficient config settings in the config file, this will generate an error.
-    pub(crate) async fn builder_for(
+    /// If no profile is given and no network is given, the default profile
+    /// from the config file is used, followed by the default network. An
+    /// explicit network takes precedence over the profile's network, but the
+    /// profile's overrides are still applied.
+    pub async fn builder_for(
         &self,
-        network: &str,
+        network: Option<&str>,
+        profile: Option<&str>,
     ) -> Result<CosmosBuilder, CosmosConfigError> {
+        let profile = match profile {
+            Some(profile) => Some(profile),
+            None if network.is_none() => self.inner.default.profile.as_deref(),
+            None => None,
+        };
+        let profile = profile
+            .map(|name| {
+                self.inner
+                    .profile
+                    .get(name)
+                    .ok_or_else(|| CosmosConfigError::UnknownProfile {
+                        profile: name.to_owned(),
+                        config: self.path.clone(),
+                    })
+            })
+            .transpose()?;
+        let network = network
+            .or(profile.map(|profile| profile.network.as_str()))
+            .or(self.inner.default.network.as_deref())
+            .ok_or_else(|| CosmosConfigError::NoNetworkSelected {
+                config: self.path.clone(),
+            })?;
+        let mut builder = self.builder_for_network(network).await?;
+        if let Some(profile) = profile {
+            profile.overrides.apply_base_config(&mut builder);
+            profile.overrides.apply_extra_config(&mut builder);
+        }
+        Ok(builder)
+    }
+
+    /// Generate a builder for the given network name
+    ///
+    /// If the network name is not a valid [CosmosNetwork], and there are insufficient config settings in the config file, this will generate an error.
+    async fn builder_for_network(&self, network: &str) -> Result<CosmosBuilder, CosmosConfigError> {
         match (
             CosmosNetwork::from_str(network).ok(),
             self.inner.network.get(network),
@@ -185,39 +540,45 @@ impl CosmosConfig {
         }
     }
 
+    /// Names of all networks configured in the file, sorted alphabetically
+    pub fn network_names(&self) -> Vec<&str> {
+        let mut names = self
+            .inner
+            .network
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
     /// Print out a description of the config file
     pub fn print(&self) {
         println!("Location: {}", self.path.display());
+        for source in self.sources.iter().filter(|source| **source != self.path) {
+            println!("Merged from: {}", source.display());
+        }
+        let DefaultConfig { network, profile } = &self.inner.default;
+        if let Some(network) = network {
+            println!("Default network: {network}");
+        }
+        if let Some(profile) = profile {
+            println!("Default profile: {profile}");
+        }
         let mut networks = self.inner.network.iter().collect::<Vec<_>>();
         networks.sort_by_key(|x| x.0);
-        for (
-            network,
-            NetworkConfig {
-                grpc,
-                chain_id,
-                gas_coin,
-                hrp,
-                grpc_fallbacks,
-            },
-        ) in networks
-        {
+        for (network, config) in networks {
             println!();
             println!("{network}");
-            if let Some(grpc) = grpc {
-                println!("Primary endpoint: {grpc}");
-            }
-            for (idx, fallback) in grpc_fallbacks.iter().enumerate() {
-                println!("Fallback #{}: {fallback}", idx + 1);
-            }
-            if let Some(chain_id) = chain_id {
-                println!("Chain ID: {chain_id}");
-            }
-            if let Some(gas_coin) = gas_coin {
-                println!("Gas coin: {gas_coin}");
-            }
-            if let Some(hrp) = hrp {
-                println!("Address prefix (HRP): {hrp}");
-            }
+            config.print();
+        }
+        let mut profiles = self.inner.profile.iter().collect::<Vec<_>>();
+        profiles.sort_by_key(|x| x.0);
+        for (name, ProfileConfig { network, overrides }) in profiles {
+            println!();
+            println!("Profile {name}");
+            println!("Network: {network}");
+            overrides.print();
         }
     }
 
@@ -230,16 +591,18 @@ impl CosmosConfig {
         gas_coin: String,
         hrp: AddressHrp,
     ) {
-        self.inner.network.insert(
-            name,
-            NetworkConfig {
-                grpc: Some(grpc),
-                chain_id: Some(chain_id),
-                gas_coin: Some(gas_coin),
-                hrp: Some(hrp),
-                grpc_fallbacks: vec![],
-            },
-        );
+        self.edit(|inner| {
+            inner.network.insert(
+                name.clone(),
+                NetworkConfig {
+                    grpc: Some(grpc.clone()),
+                    chain_id: Some(chain_id.clone()),
+                    gas_coin: Some(gas_coin.clone()),
+                    hrp: Some(hrp),
+                    ..NetworkConfig::default()
+                },
+            );
+        });
     }
 
     /// Write the config to the original file.
@@ -249,7 +612,7 @@ impl CosmosConfig {
 
     /// Write the config to the given file.
     pub fn save_to(&self, path: impl AsRef<Path>) -> Result<(), CosmosConfigError> {
-        let s = toml::to_string_pretty(&self.inner)
+        let s = toml::to_string_pretty(&self.file)
             .map_err(|source| CosmosConfigError::TomlSerialization { source })?;
         let path = path.as_ref();
         if let Some(parent) = path.parent() {
@@ -266,32 +629,202 @@ impl CosmosConfig {
 
     /// Set the primary gRPC endpoint
     pub fn set_grpc(&mut self, name: String, url: String) {
-        self.inner.network.entry(name).or_default().grpc = Some(url);
+        self.edit(|inner| inner.network.entry(name.clone()).or_default().grpc = Some(url.clone()));
     }
 
     /// Set the chain ID
     pub fn set_chain_id(&mut self, name: String, chain_id: String) {
-        self.inner.network.entry(name).or_default().chain_id = Some(chain_id);
+        self.edit(|inner| {
+            inner.network.entry(name.clone()).or_default().chain_id = Some(chain_id.clone())
+        });
     }
 
     /// Set the Human Readable Part (HRP)
     pub fn set_hrp(&mut self, name: String, hrp: AddressHrp) {
-        self.inner.network.entry(name).or_default().hrp = Some(hrp);
+        self.edit(|inner| inner.network.entry(name.clone()).or_default().hrp = Some(hrp));
     }
 
     /// Set the gas coin
     pub fn set_gas_coin(&mut self, name: String, gas_coin: String) {
-        self.inner.network.entry(name).or_default().gas_coin = Some(gas_coin);
+        self.edit(|inner| {
+            inner.network.entry(name.clone()).or_default().gas_coin = Some(gas_coin.clone())
+        });
+    }
+
+    /// Set the network used when none is specified
+    pub fn set_default_network(&mut self, network: Option<String>) {
+        self.edit(|inner| inner.default.network = network.clone());
+    }
+
+    /// Set the profile used when neither a network nor a profile is specified
+    pub fn set_default_profile(&mut self, profile: Option<String>) {
+        self.edit(|inner| inner.default.profile = profile.clone());
+    }
+
+    /// Add or update a network using the Cosmos chain registry.
+    ///
+    /// `chain` is the registry path of the chain, e.g. `juno` or
+    /// `testnets/junotestnet`. The chain ID, HRP, fee denom and endpoints are
+    /// overwritten, other settings for the network are left as-is. The first
+    /// listed gRPC endpoint becomes the primary, the rest become fallbacks.
+    pub async fn import_from_registry(
+        &mut self,
+        name: String,
+        chain: &str,
+    ) -> Result<(), CosmosConfigError> {
+        let url = format!("{CHAIN_REGISTRY_URL}/{chain}/chain.json");
+        let info: RegistryChain = crate::cosmos_network::load_json(&url, &reqwest::Client::new())
+            .await
+            .map_err(|source| CosmosConfigError::Builder { source })?;
+        let missing = |field| CosmosConfigError::RegistryMissingField {
+            chain: chain.to_owned(),
+            field,
+        };
+        let hrp = AddressHrp::new(&info.bech32_prefix).map_err(|_| missing("bech32_prefix"))?;
+        let gas_coin = info
+            .fees
+            .fee_tokens
+            .into_iter()
+            .next()
+            .ok_or_else(|| missing("fees.fee_tokens"))?
+            .denom;
+        let mut grpcs = info
+            .apis
+            .grpc
+            .into_iter()
+            .map(|endpoint| grpc_url_from_registry(&endpoint.address));
+        let grpc = grpcs.next().ok_or_else(|| missing("apis.grpc"))?;
+
+        let grpc_fallbacks = grpcs.collect::<Vec<_>>();
+        let rpc = info.apis.rpc.into_iter().next().map(|x| x.address);
+        self.edit(|inner| {
+            let network = inner.network.entry(name.clone()).or_default();
+            network.chain_id = Some(info.chain_id.clone());
+            network.hrp = Some(hrp);
+            network.gas_coin = Some(gas_coin.clone());
+            network.grpc = Some(grpc.clone());
+            network.grpc_fallbacks = grpc_fallbacks.clone();
+            network.rpc = rpc.clone();
+        });
+        Ok(())
+    }
+
+    /// Look up a named wallet
+    pub fn get_wallet(&self, name: &str) -> Result<&WalletConfig, CosmosConfigError> {
+        self.inner
+            .wallet
+            .get(name)
+            .ok_or_else(|| CosmosConfigError::UnknownWallet {
+                name: name.to_owned(),
+                config: self.path.clone(),
+            })
+    }
+
+    /// Load the seed phrase for a named wallet.
+    ///
+    /// `base` is only used if the wallet has no keyring entry or keystore
+    /// file, and `keystore_passphrase` is only needed for keystore files. If
+    /// the wallet has a derivation index, the returned phrase uses that index
+    /// within the default derivation path for `hrp`.
+    pub fn load_wallet_phrase(
+        &self,
+        name: &str,
+        hrp: AddressHrp,
+        base: Option<SeedPhrase>,
+        keystore_passphrase: Option<&str>,
+    ) -> Result<SeedPhrase, CosmosConfigError> {
+        let wallet = self.get_wallet(name)?;
+        let with_name = |source| CosmosConfigError::Wallet {
+            name: name.to_owned(),
+            source,
+        };
+        let phrase = match (&wallet.keyring, &wallet.keystore) {
+            #[cfg(feature = "keyring")]
+            (Some(entry), _) => SeedPhrase::load_from_keyring(entry).map_err(with_name)?,
+            #[cfg(not(feature = "keyring"))]
+            (Some(_), _) => {
+                return Err(CosmosConfigError::KeyringUnavailable {
+                    name: name.to_owned(),
+                })
+            }
+            (None, Some(keystore)) => {
+                let passphrase = keystore_passphrase.ok_or_else(|| {
+                    CosmosConfigError::KeystorePassphraseRequired {
+                        name: name.to_owned(),
+                    }
+                })?;
+                SeedPhrase::load_encrypted(keystore, passphrase).map_err(with_name)?
+            }
+            (None, None) => base.ok_or_else(|| CosmosConfigError::WalletMissingSource {
+                name: name.to_owned(),
+            })?,
+        };
+        Ok(match wallet.derivation_index {
+            Some(index) => {
+                phrase.with_derivation_path(Some(hrp.default_derivation_path_with_index(index)))
+            }
+            None => phrase,
+        })
     }
 
     /// Add a gRPC fallback
     pub fn add_grpc_fallback(&mut self, name: String, url: String) {
-        self.inner
-            .network
-            .entry(name)
-            .or_default()
-            .grpc_fallbacks
-            .push(url);
+        self.edit(|inner| {
+            inner
+                .network
+                .entry(name.clone())
+                .or_default()
+                .grpc_fallbacks
+                .push(url.clone())
+        });
+    }
+}
+
+const CHAIN_REGISTRY_URL: &str = "https://raw.githubusercontent.com/cosmos/chain-registry/master";
+
+/// The subset of a chain registry `chain.json` file we care about.
+#[derive(serde::Deserialize)]
+struct RegistryChain {
+    chain_id: String,
+    bech32_prefix: String,
+    #[serde(default)]
+    fees: RegistryFees,
+    #[serde(default)]
+    apis: RegistryApis,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct RegistryFees {
+    #[serde(default)]
+    fee_tokens: Vec<RegistryFeeToken>,
+}
+
+#[derive(serde::Deserialize)]
+struct RegistryFeeToken {
+    denom: String,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct RegistryApis {
+    #[serde(default)]
+    grpc: Vec<RegistryEndpoint>,
+    #[serde(default)]
+    rpc: Vec<RegistryEndpoint>,
+}
+
+#[derive(serde::Deserialize)]
+struct RegistryEndpoint {
+    address: String,
+}
+
+/// The registry often lists gRPC endpoints without a scheme. Assume TLS only on port 443.
+fn grpc_url_from_registry(address: &str) -> String {
+    if address.contains("://") {
+        address.to_owned()
+    } else if address.ends_with(":443") {
+        format!("https://{address}")
+    } else {
+        format!("http://{address}")
     }
 }
 
@@ -299,6 +832,240 @@ impl CosmosNetwork {
     /// Generating a builder, respecting the default config file.
     pub async fn builder_with_config(&self) -> Result<CosmosBuilder, CosmosConfigError> {
         let config = CosmosConfig::load()?;
-        config.builder_for(self.as_str()).await
+        config.builder_for(Some(self.as_str()), None).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HasAddress;
+
+    #[test]
+    fn wallet_derivation_index() {
+        let inner: CosmosConfigInner = toml::from_str(
+            r#"
+            [wallet.ops]
+            derivation-index = 2
+            "#,
+        )
+        .unwrap();
+        let config = CosmosConfig {
+            path: PathBuf::from("config.toml"),
+            sources: vec![],
+            inner,
+            file: CosmosConfigInner::default(),
+        };
+        let hrp = AddressHrp::from_static("osmo");
+        let base = SeedPhrase::random();
+        let phrase = config
+            .load_wallet_phrase("ops", hrp, Some(base.clone()), None)
+            .unwrap();
+        assert_eq!(
+            phrase.with_hrp(hrp).unwrap().get_address(),
+            base.with_cosmos_numbered(2)
+                .with_hrp(hrp)
+                .unwrap()
+                .get_address()
+        );
+        assert!(matches!(
+            config.load_wallet_phrase("ops", hrp, None, None),
+            Err(CosmosConfigError::WalletMissingSource { .. })
+        ));
+        assert!(matches!(
+            config.load_wallet_phrase("missing", hrp, None, None),
+            Err(CosmosConfigError::UnknownWallet { .. })
+        ));
+    }
+
+    #[test]
+    fn registry_chain() {
+        let info: RegistryChain = serde_json::from_str(
+            r#"{
+                "chain_name": "juno",
+                "chain_id": "juno-1",
+                "bech32_prefix": "juno",
+                "fees": {"fee_tokens": [{"denom": "ujuno", "low_gas_price": 0.075}]},
+                "apis": {"grpc": [{"address": "grpc.juno.example:443"}, {"address": "juno.example:9090"}]}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(info.fees.fee_tokens[0].denom, "ujuno");
+        assert!(info.apis.rpc.is_empty());
+        assert_eq!(
+            grpc_url_from_registry(&info.apis.grpc[0].address),
+            "https://grpc.juno.example:443"
+        );
+        assert_eq!(
+            grpc_url_from_registry(&info.apis.grpc[1].address),
+            "http://juno.example:9090"
+        );
+        assert_eq!(
+            grpc_url_from_registry("https://juno.example"),
+            "https://juno.example"
+        );
+    }
+
+    #[tokio::test]
+    async fn profiles_and_defaults() {
+        let inner: CosmosConfigInner = toml::from_str(
+            r#"
+            [default]
+            network = "local"
+
+            [network.local]
+            grpc = "http://localhost:9090"
+            chain-id = "local-1"
+            gas-coin = "ulocal"
+            hrp = "local"
+
+            [profile.staging]
+            network = "local"
+            chain-id = "staging-1"
+            query-timeout-seconds = 30
+            "#,
+        )
+        .unwrap();
+        let mut config = CosmosConfig {
+            path: PathBuf::from("config.toml"),
+            sources: vec![],
+            inner,
+            file: CosmosConfigInner::default(),
+        };
+
+        let builder = config.builder_for(None, None).await.unwrap();
+        assert_eq!(builder.chain_id(), "local-1");
+
+        let builder = config.builder_for(None, Some("staging")).await.unwrap();
+        assert_eq!(builder.chain_id(), "staging-1");
+        assert_eq!(builder.query_timeout_seconds(), 30);
+
+        config.set_default_profile(Some("staging".to_owned()));
+        let builder = config.builder_for(None, None).await.unwrap();
+        assert_eq!(builder.chain_id(), "staging-1");
+        let builder = config.builder_for(Some("local"), None).await.unwrap();
+        assert_eq!(builder.chain_id(), "local-1");
+
+        assert!(matches!(
+            config.builder_for(None, Some("prod")).await,
+            Err(CosmosConfigError::UnknownProfile { .. })
+        ));
+    }
+
+    #[test]
+    fn layered_sources() {
+        let dir = std::env::temp_dir().join(format!("cosmos-config-{}", std::process::id()));
+        fs_err::create_dir_all(&dir).unwrap();
+        let system = dir.join("system.toml");
+        let user = dir.join("user.toml");
+        let local = dir.join(LOCAL_CONFIG_FILE);
+        fs_err::write(
+            &system,
+            r#"
+            [network.local]
+            chain-id = "system-1"
+            gas-coin = "usystem"
+            "#,
+        )
+        .unwrap();
+        fs_err::write(
+            &user,
+            r#"
+            [network.local]
+            referer-header = "my-api-key"
+            gas-coin = "uuser"
+            "#,
+        )
+        .unwrap();
+        fs_err::write(
+            &local,
+            r#"
+            [network.local]
+            grpc = "http://localhost:9090"
+            chain-id = "local-1"
+            "#,
+        )
+        .unwrap();
+
+        let mut config =
+            CosmosConfig::load_layered(user.clone(), vec![system, user.clone(), local]).unwrap();
+        let network = &config.inner.network["local"];
+        assert_eq!(network.chain_id.as_deref(), Some("local-1"));
+        assert_eq!(network.gas_coin.as_deref(), Some("uuser"));
+        assert_eq!(network.grpc.as_deref(), Some("http://localhost:9090"));
+        assert_eq!(network.referer_header.as_deref(), Some("my-api-key"));
+
+        config.set_hrp("local".to_owned(), AddressHrp::from_static("local"));
+        config.save().unwrap();
+        let saved = CosmosConfig::load_from(&user, true).unwrap();
+        let network = &saved.inner.network["local"];
+        assert_eq!(network.grpc, None);
+        assert_eq!(network.chain_id, None);
+        assert_eq!(network.hrp, Some(AddressHrp::from_static("local")));
+
+        fs_err::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn env_overrides() {
+        let dir = std::env::temp_dir().join(format!("cosmos-config-env-{}", std::process::id()));
+        fs_err::create_dir_all(&dir).unwrap();
+        let user = dir.join("user.toml");
+        fs_err::write(
+            &user,
+            r#"
+            [network.env-test]
+            chain-id = "file-1"
+            gas-coin = "ufile"
+            "#,
+        )
+        .unwrap();
+
+        std::env::set_var("COSMOS_CONFIG_NETWORK__ENV_TEST__CHAIN_ID", "env-1");
+        let config = CosmosConfig::load_layered(user.clone(), vec![user]);
+        std::env::remove_var("COSMOS_CONFIG_NETWORK__ENV_TEST__CHAIN_ID");
+        let config = config.unwrap();
+        let network = &config.inner.network["env-test"];
+        assert_eq!(network.chain_id.as_deref(), Some("env-1"));
+        assert_eq!(network.gas_coin.as_deref(), Some("ufile"));
+
+        fs_err::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn apply_tunables() {
+        let config: NetworkConfig = toml::from_str(
+            r#"
+            query-timeout-seconds = 12
+            block-lag-allowed = 100
+            connection-timeout-ms = 2500
+            all-nodes-broadcast = true
+            referer-header = "https://example.com"
+            memo = "sent by the ops team"
+            timeout-height-offset = 50
+            broadcast-mode = "async"
+
+            [dynamic-gas]
+            high = 5.0
+            "#,
+        )
+        .unwrap();
+        let mut builder = CosmosBuilder::new(
+            "test-1",
+            "utest",
+            AddressHrp::from_static("test"),
+            "http://localhost:9090",
+        );
+        config.apply_extra_config(&mut builder);
+        assert_eq!(builder.query_timeout_seconds(), 12);
+        assert_eq!(builder.block_lag_allowed(), 100);
+        assert_eq!(builder.connection_timeout(), Duration::from_millis(2500));
+        assert!(builder.get_all_nodes_broadcast());
+        assert_eq!(builder.referer_header(), Some("https://example.com"));
+        assert_eq!(builder.query_retries(), 3);
+        assert_eq!(builder.get_default_memo(), Some("sent by the ops team"));
+        assert_eq!(builder.get_timeout_height_offset(), Some(50));
+        assert_eq!(builder.get_default_broadcast_mode(), BroadcastMode::Async);
+        assert_eq!(builder.get_default_fee_granter(), None);
     }
 }
@@ -1,23 +1,25 @@
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, path::Path, str::FromStr};
 
 use cosmos_sdk_proto::{
     cosmos::{
-        base::{abci::v1beta1::TxResponse, v1beta1::Coin},
+        base::{abci::v1beta1::TxResponse, query::v1beta1::PageRequest, v1beta1::Coin},
         tx::v1beta1::SimulateResponse,
     },
     cosmwasm::wasm::v1::{
-        ContractInfo, MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract,
-        QueryContractHistoryRequest, QueryContractHistoryResponse, QueryContractInfoRequest,
-        QueryRawContractStateRequest, QuerySmartContractStateRequest,
+        ContractInfo, Model, MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract,
+        QueryAllContractStateRequest, QueryContractHistoryRequest, QueryContractHistoryResponse,
+        QueryContractInfoRequest, QueryRawContractStateRequest, QuerySmartContractStateRequest,
     },
 };
+use futures::{Stream, StreamExt, TryStreamExt};
 
 use crate::{
     address::{AddressHrp, HasAddressHrp},
+    client::next_page_request,
     error::{Action, ContractAdminParseError, QueryError},
     TxResponseExt,
 };
-use crate::{Address, CodeId, Cosmos, HasAddress, HasCosmos, TxBuilder, Wallet};
+use crate::{Address, CodeId, Cosmos, HasAddress, HasCosmos, TxBuilder, Wallet, WatchWallet};
 
 /// A Cosmos smart contract
 #[derive(Clone)]
@@ -56,6 +58,27 @@ impl Cosmos {
         }
     }
 
+    /// Run many smart queries concurrently, returning results in input order.
+    ///
+    /// Queries are issued in parallel up to the builder's
+    /// [request count](crate::CosmosBuilder::request_count), and each
+    /// individual query still waits on a node permit like any other request.
+    /// A failure in one query does not affect the others.
+    pub async fn batch_smart_query<T, Msg>(
+        &self,
+        queries: Vec<(Address, Msg)>,
+    ) -> Vec<Result<T, crate::Error>>
+    where
+        T: serde::de::DeserializeOwned,
+        Msg: serde::Serialize,
+    {
+        futures::stream::iter(queries)
+            .map(|(address, msg)| async move { self.make_contract(address).query(msg).await })
+            .buffered(self.get_cosmos_builder().request_count())
+            .collect()
+            .await
+    }
+
     /// Make a new [CodeId] for the given numeric ID.
     pub fn make_code_id(&self, code_id: u64) -> CodeId {
         CodeId {
@@ -88,21 +111,7 @@ impl CodeId {
         msg: impl Into<String>,
         admin: ContractAdmin,
     ) -> Result<Contract, crate::Error> {
-        let msg = msg.into();
-        let msg = MsgInstantiateContract {
-            sender: wallet.get_address().to_string(),
-            admin: match admin {
-                ContractAdmin::NoAdmin => "".to_owned(),
-                ContractAdmin::Sender => wallet.get_address_string(),
-                ContractAdmin::Addr(addr) => addr.get_address_string(),
-            },
-            code_id: self.code_id,
-            label: label.into(),
-            msg: msg.into_bytes(),
-            funds,
-        };
-        let mut txbuilder = TxBuilder::default();
-        txbuilder.add_message(msg);
+        let txbuilder = self.make_instantiate_txbuilder(wallet, label, funds, msg, admin);
         let res = txbuilder.sign_and_broadcast(&self.client, wallet).await?;
 
         let addr =
@@ -134,6 +143,213 @@ impl CodeId {
             })
         }
     }
+
+    /// Generate a pre-flight report for instantiating a contract, without broadcasting anything.
+    ///
+    /// This simulates the instantiation from the given sender and checks for
+    /// common mistakes, such as an admin address for a different chain or
+    /// sending more funds than the sender holds. Any problems found are
+    /// reported in [InstantiatePlan::warnings]. Simulation failures are
+    /// returned as errors.
+    ///
+    /// No private key is needed: pass a [WatchWallet] to plan an
+    /// instantiation from an account you do not control.
+    pub async fn plan_instantiate(
+        &self,
+        sender: impl Into<WatchWallet>,
+        label: impl Into<String>,
+        funds: Vec<Coin>,
+        msg: impl serde::Serialize,
+        admin: ContractAdmin,
+    ) -> Result<InstantiatePlan, crate::Error> {
+        let watch = sender.into();
+        let sender = watch.get_address();
+        let label = label.into();
+        let mut warnings = vec![];
+
+        let admin_addr = match admin {
+            ContractAdmin::NoAdmin => {
+                warnings.push(InstantiateWarning::NoAdmin);
+                None
+            }
+            ContractAdmin::Sender => Some(sender),
+            ContractAdmin::Addr(addr) => {
+                if addr.get_address_hrp() != self.get_address_hrp() {
+                    warnings.push(InstantiateWarning::AdminHrpMismatch {
+                        admin: addr,
+                        expected: self.get_address_hrp(),
+                    });
+                }
+                Some(addr)
+            }
+        };
+
+        if label.trim().is_empty() {
+            warnings.push(InstantiateWarning::EmptyLabel);
+        } else if label.trim() != label {
+            warnings.push(InstantiateWarning::LabelWhitespace);
+        } else if label.len() > MAX_LABEL_SIZE {
+            warnings.push(InstantiateWarning::LabelTooLong { len: label.len() });
+        }
+
+        if !funds.is_empty() {
+            let balances = self.client.all_balances(sender).await?;
+            for coin in &funds {
+                let requested = coin.amount.parse::<u128>().unwrap_or(u128::MAX);
+                let available = balances
+                    .iter()
+                    .find(|balance| balance.denom == coin.denom)
+                    .and_then(|balance| balance.amount.parse::<u128>().ok())
+                    .unwrap_or_default();
+                if requested > available {
+                    warnings.push(InstantiateWarning::InsufficientFunds {
+                        denom: coin.denom.clone(),
+                        requested,
+                        available,
+                    });
+                }
+            }
+        }
+
+        let txbuilder = self.make_instantiate_txbuilder(
+            sender,
+            label.clone(),
+            funds.clone(),
+            serde_json::to_string(&msg)?,
+            admin,
+        );
+        let simres = txbuilder.simulate(&self.client, &[watch]).await?;
+
+        Ok(InstantiatePlan {
+            code_id: self.code_id,
+            sender,
+            admin: admin_addr,
+            label,
+            funds,
+            gas_used: simres.gas_used,
+            txbuilder,
+            warnings,
+        })
+    }
+
+    fn make_instantiate_txbuilder(
+        &self,
+        sender: impl HasAddress,
+        label: impl Into<String>,
+        funds: Vec<Coin>,
+        msg: impl Into<String>,
+        admin: ContractAdmin,
+    ) -> TxBuilder {
+        let msg = msg.into();
+        let msg = MsgInstantiateContract {
+            sender: sender.get_address_string(),
+            admin: match admin {
+                ContractAdmin::NoAdmin => "".to_owned(),
+                ContractAdmin::Sender => sender.get_address_string(),
+                ContractAdmin::Addr(addr) => addr.get_address_string(),
+            },
+            code_id: self.code_id,
+            label: label.into(),
+            msg: msg.into_bytes(),
+            funds,
+        };
+        let mut txbuilder = TxBuilder::default();
+        txbuilder.add_message(msg);
+        txbuilder
+    }
+}
+
+/// Maximum label length accepted by wasmd.
+const MAX_LABEL_SIZE: usize = 128;
+
+/// Pre-flight report generated by [CodeId::plan_instantiate].
+#[derive(Debug)]
+pub struct InstantiatePlan {
+    /// Code ID that will be instantiated
+    pub code_id: u64,
+    /// Sender of the instantiate message
+    pub sender: Address,
+    /// Admin that will be set on the contract, if any
+    pub admin: Option<Address>,
+    /// Contract label
+    pub label: String,
+    /// Funds sent with the instantiation
+    pub funds: Vec<Coin>,
+    /// Gas used during simulation
+    pub gas_used: u64,
+    /// Transaction which can be signed and broadcast to perform the instantiation
+    pub txbuilder: TxBuilder,
+    /// Problems detected during planning
+    pub warnings: Vec<InstantiateWarning>,
+}
+
+impl InstantiatePlan {
+    /// Were any problems detected?
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+}
+
+/// A potential problem detected by [CodeId::plan_instantiate].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstantiateWarning {
+    /// No admin is set, so the contract can never be migrated
+    NoAdmin,
+    /// The admin address belongs to a different chain
+    AdminHrpMismatch {
+        /// Provided admin address
+        admin: Address,
+        /// HRP of the chain we're instantiating on
+        expected: AddressHrp,
+    },
+    /// The label is empty
+    EmptyLabel,
+    /// The label has leading or trailing whitespace
+    LabelWhitespace,
+    /// The label is longer than wasmd allows
+    LabelTooLong {
+        /// Length of the provided label
+        len: usize,
+    },
+    /// The sender does not hold enough of a denom to cover the funds sent
+    InsufficientFunds {
+        /// Denom of the coin
+        denom: String,
+        /// Amount sent with the instantiation
+        requested: u128,
+        /// Amount held by the sender
+        available: u128,
+    },
+}
+
+impl Display for InstantiateWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstantiateWarning::NoAdmin => {
+                write!(f, "No admin set, contract will not be migratable")
+            }
+            InstantiateWarning::AdminHrpMismatch { admin, expected } => write!(
+                f,
+                "Admin address {admin} does not match the chain's address HRP {expected}"
+            ),
+            InstantiateWarning::EmptyLabel => write!(f, "Contract label is empty"),
+            InstantiateWarning::LabelWhitespace => {
+                write!(f, "Contract label has leading or trailing whitespace")
+            }
+            InstantiateWarning::LabelTooLong { len } => write!(
+                f,
+                "Contract label is {len} bytes, maximum allowed is {MAX_LABEL_SIZE}"
+            ),
+            InstantiateWarning::InsufficientFunds {
+                denom,
+                requested,
+                available,
+            } => write!(
+                f,
+                "Sending {requested}{denom}, but sender only holds {available}{denom}"
+            ),
+        }
+    }
 }
 
 impl Contract {
@@ -231,12 +447,95 @@ impl Contract {
             .data)
     }
 
+    /// Get the contract name and version recorded by the cw2 standard.
+    ///
+    /// Returns `None` if the contract does not store cw2 information or
+    /// stores something else under the `contract_info` key.
+    pub async fn version(&self) -> Result<Option<ContractVersion>, crate::Error> {
+        let data = self.query_raw(CW2_KEY).await?;
+        Ok(ContractVersion::parse(&data))
+    }
+
+    /// Stream all raw key/value pairs in the contract's storage.
+    ///
+    /// If a prefix is provided, only keys starting with it are returned.
+    /// Pages are requested lazily as the stream is consumed.
+    pub fn all_state(
+        &self,
+        prefix: Option<Vec<u8>>,
+    ) -> impl Stream<Item = Result<Model, crate::Error>> + Send + 'static {
+        let contract = self.clone();
+        let prefix = prefix.unwrap_or_default();
+        // Starting the pagination at the prefix skips every key sorting before it.
+        let first = PageRequest {
+            key: prefix.clone(),
+            ..PageRequest::default()
+        };
+        futures::stream::try_unfold(Some(first), move |pagination| {
+            let contract = contract.clone();
+            let prefix = prefix.clone();
+            async move {
+                let Some(pagination) = pagination else {
+                    return Ok(None);
+                };
+                let res = contract
+                    .client
+                    .perform_query(
+                        QueryAllContractStateRequest {
+                            address: contract.address.into(),
+                            pagination: Some(pagination),
+                        },
+                        Action::QueryAllContractState(contract.address),
+                    )
+                    .run()
+                    .await?
+                    .into_inner();
+                let mut models = res.models;
+                let next = match models
+                    .iter()
+                    .position(|model| !model.key.starts_with(&prefix))
+                {
+                    Some(end) => {
+                        models.truncate(end);
+                        None
+                    }
+                    None => next_page_request(res.pagination),
+                };
+                Ok::<_, crate::Error>(Some((models, next)))
+            }
+        })
+        .map_ok(|models| futures::stream::iter(models.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
     /// Return a modified [Contract] that queries at the given height.
     pub fn at_height(mut self, height: Option<u64>) -> Self {
         self.client = self.client.at_height(height);
         self
     }
 
+    /// Run the same smart query against several historical heights.
+    ///
+    /// Queries run concurrently, limited like [Cosmos::batch_smart_query].
+    /// Results are returned in the same order as `heights`, and a failure
+    /// at one height, e.g. because it was pruned, does not affect the others.
+    pub async fn query_at_heights<T: serde::de::DeserializeOwned>(
+        &self,
+        msg: impl serde::Serialize,
+        heights: impl IntoIterator<Item = u64>,
+    ) -> Result<Vec<(u64, Result<T, crate::Error>)>, crate::Error> {
+        let msg = serde_json::to_vec(&msg)?;
+        Ok(futures::stream::iter(heights)
+            .map(|height| {
+                let contract = self.clone().at_height(Some(height));
+                let msg = msg.clone();
+                async move { (height, contract.query_rendered(msg).await) }
+            })
+            .buffered(self.client.get_cosmos_builder().request_count())
+            .collect()
+            .await)
+    }
+
     /// Perform a query and return the raw unparsed JSON bytes.
     pub async fn query_bytes(&self, msg: impl serde::Serialize) -> Result<Vec<u8>, crate::Error> {
         self.query_rendered_bytes(serde_json::to_vec(&msg).map_err(crate::Error::JsonSerialize)?)
@@ -331,6 +630,39 @@ impl Contract {
         wallet.broadcast_message(&self.client, msg).await
     }
 
+    /// Upload new code from a file and migrate this contract to it.
+    ///
+    /// After migrating, the contract info is checked to confirm the contract
+    /// now runs the new code ID. `on_progress` is called after each step.
+    pub async fn upgrade_from_file(
+        &self,
+        wallet: &Wallet,
+        wasm_path: impl AsRef<Path>,
+        migrate_msg: impl serde::Serialize,
+        mut on_progress: impl FnMut(UpgradeProgress),
+    ) -> Result<CodeId, crate::Error> {
+        let msg = serde_json::to_vec(&migrate_msg)?;
+        let code_id = self.client.store_code_path(wallet, wasm_path).await?;
+        on_progress(UpgradeProgress::Stored {
+            code_id: code_id.get_code_id(),
+        });
+        let res = self
+            .migrate_binary(wallet, code_id.get_code_id(), msg)
+            .await?;
+        on_progress(UpgradeProgress::Migrated { txhash: res.txhash });
+        let actual = self.info().await?.code_id;
+        if actual != code_id.get_code_id() {
+            return Err(crate::Error::InvalidChainResponse {
+                message: format!("Contract reports code ID {actual} after migrating to {code_id}"),
+                action: Action::ContractInfo(self.address).into(),
+            });
+        }
+        on_progress(UpgradeProgress::Verified {
+            code_id: code_id.get_code_id(),
+        });
+        Ok(code_id)
+    }
+
     /// Get the contract info metadata
     pub async fn info(&self) -> Result<ContractInfo, crate::Error> {
         let action = Action::ContractInfo(self.address);
@@ -392,6 +724,53 @@ impl HasCosmos for Contract {
     }
 }
 
+/// Steps completed by [Contract::upgrade_from_file].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UpgradeProgress {
+    /// New code was uploaded
+    Stored {
+        /// Code ID of the uploaded code
+        code_id: u64,
+    },
+    /// The migration transaction succeeded
+    Migrated {
+        /// Hash of the migrate transaction
+        txhash: String,
+    },
+    /// Contract info confirms the contract runs the new code
+    Verified {
+        /// Code ID the contract now runs
+        code_id: u64,
+    },
+}
+
+/// Raw storage key used by cw2 for contract version information.
+const CW2_KEY: &[u8] = b"contract_info";
+
+/// Contract name and version, as stored by the cw2 standard.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct ContractVersion {
+    /// Contract name, usually the crate name, e.g. `crates.io:cw20-base`
+    pub contract: String,
+    /// Contract version, usually semver
+    pub version: String,
+}
+
+impl ContractVersion {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.is_empty() {
+            return None;
+        }
+        match serde_json::from_slice(data) {
+            Ok(version) => Some(version),
+            Err(e) => {
+                tracing::debug!("Unable to parse cw2 contract_info: {e}");
+                None
+            }
+        }
+    }
+}
+
 /// The on-chain admin for a contract set during instantiation
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ContractAdmin {
@@ -419,3 +798,21 @@ impl FromStr for ContractAdmin {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cw2_version() {
+        assert_eq!(
+            ContractVersion::parse(br#"{"contract":"crates.io:cw20-base","version":"1.1.2"}"#),
+            Some(ContractVersion {
+                contract: "crates.io:cw20-base".to_owned(),
+                version: "1.1.2".to_owned(),
+            })
+        );
+        assert_eq!(ContractVersion::parse(b""), None);
+        assert_eq!(ContractVersion::parse(br#"{"owner":"someone"}"#), None);
+    }
+}
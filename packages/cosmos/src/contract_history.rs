@@ -0,0 +1,201 @@
+use cosmos_sdk_proto::cosmwasm::wasm::v1::{
+    ContractCodeHistoryEntry, ContractCodeHistoryOperationType, QueryContractHistoryRequest,
+};
+
+use crate::{
+    client::next_page_request, error::Action, Address, Contract, HasAddress, HasCosmos,
+    TxResponseExt,
+};
+
+/// Page size used when searching for admin change transactions.
+const ADMIN_SEARCH_PAGE_SIZE: u64 = 50;
+
+/// A single entry in a contract's code history.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    /// Whether this was an instantiation, migration, or genesis import
+    pub operation: ContractCodeHistoryOperationType,
+    /// Code ID the contract ran after this operation
+    pub code_id: u64,
+    /// Block height of the operation, if known
+    pub block_height: Option<u64>,
+    /// Index of the transaction within the block, if known
+    pub tx_index: Option<u64>,
+    /// Instantiate or migrate message
+    ///
+    /// Messages which are not valid JSON are returned as a string.
+    pub msg: serde_json::Value,
+}
+
+impl HistoryEntry {
+    fn from_proto(entry: ContractCodeHistoryEntry) -> Self {
+        let msg = if entry.msg.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&entry.msg).unwrap_or_else(|_| {
+                serde_json::Value::String(String::from_utf8_lossy(&entry.msg).into_owned())
+            })
+        };
+        HistoryEntry {
+            operation: ContractCodeHistoryOperationType::try_from(entry.operation)
+                .unwrap_or(ContractCodeHistoryOperationType::Unspecified),
+            code_id: entry.code_id,
+            block_height: entry.updated.as_ref().map(|pos| pos.block_height),
+            tx_index: entry.updated.as_ref().map(|pos| pos.tx_index),
+            msg,
+        }
+    }
+}
+
+/// A change of the code a contract runs, see [code_changes].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeChange {
+    /// Previous code ID, `None` for the initial instantiation
+    pub from: Option<u64>,
+    /// New code ID
+    pub to: u64,
+    /// Block height of the change, if known
+    pub block_height: Option<u64>,
+}
+
+/// Summarize the code changes in a contract's history.
+///
+/// Migrations to the same code ID are skipped.
+pub fn code_changes(entries: &[HistoryEntry]) -> Vec<CodeChange> {
+    let mut current = None;
+    let mut changes = vec![];
+    for entry in entries {
+        if current != Some(entry.code_id) {
+            changes.push(CodeChange {
+                from: current,
+                to: entry.code_id,
+                block_height: entry.block_height,
+            });
+            current = Some(entry.code_id);
+        }
+    }
+    changes
+}
+
+/// A change of a contract's admin, found by [Contract::admin_changes].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdminChange {
+    /// Transaction performing the change
+    pub txhash: String,
+    /// Block height of the transaction
+    pub height: i64,
+    /// New admin, `None` if the admin was cleared
+    pub new_admin: Option<Address>,
+}
+
+impl Contract {
+    /// Get the full code history of this contract, in chronological order.
+    pub async fn history_entries(&self) -> Result<Vec<HistoryEntry>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let page = self
+                .get_cosmos()
+                .perform_query(
+                    QueryContractHistoryRequest {
+                        address: self.get_address_string(),
+                        pagination: pagination.take(),
+                    },
+                    Action::ContractHistory(self.get_address()),
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.extend(page.entries.into_iter().map(HistoryEntry::from_proto));
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Find all admin changes for this contract, in chronological order.
+    ///
+    /// The contract history does not record admin changes, so this searches
+    /// for `update_contract_admin` events. It requires a node with
+    /// transaction indexing enabled and only finds changes the node still has.
+    pub async fn admin_changes(&self) -> Result<Vec<AdminChange>, crate::Error> {
+        let address = self.get_address_string();
+        let query = format!("update_contract_admin._contract_address='{address}'");
+        let mut res = vec![];
+        for page in 1.. {
+            let txs = self
+                .get_cosmos()
+                .query_transactions(query.clone(), Some(ADMIN_SEARCH_PAGE_SIZE), Some(page))
+                .await?;
+            let count = txs.transactions.len() as u64;
+            for tx in txs.transactions {
+                let tx = tx.response;
+                for event in tx.deduplicated_events() {
+                    if event.r#type != "update_contract_admin"
+                        || event.get("_contract_address") != Some(address.as_str())
+                    {
+                        continue;
+                    }
+                    let new_admin =
+                        match event.get("new_admin_address") {
+                            None | Some("") => None,
+                            Some(admin) => Some(admin.parse().map_err(|_| {
+                                crate::Error::InvalidChainResponse {
+                                    message: format!("Invalid new admin address {admin:?}"),
+                                    action: Action::SearchTransactions(query.clone()).into(),
+                                }
+                            })?),
+                        };
+                    res.push(AdminChange {
+                        txhash: tx.txhash.clone(),
+                        height: tx.height,
+                        new_admin,
+                    });
+                }
+            }
+            if count < ADMIN_SEARCH_PAGE_SIZE || page * ADMIN_SEARCH_PAGE_SIZE >= txs.total {
+                break;
+            }
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(code_id: u64, block_height: u64) -> HistoryEntry {
+        HistoryEntry {
+            operation: ContractCodeHistoryOperationType::Migrate,
+            code_id,
+            block_height: Some(block_height),
+            tx_index: Some(0),
+            msg: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn summarize_code_changes() {
+        let entries = [entry(1, 10), entry(2, 20), entry(2, 30), entry(5, 40)];
+        let changes = code_changes(&entries);
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].from, None);
+        assert_eq!(changes[1].from, Some(1));
+        assert_eq!(changes[2].to, 5);
+        assert_eq!(changes[2].block_height, Some(40));
+    }
+
+    #[test]
+    fn non_json_msg() {
+        let entry = HistoryEntry::from_proto(ContractCodeHistoryEntry {
+            operation: ContractCodeHistoryOperationType::Init as i32,
+            code_id: 1,
+            updated: None,
+            msg: b"not json".to_vec(),
+        });
+        assert_eq!(entry.msg, serde_json::Value::String("not json".to_owned()));
+        assert_eq!(entry.block_height, None);
+    }
+}
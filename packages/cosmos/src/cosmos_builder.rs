@@ -1,13 +1,17 @@
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use crate::{
+    error::ActionCategory,
+    feemarket::uses_feemarket,
     gas_multiplier::{GasMultiplier, GasMultiplierConfig},
     gas_price::GasPriceMethod,
-    AddressHrp, DynamicGasMultiplier,
+    osmosis::EpochListener,
+    sequence_store::SequenceStore,
+    Address, AddressHrp, BroadcastMode, Coin, DynamicGasMultiplier, FeeBudget, Wallet,
 };
 
 #[derive(Clone, Copy, Debug)]
-pub(crate) struct OsmosisGasParams {
+pub(crate) struct DynamicGasParams {
     pub(crate) low_multiplier: f64,
     pub(crate) high_multiplier: f64,
 }
@@ -24,22 +28,28 @@ pub struct CosmosBuilder {
 
     // Values with defaults
     gas_estimate_multiplier: GasMultiplierConfig,
+    message_gas_multipliers: HashMap<String, f64>,
     pub(crate) gas_price_method: Option<GasPriceMethod>,
     gas_price_retry_attempts: Option<u64>,
     transaction_attempts: Option<usize>,
     referer_header: Option<String>,
+    rpc_url: Option<String>,
     request_count: Option<usize>,
     connection_timeout: Option<Duration>,
     idle_timeout_seconds: Option<u32>,
     query_timeout_seconds: Option<u32>,
+    action_timeouts: HashMap<ActionCategory, Duration>,
     query_retries: Option<usize>,
     block_lag_allowed: Option<u32>,
     latest_block_age_allowed: Option<Duration>,
     fallback_timeout: Option<Duration>,
     pub(crate) chain_paused_method: ChainPausedMethod,
     pub(crate) autofix_simulate_sequence_mismatch: Option<bool>,
+    broadcast_sequence_mismatch_retries: Option<u32>,
     dynamic_gas_retries: Option<u32>,
-    osmosis_gas_params: Option<OsmosisGasParams>,
+    osmosis_gas_params: Option<DynamicGasParams>,
+    feemarket_gas_params: Option<DynamicGasParams>,
+    feemarket_gas_price: bool,
     osmosis_gas_price_too_old_seconds: Option<u64>,
     max_price: Option<f64>,
     rate_limit_per_second: Option<u64>,
@@ -50,6 +60,21 @@ pub struct CosmosBuilder {
     keep_alive_while_idle: Option<bool>,
     simulate_with_gas_coin: bool,
     delay_before_fallback: Option<tokio::time::Duration>,
+    query_auth: Option<Arc<Wallet>>,
+    sequence_store: Option<Arc<dyn SequenceStore>>,
+    epoch_listener: Option<Arc<dyn EpochListener>>,
+    cross_check_queries: Option<bool>,
+    fee_denom: Option<String>,
+    max_fee: Option<Coin>,
+    fee_budget: Option<FeeBudget>,
+    static_gas_estimates: HashMap<String, Option<u64>>,
+    static_gas_base: Option<u64>,
+    use_static_gas_estimates: Option<bool>,
+    simulation_cache_duration: Option<Duration>,
+    default_memo: Option<String>,
+    default_fee_granter: Option<Address>,
+    default_broadcast_mode: Option<BroadcastMode>,
+    timeout_height_offset: Option<u64>,
 }
 
 impl CosmosBuilder {
@@ -69,22 +94,28 @@ impl CosmosBuilder {
             gas_coin: gas_coin.into(),
             hrp,
             gas_estimate_multiplier: GasMultiplierConfig::Default,
+            message_gas_multipliers: HashMap::new(),
             gas_price_method: None,
             gas_price_retry_attempts: None,
             transaction_attempts: None,
             referer_header: None,
+            rpc_url: None,
             request_count: None,
             connection_timeout: None,
             idle_timeout_seconds: None,
             query_timeout_seconds: None,
+            action_timeouts: HashMap::new(),
             query_retries: None,
             block_lag_allowed: None,
             latest_block_age_allowed: None,
             fallback_timeout: None,
             chain_paused_method: ChainPausedMethod::None,
             autofix_simulate_sequence_mismatch: None,
+            broadcast_sequence_mismatch_retries: None,
             dynamic_gas_retries: None,
             osmosis_gas_params: None,
+            feemarket_gas_params: None,
+            feemarket_gas_price: false,
             osmosis_gas_price_too_old_seconds: None,
             max_price: None,
             rate_limit_per_second: None,
@@ -96,6 +127,21 @@ impl CosmosBuilder {
             keep_alive_while_idle: None,
             simulate_with_gas_coin,
             delay_before_fallback: None,
+            query_auth: None,
+            sequence_store: None,
+            epoch_listener: None,
+            cross_check_queries: None,
+            fee_denom: None,
+            max_fee: None,
+            fee_budget: None,
+            static_gas_estimates: HashMap::new(),
+            static_gas_base: None,
+            use_static_gas_estimates: None,
+            simulation_cache_duration: None,
+            default_memo: None,
+            default_fee_granter: None,
+            default_broadcast_mode: None,
+            timeout_height_offset: None,
         }
     }
 
@@ -179,6 +225,33 @@ impl CosmosBuilder {
         self.gas_estimate_multiplier = GasMultiplierConfig::Dynamic(config);
     }
 
+    /// Gas multiplier for transactions dominated by the given message type URL.
+    ///
+    /// Overrides the static or dynamic multiplier for transactions whose most
+    /// common message type is `type_url`, e.g. to pad `MsgExecuteContract` more
+    /// than `MsgSend`. Such transactions do not adjust the dynamic multiplier.
+    /// See [crate::TxBuilder::dominant_type_url].
+    ///
+    /// Default: [None] for all message types
+    pub fn get_message_gas_multiplier(&self, type_url: &str) -> Option<f64> {
+        self.message_gas_multipliers.get(type_url).copied()
+    }
+
+    /// See [Self::get_message_gas_multiplier]
+    ///
+    /// Passing [None] removes the override for the type URL.
+    pub fn set_message_gas_multiplier(
+        &mut self,
+        type_url: impl Into<String>,
+        multiplier: Option<f64>,
+    ) {
+        let type_url = type_url.into();
+        match multiplier {
+            Some(multiplier) => self.message_gas_multipliers.insert(type_url, multiplier),
+            None => self.message_gas_multipliers.remove(&type_url),
+        };
+    }
+
     /// How many times to retry a transaction with corrected gas multipliers.
     ///
     /// If you're using a dynamic gas estimate multiplier, this will indicate
@@ -197,6 +270,71 @@ impl CosmosBuilder {
         self.dynamic_gas_retries = dynamic_gas_retries;
     }
 
+    /// Static gas estimate for a single message of the given type URL.
+    ///
+    /// Used by [crate::TxBuilder::estimate_gas_static] to skip simulation
+    /// for messages with predictable gas usage. Estimates should be on the
+    /// conservative side, since no gas multiplier is applied.
+    ///
+    /// Default: 50,000 for `/cosmos.bank.v1beta1.MsgSend`, none for other messages
+    pub fn get_static_gas_estimate(&self, type_url: &str) -> Option<u64> {
+        match self.static_gas_estimates.get(type_url) {
+            Some(gas) => *gas,
+            None => match type_url {
+                "/cosmos.bank.v1beta1.MsgSend" => Some(50_000),
+                _ => None,
+            },
+        }
+    }
+
+    /// See [Self::get_static_gas_estimate]
+    ///
+    /// Passing [None] removes any estimate for the type URL, including the default.
+    pub fn set_static_gas_estimate(&mut self, type_url: impl Into<String>, gas: Option<u64>) {
+        self.static_gas_estimates.insert(type_url.into(), gas);
+    }
+
+    /// Gas added to static estimates for per-transaction overhead, such as signature verification.
+    ///
+    /// Default: 60,000
+    pub fn get_static_gas_base(&self) -> u64 {
+        self.static_gas_base.unwrap_or(60_000)
+    }
+
+    /// See [Self::get_static_gas_base]
+    pub fn set_static_gas_base(&mut self, gas: u64) {
+        self.static_gas_base = Some(gas);
+    }
+
+    /// Should [crate::TxBuilder::sign_and_broadcast] skip simulation when a static gas estimate is available?
+    ///
+    /// Default: false
+    pub fn get_use_static_gas_estimates(&self) -> bool {
+        self.use_static_gas_estimates.unwrap_or_default()
+    }
+
+    /// See [Self::get_use_static_gas_estimates]
+    pub fn set_use_static_gas_estimates(&mut self, value: bool) {
+        self.use_static_gas_estimates = Some(value);
+    }
+
+    /// How long to reuse simulated gas for identical transactions.
+    ///
+    /// When set, [crate::TxBuilder::sign_and_broadcast] remembers the gas used
+    /// when simulating a set of messages from a set of signers, and skips
+    /// simulation for identical transactions until the entry expires. Entries
+    /// are dropped early if a transaction runs out of gas.
+    ///
+    /// Default: [None], always simulate
+    pub fn get_simulation_cache_duration(&self) -> Option<Duration> {
+        self.simulation_cache_duration
+    }
+
+    /// See [Self::get_simulation_cache_duration]
+    pub fn set_simulation_cache_duration(&mut self, duration: Option<Duration>) {
+        self.simulation_cache_duration = duration;
+    }
+
     /// Set the lower and upper bounds of gas price.
     pub fn set_gas_price(&mut self, low: f64, high: f64) {
         self.gas_price_method = Some(GasPriceMethod::new_static(low, high));
@@ -244,6 +382,18 @@ impl CosmosBuilder {
         self.referer_header = referer_header;
     }
 
+    /// CometBFT RPC endpoint, used for queries not available over gRPC
+    ///
+    /// Only needed for [crate::Cosmos::get_block_results].
+    pub fn rpc_url(&self) -> Option<&str> {
+        self.rpc_url.as_deref()
+    }
+
+    /// See [Self::rpc_url]
+    pub fn set_rpc_url(&mut self, rpc_url: Option<String>) {
+        self.rpc_url = rpc_url;
+    }
+
     /// The maximum number of concurrent requests
     ///
     /// This is a global limit for the generated [Cosmos], and will apply across all endpoints.
@@ -289,6 +439,9 @@ impl CosmosBuilder {
 
     /// Sets the number of seconds before an idle connection is reaped
     ///
+    /// The next query after this much idle time will use a freshly built
+    /// channel instead of the possibly stale one.
+    ///
     /// Defaults to 20 seconds
     pub fn idle_timeout_seconds(&self) -> u32 {
         self.idle_timeout_seconds.unwrap_or(20)
@@ -311,6 +464,28 @@ impl CosmosBuilder {
         self.query_timeout_seconds = query_timeout_seconds;
     }
 
+    /// Timeout for a single gRPC query in the given [ActionCategory].
+    ///
+    /// Defaults to [Self::query_timeout_seconds]
+    pub fn get_action_timeout(&self, category: ActionCategory) -> Duration {
+        self.action_timeouts
+            .get(&category)
+            .copied()
+            .unwrap_or_else(|| Duration::from_secs(self.query_timeout_seconds().into()))
+    }
+
+    /// See [Self::get_action_timeout]
+    pub fn set_action_timeout(&mut self, category: ActionCategory, timeout: Option<Duration>) {
+        match timeout {
+            Some(timeout) => {
+                self.action_timeouts.insert(category, timeout);
+            }
+            None => {
+                self.action_timeouts.remove(&category);
+            }
+        }
+    }
+
     /// Number of attempts to make at a query before giving up.
     ///
     /// Only retries if there is a tonic-level error.
@@ -377,7 +552,7 @@ impl CosmosBuilder {
     }
 
     /// Should we automatically retry transactions with corrected
-    /// sequence numbers during simulating transaction ?
+    /// sequence numbers during simulating and broadcasting transactions?
     ///
     /// Default: [true]
     pub fn autofix_sequence_mismatch(&self) -> bool {
@@ -389,13 +564,28 @@ impl CosmosBuilder {
         self.autofix_simulate_sequence_mismatch = autofix_sequence_mismatch;
     }
 
+    /// How many times to retry a broadcast which failed with a sequence mismatch.
+    ///
+    /// Each retry uses the sequence number the chain reported as expected.
+    /// Only applies when [Self::autofix_sequence_mismatch] is enabled.
+    ///
+    /// Default: 3
+    pub fn get_broadcast_sequence_mismatch_retries(&self) -> u32 {
+        self.broadcast_sequence_mismatch_retries.unwrap_or(3)
+    }
+
+    /// See [Self::get_broadcast_sequence_mismatch_retries]
+    pub fn set_broadcast_sequence_mismatch_retries(&mut self, retries: Option<u32>) {
+        self.broadcast_sequence_mismatch_retries = retries;
+    }
+
     /// Set parameters for Osmosis's EIP fee market gas.
     ///
     /// Low and high multiplier indicate how much to multiply the base fee by to get low and high prices, respectively. The max price is a cap on what those results will be.
     ///
     /// Defaults: 1.2, 10.0, and 0.01
     pub fn set_osmosis_gas_params(&mut self, low_multiplier: f64, high_multiplier: f64) {
-        self.osmosis_gas_params = Some(OsmosisGasParams {
+        self.osmosis_gas_params = Some(DynamicGasParams {
             low_multiplier,
             high_multiplier,
         });
@@ -406,18 +596,59 @@ impl CosmosBuilder {
         self.max_price = Some(max_price);
     }
 
-    pub(crate) fn get_osmosis_gas_params(&self) -> OsmosisGasParams {
-        self.osmosis_gas_params.unwrap_or(OsmosisGasParams {
+    pub(crate) fn get_osmosis_gas_params(&self) -> DynamicGasParams {
+        self.osmosis_gas_params.unwrap_or(DynamicGasParams {
             low_multiplier: 1.2,
             high_multiplier: 10.0,
         })
     }
 
+    /// Set parameters for gas prices on chains using the feemarket module.
+    ///
+    /// Low and high multiplier indicate how much to multiply the base gas price by to get low and high prices, respectively.
+    /// Prices are only capped if [Self::set_max_gas_price] is called.
+    ///
+    /// Defaults: 1.2 and 5.0
+    pub fn set_feemarket_gas_params(&mut self, low_multiplier: f64, high_multiplier: f64) {
+        self.feemarket_gas_params = Some(DynamicGasParams {
+            low_multiplier,
+            high_multiplier,
+        });
+    }
+
+    /// Load gas prices from the feemarket module.
+    ///
+    /// This is done automatically for chains known to run the module, such
+    /// as Neutron and the Cosmos Hub, unless a gas price is set explicitly.
+    /// Calling this overrides any explicit gas price.
+    pub fn set_feemarket_gas_price(&mut self) {
+        self.feemarket_gas_price = true;
+    }
+
+    /// Switch to feemarket gas prices if requested or known to be needed.
+    pub(crate) fn resolve_gas_price_method(&mut self) {
+        if self.feemarket_gas_price
+            || (self.gas_price_method.is_none() && uses_feemarket(&self.chain_id))
+        {
+            self.gas_price_method = Some(GasPriceMethod::new_feemarket(
+                self.get_feemarket_gas_params(),
+                self.max_price,
+            ));
+        }
+    }
+
+    pub(crate) fn get_feemarket_gas_params(&self) -> DynamicGasParams {
+        self.feemarket_gas_params.unwrap_or(DynamicGasParams {
+            low_multiplier: 1.2,
+            high_multiplier: 5.0,
+        })
+    }
+
     pub(crate) fn get_init_max_gas_price(&self) -> f64 {
         self.max_price.unwrap_or(0.01)
     }
 
-    /// How many seconds old the Osmosis gas price needs to be before we recheck.
+    /// How many seconds old the Osmosis or feemarket gas price needs to be before we recheck.
     ///
     /// Default: 5 seconds
     pub fn get_osmosis_gas_price_too_old_seconds(&self) -> u64 {
@@ -441,6 +672,117 @@ impl CosmosBuilder {
         self.log_requests = Some(log_requests);
     }
 
+    /// Should all state queries be cross-checked against a second node?
+    ///
+    /// See [crate::Cosmos::with_cross_check] for details and for enabling this per query.
+    ///
+    /// Default: false
+    pub fn get_cross_check_queries(&self) -> bool {
+        self.cross_check_queries.unwrap_or_default()
+    }
+
+    /// See [Self::get_cross_check_queries]
+    pub fn set_cross_check_queries(&mut self, cross_check_queries: bool) {
+        self.cross_check_queries = Some(cross_check_queries);
+    }
+
+    /// Denom to pay transaction fees in.
+    ///
+    /// When this differs from [Self::gas_coin], fees are converted using the
+    /// spot price from the Osmosis txfees module, which only works on Osmosis
+    /// and only for whitelisted fee tokens (see
+    /// [crate::Cosmos::get_osmosis_fee_tokens]). Can be overridden per
+    /// transaction with [crate::TxBuilder::set_fee_denom].
+    ///
+    /// Default: [None], fees are paid in [Self::gas_coin]
+    pub fn get_fee_denom(&self) -> Option<&str> {
+        self.fee_denom.as_deref()
+    }
+
+    /// See [Self::get_fee_denom]
+    pub fn set_fee_denom(&mut self, fee_denom: Option<String>) {
+        self.fee_denom = fee_denom;
+    }
+
+    /// Maximum fee any transaction may pay.
+    ///
+    /// Broadcasting fails with [crate::Error::FeeExceedsMax] before signing if
+    /// the computed fee is higher, including during gas price retries. Can be
+    /// overridden per transaction with [crate::TxBuilder::set_max_fee].
+    ///
+    /// Default: [None], no limit
+    pub fn get_max_fee(&self) -> Option<&Coin> {
+        self.max_fee.as_ref()
+    }
+
+    /// See [Self::get_max_fee]
+    pub fn set_max_fee(&mut self, max_fee: Option<Coin>) {
+        self.max_fee = max_fee;
+    }
+
+    /// Memo applied to transactions created with [crate::Cosmos::tx_builder].
+    ///
+    /// Default: [None]
+    pub fn get_default_memo(&self) -> Option<&str> {
+        self.default_memo.as_deref()
+    }
+
+    /// See [Self::get_default_memo]
+    pub fn set_default_memo(&mut self, memo: Option<String>) {
+        self.default_memo = memo;
+    }
+
+    /// Fee granter applied to transactions created with [crate::Cosmos::tx_builder].
+    ///
+    /// Default: [None]
+    pub fn get_default_fee_granter(&self) -> Option<Address> {
+        self.default_fee_granter
+    }
+
+    /// See [Self::get_default_fee_granter]
+    pub fn set_default_fee_granter(&mut self, granter: Option<Address>) {
+        self.default_fee_granter = granter;
+    }
+
+    /// Broadcast mode applied to transactions created with [crate::Cosmos::tx_builder].
+    ///
+    /// Default: [BroadcastMode::Sync]
+    pub fn get_default_broadcast_mode(&self) -> BroadcastMode {
+        self.default_broadcast_mode.unwrap_or_default()
+    }
+
+    /// See [Self::get_default_broadcast_mode]
+    pub fn set_default_broadcast_mode(&mut self, mode: Option<BroadcastMode>) {
+        self.default_broadcast_mode = mode;
+    }
+
+    /// Number of blocks after the latest block that transactions created with
+    /// [crate::Cosmos::tx_builder] remain valid for.
+    ///
+    /// See [crate::TxBuilder::set_timeout_height].
+    ///
+    /// Default: [None], no timeout
+    pub fn get_timeout_height_offset(&self) -> Option<u64> {
+        self.timeout_height_offset
+    }
+
+    /// See [Self::get_timeout_height_offset]
+    pub fn set_timeout_height_offset(&mut self, offset: Option<u64>) {
+        self.timeout_height_offset = offset;
+    }
+
+    /// Rolling spend limit on fees paid by each wallet.
+    ///
+    /// Default: [None], no limit
+    pub fn get_fee_budget(&self) -> Option<&FeeBudget> {
+        self.fee_budget.as_ref()
+    }
+
+    /// See [Self::get_fee_budget]
+    pub fn set_fee_budget(&mut self, fee_budget: Option<FeeBudget>) {
+        self.fee_budget = fee_budget;
+    }
+
     /// Limits the maximum size of a decoded message.
     ///
     /// Default: 16 MB
@@ -519,6 +861,54 @@ impl CosmosBuilder {
     pub fn set_delay_before_fallback(&mut self, delay: tokio::time::Duration) {
         self.delay_before_fallback = Some(delay);
     }
+
+    /// Wallet used to authenticate queries, for chains which require signed queries.
+    ///
+    /// When set, every gRPC request includes headers identifying the wallet
+    /// along with an ADR-36 signature over the current timestamp. See
+    /// [Wallet::query_auth_headers] for details.
+    ///
+    /// Default: [None]
+    pub fn get_query_auth(&self) -> Option<&Arc<Wallet>> {
+        self.query_auth.as_ref()
+    }
+
+    /// See [Self::get_query_auth]
+    pub fn set_query_auth(&mut self, wallet: Wallet) {
+        self.query_auth = Some(Arc::new(wallet));
+    }
+
+    /// See [Self::get_query_auth]
+    pub fn clear_query_auth(&mut self) {
+        self.query_auth = None;
+    }
+
+    /// Storage backend for locally tracked sequence numbers.
+    ///
+    /// Default: [None], meaning an [crate::InMemorySequenceStore] private to the built [crate::Cosmos]
+    pub fn get_sequence_store(&self) -> Option<&Arc<dyn SequenceStore>> {
+        self.sequence_store.as_ref()
+    }
+
+    /// See [Self::get_sequence_store]
+    pub fn set_sequence_store(&mut self, store: Arc<dyn SequenceStore>) {
+        self.sequence_store = Some(store);
+    }
+
+    /// Listener notified when an Osmosis epoch starts and ends.
+    ///
+    /// Only called on chains where epoch pauses are tracked, currently Osmosis
+    /// Mainnet. See [crate::Cosmos::is_chain_paused].
+    ///
+    /// Default: [None]
+    pub fn get_epoch_listener(&self) -> Option<&Arc<dyn EpochListener>> {
+        self.epoch_listener.as_ref()
+    }
+
+    /// See [Self::get_epoch_listener]
+    pub fn set_epoch_listener(&mut self, listener: Option<Arc<dyn EpochListener>>) {
+        self.epoch_listener = listener;
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -281,7 +281,7 @@ async fn get_sei_min_gas_price(
         })
 }
 
-async fn load_json<T>(url: &str, client: &reqwest::Client) -> Result<T, BuilderError>
+pub(crate) async fn load_json<T>(url: &str, client: &reqwest::Client) -> Result<T, BuilderError>
 where
     T: serde::de::DeserializeOwned,
 {
@@ -0,0 +1,201 @@
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmwasm_std::{Binary, Uint128};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Address, AddressHrp, Contract, Cosmos, HasAddress, HasAddressHrp, HasContract, HasCosmos,
+    Wallet,
+};
+
+/// A CW20 fungible token contract.
+///
+/// Provides typed access to the standard CW20 queries and execute messages.
+#[derive(Clone)]
+pub struct Cw20Contract(Contract);
+
+/// Metadata about a CW20 token.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cw20TokenInfo {
+    /// Token name
+    pub name: String,
+    /// Ticker symbol
+    pub symbol: String,
+    /// Number of decimal places used for display
+    pub decimals: u8,
+    /// Total supply in the smallest unit
+    pub total_supply: Uint128,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum QueryMsg {
+    Balance { address: String },
+    TokenInfo {},
+}
+
+#[derive(Deserialize)]
+struct BalanceResponse {
+    balance: Uint128,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ExecuteMsg {
+    Transfer {
+        recipient: String,
+        amount: Uint128,
+    },
+    Send {
+        contract: String,
+        amount: Uint128,
+        msg: Binary,
+    },
+    IncreaseAllowance {
+        spender: String,
+        amount: Uint128,
+    },
+}
+
+impl Cosmos {
+    /// Make a new [Cw20Contract] for the given token address.
+    pub fn make_cw20(&self, address: Address) -> Cw20Contract {
+        Cw20Contract(self.make_contract(address))
+    }
+}
+
+impl From<Contract> for Cw20Contract {
+    fn from(contract: Contract) -> Self {
+        Cw20Contract(contract)
+    }
+}
+
+impl Cw20Contract {
+    /// Get the token balance of the given address.
+    pub async fn balance(&self, address: impl HasAddress) -> Result<Uint128, crate::Error> {
+        let BalanceResponse { balance } = self
+            .0
+            .query(QueryMsg::Balance {
+                address: address.get_address_string(),
+            })
+            .await?;
+        Ok(balance)
+    }
+
+    /// Get the token's name, symbol, decimals and total supply.
+    pub async fn token_info(&self) -> Result<Cw20TokenInfo, crate::Error> {
+        self.0.query(QueryMsg::TokenInfo {}).await
+    }
+
+    /// Transfer tokens to another address.
+    pub async fn transfer(
+        &self,
+        wallet: &Wallet,
+        recipient: impl HasAddress,
+        amount: Uint128,
+    ) -> Result<TxResponse, crate::Error> {
+        self.0
+            .execute(
+                wallet,
+                vec![],
+                ExecuteMsg::Transfer {
+                    recipient: recipient.get_address_string(),
+                    amount,
+                },
+            )
+            .await
+    }
+
+    /// Send tokens to a contract, triggering its `receive` handler with `msg`.
+    pub async fn send(
+        &self,
+        wallet: &Wallet,
+        contract: impl HasAddress,
+        amount: Uint128,
+        msg: impl serde::Serialize,
+    ) -> Result<TxResponse, crate::Error> {
+        self.0
+            .execute(
+                wallet,
+                vec![],
+                ExecuteMsg::Send {
+                    contract: contract.get_address_string(),
+                    amount,
+                    msg: serde_json::to_vec(&msg)?.into(),
+                },
+            )
+            .await
+    }
+
+    /// Allow `spender` to transfer up to `amount` additional tokens on the wallet's behalf.
+    pub async fn increase_allowance(
+        &self,
+        wallet: &Wallet,
+        spender: impl HasAddress,
+        amount: Uint128,
+    ) -> Result<TxResponse, crate::Error> {
+        self.0
+            .execute(
+                wallet,
+                vec![],
+                ExecuteMsg::IncreaseAllowance {
+                    spender: spender.get_address_string(),
+                    amount,
+                },
+            )
+            .await
+    }
+}
+
+impl HasContract for Cw20Contract {
+    fn get_contract(&self) -> &Contract {
+        &self.0
+    }
+}
+
+impl HasAddress for Cw20Contract {
+    fn get_address(&self) -> Address {
+        self.0.get_address()
+    }
+}
+
+impl HasAddressHrp for Cw20Contract {
+    fn get_address_hrp(&self) -> AddressHrp {
+        self.0.get_address_hrp()
+    }
+}
+
+impl HasCosmos for Cw20Contract {
+    fn get_cosmos(&self) -> &Cosmos {
+        self.0.get_cosmos()
+    }
+}
+
+impl std::fmt::Display for Cw20Contract {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_format() {
+        assert_eq!(
+            serde_json::to_string(&QueryMsg::TokenInfo {}).unwrap(),
+            r#"{"token_info":{}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ExecuteMsg::Send {
+                contract: "contract".to_owned(),
+                amount: 5u128.into(),
+                msg: serde_json::to_vec(&serde_json::json!({"swap":{}}))
+                    .unwrap()
+                    .into(),
+            })
+            .unwrap(),
+            r#"{"send":{"contract":"contract","amount":"5","msg":"eyJzd2FwIjp7fX0="}}"#
+        );
+    }
+}
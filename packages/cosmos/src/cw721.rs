@@ -0,0 +1,251 @@
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmwasm_std::Binary;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Address, AddressHrp, Contract, Cosmos, HasAddress, HasAddressHrp, HasContract, HasCosmos,
+    TxBuilder, Wallet,
+};
+
+/// A CW721 non-fungible token contract.
+///
+/// Provides typed access to the standard CW721 queries and execute messages.
+#[derive(Clone)]
+pub struct Cw721Contract(Contract);
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum QueryMsg<'a> {
+    OwnerOf {
+        token_id: &'a str,
+    },
+    Tokens {
+        owner: String,
+        start_after: Option<&'a str>,
+        limit: Option<u32>,
+    },
+    AllTokens {
+        start_after: Option<&'a str>,
+        limit: Option<u32>,
+    },
+}
+
+#[derive(Deserialize)]
+struct OwnerOfResponse {
+    owner: Address,
+}
+
+#[derive(Deserialize)]
+struct TokensResponse {
+    tokens: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ExecuteMsg<T> {
+    Mint {
+        token_id: String,
+        owner: String,
+        token_uri: Option<String>,
+        extension: T,
+    },
+    TransferNft {
+        recipient: String,
+        token_id: String,
+    },
+    SendNft {
+        contract: String,
+        token_id: String,
+        msg: Binary,
+    },
+}
+
+impl Cosmos {
+    /// Make a new [Cw721Contract] for the given NFT contract address.
+    pub fn make_cw721(&self, address: Address) -> Cw721Contract {
+        Cw721Contract(self.make_contract(address))
+    }
+}
+
+impl From<Contract> for Cw721Contract {
+    fn from(contract: Contract) -> Self {
+        Cw721Contract(contract)
+    }
+}
+
+impl Cw721Contract {
+    /// Get the current owner of a token.
+    pub async fn owner_of(&self, token_id: &str) -> Result<Address, crate::Error> {
+        let OwnerOfResponse { owner } = self.0.query(QueryMsg::OwnerOf { token_id }).await?;
+        Ok(owner)
+    }
+
+    /// List token IDs held by the given owner.
+    ///
+    /// Results are paginated by the contract; pass the last returned ID as
+    /// `start_after` to get the next page.
+    pub async fn tokens(
+        &self,
+        owner: impl HasAddress,
+        start_after: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<String>, crate::Error> {
+        let TokensResponse { tokens } = self
+            .0
+            .query(QueryMsg::Tokens {
+                owner: owner.get_address_string(),
+                start_after,
+                limit,
+            })
+            .await?;
+        Ok(tokens)
+    }
+
+    /// List all token IDs in the contract, paginated like [Self::tokens].
+    pub async fn all_tokens(
+        &self,
+        start_after: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<String>, crate::Error> {
+        let TokensResponse { tokens } = self
+            .0
+            .query(QueryMsg::AllTokens { start_after, limit })
+            .await?;
+        Ok(tokens)
+    }
+
+    /// Mint a new token. Usually only permitted for the contract's minter.
+    ///
+    /// `extension` holds contract-specific metadata; use `()` if there is none.
+    pub async fn mint(
+        &self,
+        wallet: &Wallet,
+        token_id: impl Into<String>,
+        owner: impl HasAddress,
+        token_uri: Option<String>,
+        extension: impl serde::Serialize,
+    ) -> Result<TxResponse, crate::Error> {
+        self.0
+            .execute(
+                wallet,
+                vec![],
+                ExecuteMsg::Mint {
+                    token_id: token_id.into(),
+                    owner: owner.get_address_string(),
+                    token_uri,
+                    extension,
+                },
+            )
+            .await
+    }
+
+    /// Transfer a token to another address.
+    pub async fn transfer(
+        &self,
+        wallet: &Wallet,
+        recipient: impl HasAddress,
+        token_id: impl Into<String>,
+    ) -> Result<TxResponse, crate::Error> {
+        let mut builder = TxBuilder::default();
+        self.add_transfer_message(&mut builder, wallet, recipient, token_id)?;
+        builder.sign_and_broadcast(self.get_cosmos(), wallet).await
+    }
+
+    /// Add a transfer message to a [TxBuilder], for batching multiple transfers.
+    pub fn add_transfer_message(
+        &self,
+        builder: &mut TxBuilder,
+        sender: impl HasAddress,
+        recipient: impl HasAddress,
+        token_id: impl Into<String>,
+    ) -> Result<(), serde_json::Error> {
+        builder.add_execute_message(
+            &self.0,
+            sender,
+            vec![],
+            ExecuteMsg::<()>::TransferNft {
+                recipient: recipient.get_address_string(),
+                token_id: token_id.into(),
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Send a token to a contract, triggering its `receive_nft` handler with `msg`.
+    pub async fn send(
+        &self,
+        wallet: &Wallet,
+        contract: impl HasAddress,
+        token_id: impl Into<String>,
+        msg: impl serde::Serialize,
+    ) -> Result<TxResponse, crate::Error> {
+        self.0
+            .execute(
+                wallet,
+                vec![],
+                ExecuteMsg::<()>::SendNft {
+                    contract: contract.get_address_string(),
+                    token_id: token_id.into(),
+                    msg: serde_json::to_vec(&msg)?.into(),
+                },
+            )
+            .await
+    }
+}
+
+impl HasContract for Cw721Contract {
+    fn get_contract(&self) -> &Contract {
+        &self.0
+    }
+}
+
+impl HasAddress for Cw721Contract {
+    fn get_address(&self) -> Address {
+        self.0.get_address()
+    }
+}
+
+impl HasAddressHrp for Cw721Contract {
+    fn get_address_hrp(&self) -> AddressHrp {
+        self.0.get_address_hrp()
+    }
+}
+
+impl HasCosmos for Cw721Contract {
+    fn get_cosmos(&self) -> &Cosmos {
+        self.0.get_cosmos()
+    }
+}
+
+impl std::fmt::Display for Cw721Contract {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_format() {
+        assert_eq!(
+            serde_json::to_string(&QueryMsg::AllTokens {
+                start_after: Some("5"),
+                limit: None,
+            })
+            .unwrap(),
+            r#"{"all_tokens":{"start_after":"5","limit":null}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ExecuteMsg::Mint {
+                token_id: "1".to_owned(),
+                owner: "owner".to_owned(),
+                token_uri: None,
+                extension: (),
+            })
+            .unwrap(),
+            r#"{"mint":{"token_id":"1","owner":"owner","token_uri":null,"extension":null}}"#
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use cosmos_sdk_proto::{
+    cosmos::{
+        base::v1beta1::DecCoin,
+        distribution::v1beta1::{
+            MsgSetWithdrawAddress, MsgWithdrawDelegatorReward, MsgWithdrawValidatorCommission,
+            QueryDelegatorWithdrawAddressRequest, QueryValidatorCommissionRequest,
+            QueryValidatorOutstandingRewardsRequest,
+        },
+    },
+    traits::Message,
+};
+
+use crate::{error::Action, Address, Cosmos, HasAddress, TxMessage};
+
+impl From<MsgWithdrawDelegatorReward> for TxMessage {
+    fn from(msg: MsgWithdrawDelegatorReward) -> Self {
+        TxMessage::new(
+            "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward",
+            msg.encode_to_vec(),
+            format!(
+                "{} withdrawing rewards from {}",
+                msg.delegator_address, msg.validator_address
+            ),
+        )
+    }
+}
+
+impl From<MsgSetWithdrawAddress> for TxMessage {
+    fn from(msg: MsgSetWithdrawAddress) -> Self {
+        TxMessage::new(
+            "/cosmos.distribution.v1beta1.MsgSetWithdrawAddress",
+            msg.encode_to_vec(),
+            format!(
+                "{} setting reward withdraw address to {}",
+                msg.delegator_address, msg.withdraw_address
+            ),
+        )
+    }
+}
+
+impl From<MsgWithdrawValidatorCommission> for TxMessage {
+    fn from(msg: MsgWithdrawValidatorCommission) -> Self {
+        TxMessage::new(
+            "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission",
+            msg.encode_to_vec(),
+            format!("{} withdrawing commission", msg.validator_address),
+        )
+    }
+}
+
+impl Cosmos {
+    /// Get the rewards held by the distribution module for a validator and its delegators.
+    pub async fn validator_outstanding_rewards(
+        &self,
+        validator: impl HasAddress,
+    ) -> Result<Vec<DecCoin>, crate::Error> {
+        Ok(self
+            .perform_query(
+                QueryValidatorOutstandingRewardsRequest {
+                    validator_address: validator.get_address_string(),
+                },
+                Action::QueryValidatorOutstandingRewards(validator.get_address()),
+            )
+            .run()
+            .await?
+            .into_inner()
+            .rewards
+            .map(|rewards| rewards.rewards)
+            .unwrap_or_default())
+    }
+
+    /// Get the accumulated, unwithdrawn commission of a validator.
+    pub async fn validator_commission(
+        &self,
+        validator: impl HasAddress,
+    ) -> Result<Vec<DecCoin>, crate::Error> {
+        Ok(self
+            .perform_query(
+                QueryValidatorCommissionRequest {
+                    validator_address: validator.get_address_string(),
+                },
+                Action::QueryValidatorCommission(validator.get_address()),
+            )
+            .run()
+            .await?
+            .into_inner()
+            .commission
+            .map(|commission| commission.commission)
+            .unwrap_or_default())
+    }
+
+    /// Get the address which receives staking rewards for the given delegator.
+    pub async fn delegator_withdraw_address(
+        &self,
+        delegator: impl HasAddress,
+    ) -> Result<Address, crate::Error> {
+        let action = Action::QueryWithdrawAddress(delegator.get_address());
+        let withdraw_address = self
+            .perform_query(
+                QueryDelegatorWithdrawAddressRequest {
+                    delegator_address: delegator.get_address_string(),
+                },
+                action.clone(),
+            )
+            .run()
+            .await?
+            .into_inner()
+            .withdraw_address;
+        withdraw_address
+            .parse()
+            .map_err(|_| crate::Error::InvalidChainResponse {
+                message: format!("Invalid withdraw address: {withdraw_address}"),
+                action: action.into(),
+            })
+    }
+}
@@ -0,0 +1,658 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cosmos_sdk_proto::{
+    cosmos::tx::v1beta1::{AuthInfo, Fee, Tx},
+    traits::Message,
+};
+use serde_json::{json, Value};
+
+use crate::{error::Eip712Error, wallet::keccak, Cosmos, HasAddress, TxBuilder, Wallet};
+
+/// The sign mode used by EIP-712 signed transactions.
+const SIGN_MODE_LEGACY_AMINO_JSON: i32 = 127;
+
+/// Which Ethereum-style chain implementation we're signing for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Eip712Flavor {
+    /// Injective
+    Injective,
+    /// Ethermint-based chains such as Evmos
+    Ethermint,
+}
+
+/// Settings for producing EIP-712 signed transactions.
+#[derive(Clone, Copy, Debug)]
+pub struct Eip712Config {
+    /// Chain implementation
+    pub flavor: Eip712Flavor,
+    /// Ethereum chain ID used in the EIP-712 domain
+    pub eth_chain_id: u64,
+}
+
+impl Eip712Config {
+    /// Settings for Injective with the given Ethereum chain ID.
+    ///
+    /// Injective mainnet uses 1, testnet uses 11155111.
+    pub fn injective(eth_chain_id: u64) -> Self {
+        Eip712Config {
+            flavor: Eip712Flavor::Injective,
+            eth_chain_id,
+        }
+    }
+
+    /// Settings for an Ethermint chain, parsing the Ethereum chain ID from the Cosmos chain ID.
+    ///
+    /// For example, `evmos_9001-2` results in an Ethereum chain ID of 9001.
+    pub fn ethermint(chain_id: &str) -> Result<Self, Eip712Error> {
+        let invalid = || Eip712Error::InvalidEthermintChainId {
+            chain_id: chain_id.to_owned(),
+        };
+        let (_, rest) = chain_id.split_once('_').ok_or_else(invalid)?;
+        let (eth_chain_id, _) = rest.split_once('-').ok_or_else(invalid)?;
+        Ok(Eip712Config {
+            flavor: Eip712Flavor::Ethermint,
+            eth_chain_id: eth_chain_id.parse().map_err(|_| invalid())?,
+        })
+    }
+
+    fn domain(&self) -> Value {
+        json!({
+            "name": match self.flavor {
+                Eip712Flavor::Injective => "Injective Web3",
+                Eip712Flavor::Ethermint => "Cosmos Web3",
+            },
+            "version": "1.0.0",
+            "chainId": self.eth_chain_id,
+            "verifyingContract": "cosmos",
+            "salt": "0",
+        })
+    }
+
+    fn extension_type_url(&self) -> &'static str {
+        match self.flavor {
+            Eip712Flavor::Injective => "/injective.types.v1beta1.ExtensionOptionsWeb3Tx",
+            Eip712Flavor::Ethermint => "/ethermint.types.v1.ExtensionOptionsWeb3Tx",
+        }
+    }
+}
+
+/// A single field in an EIP-712 struct type.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TypedDataField {
+    /// Field name
+    pub name: String,
+    /// Field type, e.g. `string`, `uint256`, `Coin[]`
+    pub r#type: String,
+}
+
+impl TypedDataField {
+    fn new(name: impl Into<String>, r#type: impl Into<String>) -> Self {
+        TypedDataField {
+            name: name.into(),
+            r#type: r#type.into(),
+        }
+    }
+}
+
+/// EIP-712 typed data, in the same JSON format used by `eth_signTypedData_v4`.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedData {
+    /// Struct type definitions, including `EIP712Domain`
+    pub types: BTreeMap<String, Vec<TypedDataField>>,
+    /// Name of the type of [Self::message]
+    pub primary_type: String,
+    /// Domain separator values
+    pub domain: Value,
+    /// The message being signed
+    pub message: Value,
+}
+
+impl TypedData {
+    /// Generate typed data from a legacy Amino JSON sign doc.
+    ///
+    /// Message types are inferred from the message JSON, following the
+    /// approach used by Ethermint and Injective. All messages in the sign
+    /// doc must share the same structure.
+    ///
+    /// Ethermint's legacy EIP-712 types do not include `timeout_height`, so
+    /// for [Eip712Flavor::Ethermint] it is not covered by the signature.
+    pub fn from_sign_doc(config: &Eip712Config, sign_doc: Value) -> Result<Self, Eip712Error> {
+        let mut types = BTreeMap::new();
+        types.insert(
+            "EIP712Domain".to_owned(),
+            vec![
+                TypedDataField::new("name", "string"),
+                TypedDataField::new("version", "string"),
+                TypedDataField::new("chainId", "uint256"),
+                TypedDataField::new("verifyingContract", "string"),
+                TypedDataField::new("salt", "string"),
+            ],
+        );
+        let mut tx_fields = vec![
+            TypedDataField::new("account_number", "string"),
+            TypedDataField::new("chain_id", "string"),
+            TypedDataField::new("fee", "Fee"),
+            TypedDataField::new("memo", "string"),
+            TypedDataField::new("msgs", "Msg[]"),
+            TypedDataField::new("sequence", "string"),
+        ];
+        if config.flavor == Eip712Flavor::Injective {
+            tx_fields.push(TypedDataField::new("timeout_height", "string"));
+        }
+        types.insert("Tx".to_owned(), tx_fields);
+        types.insert(
+            "Fee".to_owned(),
+            vec![
+                TypedDataField::new("feePayer", "string"),
+                TypedDataField::new("amount", "Coin[]"),
+                TypedDataField::new("gas", "string"),
+            ],
+        );
+        types.insert(
+            "Coin".to_owned(),
+            vec![
+                TypedDataField::new("denom", "string"),
+                TypedDataField::new("amount", "string"),
+            ],
+        );
+        types.insert(
+            "Msg".to_owned(),
+            vec![
+                TypedDataField::new("type", "string"),
+                TypedDataField::new("value", "MsgValue"),
+            ],
+        );
+
+        let msgs = sign_doc
+            .get("msgs")
+            .and_then(Value::as_array)
+            .ok_or(Eip712Error::MissingMessages)?;
+        let mut msg_types: Option<BTreeMap<String, Vec<TypedDataField>>> = None;
+        for msg in msgs {
+            let value = msg.get("value").ok_or(Eip712Error::MissingMessages)?;
+            let mut inferred = BTreeMap::new();
+            infer_struct_type("MsgValue", value, &mut inferred)?;
+            match &msg_types {
+                None => msg_types = Some(inferred),
+                Some(existing) => {
+                    if existing != &inferred {
+                        return Err(Eip712Error::HeterogeneousMessages);
+                    }
+                }
+            }
+        }
+        types.extend(msg_types.ok_or(Eip712Error::MissingMessages)?);
+
+        Ok(TypedData {
+            types,
+            primary_type: "Tx".to_owned(),
+            domain: config.domain(),
+            message: sign_doc,
+        })
+    }
+
+    /// Compute the hash to be signed: `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))`.
+    pub fn signing_hash(&self) -> Result<[u8; 32], Eip712Error> {
+        let mut bytes = Vec::with_capacity(66);
+        bytes.extend_from_slice(&[0x19, 0x01]);
+        bytes.extend_from_slice(&self.hash_struct("EIP712Domain", &self.domain)?);
+        bytes.extend_from_slice(&self.hash_struct(&self.primary_type, &self.message)?);
+        Ok(keccak(&bytes))
+    }
+
+    fn encode_type(&self, name: &str) -> Result<String, Eip712Error> {
+        let mut deps = BTreeSet::new();
+        self.collect_dependencies(name, &mut deps)?;
+        deps.remove(name);
+        let mut res = self.encode_single_type(name)?;
+        for dep in deps {
+            res += &self.encode_single_type(&dep)?;
+        }
+        Ok(res)
+    }
+
+    fn encode_single_type(&self, name: &str) -> Result<String, Eip712Error> {
+        let fields = self.get_fields(name)?;
+        let fields = fields
+            .iter()
+            .map(|field| format!("{} {}", field.r#type, field.name))
+            .collect::<Vec<_>>()
+            .join(",");
+        Ok(format!("{name}({fields})"))
+    }
+
+    fn collect_dependencies(
+        &self,
+        name: &str,
+        deps: &mut BTreeSet<String>,
+    ) -> Result<(), Eip712Error> {
+        if deps.contains(name) {
+            return Ok(());
+        }
+        deps.insert(name.to_owned());
+        for field in self.get_fields(name)? {
+            let base = field.r#type.trim_end_matches("[]");
+            if self.types.contains_key(base) {
+                self.collect_dependencies(base, deps)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn get_fields(&self, name: &str) -> Result<&[TypedDataField], Eip712Error> {
+        self.types
+            .get(name)
+            .map(|fields| fields.as_slice())
+            .ok_or_else(|| Eip712Error::UnknownType {
+                name: name.to_owned(),
+            })
+    }
+
+    fn hash_struct(&self, name: &str, value: &Value) -> Result<[u8; 32], Eip712Error> {
+        let mut bytes = keccak(self.encode_type(name)?.as_bytes()).to_vec();
+        for field in self.get_fields(name)? {
+            let field_value = value.get(&field.name).unwrap_or(&Value::Null);
+            bytes.extend_from_slice(&self.encode_value(&field.r#type, field_value)?);
+        }
+        Ok(keccak(&bytes))
+    }
+
+    fn encode_value(&self, ty: &str, value: &Value) -> Result<[u8; 32], Eip712Error> {
+        let invalid = || Eip712Error::InvalidValue {
+            type_name: ty.to_owned(),
+            value: value.to_string(),
+        };
+
+        if let Some(inner) = ty.strip_suffix("[]") {
+            let mut bytes = vec![];
+            if let Some(items) = value.as_array() {
+                for item in items {
+                    bytes.extend_from_slice(&self.encode_value(inner, item)?);
+                }
+            } else if !value.is_null() {
+                return Err(invalid());
+            }
+            return Ok(keccak(&bytes));
+        }
+
+        if self.types.contains_key(ty) {
+            return if value.is_null() {
+                Ok([0; 32])
+            } else {
+                self.hash_struct(ty, value)
+            };
+        }
+
+        match ty {
+            "string" => match value {
+                Value::String(s) => Ok(keccak(s.as_bytes())),
+                Value::Null => Ok(keccak(&[])),
+                _ => Ok(keccak(value.to_string().as_bytes())),
+            },
+            "bytes" => {
+                let s = value.as_str().ok_or_else(invalid)?;
+                let bytes = hex::decode(s.trim_start_matches("0x")).map_err(|_| invalid())?;
+                Ok(keccak(&bytes))
+            }
+            "bool" => {
+                let mut res = [0; 32];
+                res[31] = value.as_bool().ok_or_else(invalid)?.into();
+                Ok(res)
+            }
+            "address" => {
+                let s = value.as_str().ok_or_else(invalid)?;
+                let bytes = hex::decode(s.trim_start_matches("0x")).map_err(|_| invalid())?;
+                if bytes.len() != 20 {
+                    return Err(invalid());
+                }
+                let mut res = [0; 32];
+                res[12..].copy_from_slice(&bytes);
+                Ok(res)
+            }
+            _ if ty.starts_with("uint") => {
+                let n = match value {
+                    Value::Number(n) => n.as_u64().map(u128::from),
+                    Value::String(s) => match s.strip_prefix("0x") {
+                        Some(hex) => u128::from_str_radix(hex, 16).ok(),
+                        None => s.parse().ok(),
+                    },
+                    _ => None,
+                }
+                .ok_or_else(invalid)?;
+                let mut res = [0; 32];
+                res[16..].copy_from_slice(&n.to_be_bytes());
+                Ok(res)
+            }
+            _ if ty.starts_with("int") => {
+                let n = match value {
+                    Value::Number(n) => n.as_i64().map(i128::from),
+                    Value::String(s) => s.parse().ok(),
+                    _ => None,
+                }
+                .ok_or_else(invalid)?;
+                let mut res = if n < 0 { [0xff; 32] } else { [0; 32] };
+                res[16..].copy_from_slice(&n.to_be_bytes());
+                Ok(res)
+            }
+            _ if ty.starts_with("bytes") => {
+                let s = value.as_str().ok_or_else(invalid)?;
+                let bytes = hex::decode(s.trim_start_matches("0x")).map_err(|_| invalid())?;
+                if bytes.len() > 32 {
+                    return Err(invalid());
+                }
+                let mut res = [0; 32];
+                res[..bytes.len()].copy_from_slice(&bytes);
+                Ok(res)
+            }
+            _ => Err(Eip712Error::UnknownType {
+                name: ty.to_owned(),
+            }),
+        }
+    }
+}
+
+/// Infer the struct type for a JSON object, adding it and any nested types to `types`.
+fn infer_struct_type(
+    name: &str,
+    value: &Value,
+    types: &mut BTreeMap<String, Vec<TypedDataField>>,
+) -> Result<(), Eip712Error> {
+    let obj = value.as_object().ok_or_else(|| Eip712Error::InvalidValue {
+        type_name: name.to_owned(),
+        value: value.to_string(),
+    })?;
+    let mut fields = vec![];
+    let mut entries = obj.iter().collect::<Vec<_>>();
+    entries.sort_by_key(|(key, _)| *key);
+    for (key, value) in entries {
+        if let Some(ty) = infer_field_type(key, value, types)? {
+            fields.push(TypedDataField::new(key, ty));
+        }
+    }
+    types.insert(name.to_owned(), fields);
+    Ok(())
+}
+
+fn infer_field_type(
+    key: &str,
+    value: &Value,
+    types: &mut BTreeMap<String, Vec<TypedDataField>>,
+) -> Result<Option<String>, Eip712Error> {
+    Ok(match value {
+        Value::Null => None,
+        Value::Bool(_) => Some("bool".to_owned()),
+        Value::Number(_) => Some("int64".to_owned()),
+        Value::String(_) => Some("string".to_owned()),
+        Value::Object(_) => {
+            let name = nested_type_name(key);
+            infer_struct_type(&name, value, types)?;
+            Some(name)
+        }
+        Value::Array(items) => match items.first() {
+            // Empty arrays carry no type information, Ethermint treats them as strings
+            None => Some("string[]".to_owned()),
+            Some(first) => infer_field_type(key, first, types)?.map(|ty| format!("{ty}[]")),
+        },
+    })
+}
+
+fn nested_type_name(key: &str) -> String {
+    let mut name = "Type".to_owned();
+    for part in key.split('_') {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            name.extend(first.to_uppercase());
+            name.extend(chars);
+        }
+    }
+    name
+}
+
+/// Hand-copied from Injective and Ethermint, which share the same definition.
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct ExtensionOptionsWeb3Tx {
+    #[prost(uint64, tag = "1")]
+    typed_data_chain_id: u64,
+    #[prost(string, tag = "2")]
+    fee_payer: String,
+    #[prost(bytes = "vec", tag = "3")]
+    fee_payer_sig: Vec<u8>,
+}
+
+impl TxBuilder {
+    /// Sign this transaction using EIP-712 typed data.
+    ///
+    /// Ethereum-style chains such as Injective and Evmos accept transactions
+    /// signed over EIP-712 typed data in addition to the standard direct
+    /// signing mode. Since this is based on the legacy Amino JSON sign doc,
+    /// the Amino JSON representation of each message must be provided, in the
+    /// same order as the messages were added.
+    ///
+    /// The resulting transaction is not broadcast, see [Cosmos::broadcast_tx_raw].
+    pub async fn sign_eip712(
+        &self,
+        cosmos: &Cosmos,
+        wallet: &Wallet,
+        config: &Eip712Config,
+        amino_msgs: Vec<Value>,
+        gas_to_request: u64,
+    ) -> Result<Tx, crate::Error> {
+        if amino_msgs.len() != self.messages.len() {
+            return Err(Eip712Error::MessageCountMismatch {
+                expected: self.messages.len(),
+                actual: amino_msgs.len(),
+            }
+            .into());
+        }
+        let base_account = cosmos.get_base_account(wallet.get_address()).await?;
+        let fee = cosmos
+            .make_fee_coin(
+                self.effective_fee_denom(cosmos),
+                self.apply_priority_fee(cosmos.gas_to_coins(gas_to_request, 0).await),
+            )
+            .await?;
+
+        let sign_doc = json!({
+            "account_number": base_account.account_number.to_string(),
+            "chain_id": cosmos.get_cosmos_builder().chain_id(),
+            "fee": {
+                "amount": [{"denom": fee.denom, "amount": fee.amount}],
+                "gas": gas_to_request.to_string(),
+                "feePayer": wallet.get_address_string(),
+            },
+            "memo": self.memo.as_deref().unwrap_or_default(),
+            "msgs": amino_msgs,
+            "sequence": base_account.sequence.to_string(),
+            "timeout_height": self.timeout_height.unwrap_or_default().to_string(),
+        });
+        let typed_data = TypedData::from_sign_doc(config, sign_doc)?;
+        let signature = wallet.sign_eip712(&typed_data)?;
+
+        let mut body = self.make_tx_body();
+        body.extension_options.push(cosmos_sdk_proto::Any {
+            type_url: config.extension_type_url().to_owned(),
+            value: ExtensionOptionsWeb3Tx {
+                typed_data_chain_id: config.eth_chain_id,
+                fee_payer: wallet.get_address_string(),
+                fee_payer_sig: signature.clone(),
+            }
+            .encode_to_vec(),
+        });
+
+        #[allow(deprecated)]
+        let auth_info = AuthInfo {
+            signer_infos: vec![self.make_signer_info_with_mode(
+                base_account.sequence,
+                Some(wallet.public_key.clone()),
+                SIGN_MODE_LEGACY_AMINO_JSON,
+            )],
+            fee: Some(Fee {
+                amount: vec![fee],
+                gas_limit: gas_to_request,
+                payer: "".to_owned(),
+                granter: "".to_owned(),
+            }),
+            tip: None,
+        };
+
+        Ok(Tx {
+            body: Some(body),
+            auth_info: Some(auth_info),
+            signatures: vec![signature],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AddressHrp;
+
+    #[test]
+    fn eip712_mail_example() {
+        let typed_data: TypedData = serde_json::from_value(json!({
+            "types": {
+                "EIP712Domain": [
+                    {"name": "name", "type": "string"},
+                    {"name": "version", "type": "string"},
+                    {"name": "chainId", "type": "uint256"},
+                    {"name": "verifyingContract", "type": "address"}
+                ],
+                "Person": [
+                    {"name": "name", "type": "string"},
+                    {"name": "wallet", "type": "address"}
+                ],
+                "Mail": [
+                    {"name": "from", "type": "Person"},
+                    {"name": "to", "type": "Person"},
+                    {"name": "contents", "type": "string"}
+                ]
+            },
+            "primaryType": "Mail",
+            "domain": {
+                "name": "Ether Mail",
+                "version": "1",
+                "chainId": 1,
+                "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+            },
+            "message": {
+                "from": {"name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},
+                "to": {"name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},
+                "contents": "Hello, Bob!"
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            typed_data.encode_type("Mail").unwrap(),
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+        assert_eq!(
+            hex::encode(typed_data.signing_hash().unwrap()),
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+    }
+
+    #[test]
+    fn ethermint_known_answer() {
+        // Hardhat's first development account
+        let wallet = Wallet::from_privkey_hex(
+            "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+            AddressHrp::from_static("evmos"),
+        )
+        .unwrap();
+        let sender = "evmos17w0adeg64ky0daxwd2ugyuneellmjgnxpu2u3g";
+        let config = Eip712Config::ethermint("evmos_9001-2").unwrap();
+        let typed_data = TypedData::from_sign_doc(
+            &config,
+            json!({
+                "account_number": "8",
+                "chain_id": "evmos_9001-2",
+                "fee": {
+                    "amount": [{"denom": "aevmos", "amount": "4000000000000000"}],
+                    "gas": "200000",
+                    "feePayer": sender
+                },
+                "memo": "",
+                "msgs": [{
+                    "type": "cosmos-sdk/MsgSend",
+                    "value": {
+                        "amount": [{"amount": "1000000000000000000", "denom": "aevmos"}],
+                        "from_address": sender,
+                        "to_address": "evmos1wzvhjux9rqfdcwspp37srdgwp5tac7wgfhwr9w"
+                    }
+                }],
+                "sequence": "3",
+                "timeout_height": "0"
+            }),
+        )
+        .unwrap();
+        assert_eq!(
+            typed_data.encode_type("Tx").unwrap(),
+            "Tx(string account_number,string chain_id,Fee fee,string memo,Msg[] msgs,string sequence)\
+             Coin(string denom,string amount)\
+             Fee(string feePayer,Coin[] amount,string gas)\
+             Msg(string type,MsgValue value)\
+             MsgValue(TypeAmount[] amount,string from_address,string to_address)\
+             TypeAmount(string amount,string denom)"
+        );
+        assert_eq!(
+            hex::encode(typed_data.signing_hash().unwrap()),
+            "849ee264e8779a2259fa3724d9585e94cc93d77c857e59f2af5cb45ee1d1f3a4"
+        );
+        assert_eq!(
+            hex::encode(wallet.sign_eip712(&typed_data).unwrap()),
+            "bd46dd6389911885ad11f6acd393c26e3f9c649a8c637f812d3129fe554a26d6\
+             6bb10d4e0a4beff1db9cc494f3d2941f5c39fbe0c1625b110f3a5e6c999b85ce1c"
+        );
+    }
+
+    #[test]
+    fn ethermint_chain_id() {
+        assert_eq!(
+            Eip712Config::ethermint("evmos_9001-2")
+                .unwrap()
+                .eth_chain_id,
+            9001
+        );
+        Eip712Config::ethermint("osmosis-1").unwrap_err();
+    }
+
+    #[test]
+    fn infer_nested_types() {
+        let config = Eip712Config::injective(1);
+        let typed_data = TypedData::from_sign_doc(
+            &config,
+            json!({
+                "account_number": "1",
+                "chain_id": "injective-1",
+                "fee": {"amount": [], "gas": "200000", "feePayer": "inj1..."},
+                "memo": "",
+                "msgs": [{
+                    "type": "cosmos-sdk/MsgSend",
+                    "value": {
+                        "from_address": "inj1...",
+                        "to_address": "inj1...",
+                        "amount": [{"denom": "inj", "amount": "1"}]
+                    }
+                }],
+                "sequence": "0",
+                "timeout_height": "0"
+            }),
+        )
+        .unwrap();
+        assert_eq!(
+            typed_data.types["MsgValue"],
+            vec![
+                TypedDataField::new("amount", "TypeAmount[]"),
+                TypedDataField::new("from_address", "string"),
+                TypedDataField::new("to_address", "string"),
+            ]
+        );
+        assert_eq!(
+            typed_data.types["Tx"].last(),
+            Some(&TypedDataField::new("timeout_height", "string"))
+        );
+        typed_data.signing_hash().unwrap();
+    }
+}
@@ -13,8 +13,22 @@ use crate::{Address, AddressHrp, CosmosBuilder, TxBuilder};
 /// Errors that can occur with token factory
 #[derive(thiserror::Error, Debug, Clone)]
 pub enum TokenFactoryError {
-    #[error("cosmos-rs does not support tokenfactory for the given chain HRP: {hrp}")]
+    #[error("No supported tokenfactory module found on chain with HRP: {hrp}")]
     Unsupported { hrp: AddressHrp },
+    #[error("The tokenfactory module on chain HRP {hrp} does not support {operation}")]
+    UnsupportedOperation {
+        hrp: AddressHrp,
+        operation: &'static str,
+    },
+    #[error("{denom} is not a tokenfactory denom of the form factory/CREATOR/SUBDENOM")]
+    InvalidDenom { denom: String },
+}
+
+/// Errors specific to THORChain and Rujira.
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum RujiraError {
+    #[error("Invalid THORChain asset {asset:?}, expected a form like BTC.BTC")]
+    InvalidAsset { asset: String },
 }
 
 /// Errors that can occur while working with [crate::Address].
@@ -29,6 +43,12 @@ pub enum AddressError {
     InvalidByteCount { address: String, actual: usize },
     #[error("Invalid HRP provided: {hrp:?}")]
     InvalidHrp { hrp: String },
+    #[error("Invalid ed25519 public key, expected 32 bytes, received {actual}")]
+    InvalidEd25519PublicKey { actual: usize },
+    #[error("Public key method {method:?} is not supported here")]
+    UnsupportedPublicKeyMethod { method: crate::PublicKeyMethod },
+    #[error("Invalid secp256k1 public key: {source}")]
+    InvalidPublicKey { source: bitcoin::secp256k1::Error },
 }
 
 /// Errors that can occur while working with [crate::Wallet].
@@ -49,6 +69,52 @@ pub enum WalletError {
     },
     #[error("Invalid seed phrase: {source}")]
     InvalidPhrase { source: <Mnemonic as FromStr>::Err },
+    #[error("ed25519 keys can only be derived with hardened derivation paths, received {derivation_path}")]
+    Ed25519NonHardenedPath {
+        derivation_path: Arc<DerivationPath>,
+    },
+    #[error(
+        "Vanity pattern {pattern:?} contains characters which never appear in bech32 addresses"
+    )]
+    InvalidVanityPattern { pattern: String },
+    #[error("Invalid private key: {reason}")]
+    InvalidPrivateKey { reason: String },
+    #[error("Wallet {address} uses an ed25519 key, but a secp256k1 key is required")]
+    Secp256k1KeyRequired { address: Address },
+    #[error("Unable to access keystore file {}: {source}", path.display())]
+    KeystoreIo {
+        path: PathBuf,
+        source: Arc<std::io::Error>,
+    },
+    #[error("Invalid keystore file {}: {message}", path.display())]
+    InvalidKeystore { path: PathBuf, message: String },
+    #[error("Unable to decrypt keystore file {}, is the passphrase correct?", path.display())]
+    KeystoreDecrypt { path: PathBuf },
+    #[cfg(feature = "keyring")]
+    #[error("Error accessing seed phrase {name:?} in the OS keyring: {source}")]
+    Keyring {
+        name: String,
+        source: Arc<keyring::Error>,
+    },
+}
+
+/// Errors that can occur while producing EIP-712 signatures.
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum Eip712Error {
+    #[error("Unknown EIP-712 type: {name}")]
+    UnknownType { name: String },
+    #[error("Invalid value for EIP-712 type {type_name}: {value}")]
+    InvalidValue { type_name: String, value: String },
+    #[error("Sign doc does not contain any messages")]
+    MissingMessages,
+    #[error("All messages in an EIP-712 transaction must have the same structure")]
+    HeterogeneousMessages,
+    #[error("Expected {expected} Amino JSON messages, received {actual}")]
+    MessageCountMismatch { expected: usize, actual: usize },
+    #[error("EIP-712 signing requires a secp256k1 key")]
+    UnsupportedKeyType,
+    #[error("Cannot parse Ethereum chain ID from Ethermint chain ID {chain_id}")]
+    InvalidEthermintChainId { chain_id: String },
 }
 
 /// Error while parsing a [crate::ParsedCoin].
@@ -67,6 +133,32 @@ pub enum ParsedCoinError {
         input: String,
         source: std::num::ParseIntError,
     },
+    #[error("Total amount of {denom} overflows")]
+    AmountOverflow { denom: String },
+}
+
+/// Error while restoring a [crate::DynamicGasState].
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum DynamicGasStateError {
+    #[error("Dynamic gas field {field} is not a finite number: {value}")]
+    NotFinite { field: &'static str, value: f64 },
+    #[error("Dynamic gas lower bound {low} is above the upper bound {high}")]
+    InvalidBounds { low: f64, high: f64 },
+}
+
+/// Invalid options passed to a [crate::TxBuilder].
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum TxBuilderError {
+    #[error("Priority fee multiplier must be a positive finite number, got {multiplier}")]
+    InvalidPriorityFeeMultiplier { multiplier: f64 },
+    #[error("Invalid tip amount {amount:?} for denom {denom}: {source}")]
+    InvalidTipAmount {
+        denom: String,
+        amount: String,
+        source: std::num::ParseIntError,
+    },
+    #[error("Tip coin with amount {amount} has an empty denom")]
+    EmptyTipDenom { amount: String },
 }
 
 /// Errors that can occur while building a connection.
@@ -130,6 +222,16 @@ pub enum ChainParseError {
     TxFees {
         err: String,
     },
+    InvalidCustomEvent {
+        event_type: &'static str,
+        txhash: String,
+        source: Arc<serde_json::Error>,
+    },
+    InvalidStandardEvent {
+        event_type: String,
+        txhash: String,
+        message: String,
+    },
 }
 
 impl Display for ChainParseError {
@@ -180,6 +282,22 @@ impl ChainParseError {
             ChainParseError::TxFees { err } => {
                 write!(f, "TxFees {err}")
             }
+            ChainParseError::InvalidCustomEvent {
+                event_type,
+                txhash,
+                source,
+            } => write!(
+                f,
+                "Invalid {event_type} event in transaction {txhash}: {source}"
+            ),
+            ChainParseError::InvalidStandardEvent {
+                event_type,
+                txhash,
+                message,
+            } => write!(
+                f,
+                "Invalid {event_type} event in transaction {txhash}: {message}"
+            ),
         }
     }
 }
@@ -343,6 +461,50 @@ pub enum Error {
     WasmGzipFailed {
         source: std::io::Error,
     },
+    SignerFailed {
+        address: Address,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    Eip712(#[from] Eip712Error),
+    NoSignersProvided,
+    CannotRebroadcast {
+        reason: String,
+    },
+    SequenceStore {
+        address: Address,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    InvalidCoin(#[from] ParsedCoinError),
+    StateSnapshotIo {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    StateSnapshotParse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    NoRpcUrl {
+        action: Box<Action>,
+    },
+    RpcRequest {
+        url: String,
+        source: reqwest::Error,
+        action: Box<Action>,
+    },
+    FeeExceedsMax {
+        fee: cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
+        max_fee: cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
+        action: Box<Action>,
+    },
+    FeeBudgetExceeded {
+        address: Address,
+        spent: u128,
+        fee: cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
+        limit: cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
+        action: Box<Action>,
+    },
+    TokenFactory(#[from] TokenFactoryError),
+    Wallet(#[from] WalletError),
 }
 
 impl Display for Error {
@@ -410,6 +572,101 @@ impl Error {
             Error::WasmGzipFailed { source } => {
                 write!(f, "Error during wasm Gzip compression: {source}")
             }
+            Error::SignerFailed { address, source } => {
+                write!(f, "Signer for {address} failed to sign: {source}")
+            }
+            Error::Eip712(e) => write!(f, "{e}"),
+            Error::TokenFactory(e) => write!(f, "{e}"),
+            Error::Wallet(e) => write!(f, "{e}"),
+            Error::NoSignersProvided => {
+                write!(f, "At least one signer must be provided for a transaction")
+            }
+            Error::CannotRebroadcast { reason } => {
+                write!(
+                    f,
+                    "Cannot rebroadcast transaction with a higher fee: {reason}"
+                )
+            }
+            Error::SequenceStore { address, source } => {
+                write!(f, "Sequence store failed for {address}: {source}")
+            }
+            Error::InvalidCoin(e) => write!(f, "Invalid coin: {e}"),
+            Error::StateSnapshotIo { path, source } => {
+                write!(
+                    f,
+                    "Unable to access contract state snapshot {}: {source}",
+                    path.display()
+                )
+            }
+            Error::StateSnapshotParse { path, source } => {
+                write!(
+                    f,
+                    "Invalid contract state snapshot {}: {source}",
+                    path.display()
+                )
+            }
+            Error::NoRpcUrl { action } => {
+                write!(f, "No CometBFT RPC URL configured, needed for ")?;
+                action.fmt_helper(f, pretty)
+            }
+            Error::RpcRequest {
+                url,
+                source,
+                action,
+            } => {
+                write!(
+                    f,
+                    "RPC request to {url} failed: {source}. While performing: "
+                )?;
+                action.fmt_helper(f, pretty)
+            }
+            Error::FeeExceedsMax {
+                fee,
+                max_fee,
+                action,
+            } => {
+                write!(
+                    f,
+                    "Transaction fee of {}{} exceeds the maximum of {}{}, refusing to sign. While performing: ",
+                    fee.amount, fee.denom, max_fee.amount, max_fee.denom
+                )?;
+                action.fmt_helper(f, pretty)
+            }
+            Error::FeeBudgetExceeded {
+                address,
+                spent,
+                fee,
+                limit,
+                action,
+            } => {
+                write!(
+                    f,
+                    "Fee budget exceeded for {address}: already spent {spent}{} of {}{}, cannot pay {}{}. While performing: ",
+                    limit.denom, limit.amount, limit.denom, fee.amount, fee.denom
+                )?;
+                action.fmt_helper(f, pretty)
+            }
+        }
+    }
+
+    /// Does this error mean the transaction definitely never reached the chain?
+    ///
+    /// True for errors before broadcasting and for rejections during `CheckTx`.
+    /// Connection and query errors while broadcasting, timeouts while waiting,
+    /// and failures after inclusion in a block are not definite, since the
+    /// transaction may have consumed its sequence number and fee.
+    pub(crate) fn is_definite_tx_failure(&self) -> bool {
+        match self {
+            Error::TransactionFailed { stage, .. } => {
+                matches!(stage, TransactionStage::Broadcast)
+            }
+            Error::Query(_) => self.get_sequence_mismatch_status().is_some(),
+            Error::Connection(_)
+            | Error::InvalidChainResponse { .. }
+            | Error::WaitForTransactionTimedOut { .. }
+            | Error::WaitForTransactionTimedOutWhile { .. }
+            | Error::RpcRequest { .. } => false,
+            _ => true,
         }
     }
 
@@ -444,12 +701,70 @@ impl Display for TransactionStage {
 pub enum Action {
     GetBaseAccount(Address),
     QueryAllBalances(Address),
+    QuerySpendableBalances(Address),
+    QueryDenomMetadata(String),
+    QueryDenomsMetadata,
+    QueryTotalSupply,
+    QuerySupplyOf(String),
     QueryGranterGrants(Address),
+    QueryGranteeGrants(Address),
+    QueryDelegations(Address),
+    QueryUnbondingDelegations(Address),
+    QueryDelegationRewards(Address),
+    QueryValidators,
+    QueryValidator(Address),
+    QueryValidatorOutstandingRewards(Address),
+    QueryValidatorCommission(Address),
+    QueryWithdrawAddress(Address),
+    QueryProposals,
+    QueryProposal(u64),
+    QueryTallyResult(u64),
+    QueryFeeAllowance {
+        granter: Address,
+        grantee: Address,
+    },
+    QueryFeeAllowances(Address),
+    QueryFeeAllowancesByGranter(Address),
+    QueryIbcDenomTrace(String),
+    QueryIbcDenomTraces,
+    QueryIbcChannels,
+    QueryIbcClientStates,
+    QueryIbcClientState(String),
+    QueryIbcClientStatus(String),
+    QueryIbcConnections,
+    QueryIbcConnection(String),
+    QueryIbcChannel {
+        port_id: String,
+        channel_id: String,
+    },
+    QueryIbcPacketCommitments {
+        port_id: String,
+        channel_id: String,
+    },
+    QueryInterchainAccount {
+        owner: Address,
+        connection_id: String,
+    },
     CodeInfo(u64),
+    ListContractsByCode(u64),
+    ListCodes,
     GetTransactionBody(String),
+    GetMempoolTransaction(String),
     ListTransactionsFor(Address),
+    SearchTransactions(String),
     GetBlock(i64),
+    GetBlockResults(i64),
+    GetValidatorSet(i64),
+    GetLatestValidatorSet,
     GetLatestBlock,
+    GetNodeInfo,
+    ListMessageTypes,
+    GetSyncing,
+    QueryModuleParams(&'static str),
+    AbciQuery {
+        path: String,
+        height: i64,
+    },
     Simulate(TxBuilder),
     Broadcast {
         txbuilder: TxBuilder,
@@ -470,10 +785,42 @@ pub enum Action {
     },
     ContractInfo(Address),
     ContractHistory(Address),
+    QueryAllContractState(Address),
     GetEarliestBlock,
     WaitForTransaction(String),
     OsmosisEpochsInfo,
     OsmosisTxFeesInfo,
+    OsmosisFeeTokens,
+    OsmosisFeeTokenPrice(String),
+    OsmosisSpotPrice {
+        pool_id: u64,
+        base: String,
+        quote: String,
+    },
+    OsmosisEstimateSwap(String),
+    OsmosisTwap {
+        pool_id: u64,
+        base: String,
+        quote: String,
+        geometric: bool,
+    },
+    OsmosisClPositions(Address),
+    OsmosisClIncentiveRecords(u64),
+    RujiraPool(String),
+    RujiraPools,
+    RujiraQuoteSwap {
+        from_asset: String,
+        to_asset: String,
+    },
+    InjectiveSpotMarkets,
+    InjectiveDerivativeMarkets,
+    InjectiveSubaccountDeposits(String),
+    InjectiveOraclePrice {
+        oracle_type: String,
+        base: String,
+        quote: String,
+    },
+    FeemarketGasPrice(String),
     StoreCode {
         txbuilder: TxBuilder,
         txhash: String,
@@ -487,6 +834,9 @@ pub enum Action {
         txhash: String,
     },
     BroadcastRaw,
+    WaitForRebroadcast {
+        txhash: String,
+    },
 }
 
 impl Display for Action {
@@ -495,17 +845,209 @@ impl Display for Action {
     }
 }
 
+/// Broad categories of [Action]s, used for configuring per-category timeouts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionCategory {
+    /// Simulating a transaction
+    Simulate,
+    /// Broadcasting a transaction
+    Broadcast,
+    /// Looking up transactions, including waiting for them to land
+    GetTx,
+    /// Querying contract state and metadata
+    ContractQuery,
+    /// Fetching block information
+    Block,
+    /// Querying account information and balances
+    Account,
+    /// Anything else
+    Other,
+}
+
 impl Action {
+    /// Which [ActionCategory] this action falls into.
+    pub fn category(&self) -> ActionCategory {
+        match self {
+            Action::Simulate(_) => ActionCategory::Simulate,
+            Action::Broadcast { .. } | Action::BroadcastRaw => ActionCategory::Broadcast,
+            Action::GetTransactionBody(_)
+            | Action::GetMempoolTransaction(_)
+            | Action::ListTransactionsFor(_)
+            | Action::SearchTransactions(_)
+            | Action::WaitForTransaction(_)
+            | Action::WaitForBroadcast { .. }
+            | Action::WaitForRebroadcast { .. }
+            | Action::StoreCode { .. }
+            | Action::InstantiateContract { .. }
+            | Action::TokenFactory { .. } => ActionCategory::GetTx,
+            Action::RawQuery { .. }
+            | Action::SmartQuery { .. }
+            | Action::ContractInfo(_)
+            | Action::ContractHistory(_)
+            | Action::QueryAllContractState(_)
+            | Action::CodeInfo(_)
+            | Action::ListContractsByCode(_)
+            | Action::ListCodes => ActionCategory::ContractQuery,
+            Action::GetBlock(_)
+            | Action::GetBlockResults(_)
+            | Action::GetValidatorSet(_)
+            | Action::GetLatestValidatorSet
+            | Action::GetLatestBlock
+            | Action::GetEarliestBlock => ActionCategory::Block,
+            Action::GetBaseAccount(_)
+            | Action::QueryAllBalances(_)
+            | Action::QuerySpendableBalances(_)
+            | Action::QueryGranterGrants(_)
+            | Action::QueryGranteeGrants(_)
+            | Action::QueryDelegations(_)
+            | Action::QueryUnbondingDelegations(_)
+            | Action::QueryDelegationRewards(_)
+            | Action::QueryWithdrawAddress(_)
+            | Action::QueryFeeAllowance { .. }
+            | Action::QueryFeeAllowances(_)
+            | Action::QueryFeeAllowancesByGranter(_) => ActionCategory::Account,
+            Action::GetNodeInfo
+            | Action::ListMessageTypes
+            | Action::GetSyncing
+            | Action::QueryModuleParams(_)
+            | Action::AbciQuery { .. }
+            | Action::OsmosisEpochsInfo
+            | Action::OsmosisTxFeesInfo
+            | Action::OsmosisFeeTokens
+            | Action::OsmosisFeeTokenPrice(_)
+            | Action::OsmosisSpotPrice { .. }
+            | Action::OsmosisEstimateSwap(_)
+            | Action::OsmosisTwap { .. }
+            | Action::OsmosisClPositions(_)
+            | Action::OsmosisClIncentiveRecords(_)
+            | Action::RujiraPool(_)
+            | Action::RujiraPools
+            | Action::RujiraQuoteSwap { .. }
+            | Action::InjectiveSpotMarkets
+            | Action::InjectiveDerivativeMarkets
+            | Action::InjectiveSubaccountDeposits(_)
+            | Action::InjectiveOraclePrice { .. }
+            | Action::FeemarketGasPrice(_)
+            | Action::QueryValidators
+            | Action::QueryValidator(_)
+            | Action::QueryValidatorOutstandingRewards(_)
+            | Action::QueryValidatorCommission(_)
+            | Action::QueryProposals
+            | Action::QueryProposal(_)
+            | Action::QueryTallyResult(_)
+            | Action::QueryIbcDenomTrace(_)
+            | Action::QueryIbcDenomTraces
+            | Action::QueryIbcChannels
+            | Action::QueryIbcClientStates
+            | Action::QueryIbcClientState(_)
+            | Action::QueryIbcClientStatus(_)
+            | Action::QueryIbcConnections
+            | Action::QueryIbcConnection(_)
+            | Action::QueryIbcChannel { .. }
+            | Action::QueryIbcPacketCommitments { .. }
+            | Action::QueryDenomMetadata(_)
+            | Action::QueryDenomsMetadata
+            | Action::QueryTotalSupply
+            | Action::QuerySupplyOf(_)
+            | Action::QueryInterchainAccount { .. } => ActionCategory::Other,
+        }
+    }
+
     fn fmt_helper(&self, f: &mut std::fmt::Formatter, pretty: bool) -> std::fmt::Result {
         match self {
             Action::GetBaseAccount(address) => write!(f, "get base account {address}"),
             Action::QueryAllBalances(address) => write!(f, "query all balances for {address}"),
+            Action::QuerySpendableBalances(address) => {
+                write!(f, "query spendable balances for {address}")
+            }
+            Action::QueryDenomMetadata(denom) => write!(f, "query denom metadata for {denom}"),
+            Action::QueryDenomsMetadata => f.write_str("query all denoms metadata"),
+            Action::QueryTotalSupply => f.write_str("query total supply"),
+            Action::QuerySupplyOf(denom) => write!(f, "query supply of {denom}"),
             Action::QueryGranterGrants(address) => write!(f, "query granter grants for {address}"),
+            Action::QueryGranteeGrants(address) => write!(f, "query grantee grants for {address}"),
+            Action::QueryDelegations(address) => write!(f, "query delegations for {address}"),
+            Action::QueryUnbondingDelegations(address) => {
+                write!(f, "query unbonding delegations for {address}")
+            }
+            Action::QueryDelegationRewards(address) => {
+                write!(f, "query delegation rewards for {address}")
+            }
+            Action::QueryValidators => f.write_str("query validators"),
+            Action::QueryValidator(address) => write!(f, "query validator {address}"),
+            Action::QueryValidatorOutstandingRewards(address) => {
+                write!(f, "query outstanding rewards for validator {address}")
+            }
+            Action::QueryValidatorCommission(address) => {
+                write!(f, "query commission for validator {address}")
+            }
+            Action::QueryWithdrawAddress(address) => {
+                write!(f, "query reward withdraw address for {address}")
+            }
+            Action::QueryProposals => f.write_str("query governance proposals"),
+            Action::QueryProposal(id) => write!(f, "query governance proposal {id}"),
+            Action::QueryTallyResult(id) => write!(f, "query tally result for proposal {id}"),
+            Action::QueryFeeAllowance { granter, grantee } => {
+                write!(f, "query fee allowance from {granter} to {grantee}")
+            }
+            Action::QueryFeeAllowances(grantee) => {
+                write!(f, "query fee allowances granted to {grantee}")
+            }
+            Action::QueryFeeAllowancesByGranter(granter) => {
+                write!(f, "query fee allowances granted by {granter}")
+            }
+            Action::QueryIbcDenomTrace(denom) => write!(f, "query IBC denom trace for {denom}"),
+            Action::QueryIbcDenomTraces => f.write_str("query IBC denom traces"),
+            Action::QueryIbcChannels => f.write_str("query IBC channels"),
+            Action::QueryIbcClientStates => f.write_str("query IBC client states"),
+            Action::QueryIbcClientState(client_id) => {
+                write!(f, "query IBC client state for {client_id}")
+            }
+            Action::QueryIbcClientStatus(client_id) => {
+                write!(f, "query IBC client status for {client_id}")
+            }
+            Action::QueryIbcConnections => f.write_str("query IBC connections"),
+            Action::QueryIbcConnection(connection_id) => {
+                write!(f, "query IBC connection {connection_id}")
+            }
+            Action::QueryIbcChannel {
+                port_id,
+                channel_id,
+            } => write!(f, "query IBC channel {port_id}/{channel_id}"),
+            Action::QueryIbcPacketCommitments {
+                port_id,
+                channel_id,
+            } => write!(f, "query IBC packet commitments on {port_id}/{channel_id}"),
+            Action::QueryInterchainAccount {
+                owner,
+                connection_id,
+            } => write!(
+                f,
+                "query interchain account of {owner} over {connection_id}"
+            ),
             Action::CodeInfo(code_id) => write!(f, "get code info for code ID {code_id}"),
+            Action::ListContractsByCode(code_id) => {
+                write!(f, "list contracts for code ID {code_id}")
+            }
+            Action::ListCodes => f.write_str("list uploaded codes"),
             Action::GetTransactionBody(txhash) => write!(f, "get transaction {txhash}"),
+            Action::GetMempoolTransaction(txhash) => {
+                write!(f, "get mempool transaction {txhash}")
+            }
             Action::ListTransactionsFor(address) => write!(f, "list transactions for {address}"),
+            Action::SearchTransactions(query) => write!(f, "search transactions matching {query}"),
             Action::GetBlock(height) => write!(f, "get block {height}"),
+            Action::GetBlockResults(height) => write!(f, "get block results {height}"),
+            Action::GetValidatorSet(height) => write!(f, "get validator set at height {height}"),
+            Action::GetLatestValidatorSet => f.write_str("get latest validator set"),
             Action::GetLatestBlock => f.write_str("get latest block"),
+            Action::GetNodeInfo => f.write_str("get node info"),
+            Action::ListMessageTypes => f.write_str("list registered message types"),
+            Action::GetSyncing => f.write_str("get node syncing status"),
+            Action::QueryModuleParams(module) => write!(f, "query {module} module params"),
+            Action::AbciQuery { path, height } => {
+                write!(f, "ABCI query {path} at height {height}")
+            }
             Action::Simulate(txbuilder) => write!(f, "simulating transaction: {txbuilder}"),
             Action::Broadcast {
                 txbuilder,
@@ -530,10 +1072,70 @@ impl Action {
             }
             Action::ContractInfo(address) => write!(f, "contract info for {address}"),
             Action::ContractHistory(address) => write!(f, "contract history for {address}"),
+            Action::QueryAllContractState(address) => {
+                write!(f, "query all contract state for {address}")
+            }
             Action::GetEarliestBlock => f.write_str("get earliest block"),
             Action::WaitForTransaction(txhash) => write!(f, "wait for transaction {txhash}"),
             Action::OsmosisEpochsInfo => f.write_str("get Osmosis epochs info"),
             Action::OsmosisTxFeesInfo => f.write_str("get Osmosis txfees info"),
+            Action::OsmosisFeeTokens => f.write_str("get Osmosis fee tokens"),
+            Action::OsmosisFeeTokenPrice(denom) => {
+                write!(f, "get Osmosis fee token price for {denom}")
+            }
+            Action::OsmosisSpotPrice {
+                pool_id,
+                base,
+                quote,
+            } => write!(
+                f,
+                "get Osmosis spot price of {base} in {quote} from pool {pool_id}"
+            ),
+            Action::OsmosisEstimateSwap(desc) => write!(f, "estimate Osmosis swap of {desc}"),
+            Action::OsmosisTwap {
+                pool_id,
+                base,
+                quote,
+                geometric,
+            } => write!(
+                f,
+                "get Osmosis {} TWAP of {base} in {quote} from pool {pool_id}",
+                if *geometric {
+                    "geometric"
+                } else {
+                    "arithmetic"
+                }
+            ),
+            Action::OsmosisClPositions(address) => {
+                write!(
+                    f,
+                    "get Osmosis concentrated liquidity positions for {address}"
+                )
+            }
+            Action::OsmosisClIncentiveRecords(pool_id) => write!(
+                f,
+                "get Osmosis concentrated liquidity incentives for pool {pool_id}"
+            ),
+            Action::RujiraPool(asset) => write!(f, "get Rujira pool {asset}"),
+            Action::RujiraPools => f.write_str("get Rujira pools"),
+            Action::RujiraQuoteSwap {
+                from_asset,
+                to_asset,
+            } => write!(f, "get Rujira swap quote from {from_asset} to {to_asset}"),
+            Action::InjectiveSpotMarkets => f.write_str("get Injective spot markets"),
+            Action::InjectiveDerivativeMarkets => f.write_str("get Injective derivative markets"),
+            Action::InjectiveSubaccountDeposits(subaccount_id) => {
+                write!(f, "get Injective deposits for subaccount {subaccount_id}")
+            }
+            Action::InjectiveOraclePrice {
+                oracle_type,
+                base,
+                quote,
+            } => write!(
+                f,
+                "get Injective {oracle_type} oracle price for {base}/{quote}"
+            ),
+            Action::FeemarketGasPrice(denom) => write!(f, "get feemarket gas price for {denom}"),
             Action::StoreCode { txbuilder, txhash } => {
                 if pretty {
                     write!(f, "store code in {txhash}")
@@ -559,6 +1161,9 @@ impl Action {
                 }
             }
             Action::BroadcastRaw => f.write_str("broadcasting a raw transaction"),
+            Action::WaitForRebroadcast { txhash } => {
+                write!(f, "waiting for rebroadcast transaction {txhash} to land")
+            }
             Action::WaitForBroadcast { txbuilder, txhash } => {
                 if pretty {
                     write!(f, "waiting for transaction {txhash}")
@@ -640,6 +1245,16 @@ pub enum QueryErrorDetails {
     NotGrpc {
         source: tonic::Status,
     },
+    NodesDisagree {
+        height: Option<u64>,
+        first_grpc_url: Arc<String>,
+        first: String,
+        second_grpc_url: Arc<String>,
+        second: String,
+    },
+    CrossCheckUnavailable {
+        healthy_nodes: usize,
+    },
 }
 
 impl Display for QueryErrorDetails {
@@ -748,6 +1363,22 @@ impl QueryErrorDetails {
                     pretty_status(source, pretty)
                 )
             }
+            QueryErrorDetails::NodesDisagree {
+                height,
+                first_grpc_url,
+                first,
+                second_grpc_url,
+                second,
+            } => {
+                let height = match height {
+                    Some(height) => height.to_string(),
+                    None => "unknown height".to_owned(),
+                };
+                write!(f, "Nodes disagree on query result at {height}. {first_grpc_url} returned: {first}. {second_grpc_url} returned: {second}")
+            }
+            QueryErrorDetails::CrossCheckUnavailable { healthy_nodes } => {
+                write!(f, "Cross-checked query requires at least 2 healthy nodes, but only {healthy_nodes} available")
+            }
         }
     }
 }
@@ -898,6 +1529,9 @@ impl QueryErrorDetails {
             QueryErrorDetails::RateLimited { .. } => NetworkIssue,
             QueryErrorDetails::Forbidden { .. } => NetworkIssue,
             QueryErrorDetails::NotGrpc { .. } => NetworkIssue,
+            // Retrying against the same nodes won't help
+            QueryErrorDetails::NodesDisagree { .. } => ConnectionIsFine,
+            QueryErrorDetails::CrossCheckUnavailable { .. } => ConnectionIsFine,
         }
     }
 
@@ -990,7 +1624,9 @@ impl QueryErrorDetails {
             | QueryErrorDetails::BlocksLagDetected { .. }
             | QueryErrorDetails::NoNewBlockFound { .. }
             | QueryErrorDetails::AccountSequenceMismatch(_)
-            | QueryErrorDetails::NotGrpc { .. } => false,
+            | QueryErrorDetails::NotGrpc { .. }
+            | QueryErrorDetails::NodesDisagree { .. }
+            | QueryErrorDetails::CrossCheckUnavailable { .. } => false,
             QueryErrorDetails::RateLimited { .. } | QueryErrorDetails::Forbidden { .. } => true,
         }
     }
@@ -1,7 +1,14 @@
+use base64::Engine;
 use chrono::{DateTime, Utc};
 use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
 
-use crate::{error::ChainParseError, Address};
+use crate::{
+    error::ChainParseError,
+    tx_events::{
+        self, InstantiateEvent, MessageEvent, TransferEvent, WasmEvent, WriteAcknowledgementEvent,
+    },
+    Address,
+};
 
 /// Extension trait to add some helper methods to [TxResponse].
 pub trait TxResponseExt {
@@ -19,6 +26,125 @@ pub trait TxResponseExt {
 
     /// Return the first code ID stored in this transaction
     fn parse_first_stored_code_id(&self) -> Result<u64, ChainParseError>;
+
+    /// Return all events in this transaction with normalized attributes.
+    ///
+    /// Events from both the ABCI logs and the top-level events field are
+    /// included. See [normalize_event_attributes] for details on normalization.
+    fn normalized_events(&self) -> Vec<NormalizedEvent>;
+
+    /// Parse all events of the given [CustomEvent] type in this transaction.
+    fn parse_custom_events<E: CustomEvent>(&self) -> Result<Vec<E>, ChainParseError>;
+
+    /// Return the events of this transaction without duplicates.
+    ///
+    /// Newer chains include all events in both the logs and the events, so
+    /// the logs are only used when the top-level events field is empty.
+    fn deduplicated_events(&self) -> Vec<NormalizedEvent>;
+
+    /// Parse all bank `transfer` events.
+    fn transfer_events(&self) -> Result<Vec<TransferEvent>, ChainParseError>;
+
+    /// Parse all `wasm` and `wasm-<custom>` events emitted by contracts.
+    fn wasm_events(&self) -> Result<Vec<WasmEvent>, ChainParseError>;
+
+    /// Parse all `message` events.
+    fn message_events(&self) -> Result<Vec<MessageEvent>, ChainParseError>;
+
+    /// Parse all wasm `instantiate` events.
+    fn instantiate_events(&self) -> Result<Vec<InstantiateEvent>, ChainParseError>;
+
+    /// Parse all IBC `write_acknowledgement` events.
+    fn write_acknowledgement_events(
+        &self,
+    ) -> Result<Vec<WriteAcknowledgementEvent>, ChainParseError>;
+}
+
+/// A chain-specific event type which can be parsed from transaction events.
+///
+/// Attributes are collected into a JSON object and deserialized. Quoted
+/// strings, objects, and arrays (as emitted by typed protobuf events) are
+/// parsed as JSON, anything else is included as a plain string.
+pub trait CustomEvent: serde::de::DeserializeOwned {
+    /// The event type, e.g. `mychain.mymodule.v1.EventThingDone` or `wasm-thing`.
+    const EVENT_TYPE: &'static str;
+}
+
+/// An event with attribute keys and values decoded to plain strings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NormalizedEvent {
+    /// Event type, e.g. `instantiate` or `wasm`
+    pub r#type: String,
+    /// Attribute key/value pairs, in the order they were emitted
+    pub attributes: Vec<(String, String)>,
+}
+
+impl NormalizedEvent {
+    /// Find the first attribute value with the given key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Iterate over all attribute values with the given key.
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> {
+        self.attributes
+            .iter()
+            .filter(move |(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Normalize the attributes of a single event.
+///
+/// Cosmos SDK versions before 0.45 (and some archive nodes serving old heights)
+/// return attribute keys and values base64-encoded, while newer versions return
+/// plain strings. Encoding is detected per event: if every key decodes to a
+/// plausible attribute key, all keys and values are decoded. Otherwise the
+/// attributes are returned unchanged.
+pub fn normalize_event_attributes<'a, I>(attributes: I) -> Vec<(String, String)>
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    let attributes = attributes.into_iter().collect::<Vec<_>>();
+    let decoded_keys = attributes
+        .iter()
+        .map(|(key, _)| decode_base64_key(key))
+        .collect::<Option<Vec<_>>>();
+    match decoded_keys {
+        Some(keys) if !keys.is_empty() => keys
+            .into_iter()
+            .zip(attributes)
+            .map(|(key, (_, value))| {
+                let value = decode_base64_utf8(value).unwrap_or_else(|| value.to_owned());
+                (key, value)
+            })
+            .collect(),
+        _ => attributes
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect(),
+    }
+}
+
+fn decode_base64_utf8(s: &str) -> Option<String> {
+    let bytes = base64::engine::general_purpose::STANDARD.decode(s).ok()?;
+    String::from_utf8(bytes).ok()
+}
+
+fn decode_base64_key(key: &str) -> Option<String> {
+    let decoded = decode_base64_utf8(key)?;
+    let plausible = !decoded.is_empty()
+        && decoded
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'.' || b == b'-');
+    if plausible {
+        Some(decoded)
+    } else {
+        None
+    }
 }
 
 impl TxResponseExt for TxResponse {
@@ -40,9 +166,15 @@ impl TxResponseExt for TxResponse {
                 if event.r#type == "instantiate"
                     || event.r#type == "cosmwasm.wasm.v1.EventContractInstantiated"
                 {
-                    for attr in &event.attributes {
-                        if attr.key == "_contract_address" || attr.key == "contract_address" {
-                            let address = strip_quotes(&attr.value);
+                    let attributes = normalize_event_attributes(
+                        event
+                            .attributes
+                            .iter()
+                            .map(|attr| (attr.key.as_str(), attr.value.as_str())),
+                    );
+                    for (key, value) in &attributes {
+                        if key == "_contract_address" || key == "contract_address" {
+                            let address = strip_quotes(value);
                             let address: Address = address.parse().map_err(|source| {
                                 ChainParseError::InvalidInstantiatedContract {
                                     address: address.to_owned(),
@@ -61,12 +193,17 @@ impl TxResponseExt for TxResponse {
             self.events
                 .iter()
                 .filter(|event| event.r#type == "instantiate")
-                .flat_map(|event| event.attributes.iter())
-                .filter(|attr| {
-                    &*attr.key == "_contract_address" || &*attr.key == "contract_address"
+                .flat_map(|event| {
+                    normalize_event_attributes(
+                        event
+                            .attributes
+                            .iter()
+                            .map(|attr| (attr.key.as_str(), attr.value.as_str())),
+                    )
                 })
-                .flat_map(|attr| {
-                    let result: Result<Address, _> = attr.value.clone().parse();
+                .filter(|(key, _)| key == "_contract_address" || key == "contract_address")
+                .flat_map(|(_, value)| {
+                    let result: Result<Address, _> = strip_quotes(&value).parse();
                     result
                 }),
         );
@@ -88,9 +225,15 @@ impl TxResponseExt for TxResponse {
 
         for log in &self.logs {
             for event in &log.events {
-                for attr in &event.attributes {
-                    if attr.key == "code_id" {
-                        let value = strip_quotes(&attr.value);
+                let attributes = normalize_event_attributes(
+                    event
+                        .attributes
+                        .iter()
+                        .map(|attr| (attr.key.as_str(), attr.value.as_str())),
+                );
+                for (key, value) in &attributes {
+                    if key == "code_id" {
+                        let value = strip_quotes(value);
                         let value = value.parse::<u64>().map_err(|source| {
                             ChainParseError::InvalidCodeId {
                                 code_id: value.to_owned(),
@@ -111,12 +254,16 @@ impl TxResponseExt for TxResponse {
                     event.r#type == "store_code"
                         || event.r#type == "cosmwasm.wasm.v1.EventCodeStored"
                 })
-                .flat_map(|event| event.attributes.iter())
-                .filter(|attr| &*attr.key == "code_id")
-                .flat_map(|attr| {
-                    let code_id = strip_quotes(&attr.value);
-                    code_id.parse::<u64>().ok()
-                }),
+                .flat_map(|event| {
+                    normalize_event_attributes(
+                        event
+                            .attributes
+                            .iter()
+                            .map(|attr| (attr.key.as_str(), attr.value.as_str())),
+                    )
+                })
+                .filter(|(key, _)| key == "code_id")
+                .flat_map(|(_, value)| strip_quotes(&value).parse::<u64>().ok()),
         );
 
         Ok(res)
@@ -130,6 +277,122 @@ impl TxResponseExt for TxResponse {
                 txhash: self.txhash.clone(),
             })
     }
+
+    fn normalized_events(&self) -> Vec<NormalizedEvent> {
+        let from_logs = self
+            .logs
+            .iter()
+            .flat_map(|log| &log.events)
+            .map(|event| NormalizedEvent {
+                r#type: event.r#type.clone(),
+                attributes: normalize_event_attributes(
+                    event
+                        .attributes
+                        .iter()
+                        .map(|attr| (attr.key.as_str(), attr.value.as_str())),
+                ),
+            });
+        let from_events = self.events.iter().map(|event| NormalizedEvent {
+            r#type: event.r#type.clone(),
+            attributes: normalize_event_attributes(
+                event
+                    .attributes
+                    .iter()
+                    .map(|attr| (attr.key.as_str(), attr.value.as_str())),
+            ),
+        });
+        from_logs.chain(from_events).collect()
+    }
+
+    fn parse_custom_events<E: CustomEvent>(&self) -> Result<Vec<E>, ChainParseError> {
+        self.normalized_events()
+            .into_iter()
+            .filter(|event| event.r#type == E::EVENT_TYPE)
+            .map(|event| {
+                let object = event
+                    .attributes
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let value = if value.starts_with(['"', '{', '[']) {
+                            serde_json::from_str(&value).ok()
+                        } else {
+                            None
+                        }
+                        .unwrap_or(serde_json::Value::String(value));
+                        (key, value)
+                    })
+                    .collect::<serde_json::Map<_, _>>();
+                serde_json::from_value(serde_json::Value::Object(object)).map_err(|source| {
+                    ChainParseError::InvalidCustomEvent {
+                        event_type: E::EVENT_TYPE,
+                        txhash: self.txhash.clone(),
+                        source: source.into(),
+                    }
+                })
+            })
+            .collect()
+    }
+
+    fn deduplicated_events(&self) -> Vec<NormalizedEvent> {
+        let normalize = |event: &cosmos_sdk_proto::tendermint::abci::Event| NormalizedEvent {
+            r#type: event.r#type.clone(),
+            attributes: normalize_event_attributes(
+                event
+                    .attributes
+                    .iter()
+                    .map(|attr| (attr.key.as_str(), attr.value.as_str())),
+            ),
+        };
+        if self.events.is_empty() {
+            self.normalized_events()
+        } else {
+            self.events.iter().map(normalize).collect()
+        }
+    }
+
+    fn transfer_events(&self) -> Result<Vec<TransferEvent>, ChainParseError> {
+        let mut res = vec![];
+        for event in self.deduplicated_events() {
+            if event.r#type == "transfer" {
+                res.extend(tx_events::transfer_events(&event, &self.txhash)?);
+            }
+        }
+        Ok(res)
+    }
+
+    fn wasm_events(&self) -> Result<Vec<WasmEvent>, ChainParseError> {
+        self.deduplicated_events()
+            .iter()
+            .filter(|event| event.r#type == "wasm" || event.r#type.starts_with("wasm-"))
+            .map(|event| tx_events::wasm_event(event, &self.txhash))
+            .collect()
+    }
+
+    fn message_events(&self) -> Result<Vec<MessageEvent>, ChainParseError> {
+        self.deduplicated_events()
+            .iter()
+            .filter(|event| event.r#type == "message")
+            .map(|event| tx_events::message_event(event, &self.txhash))
+            .collect()
+    }
+
+    fn instantiate_events(&self) -> Result<Vec<InstantiateEvent>, ChainParseError> {
+        self.deduplicated_events()
+            .iter()
+            .filter(|event| event.r#type == "instantiate")
+            .map(|event| tx_events::instantiate_event(event, &self.txhash))
+            .collect()
+    }
+
+    fn write_acknowledgement_events(
+        &self,
+    ) -> Result<Vec<WriteAcknowledgementEvent>, ChainParseError> {
+        self.deduplicated_events()
+            .iter()
+            .filter(|event| event.r#type == "write_acknowledgement")
+            .map(|event| tx_events::write_acknowledgement_event(event, &self.txhash))
+            .collect()
+    }
 }
 
 fn strip_quotes(s: &str) -> &str {
@@ -137,3 +400,108 @@ fn strip_quotes(s: &str) -> &str {
         .and_then(|s| s.strip_suffix('\"'))
         .unwrap_or(s)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_attributes_untouched() {
+        let attrs = normalize_event_attributes([("code_id", "12"), ("sender", "osmo1abc")]);
+        assert_eq!(
+            attrs,
+            vec![
+                ("code_id".to_owned(), "12".to_owned()),
+                ("sender".to_owned(), "osmo1abc".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn base64_attributes_decoded() {
+        // code_id = 12, sender = osmo1abc
+        let attrs =
+            normalize_event_attributes([("Y29kZV9pZA==", "MTI="), ("c2VuZGVy", "b3NtbzFhYmM=")]);
+        assert_eq!(
+            attrs,
+            vec![
+                ("code_id".to_owned(), "12".to_owned()),
+                ("sender".to_owned(), "osmo1abc".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn mixed_keys_left_alone() {
+        // "Y29kZV9pZA==" decodes, but "amount" does not, so nothing is decoded
+        let attrs = normalize_event_attributes([("Y29kZV9pZA==", "MTI="), ("amount", "5uosmo")]);
+        assert_eq!(attrs[0].0, "Y29kZV9pZA==");
+        assert_eq!(attrs[1].1, "5uosmo");
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct ThingDone {
+        owner: String,
+        amount: String,
+        tags: Vec<String>,
+    }
+
+    impl CustomEvent for ThingDone {
+        const EVENT_TYPE: &'static str = "mychain.things.v1.EventThingDone";
+    }
+
+    #[test]
+    fn custom_events_parsed() {
+        use cosmos_sdk_proto::cosmos::base::abci::v1beta1::{
+            AbciMessageLog, Attribute, StringEvent,
+        };
+        let event = |r#type: &str, attrs: &[(&str, &str)]| StringEvent {
+            r#type: r#type.to_owned(),
+            attributes: attrs
+                .iter()
+                .map(|(key, value)| Attribute {
+                    key: (*key).to_owned(),
+                    value: (*value).to_owned(),
+                })
+                .collect(),
+        };
+        let log = |events: Vec<StringEvent>| AbciMessageLog {
+            msg_index: 0,
+            log: String::new(),
+            events,
+        };
+        let res = TxResponse {
+            txhash: "ABCD".to_owned(),
+            logs: vec![log(vec![
+                event("message", &[("action", "do_thing")]),
+                event(
+                    ThingDone::EVENT_TYPE,
+                    &[
+                        ("owner", "\"osmo1abc\""),
+                        ("amount", "12"),
+                        ("tags", "[\"a\",\"b\"]"),
+                        ("msg_index", "0"),
+                    ],
+                ),
+            ])],
+            ..Default::default()
+        };
+        assert_eq!(
+            res.parse_custom_events::<ThingDone>().unwrap(),
+            vec![ThingDone {
+                owner: "osmo1abc".to_owned(),
+                amount: "12".to_owned(),
+                tags: vec!["a".to_owned(), "b".to_owned()],
+            }]
+        );
+
+        let bad = TxResponse {
+            logs: vec![log(vec![event(
+                ThingDone::EVENT_TYPE,
+                &[("owner", "osmo1abc")],
+            )])],
+            ..res
+        };
+        assert!(bad.parse_custom_events::<ThingDone>().is_err());
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+use parking_lot::Mutex;
+use tokio::time::Instant;
+
+use crate::{Address, Coin};
+
+/// Limits the fees each wallet may spend over a rolling window.
+///
+/// Set on a [crate::CosmosBuilder] with [crate::CosmosBuilder::set_fee_budget].
+/// Every broadcast charges its fee to the payer (the fee granter if set,
+/// otherwise the first signer) before the transaction is sent, and fails with
+/// [crate::Error::FeeBudgetExceeded] once the limit is reached. Fees for
+/// transactions which fail to sign or are rejected at broadcast are refunded,
+/// while fees for broadcasts with an unknown outcome, such as a connection
+/// error or timeout, are kept since the transaction may still land.
+///
+/// Each denom is tracked separately. Fees in a denom without a limit, see
+/// [Self::add_limit], are refused as if their limit was zero. Clones share the
+/// same spending history, so one budget can be shared by multiple
+/// [crate::Cosmos] values.
+#[derive(Clone, Debug)]
+pub struct FeeBudget {
+    limits: Arc<HashMap<String, u128>>,
+    window: Duration,
+    spent: Arc<Mutex<HashMap<(Address, String), VecDeque<(Instant, u128)>>>>,
+}
+
+/// A fee charged to a [FeeBudget].
+///
+/// Refunded on drop unless [Self::commit] is called, so every error path
+/// before the transaction is sent gives the fee back.
+pub(crate) struct FeeCharge {
+    budget: FeeBudget,
+    key: (Address, String),
+    when: Instant,
+    amount: u128,
+    committed: bool,
+}
+
+impl FeeCharge {
+    /// Keep the charge, the chain accepted the transaction.
+    pub(crate) fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl Drop for FeeCharge {
+    fn drop(&mut self) {
+        if self.committed {
+            return;
+        }
+        if let Some(entries) = self.budget.spent.lock().get_mut(&self.key) {
+            if let Some(idx) = entries
+                .iter()
+                .position(|entry| *entry == (self.when, self.amount))
+            {
+                entries.remove(idx);
+            }
+        }
+    }
+}
+
+impl FeeBudget {
+    /// Allow each wallet to spend at most `limit` in fees within any `window`.
+    pub fn new(limit: u128, denom: impl Into<String>, window: Duration) -> Self {
+        FeeBudget {
+            limits: Arc::new(HashMap::from([(denom.into(), limit)])),
+            window,
+            spent: Default::default(),
+        }
+    }
+
+    /// Also allow each wallet to spend at most `limit` of another denom within the window.
+    pub fn add_limit(mut self, limit: u128, denom: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.limits).insert(denom.into(), limit);
+        self
+    }
+
+    /// Maximum spend per wallet within the window, for each denom.
+    pub fn get_limits(&self) -> Vec<Coin> {
+        self.limits
+            .iter()
+            .map(|(denom, limit)| Coin {
+                denom: denom.clone(),
+                amount: limit.to_string(),
+            })
+            .collect()
+    }
+
+    /// Maximum spend per wallet within the window for the given denom.
+    ///
+    /// Returns 0 for denoms without a limit.
+    pub fn get_limit(&self, denom: &str) -> u128 {
+        self.limits.get(denom).copied().unwrap_or_default()
+    }
+
+    /// Length of the rolling window.
+    pub fn get_window(&self) -> Duration {
+        self.window
+    }
+
+    /// Fees in the given denom spent by the wallet within the current window.
+    pub fn get_spent(&self, address: Address, denom: &str) -> u128 {
+        let mut spent = self.spent.lock();
+        match spent.get_mut(&(address, denom.to_owned())) {
+            None => 0,
+            Some(entries) => self.prune_and_sum(entries),
+        }
+    }
+
+    fn prune_and_sum(&self, entries: &mut VecDeque<(Instant, u128)>) -> u128 {
+        while let Some((when, _)) = entries.front() {
+            if when.elapsed() < self.window {
+                break;
+            }
+            entries.pop_front();
+        }
+        entries.iter().map(|(_, amount)| amount).sum()
+    }
+
+    /// Charge a fee, or return the amount already spent if it would exceed the limit.
+    pub(crate) fn charge(&self, address: Address, fee: &Coin) -> Result<FeeCharge, u128> {
+        let limit = self.get_limit(&fee.denom);
+        let amount = fee.amount.parse::<u128>().unwrap_or(u128::MAX);
+        let key = (address, fee.denom.clone());
+        let mut spent = self.spent.lock();
+        let entries = spent.entry(key.clone()).or_default();
+        let total = self.prune_and_sum(entries);
+        if total.saturating_add(amount) > limit {
+            return Err(total);
+        }
+        let when = Instant::now();
+        entries.push_back((when, amount));
+        Ok(FeeCharge {
+            budget: self.clone(),
+            key,
+            when,
+            amount,
+            committed: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(amount: u128, denom: &str) -> Coin {
+        Coin {
+            denom: denom.to_owned(),
+            amount: amount.to_string(),
+        }
+    }
+
+    #[test]
+    fn limit_and_refund() {
+        let address: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        let budget = FeeBudget::new(1000, "uosmo", Duration::from_secs(3600));
+        let charge = budget.charge(address, &coin(600, "uosmo")).unwrap();
+        assert_eq!(budget.get_spent(address, "uosmo"), 600);
+        assert_eq!(budget.charge(address, &coin(500, "uosmo")).err(), Some(600));
+
+        drop(charge);
+        assert_eq!(budget.get_spent(address, "uosmo"), 0);
+        budget
+            .charge(address, &coin(1000, "uosmo"))
+            .unwrap()
+            .commit();
+        assert_eq!(budget.get_spent(address, "uosmo"), 1000);
+    }
+
+    #[test]
+    fn per_denom_limits() {
+        let address: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        let budget = FeeBudget::new(1000, "uosmo", Duration::from_secs(3600));
+        assert_eq!(budget.charge(address, &coin(1, "uatom")).err(), Some(0));
+        budget.charge(address, &coin(0, "uatom")).unwrap().commit();
+
+        let budget = budget.add_limit(50, "uatom");
+        budget
+            .charge(address, &coin(1000, "uosmo"))
+            .unwrap()
+            .commit();
+        budget.charge(address, &coin(50, "uatom")).unwrap().commit();
+        assert_eq!(budget.get_spent(address, "uosmo"), 1000);
+        assert_eq!(budget.get_spent(address, "uatom"), 50);
+        assert_eq!(budget.charge(address, &coin(1, "uatom")).err(), Some(50));
+    }
+
+    #[test]
+    fn window_expiry() {
+        let address: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        let budget = FeeBudget::new(1000, "uosmo", Duration::ZERO);
+        budget
+            .charge(address, &coin(1000, "uosmo"))
+            .unwrap()
+            .commit();
+        assert_eq!(budget.get_spent(address, "uosmo"), 0);
+    }
+}
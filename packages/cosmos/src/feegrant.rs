@@ -0,0 +1,122 @@
+use cosmos_sdk_proto::{
+    cosmos::feegrant::v1beta1::{
+        Grant, MsgGrantAllowance, MsgRevokeAllowance, QueryAllowanceRequest,
+        QueryAllowancesByGranterRequest, QueryAllowancesRequest,
+    },
+    traits::Message,
+};
+
+use crate::{client::next_page_request, error::Action, Cosmos, HasAddress, TxMessage};
+
+impl From<MsgGrantAllowance> for TxMessage {
+    fn from(msg: MsgGrantAllowance) -> Self {
+        TxMessage::new(
+            "/cosmos.feegrant.v1beta1.MsgGrantAllowance",
+            msg.encode_to_vec(),
+            format!(
+                "{} grants {} a fee allowance of type {}",
+                msg.granter,
+                msg.grantee,
+                msg.allowance
+                    .as_ref()
+                    .map_or("<none>", |allowance| allowance.type_url.as_str())
+            ),
+        )
+    }
+}
+
+impl From<MsgRevokeAllowance> for TxMessage {
+    fn from(msg: MsgRevokeAllowance) -> Self {
+        TxMessage::new(
+            "/cosmos.feegrant.v1beta1.MsgRevokeAllowance",
+            msg.encode_to_vec(),
+            format!(
+                "{} revokes the fee allowance for {}",
+                msg.granter, msg.grantee
+            ),
+        )
+    }
+}
+
+impl Cosmos {
+    /// Get the fee allowance `granter` has granted to `grantee`.
+    pub async fn fee_allowance(
+        &self,
+        granter: impl HasAddress,
+        grantee: impl HasAddress,
+    ) -> Result<Grant, crate::Error> {
+        let action = Action::QueryFeeAllowance {
+            granter: granter.get_address(),
+            grantee: grantee.get_address(),
+        };
+        self.perform_query(
+            QueryAllowanceRequest {
+                granter: granter.get_address_string(),
+                grantee: grantee.get_address_string(),
+            },
+            action.clone(),
+        )
+        .run()
+        .await?
+        .into_inner()
+        .allowance
+        .ok_or_else(|| crate::Error::InvalidChainResponse {
+            message: "No fee allowance returned".to_owned(),
+            action: action.into(),
+        })
+    }
+
+    /// Get all fee allowances granted to the given address.
+    pub async fn fee_allowances(
+        &self,
+        grantee: impl HasAddress,
+    ) -> Result<Vec<Grant>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    QueryAllowancesRequest {
+                        grantee: grantee.get_address_string(),
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryFeeAllowances(grantee.get_address()),
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.allowances);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get all fee allowances granted by the given address.
+    pub async fn fee_allowances_by_granter(
+        &self,
+        granter: impl HasAddress,
+    ) -> Result<Vec<Grant>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    QueryAllowancesByGranterRequest {
+                        granter: granter.get_address_string(),
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryFeeAllowancesByGranter(granter.get_address()),
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.allowances);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+}
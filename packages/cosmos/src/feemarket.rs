@@ -0,0 +1,91 @@
+//! Support for the Skip feemarket module, which provides an EIP-1559 style
+//! base gas price on chains like Neutron and the Cosmos Hub.
+use cosmwasm_std::Decimal;
+
+use crate::{error::Action, osmosis::parse_osmosis_dec, Cosmos};
+
+/// Chain IDs known to run the feemarket module.
+///
+/// Injective is deliberately missing: it does not run the Skip feemarket
+/// module, so the gas price query fails there, and it charges a fixed minimum
+/// gas price instead. Use [crate::CosmosBuilder::set_feemarket_gas_price] to
+/// opt in for chains not listed here.
+const FEEMARKET_CHAIN_IDS: &[&str] = &["neutron-1", "pion-1", "cosmoshub-4", "provider"];
+
+/// Does the given chain use the feemarket module for gas prices?
+pub(crate) fn uses_feemarket(chain_id: &str) -> bool {
+    FEEMARKET_CHAIN_IDS.contains(&chain_id)
+}
+
+impl Cosmos {
+    /// Get the current base gas price in the gas coin from the feemarket module.
+    ///
+    /// Note that this query will fail on chains without the feemarket module.
+    pub async fn get_feemarket_gas_price(&self) -> Result<Decimal, crate::Error> {
+        let denom = self.get_cosmos_builder().gas_coin().to_owned();
+        let action = Action::FeemarketGasPrice(denom.clone());
+        let price = self
+            .perform_query(GasPriceRequest { denom }, action.clone())
+            .run()
+            .await?
+            .into_inner()
+            .price
+            .ok_or_else(|| crate::Error::InvalidChainResponse {
+                message: "Missing price field".to_owned(),
+                action: action.clone().into(),
+            })?;
+        parse_osmosis_dec(&price.amount, action)
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct GasPriceRequest {
+    #[prost(string, tag = "1")]
+    pub denom: ::prost::alloc::string::String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct GasPriceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub price: ::core::option::Option<cosmos_sdk_proto::cosmos::base::v1beta1::DecCoin>,
+}
+
+pub(crate) mod query_client {
+    use tonic::codegen::*;
+
+    #[derive(Debug, Clone)]
+    pub(crate) struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub(crate) fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+
+        pub(crate) async fn gas_price(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GasPriceRequest>,
+        ) -> Result<tonic::Response<super::GasPriceResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/feemarket.feemarket.v1.Query/GasPrice");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use std::sync::Arc;
+use std::{collections::VecDeque, sync::Arc};
 
 use parking_lot::RwLock;
 
-use crate::{CosmosTxResponse, Error};
+use crate::{error::DynamicGasStateError, CosmosTxResponse, Error};
 
 #[derive(Clone, Debug)]
 pub(crate) enum GasMultiplierConfig {
@@ -26,6 +26,8 @@ impl GasMultiplierConfig {
                 underpay_ratio: too_low_ratio,
             }) => GasMultiplier::Dynamic(Arc::new(Dynamic {
                 current: RwLock::new(*initial),
+                recent: RwLock::new(VecDeque::new()),
+                initial: *initial,
                 low: *low,
                 high: *high,
                 step_up: *step_up,
@@ -50,10 +52,32 @@ impl GasMultiplier {
         }
     }
 
+    /// Export the learned state, if this is a dynamic multiplier.
+    pub(crate) fn get_state(&self) -> Option<DynamicGasState> {
+        match self {
+            GasMultiplier::Static(_) => None,
+            GasMultiplier::Dynamic(d) => Some(DynamicGasState {
+                config: DynamicGasMultiplier {
+                    low: d.low,
+                    high: d.high,
+                    initial: d.initial,
+                    step_up: d.step_up,
+                    step_down: d.step_down,
+                    overpay_ratio: d.overpay_ratio,
+                    underpay_ratio: d.underpay_ratio,
+                },
+                current: *d.current.read(),
+                recent: d.recent.read().iter().copied().collect(),
+            }),
+        }
+    }
+
     /// Returns true if any change was made, false otherwise.
     pub(crate) fn update(&self, res: &Result<CosmosTxResponse, Error>) -> bool {
         let Dynamic {
             current,
+            recent,
+            initial: _,
             low,
             high,
             step_up,
@@ -73,6 +97,25 @@ impl GasMultiplier {
             Increase(IncreaseReason),
             Decrease { actual: f64, used: i64, wanted: i64 },
         }
+        let outcome = match res {
+            Ok(res) => Some(GasOutcome::Succeeded {
+                used: res.response.gas_used,
+                wanted: res.response.gas_wanted,
+            }),
+            Err(Error::TransactionFailed {
+                code: crate::error::CosmosSdkError::OutOfGas,
+                ..
+            }) => Some(GasOutcome::OutOfGas),
+            Err(_) => None,
+        };
+        if let Some(outcome) = outcome {
+            let mut recent = recent.write();
+            if recent.len() == RECENT_OUTCOMES {
+                recent.pop_front();
+            }
+            recent.push_back(outcome);
+        }
+
         let action = match res {
             Ok(res) => {
                 let ratio = res.response.gas_used as f64 / res.response.gas_wanted as f64;
@@ -138,8 +181,13 @@ impl GasMultiplier {
     }
 }
 
+/// How many outcomes to keep in [DynamicGasState::recent].
+const RECENT_OUTCOMES: usize = 20;
+
 pub(crate) struct Dynamic {
     current: RwLock<f64>,
+    recent: RwLock<VecDeque<GasOutcome>>,
+    initial: f64,
     low: f64,
     high: f64,
     step_up: f64,
@@ -157,7 +205,8 @@ pub(crate) struct Dynamic {
 /// * Decrease automatically when our gas estimate was too high.
 ///
 /// See comments on the field below for more details.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct DynamicGasMultiplier {
     /// The lowest the gas multiplier is allowed to go. Default: `1.2`.
     pub low: f64,
@@ -192,3 +241,169 @@ impl Default for DynamicGasMultiplier {
         }
     }
 }
+
+/// Learned state of a [DynamicGasMultiplier].
+///
+/// Export with [crate::Cosmos::get_dynamic_gas_state] and restore with
+/// [crate::Cosmos::with_dynamic_gas_state], e.g. to keep the multiplier across
+/// service restarts instead of relearning it from [DynamicGasMultiplier::initial].
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DynamicGasState {
+    /// Config parameters of the multiplier
+    pub config: DynamicGasMultiplier,
+    /// Multiplier value learned so far
+    pub current: f64,
+    /// Outcomes of the most recent transactions, oldest first
+    ///
+    /// At most the last 20 transactions are kept.
+    #[serde(default)]
+    pub recent: Vec<GasOutcome>,
+}
+
+/// Outcome of a transaction broadcast with a dynamic gas multiplier, see [DynamicGasState::recent].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GasOutcome {
+    /// The transaction ran out of gas
+    OutOfGas,
+    /// The transaction succeeded
+    Succeeded {
+        /// Gas used by the transaction
+        used: i64,
+        /// Gas requested by the transaction
+        wanted: i64,
+    },
+}
+
+impl DynamicGasState {
+    /// Build a multiplier starting from the learned value.
+    ///
+    /// The value is clamped to the configured bounds, in case they changed
+    /// since the state was saved. Fails if the state is corrupt, e.g. with
+    /// inverted bounds or NaN values.
+    pub(crate) fn build(&self) -> Result<GasMultiplier, DynamicGasStateError> {
+        let DynamicGasMultiplier {
+            low,
+            high,
+            initial,
+            step_up,
+            step_down,
+            overpay_ratio,
+            underpay_ratio,
+        } = self.config;
+        for (field, value) in [
+            ("current", self.current),
+            ("low", low),
+            ("high", high),
+            ("initial", initial),
+            ("step_up", step_up),
+            ("step_down", step_down),
+            ("overpay_ratio", overpay_ratio),
+            ("underpay_ratio", underpay_ratio),
+        ] {
+            if !value.is_finite() {
+                return Err(DynamicGasStateError::NotFinite { field, value });
+            }
+        }
+        if low > high {
+            return Err(DynamicGasStateError::InvalidBounds { low, high });
+        }
+        let multiplier = GasMultiplierConfig::Dynamic(self.config.clone()).build();
+        if let GasMultiplier::Dynamic(d) = &multiplier {
+            *d.current.write() = self.current.clamp(low, high);
+            let skip = self.recent.len().saturating_sub(RECENT_OUTCOMES);
+            *d.recent.write() = self.recent.iter().skip(skip).copied().collect();
+        }
+        Ok(multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_roundtrip() {
+        let state = DynamicGasState {
+            config: DynamicGasMultiplier::default(),
+            current: 1.75,
+            recent: vec![
+                GasOutcome::OutOfGas,
+                GasOutcome::Succeeded {
+                    used: 80,
+                    wanted: 100,
+                },
+            ],
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        let parsed: DynamicGasState = serde_json::from_str(&json).unwrap();
+        let restored = parsed.build().unwrap().get_state().unwrap();
+        assert_eq!(restored.current, 1.75);
+        assert_eq!(restored.recent, state.recent);
+        assert_eq!(restored.config, state.config);
+        assert_eq!(restored.config.initial, 1.3);
+    }
+
+    #[test]
+    fn state_records_outcomes() {
+        let multiplier = GasMultiplierConfig::Dynamic(DynamicGasMultiplier::default()).build();
+        for _ in 0..RECENT_OUTCOMES + 5 {
+            multiplier.update(&Ok(CosmosTxResponse {
+                response: cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse {
+                    gas_used: 80,
+                    gas_wanted: 100,
+                    ..Default::default()
+                },
+                tx: Default::default(),
+            }));
+        }
+        let state = multiplier.get_state().unwrap();
+        assert_eq!(state.recent.len(), RECENT_OUTCOMES);
+        assert_eq!(
+            state.recent[0],
+            GasOutcome::Succeeded {
+                used: 80,
+                wanted: 100
+            }
+        );
+    }
+
+    #[test]
+    fn state_clamped() {
+        let state = DynamicGasState {
+            config: DynamicGasMultiplier::default(),
+            current: 50.0,
+            recent: vec![],
+        };
+        assert_eq!(state.build().unwrap().get_current(), 10.0);
+    }
+
+    #[test]
+    fn state_corrupt() {
+        let inverted = DynamicGasState {
+            config: DynamicGasMultiplier {
+                low: 5.0,
+                high: 2.0,
+                ..DynamicGasMultiplier::default()
+            },
+            current: 3.0,
+            recent: vec![],
+        };
+        assert!(matches!(
+            inverted.build(),
+            Err(DynamicGasStateError::InvalidBounds { .. })
+        ));
+        let nan = DynamicGasState {
+            config: DynamicGasMultiplier {
+                high: f64::NAN,
+                ..DynamicGasMultiplier::default()
+            },
+            current: 3.0,
+            recent: vec![],
+        };
+        assert!(matches!(
+            nan.build(),
+            Err(DynamicGasStateError::NotFinite { field: "high", .. })
+        ));
+    }
+}
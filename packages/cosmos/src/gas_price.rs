@@ -1,8 +1,8 @@
-//! Gas price query for osmosis mainnet from lcd endpoint /osmosis/txfees/v1beta1/cur_eip_base_fee
+//! Dynamic gas prices, loaded from Osmosis's txfees module or the feemarket module on other chains.
 
-use std::{num::ParseFloatError, sync::Arc, time::Instant};
+use std::{future::Future, num::ParseFloatError, sync::Arc, time::Instant};
 
-use crate::{cosmos_builder::OsmosisGasParams, error::BuilderError, osmosis::TxFeesInfo, Cosmos};
+use crate::{cosmos_builder::DynamicGasParams, error::BuilderError, osmosis::TxFeesInfo, Cosmos};
 
 /// Mechanism used for determining the gas price
 #[derive(Clone, Debug)]
@@ -24,8 +24,14 @@ enum GasPriceMethodInner {
     },
     /// Reloads from EIP values regularly, starting with the values below.
     OsmosisMainnet {
-        price: Arc<tokio::sync::RwLock<OsmosisGasPrice>>,
-        params: OsmosisGasParams,
+        price: Arc<tokio::sync::RwLock<LoadedGasPrice>>,
+        params: DynamicGasParams,
+    },
+    /// Reloads the base gas price from the feemarket module regularly.
+    Feemarket {
+        price: Arc<tokio::sync::RwLock<LoadedGasPrice>>,
+        params: DynamicGasParams,
+        max_price: Option<f64>,
     },
 }
 
@@ -46,68 +52,53 @@ impl GasPriceMethod {
             GasPriceMethodInner::OsmosisMainnet {
                 price,
                 params:
-                    OsmosisGasParams {
+                    DynamicGasParams {
                         low_multiplier,
                         high_multiplier,
                     },
             } => {
-                // We're going to check if we have a recent enough value, so get
-                // the current timestamp for use below.
-                let now = Instant::now();
-                let too_old_seconds = cosmos
-                    .get_cosmos_builder()
-                    .get_osmosis_gas_price_too_old_seconds();
-
-                // Locking optimization. First take a read lock and, if we
-                // don't need to reload the price, no need for a write lock.
-                let orig = *price.read().await;
-                let reported = if osmosis_too_old(orig.last_loaded, now, too_old_seconds) {
-                    // OK, we think we need to reload. Now take a write lock.
-                    // We'll end up waiting if another task is already in the process of reloading,
-                    // which is exactly what we want (to avoid two concurrent loads).
-                    let mut guard = price.write().await;
-                    if osmosis_too_old(guard.last_loaded, now, too_old_seconds) {
-                        // No other task updated this, so we'll do it. We're
-                        // still holding the write lock, so all other tasks will wait on us. We rely
-                        // on existing timeouts in the rest of the system to ensure this completes in
-                        // a reasonable amount of time. This is considered acceptable, since any other
-                        // actions we'd want to take would have the same latency from slow gRPC queries.
-                        match load_osmosis_gas_base_fee(cosmos).await {
-                            Ok(reported) => {
-                                guard.reported = reported;
-                                guard.last_loaded = Some(now);
-                                reported
-                            }
-                            Err(e) => {
-                                tracing::error!(
-                                    "Unable to load Osmosis gas price (aka base fee): {e}"
-                                );
-                                guard.reported
-                            }
-                        }
-                    } else {
-                        guard.reported
-                    }
-                } else {
-                    orig.reported
-                };
-
+                let reported = cached_gas_price(price, cosmos, "Osmosis", || {
+                    load_osmosis_gas_base_fee(cosmos)
+                })
+                .await;
                 CurrentGasPrice {
                     base: reported,
                     low: (reported * low_multiplier).min(cosmos.max_price),
                     high: (reported * high_multiplier).min(cosmos.max_price),
                 }
             }
+            GasPriceMethodInner::Feemarket {
+                price,
+                params:
+                    DynamicGasParams {
+                        low_multiplier,
+                        high_multiplier,
+                    },
+                max_price,
+            } => {
+                let reported = cached_gas_price(price, cosmos, "feemarket", || {
+                    load_feemarket_gas_price(cosmos)
+                })
+                .await;
+                // Unlike on Osmosis, the base fee is reliable, so only cap
+                // the price when a maximum was explicitly requested.
+                let cap = |price: f64| max_price.map_or(price, |max| price.min(max));
+                CurrentGasPrice {
+                    base: reported,
+                    low: cap(reported * low_multiplier),
+                    high: cap(reported * high_multiplier),
+                }
+            }
         }
     }
 
     pub(crate) async fn new_osmosis_mainnet(
-        params: OsmosisGasParams,
+        params: DynamicGasParams,
     ) -> Result<Self, BuilderError> {
         Ok(GasPriceMethod {
             inner: GasPriceMethodInner::OsmosisMainnet {
-                price: Arc::new(tokio::sync::RwLock::new(OsmosisGasPrice {
-                    reported: OsmosisGasPrice::DEFAULT_REPORTED,
+                price: Arc::new(tokio::sync::RwLock::new(LoadedGasPrice {
+                    reported: LoadedGasPrice::OSMOSIS_DEFAULT_REPORTED,
                     last_loaded: None,
                 })),
                 params,
@@ -115,6 +106,19 @@ impl GasPriceMethod {
         })
     }
 
+    pub(crate) fn new_feemarket(params: DynamicGasParams, max_price: Option<f64>) -> Self {
+        GasPriceMethod {
+            inner: GasPriceMethodInner::Feemarket {
+                price: Arc::new(tokio::sync::RwLock::new(LoadedGasPrice {
+                    reported: DEFAULT_GAS_PRICE.base,
+                    last_loaded: None,
+                })),
+                params,
+                max_price,
+            },
+        }
+    }
+
     pub(crate) fn new_static(low: f64, high: f64) -> GasPriceMethod {
         GasPriceMethod {
             inner: GasPriceMethodInner::Static { low, high },
@@ -122,7 +126,61 @@ impl GasPriceMethod {
     }
 }
 
-fn osmosis_too_old(last_loaded: Option<Instant>, now: Instant, too_old_seconds: u64) -> bool {
+/// Get a cached gas price, reloading it if it's too old.
+///
+/// If reloading fails, the previous value is used.
+async fn cached_gas_price<F, Fut, E>(
+    price: &tokio::sync::RwLock<LoadedGasPrice>,
+    cosmos: &Cosmos,
+    source: &str,
+    load: F,
+) -> f64
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<f64, E>>,
+    E: std::fmt::Display,
+{
+    // We're going to check if we have a recent enough value, so get
+    // the current timestamp for use below.
+    let now = Instant::now();
+    let too_old_seconds = cosmos
+        .get_cosmos_builder()
+        .get_osmosis_gas_price_too_old_seconds();
+
+    // Locking optimization. First take a read lock and, if we
+    // don't need to reload the price, no need for a write lock.
+    let orig = *price.read().await;
+    if !too_old(orig.last_loaded, now, too_old_seconds) {
+        return orig.reported;
+    }
+
+    // OK, we think we need to reload. Now take a write lock.
+    // We'll end up waiting if another task is already in the process of reloading,
+    // which is exactly what we want (to avoid two concurrent loads).
+    let mut guard = price.write().await;
+    if !too_old(guard.last_loaded, now, too_old_seconds) {
+        return guard.reported;
+    }
+
+    // No other task updated this, so we'll do it. We're
+    // still holding the write lock, so all other tasks will wait on us. We rely
+    // on existing timeouts in the rest of the system to ensure this completes in
+    // a reasonable amount of time. This is considered acceptable, since any other
+    // actions we'd want to take would have the same latency from slow gRPC queries.
+    match load().await {
+        Ok(reported) => {
+            guard.reported = reported;
+            guard.last_loaded = Some(now);
+            reported
+        }
+        Err(e) => {
+            tracing::error!("Unable to load {source} gas price (aka base fee): {e}");
+            guard.reported
+        }
+    }
+}
+
+fn too_old(last_loaded: Option<Instant>, now: Instant, too_old_seconds: u64) -> bool {
     let last_loaded = match last_loaded {
         Some(last_loaded) => last_loaded,
         None => return true,
@@ -137,13 +195,13 @@ fn osmosis_too_old(last_loaded: Option<Instant>, now: Instant, too_old_seconds:
 }
 
 #[derive(Debug, Clone, Copy)]
-struct OsmosisGasPrice {
+struct LoadedGasPrice {
     reported: f64,
     last_loaded: Option<Instant>,
 }
 
-impl OsmosisGasPrice {
-    pub(crate) const DEFAULT_REPORTED: f64 = 0.0025;
+impl LoadedGasPrice {
+    pub(crate) const OSMOSIS_DEFAULT_REPORTED: f64 = 0.0025;
 }
 
 /// Loads current eip base fee from Osmosis txfees module
@@ -153,11 +211,20 @@ async fn load_osmosis_gas_base_fee(cosmos: &Cosmos) -> Result<f64, LoadOsmosisGa
 
     // There seems to be a bug where this endpoint occassionally returns 0. Just
     // set a minimum.
-    let base_fee = base_fee.max(OsmosisGasPrice::DEFAULT_REPORTED);
+    let base_fee = base_fee.max(LoadedGasPrice::OSMOSIS_DEFAULT_REPORTED);
 
     Ok(base_fee)
 }
 
+/// Loads the current base gas price from the feemarket module
+async fn load_feemarket_gas_price(cosmos: &Cosmos) -> Result<f64, LoadOsmosisGasPriceError> {
+    Ok(cosmos
+        .get_feemarket_gas_price()
+        .await?
+        .to_string()
+        .parse()?)
+}
+
 #[derive(thiserror::Error, Debug)]
 /// Verbose error for the gas price base fee request
 enum LoadOsmosisGasPriceError {
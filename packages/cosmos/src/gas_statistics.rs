@@ -0,0 +1,170 @@
+use std::collections::VecDeque;
+
+use crate::{CosmosTxResponse, Error};
+
+/// Number of most recent broadcasts kept for [GasStatistics].
+const MAX_SAMPLES: usize = 1000;
+
+/// Gas usage of a single broadcast using simulated gas.
+#[derive(Clone, Copy, Debug)]
+struct GasSample {
+    /// Gas used during simulation
+    simulated: u64,
+    /// Multiplier applied to the simulated gas
+    multiplier: f64,
+    /// Gas requested in the transaction
+    wanted: u64,
+    /// Gas actually used by the transaction
+    used: u64,
+}
+
+#[derive(Default)]
+pub(crate) struct GasStatisticsTracker {
+    samples: VecDeque<GasSample>,
+    out_of_gas: u64,
+}
+
+impl GasStatisticsTracker {
+    /// Record the result of broadcasting a transaction with the given simulated gas and multiplier.
+    pub(crate) fn record(
+        &mut self,
+        simulated: u64,
+        multiplier: f64,
+        res: &Result<CosmosTxResponse, Error>,
+    ) {
+        match res {
+            Ok(res) => {
+                if self.samples.len() >= MAX_SAMPLES {
+                    self.samples.pop_front();
+                }
+                self.samples.push_back(GasSample {
+                    simulated,
+                    multiplier,
+                    wanted: res.response.gas_wanted.try_into().unwrap_or_default(),
+                    used: res.response.gas_used.try_into().unwrap_or_default(),
+                });
+            }
+            Err(Error::TransactionFailed {
+                code: crate::error::CosmosSdkError::OutOfGas,
+                ..
+            }) => self.out_of_gas += 1,
+            Err(_) => (),
+        }
+    }
+
+    pub(crate) fn statistics(&self) -> GasStatistics {
+        let samples = self
+            .samples
+            .iter()
+            .filter(|sample| sample.simulated > 0 && sample.used > 0)
+            .collect::<Vec<_>>();
+        let count = samples.len();
+        let mean = |f: &dyn Fn(&GasSample) -> f64| {
+            if count == 0 {
+                0.0
+            } else {
+                samples.iter().map(|sample| f(sample)).sum::<f64>() / count as f64
+            }
+        };
+        let mean_multiplier = mean(&|sample| sample.multiplier);
+        let mean_multiplier_error =
+            mean(&|sample| sample.multiplier - sample.used as f64 / sample.simulated as f64);
+
+        let mut overshoot = samples
+            .iter()
+            .map(|sample| (sample.wanted as f64 - sample.used as f64) / sample.used as f64)
+            .collect::<Vec<_>>();
+        overshoot.sort_by(f64::total_cmp);
+        let percentile = |p: f64| {
+            if overshoot.is_empty() {
+                0.0
+            } else {
+                let idx = ((overshoot.len() - 1) as f64 * p).round() as usize;
+                overshoot[idx]
+            }
+        };
+
+        GasStatistics {
+            count,
+            out_of_gas: self.out_of_gas,
+            mean_multiplier,
+            mean_multiplier_error,
+            overshoot_p50: percentile(0.5),
+            overshoot_p90: percentile(0.9),
+            overshoot_p99: percentile(0.99),
+        }
+    }
+}
+
+/// Summary of gas requested versus gas used by recent broadcasts.
+///
+/// Only transactions whose gas was estimated by simulation are included, and
+/// only the most recent 1000 successful broadcasts are considered. Useful for
+/// tuning [crate::DynamicGasMultiplier] settings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GasStatistics {
+    /// Number of successful broadcasts included
+    pub count: usize,
+    /// Number of broadcasts which failed with an out of gas error
+    pub out_of_gas: u64,
+    /// Average multiplier applied to simulated gas
+    pub mean_multiplier: f64,
+    /// Average difference between the applied multiplier and the ideal one
+    ///
+    /// The ideal multiplier is the ratio of gas used to simulated gas.
+    /// Positive values mean we overpaid, negative values mean we cut it close.
+    pub mean_multiplier_error: f64,
+    /// Median overshoot, as a fraction of gas used
+    ///
+    /// An overshoot of `0.25` means we requested 25% more gas than was used.
+    pub overshoot_p50: f64,
+    /// 90th percentile overshoot, as a fraction of gas used
+    pub overshoot_p90: f64,
+    /// 99th percentile overshoot, as a fraction of gas used
+    pub overshoot_p99: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker(samples: &[(u64, f64, u64, u64)]) -> GasStatisticsTracker {
+        GasStatisticsTracker {
+            samples: samples
+                .iter()
+                .map(|&(simulated, multiplier, wanted, used)| GasSample {
+                    simulated,
+                    multiplier,
+                    wanted,
+                    used,
+                })
+                .collect(),
+            out_of_gas: 2,
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let stats = GasStatisticsTracker::default().statistics();
+        assert_eq!(stats.count, 0);
+        assert_eq!(stats.mean_multiplier_error, 0.0);
+        assert_eq!(stats.overshoot_p99, 0.0);
+    }
+
+    #[test]
+    fn calculations() {
+        let stats = tracker(&[
+            (1000, 1.5, 1500, 1000),
+            (1000, 1.5, 1500, 1200),
+            (1000, 1.5, 1500, 1500),
+        ])
+        .statistics();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.out_of_gas, 2);
+        assert_eq!(stats.mean_multiplier, 1.5);
+        assert!((stats.mean_multiplier_error - (0.5 + 0.3 + 0.0) / 3.0).abs() < 1e-9);
+        assert_eq!(stats.overshoot_p50, 0.25);
+        assert_eq!(stats.overshoot_p90, 0.5);
+        assert_eq!(stats.overshoot_p99, 0.5);
+    }
+}
@@ -0,0 +1,239 @@
+use cosmos_sdk_proto::{
+    cosmos::gov::{v1, v1beta1},
+    traits::Message,
+};
+
+use crate::{client::next_page_request, error::Action, messages::PrettyCoins, Cosmos, TxMessage};
+
+impl From<v1::MsgVote> for TxMessage {
+    fn from(msg: v1::MsgVote) -> Self {
+        TxMessage::new(
+            "/cosmos.gov.v1.MsgVote",
+            msg.encode_to_vec(),
+            format!(
+                "{} voting {} on proposal {}",
+                msg.voter,
+                vote_option_name(msg.option),
+                msg.proposal_id
+            ),
+        )
+    }
+}
+
+impl From<v1beta1::MsgVote> for TxMessage {
+    fn from(msg: v1beta1::MsgVote) -> Self {
+        TxMessage::new(
+            "/cosmos.gov.v1beta1.MsgVote",
+            msg.encode_to_vec(),
+            format!(
+                "{} voting {} on proposal {}",
+                msg.voter,
+                vote_option_name(msg.option),
+                msg.proposal_id
+            ),
+        )
+    }
+}
+
+impl From<v1::MsgDeposit> for TxMessage {
+    fn from(msg: v1::MsgDeposit) -> Self {
+        TxMessage::new(
+            "/cosmos.gov.v1.MsgDeposit",
+            msg.encode_to_vec(),
+            format!(
+                "{} depositing {} on proposal {}",
+                msg.depositor,
+                PrettyCoins(msg.amount.as_slice()),
+                msg.proposal_id
+            ),
+        )
+    }
+}
+
+impl From<v1beta1::MsgDeposit> for TxMessage {
+    fn from(msg: v1beta1::MsgDeposit) -> Self {
+        TxMessage::new(
+            "/cosmos.gov.v1beta1.MsgDeposit",
+            msg.encode_to_vec(),
+            format!(
+                "{} depositing {} on proposal {}",
+                msg.depositor,
+                PrettyCoins(msg.amount.as_slice()),
+                msg.proposal_id
+            ),
+        )
+    }
+}
+
+impl From<v1::MsgSubmitProposal> for TxMessage {
+    fn from(msg: v1::MsgSubmitProposal) -> Self {
+        TxMessage::new(
+            "/cosmos.gov.v1.MsgSubmitProposal",
+            msg.encode_to_vec(),
+            format!(
+                "{} submitting proposal {:?} with {} message(s)",
+                msg.proposer,
+                msg.title,
+                msg.messages.len()
+            ),
+        )
+    }
+}
+
+impl From<v1beta1::MsgSubmitProposal> for TxMessage {
+    fn from(msg: v1beta1::MsgSubmitProposal) -> Self {
+        TxMessage::new(
+            "/cosmos.gov.v1beta1.MsgSubmitProposal",
+            msg.encode_to_vec(),
+            format!(
+                "{} submitting legacy proposal of type {}",
+                msg.proposer,
+                msg.content
+                    .as_ref()
+                    .map_or("<none>", |content| content.type_url.as_str())
+            ),
+        )
+    }
+}
+
+fn vote_option_name(option: i32) -> &'static str {
+    match v1::VoteOption::try_from(option) {
+        Ok(v1::VoteOption::Yes) => "yes",
+        Ok(v1::VoteOption::No) => "no",
+        Ok(v1::VoteOption::Abstain) => "abstain",
+        Ok(v1::VoteOption::NoWithVeto) => "no with veto",
+        Ok(v1::VoteOption::Unspecified) | Err(_) => "unspecified",
+    }
+}
+
+impl Cosmos {
+    /// List governance proposals, optionally filtered by status.
+    ///
+    /// Uses the `cosmos.gov.v1` API, available since Cosmos SDK 0.46. See
+    /// [Self::proposals_v1beta1] for older chains.
+    pub async fn proposals(
+        &self,
+        status: Option<v1::ProposalStatus>,
+    ) -> Result<Vec<v1::Proposal>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    v1::QueryProposalsRequest {
+                        proposal_status: status.unwrap_or(v1::ProposalStatus::Unspecified) as i32,
+                        voter: String::new(),
+                        depositor: String::new(),
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryProposals,
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.proposals);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// List governance proposals using the legacy `cosmos.gov.v1beta1` API.
+    pub async fn proposals_v1beta1(
+        &self,
+        status: Option<v1beta1::ProposalStatus>,
+    ) -> Result<Vec<v1beta1::Proposal>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    v1beta1::QueryProposalsRequest {
+                        proposal_status: status.unwrap_or(v1beta1::ProposalStatus::Unspecified)
+                            as i32,
+                        voter: String::new(),
+                        depositor: String::new(),
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryProposals,
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.proposals);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get a single governance proposal by ID.
+    pub async fn proposal(&self, proposal_id: u64) -> Result<v1::Proposal, crate::Error> {
+        let action = Action::QueryProposal(proposal_id);
+        self.perform_query(v1::QueryProposalRequest { proposal_id }, action.clone())
+            .run()
+            .await?
+            .into_inner()
+            .proposal
+            .ok_or_else(|| crate::Error::InvalidChainResponse {
+                message: "No proposal returned".to_owned(),
+                action: action.into(),
+            })
+    }
+
+    /// Get a single governance proposal by ID using the legacy `cosmos.gov.v1beta1` API.
+    pub async fn proposal_v1beta1(
+        &self,
+        proposal_id: u64,
+    ) -> Result<v1beta1::Proposal, crate::Error> {
+        let action = Action::QueryProposal(proposal_id);
+        self.perform_query(
+            v1beta1::QueryProposalRequest { proposal_id },
+            action.clone(),
+        )
+        .run()
+        .await?
+        .into_inner()
+        .proposal
+        .ok_or_else(|| crate::Error::InvalidChainResponse {
+            message: "No proposal returned".to_owned(),
+            action: action.into(),
+        })
+    }
+
+    /// Get the current tally of votes for a proposal.
+    pub async fn tally_result(&self, proposal_id: u64) -> Result<v1::TallyResult, crate::Error> {
+        let action = Action::QueryTallyResult(proposal_id);
+        self.perform_query(v1::QueryTallyResultRequest { proposal_id }, action.clone())
+            .run()
+            .await?
+            .into_inner()
+            .tally
+            .ok_or_else(|| crate::Error::InvalidChainResponse {
+                message: "No tally result returned".to_owned(),
+                action: action.into(),
+            })
+    }
+
+    /// Get the current tally of votes for a proposal using the legacy `cosmos.gov.v1beta1` API.
+    pub async fn tally_result_v1beta1(
+        &self,
+        proposal_id: u64,
+    ) -> Result<v1beta1::TallyResult, crate::Error> {
+        let action = Action::QueryTallyResult(proposal_id);
+        self.perform_query(
+            v1beta1::QueryTallyResultRequest { proposal_id },
+            action.clone(),
+        )
+        .run()
+        .await?
+        .into_inner()
+        .tally
+        .ok_or_else(|| crate::Error::InvalidChainResponse {
+            message: "No tally result returned".to_owned(),
+            action: action.into(),
+        })
+    }
+}
@@ -0,0 +1,307 @@
+//! IBC queries and interchain account messages.
+//!
+//! The IBC protobufs are not included in [cosmos_sdk_proto], so the subset
+//! needed here is generated by the `prost-build` package.
+
+pub(crate) mod channel;
+pub(crate) mod client;
+pub(crate) mod commitment;
+pub(crate) mod connection;
+pub(crate) mod ica;
+pub(crate) mod ica_controller;
+pub(crate) mod transfer;
+
+pub use channel::{
+    Channel, Counterparty as ChannelCounterparty, IdentifiedChannel, Order as ChannelOrder,
+    PacketState, State as ChannelState,
+};
+pub use client::{Height, IdentifiedClientState};
+pub use commitment::MerklePrefix;
+pub use connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, IdentifiedConnection,
+    State as ConnectionState, Version as ConnectionVersion,
+};
+pub use ica::{CosmosTx, InterchainAccountPacketData, Type as InterchainAccountPacketType};
+pub use ica_controller::{MsgRegisterInterchainAccount, MsgSendTx};
+pub use transfer::DenomTrace;
+
+use crate::{client::next_page_request, error::Action, Cosmos};
+
+impl Cosmos {
+    /// Look up the path and base denom of an IBC token.
+    ///
+    /// Accepts either the full `ibc/<hash>` denom or just the hash.
+    pub async fn ibc_denom_trace(&self, denom: &str) -> Result<DenomTrace, crate::Error> {
+        let hash = denom.strip_prefix("ibc/").unwrap_or(denom);
+        let action = Action::QueryIbcDenomTrace(denom.to_owned());
+        self.perform_query(
+            transfer::QueryDenomTraceRequest {
+                hash: hash.to_owned(),
+            },
+            action.clone(),
+        )
+        .run()
+        .await?
+        .into_inner()
+        .denom_trace
+        .ok_or_else(|| crate::Error::InvalidChainResponse {
+            message: "No denom trace returned".to_owned(),
+            action: action.into(),
+        })
+    }
+
+    /// List the paths and base denoms of all IBC tokens known to this chain.
+    pub async fn ibc_denom_traces(&self) -> Result<Vec<DenomTrace>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    transfer::QueryDenomTracesRequest {
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryIbcDenomTraces,
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.denom_traces);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// List all IBC light clients on this chain.
+    pub async fn ibc_client_states(&self) -> Result<Vec<IdentifiedClientState>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    client::QueryClientStatesRequest {
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryIbcClientStates,
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.client_states);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get the state of an IBC light client.
+    ///
+    /// The state is returned encoded, since its type depends on the kind of light client.
+    pub async fn ibc_client_state(
+        &self,
+        client_id: impl Into<String>,
+    ) -> Result<prost_types::Any, crate::Error> {
+        let client_id = client_id.into();
+        let action = Action::QueryIbcClientState(client_id.clone());
+        self.perform_query(
+            client::QueryClientStateRequest { client_id },
+            action.clone(),
+        )
+        .run()
+        .await?
+        .into_inner()
+        .client_state
+        .ok_or_else(|| crate::Error::InvalidChainResponse {
+            message: "No client state returned".to_owned(),
+            action: action.into(),
+        })
+    }
+
+    /// Get the status of an IBC light client, e.g. `Active`, `Expired` or `Frozen`.
+    pub async fn ibc_client_status(
+        &self,
+        client_id: impl Into<String>,
+    ) -> Result<String, crate::Error> {
+        let client_id = client_id.into();
+        Ok(self
+            .perform_query(
+                client::QueryClientStatusRequest {
+                    client_id: client_id.clone(),
+                },
+                Action::QueryIbcClientStatus(client_id),
+            )
+            .run()
+            .await?
+            .into_inner()
+            .status)
+    }
+
+    /// List all IBC connections on this chain.
+    pub async fn ibc_connections(&self) -> Result<Vec<IdentifiedConnection>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    connection::QueryConnectionsRequest {
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryIbcConnections,
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.connections);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get an IBC connection end.
+    pub async fn ibc_connection(
+        &self,
+        connection_id: impl Into<String>,
+    ) -> Result<ConnectionEnd, crate::Error> {
+        let connection_id = connection_id.into();
+        let action = Action::QueryIbcConnection(connection_id.clone());
+        self.perform_query(
+            connection::QueryConnectionRequest {
+                connection_id: connection_id.clone(),
+            },
+            action.clone(),
+        )
+        .run()
+        .await?
+        .into_inner()
+        .connection
+        .ok_or_else(|| crate::Error::InvalidChainResponse {
+            message: "No connection returned".to_owned(),
+            action: action.into(),
+        })
+    }
+
+    /// List all IBC channels on this chain.
+    pub async fn ibc_channels(&self) -> Result<Vec<IdentifiedChannel>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    channel::QueryChannelsRequest {
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryIbcChannels,
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.channels);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get an IBC channel end.
+    pub async fn ibc_channel(
+        &self,
+        port_id: impl Into<String>,
+        channel_id: impl Into<String>,
+    ) -> Result<Channel, crate::Error> {
+        let port_id = port_id.into();
+        let channel_id = channel_id.into();
+        let action = Action::QueryIbcChannel {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+        };
+        self.perform_query(
+            channel::QueryChannelRequest {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            },
+            action.clone(),
+        )
+        .run()
+        .await?
+        .into_inner()
+        .channel
+        .ok_or_else(|| crate::Error::InvalidChainResponse {
+            message: "No channel returned".to_owned(),
+            action: action.into(),
+        })
+    }
+
+    /// List the commitments of packets sent on a channel which have not yet been acknowledged or timed out.
+    pub async fn ibc_packet_commitments(
+        &self,
+        port_id: impl Into<String>,
+        channel_id: impl Into<String>,
+    ) -> Result<Vec<PacketState>, crate::Error> {
+        let port_id = port_id.into();
+        let channel_id = channel_id.into();
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    channel::QueryPacketCommitmentsRequest {
+                        port_id: port_id.clone(),
+                        channel_id: channel_id.clone(),
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryIbcPacketCommitments {
+                        port_id: port_id.clone(),
+                        channel_id: channel_id.clone(),
+                    },
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.commitments);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+}
+
+impl DenomTrace {
+    /// The `ibc/<hash>` denom of this token on the chain.
+    pub fn ibc_denom(&self) -> String {
+        use sha2::Digest;
+
+        if self.path.is_empty() {
+            return self.base_denom.clone();
+        }
+        let hash = sha2::Sha256::digest(format!("{}/{}", self.path, self.base_denom));
+        format!("ibc/{}", hex::encode_upper(hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn denom_trace_hash() {
+        let trace = DenomTrace {
+            path: "transfer/channel-0".to_owned(),
+            base_denom: "uatom".to_owned(),
+        };
+        assert_eq!(
+            trace.ibc_denom(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+        let native = DenomTrace {
+            path: String::new(),
+            base_denom: "uosmo".to_owned(),
+        };
+        assert_eq!(native.ibc_denom(), "uosmo");
+    }
+}
@@ -0,0 +1,274 @@
+#![allow(missing_docs)]
+///////////// AUTOGENERATED FILE VIA TONIC-BUILD (DO NOT MODIFY) //////////////////////////////
+
+/// ConnectionEnd defines a stateful object on a chain connected to another
+/// separate one.
+/// NOTE: there must only be 2 defined ConnectionEnds to establish
+/// a connection between two chains.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConnectionEnd {
+    /// client associated with this connection.
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    /// IBC version which can be utilised to determine encodings or protocols for
+    /// channels or packets utilising this connection.
+    #[prost(message, repeated, tag = "2")]
+    pub versions: ::prost::alloc::vec::Vec<Version>,
+    /// current state of the connection end.
+    #[prost(enumeration = "State", tag = "3")]
+    pub state: i32,
+    /// counterparty chain associated with this connection.
+    #[prost(message, optional, tag = "4")]
+    pub counterparty: ::core::option::Option<Counterparty>,
+    /// delay period that must pass before a consensus state can be used for
+    /// packet-verification NOTE: delay period logic is only implemented by some
+    /// clients.
+    #[prost(uint64, tag = "5")]
+    pub delay_period: u64,
+}
+/// IdentifiedConnection defines a connection with additional connection
+/// identifier field.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdentifiedConnection {
+    /// connection identifier.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// client associated with this connection.
+    #[prost(string, tag = "2")]
+    pub client_id: ::prost::alloc::string::String,
+    /// IBC version which can be utilised to determine encodings or protocols for
+    /// channels or packets utilising this connection
+    #[prost(message, repeated, tag = "3")]
+    pub versions: ::prost::alloc::vec::Vec<Version>,
+    /// current state of the connection end.
+    #[prost(enumeration = "State", tag = "4")]
+    pub state: i32,
+    /// counterparty chain associated with this connection.
+    #[prost(message, optional, tag = "5")]
+    pub counterparty: ::core::option::Option<Counterparty>,
+    /// delay period associated with this connection.
+    #[prost(uint64, tag = "6")]
+    pub delay_period: u64,
+}
+/// Counterparty defines the counterparty chain associated with a connection end.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Counterparty {
+    /// identifies the client on the counterparty chain associated with a given
+    /// connection.
+    #[prost(string, tag = "1")]
+    pub client_id: ::prost::alloc::string::String,
+    /// identifies the connection end on the counterparty chain associated with a
+    /// given connection.
+    #[prost(string, tag = "2")]
+    pub connection_id: ::prost::alloc::string::String,
+    /// commitment merkle prefix of the counterparty chain.
+    #[prost(message, optional, tag = "3")]
+    pub prefix: ::core::option::Option<super::commitment::MerklePrefix>,
+}
+/// Version defines the versioning scheme used to negotiate the IBC verison in
+/// the connection handshake.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Version {
+    /// unique version identifier
+    #[prost(string, tag = "1")]
+    pub identifier: ::prost::alloc::string::String,
+    /// list of features compatible with the specified identifier
+    #[prost(string, repeated, tag = "2")]
+    pub features: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// QueryConnectionRequest is the request type for the Query/Connection RPC
+/// method
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryConnectionRequest {
+    /// connection unique identifier
+    #[prost(string, tag = "1")]
+    pub connection_id: ::prost::alloc::string::String,
+}
+/// QueryConnectionResponse is the response type for the Query/Connection RPC
+/// method. Besides the connection end, it includes a proof and the height from
+/// which the proof was retrieved.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryConnectionResponse {
+    /// connection associated with the request identifier
+    #[prost(message, optional, tag = "1")]
+    pub connection: ::core::option::Option<ConnectionEnd>,
+    /// merkle proof of existence
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof: ::prost::alloc::vec::Vec<u8>,
+    /// height at which the proof was retrieved
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: ::core::option::Option<super::client::Height>,
+}
+/// QueryConnectionsRequest is the request type for the Query/Connections RPC
+/// method
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryConnectionsRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pagination:
+        ::core::option::Option<::cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest>,
+}
+/// QueryConnectionsResponse is the response type for the Query/Connections RPC
+/// method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryConnectionsResponse {
+    /// list of stored connections of the chain.
+    #[prost(message, repeated, tag = "1")]
+    pub connections: ::prost::alloc::vec::Vec<IdentifiedConnection>,
+    /// pagination response
+    #[prost(message, optional, tag = "2")]
+    pub pagination:
+        ::core::option::Option<::cosmos_sdk_proto::cosmos::base::query::v1beta1::PageResponse>,
+    /// query block height
+    #[prost(message, optional, tag = "3")]
+    pub height: ::core::option::Option<super::client::Height>,
+}
+/// State defines if a connection is in one of the following states:
+/// INIT, TRYOPEN, OPEN or UNINITIALIZED.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum State {
+    /// Default State
+    UninitializedUnspecified = 0,
+    /// A connection end has just started the opening handshake.
+    Init = 1,
+    /// A connection end has acknowledged the handshake step on the counterparty
+    /// chain.
+    Tryopen = 2,
+    /// A connection end has completed the handshake.
+    Open = 3,
+}
+impl State {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            State::UninitializedUnspecified => "STATE_UNINITIALIZED_UNSPECIFIED",
+            State::Init => "STATE_INIT",
+            State::Tryopen => "STATE_TRYOPEN",
+            State::Open => "STATE_OPEN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STATE_UNINITIALIZED_UNSPECIFIED" => Some(Self::UninitializedUnspecified),
+            "STATE_INIT" => Some(Self::Init),
+            "STATE_TRYOPEN" => Some(Self::Tryopen),
+            "STATE_OPEN" => Some(Self::Open),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    /// Query provides defines the gRPC querier service
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> QueryClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            QueryClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Connection queries an IBC connection end.
+        pub async fn connection(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryConnectionRequest>,
+        ) -> Result<tonic::Response<super::QueryConnectionResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/ibc.core.connection.v1.Query/Connection");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Connections queries all the IBC connections of a chain.
+        pub async fn connections(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryConnectionsRequest>,
+        ) -> Result<tonic::Response<super::QueryConnectionsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/ibc.core.connection.v1.Query/Connections");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
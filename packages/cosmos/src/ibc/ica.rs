@@ -0,0 +1,51 @@
+#![allow(missing_docs)]
+///////////// AUTOGENERATED FILE VIA TONIC-BUILD (DO NOT MODIFY) //////////////////////////////
+
+/// InterchainAccountPacketData is comprised of a raw transaction, type of transaction and optional memo field.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InterchainAccountPacketData {
+    #[prost(enumeration = "Type", tag = "1")]
+    pub r#type: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "3")]
+    pub memo: ::prost::alloc::string::String,
+}
+/// CosmosTx contains a list of sdk.Msg's. It should be used when sending transactions to an SDK host chain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CosmosTx {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: ::prost::alloc::vec::Vec<::prost_types::Any>,
+}
+/// Type defines a classification of message issued from a controller chain to its associated interchain accounts
+/// host
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Type {
+    /// Default zero value enumeration
+    Unspecified = 0,
+    /// Execute a transaction on an interchain accounts host chain
+    ExecuteTx = 1,
+}
+impl Type {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Type::Unspecified => "TYPE_UNSPECIFIED",
+            Type::ExecuteTx => "TYPE_EXECUTE_TX",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "TYPE_EXECUTE_TX" => Some(Self::ExecuteTx),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Interchain accounts (ICS-27) controller support.
+
+use cosmos_sdk_proto::traits::Message;
+
+use crate::{
+    error::Action,
+    ibc::{
+        ica::{CosmosTx, InterchainAccountPacketData, Type},
+        ica_controller::{MsgRegisterInterchainAccount, MsgSendTx, QueryInterchainAccountRequest},
+        ChannelOrder,
+    },
+    Address, Cosmos, HasAddress, TxMessage,
+};
+
+impl Cosmos {
+    /// Get the address of the interchain account owned by `owner` over the given connection.
+    ///
+    /// The address lives on the host chain, so it will usually have a different HRP
+    /// than addresses on this chain.
+    pub async fn interchain_account(
+        &self,
+        owner: impl HasAddress,
+        connection_id: impl Into<String>,
+    ) -> Result<Address, crate::Error> {
+        let connection_id = connection_id.into();
+        let action = Action::QueryInterchainAccount {
+            owner: owner.get_address(),
+            connection_id: connection_id.clone(),
+        };
+        let address = self
+            .perform_query(
+                QueryInterchainAccountRequest {
+                    owner: owner.get_address_string(),
+                    connection_id,
+                },
+                action.clone(),
+            )
+            .run()
+            .await?
+            .into_inner()
+            .address;
+        address
+            .parse()
+            .map_err(|_| crate::Error::InvalidChainResponse {
+                message: format!("Invalid interchain account address: {address}"),
+                action: action.into(),
+            })
+    }
+}
+
+impl MsgRegisterInterchainAccount {
+    /// Register an interchain account for `owner` on the host chain at the other end of the connection.
+    ///
+    /// The version is left empty so that the host uses the default ICS-27 metadata.
+    pub fn new(owner: impl HasAddress, connection_id: impl Into<String>) -> Self {
+        MsgRegisterInterchainAccount {
+            owner: owner.get_address_string(),
+            connection_id: connection_id.into(),
+            version: String::new(),
+            ordering: ChannelOrder::NoneUnspecified.into(),
+        }
+    }
+
+    /// Set the ordering of the channel opened for this account.
+    ///
+    /// Older versions of ibc-go only support ordered channels.
+    pub fn with_ordering(mut self, ordering: ChannelOrder) -> Self {
+        self.ordering = ordering.into();
+        self
+    }
+
+    /// Set an explicit JSON-encoded ICS-27 version metadata.
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+}
+
+impl InterchainAccountPacketData {
+    /// Packet executing the given messages as a single transaction on the host chain.
+    ///
+    /// Messages must be signed by the interchain account's address on the host chain.
+    pub fn execute_tx<I>(messages: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<TxMessage>,
+    {
+        let tx = CosmosTx {
+            messages: messages
+                .into_iter()
+                .map(|msg| {
+                    let (any, _) = msg.into().into_protobuf();
+                    prost_types::Any {
+                        type_url: any.type_url,
+                        value: any.value,
+                    }
+                })
+                .collect(),
+        };
+        InterchainAccountPacketData {
+            r#type: Type::ExecuteTx.into(),
+            data: tx.encode_to_vec(),
+            memo: String::new(),
+        }
+    }
+
+    /// Set the packet memo.
+    pub fn with_memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = memo.into();
+        self
+    }
+}
+
+impl MsgSendTx {
+    /// Send a packet to the interchain account owned by `owner` over the given connection.
+    ///
+    /// The packet times out if not received by the host within `timeout`.
+    pub fn new(
+        owner: impl HasAddress,
+        connection_id: impl Into<String>,
+        packet_data: InterchainAccountPacketData,
+        timeout: std::time::Duration,
+    ) -> Self {
+        MsgSendTx {
+            owner: owner.get_address_string(),
+            connection_id: connection_id.into(),
+            packet_data: Some(packet_data),
+            relative_timeout: timeout.as_nanos().try_into().unwrap_or(u64::MAX),
+        }
+    }
+}
+
+impl From<MsgRegisterInterchainAccount> for TxMessage {
+    fn from(msg: MsgRegisterInterchainAccount) -> Self {
+        TxMessage::new(
+            "/ibc.applications.interchain_accounts.controller.v1.MsgRegisterInterchainAccount",
+            msg.encode_to_vec(),
+            format!(
+                "{} registering an interchain account over {}",
+                msg.owner, msg.connection_id
+            ),
+        )
+    }
+}
+
+impl From<MsgSendTx> for TxMessage {
+    fn from(msg: MsgSendTx) -> Self {
+        let count = msg
+            .packet_data
+            .as_ref()
+            .and_then(|packet| CosmosTx::decode(packet.data.as_slice()).ok())
+            .map_or(0, |tx| tx.messages.len());
+        TxMessage::new(
+            "/ibc.applications.interchain_accounts.controller.v1.MsgSendTx",
+            msg.encode_to_vec(),
+            format!(
+                "{} executing {count} message(s) via interchain account over {}",
+                msg.owner, msg.connection_id
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmos_sdk_proto::cosmos::bank::v1beta1::MsgSend;
+
+    use super::*;
+
+    #[test]
+    fn execute_tx_packet() {
+        let send = MsgSend {
+            from_address: "cosmos1host".to_owned(),
+            to_address: "cosmos1recipient".to_owned(),
+            amount: vec![],
+        };
+        let packet = InterchainAccountPacketData::execute_tx([send.clone()]);
+        assert_eq!(packet.r#type(), Type::ExecuteTx);
+        let tx = CosmosTx::decode(packet.data.as_slice()).unwrap();
+        assert_eq!(tx.messages.len(), 1);
+        assert_eq!(tx.messages[0].type_url, "/cosmos.bank.v1beta1.MsgSend");
+        assert_eq!(tx.messages[0].value, send.encode_to_vec());
+    }
+}
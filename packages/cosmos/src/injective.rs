@@ -1,9 +1,25 @@
+//! Injective-specific functionality.
 #![allow(non_snake_case)]
 use cosmos_sdk_proto::cosmos::auth::v1beta1::BaseAccount;
 
+#[cfg(feature = "injective")]
+pub mod exchange;
+#[cfg(feature = "injective")]
+pub mod oracle;
+
+/// Upstream versions of the protobuf code vendored in this module, see [crate::build_info()].
+///
+/// These are copied from `InjectiveLabs/injective-core` and patched by hand
+/// rather than generated, keep in sync with the header of each `proto.rs`.
+#[cfg(feature = "injective")]
+pub(crate) const PROTO_VERSIONS: &[(&str, &str)] = &[
+    ("injective-exchange", "v1.13.0"),
+    ("injective-oracle", "v1.13.0"),
+];
+
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EthAccount {
+pub(crate) struct EthAccount {
     #[prost(message, optional, tag = "1")]
     pub base_account: ::core::option::Option<BaseAccount>,
     #[prost(bytes = "vec", tag = "2")]
@@ -0,0 +1,294 @@
+//! Trading on the Injective exchange module.
+//!
+//! Prices, quantities and rates in this module are encoded by the chain as
+//! 18 decimal fixed point integers. Message constructors take [Decimal256]s and
+//! handle the conversion; query responses are returned as-is.
+//!
+//! Quantities are in base units, so e.g. 1 INJ is a quantity of 10^18. A
+//! [cosmwasm_std::Decimal] cannot hold more than about 340 INJ in this format.
+use std::collections::HashMap;
+
+use cosmos_sdk_proto::traits::Message;
+use cosmwasm_std::Decimal256;
+use tonic::async_trait;
+
+use crate::{
+    client::{node::Node, query::GrpcRequest},
+    error::Action,
+    Cosmos, Error, HasAddress, TxMessage,
+};
+
+mod proto;
+
+pub(crate) use proto::{
+    query_client, QueryDerivativeMarketsRequest, QueryDerivativeMarketsResponse,
+    QuerySpotMarketsRequest, QuerySpotMarketsResponse, QuerySubaccountDepositsRequest,
+    QuerySubaccountDepositsResponse,
+};
+pub use proto::{
+    Deposit, DerivativeMarket, FullDerivativeMarket, MarketStatus, MsgCancelSpotOrder,
+    MsgCancelSpotOrderResponse, MsgCreateSpotLimitOrder, MsgCreateSpotLimitOrderResponse,
+    OrderInfo, OrderType, SpotMarket, SpotOrder,
+};
+
+/// Get the subaccount ID for the given address and subaccount index.
+///
+/// Index 0 is the default subaccount, which shares its balance with the bank module.
+pub fn subaccount_id(address: impl HasAddress, index: u32) -> String {
+    format!(
+        "0x{}{index:024x}",
+        hex::encode(address.get_address().raw().as_ref())
+    )
+}
+
+/// Encode a decimal the way the chain expects for LegacyDec fields.
+fn encode_dec(value: Decimal256) -> String {
+    value.atomics().to_string()
+}
+
+impl MsgCreateSpotLimitOrder {
+    /// Place a limit order on a spot market from the given subaccount.
+    ///
+    /// `order_type` should be one of the non-stop order types, e.g.
+    /// [OrderType::Buy] or [OrderType::SellPo] for post-only.
+    pub fn new(
+        sender: impl HasAddress,
+        market_id: impl Into<String>,
+        subaccount_id: impl Into<String>,
+        order_type: OrderType,
+        price: Decimal256,
+        quantity: Decimal256,
+    ) -> Self {
+        let sender = sender.get_address_string();
+        MsgCreateSpotLimitOrder {
+            order: Some(SpotOrder {
+                market_id: market_id.into(),
+                order_info: Some(OrderInfo {
+                    subaccount_id: subaccount_id.into(),
+                    fee_recipient: sender.clone(),
+                    price: encode_dec(price),
+                    quantity: encode_dec(quantity),
+                    cid: String::new(),
+                }),
+                order_type: order_type.into(),
+                trigger_price: String::new(),
+            }),
+            sender,
+        }
+    }
+}
+
+impl MsgCancelSpotOrder {
+    /// Cancel a spot order by its hash.
+    pub fn new(
+        sender: impl HasAddress,
+        market_id: impl Into<String>,
+        subaccount_id: impl Into<String>,
+        order_hash: impl Into<String>,
+    ) -> Self {
+        MsgCancelSpotOrder {
+            sender: sender.get_address_string(),
+            market_id: market_id.into(),
+            subaccount_id: subaccount_id.into(),
+            order_hash: order_hash.into(),
+            cid: String::new(),
+        }
+    }
+}
+
+impl From<MsgCreateSpotLimitOrder> for TxMessage {
+    fn from(msg: MsgCreateSpotLimitOrder) -> Self {
+        let description = match &msg.order {
+            Some(order) => format!(
+                "{} placing {:?} limit order on market {}",
+                msg.sender,
+                order.order_type(),
+                order.market_id
+            ),
+            None => format!("{} placing empty limit order", msg.sender),
+        };
+        TxMessage::new(
+            "/injective.exchange.v1beta1.MsgCreateSpotLimitOrder",
+            msg.encode_to_vec(),
+            description,
+        )
+    }
+}
+
+impl From<MsgCancelSpotOrder> for TxMessage {
+    fn from(msg: MsgCancelSpotOrder) -> Self {
+        TxMessage::new(
+            "/injective.exchange.v1beta1.MsgCancelSpotOrder",
+            msg.encode_to_vec(),
+            format!(
+                "{} cancelling order {} on market {}",
+                msg.sender, msg.order_hash, msg.market_id
+            ),
+        )
+    }
+}
+
+impl Cosmos {
+    /// Get the Injective spot markets, optionally filtered by status (e.g. `Active`).
+    ///
+    /// Note that this query will fail if called on chains besides Injective.
+    pub async fn get_injective_spot_markets(
+        &self,
+        status: Option<&str>,
+    ) -> Result<Vec<SpotMarket>, Error> {
+        Ok(self
+            .perform_query(
+                QuerySpotMarketsRequest {
+                    status: status.unwrap_or_default().to_owned(),
+                    market_ids: vec![],
+                },
+                Action::InjectiveSpotMarkets,
+            )
+            .run()
+            .await?
+            .into_inner()
+            .markets)
+    }
+
+    /// Get the Injective derivative markets, optionally filtered by status (e.g. `Active`).
+    ///
+    /// Note that this query will fail if called on chains besides Injective.
+    pub async fn get_injective_derivative_markets(
+        &self,
+        status: Option<&str>,
+    ) -> Result<Vec<FullDerivativeMarket>, Error> {
+        Ok(self
+            .perform_query(
+                QueryDerivativeMarketsRequest {
+                    status: status.unwrap_or_default().to_owned(),
+                    market_ids: vec![],
+                    with_mid_price_and_tob: false,
+                },
+                Action::InjectiveDerivativeMarkets,
+            )
+            .run()
+            .await?
+            .into_inner()
+            .markets)
+    }
+
+    /// Get the deposits held by an Injective subaccount, keyed by denom.
+    ///
+    /// See [subaccount_id] for constructing the ID.
+    ///
+    /// Note that this query will fail if called on chains besides Injective.
+    pub async fn get_injective_subaccount_deposits(
+        &self,
+        subaccount_id: impl Into<String>,
+    ) -> Result<HashMap<String, Deposit>, Error> {
+        let subaccount_id = subaccount_id.into();
+        Ok(self
+            .perform_query(
+                QuerySubaccountDepositsRequest {
+                    subaccount_id: subaccount_id.clone(),
+                },
+                Action::InjectiveSubaccountDeposits(subaccount_id),
+            )
+            .run()
+            .await?
+            .into_inner()
+            .deposits)
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QuerySpotMarketsRequest {
+    type Response = QuerySpotMarketsResponse;
+
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .injective_exchange_query_client()
+            .spot_markets(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryDerivativeMarketsRequest {
+    type Response = QueryDerivativeMarketsResponse;
+
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .injective_exchange_query_client()
+            .derivative_markets(req)
+            .await
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QuerySubaccountDepositsRequest {
+    type Response = QuerySubaccountDepositsResponse;
+
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .injective_exchange_query_client()
+            .subaccount_deposits(req)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+
+    #[test]
+    fn default_subaccount_id() {
+        let address: Address = "inj15sws48vv977kmgawqfegptw0pqs7cfeq7mpr4c"
+            .parse()
+            .unwrap();
+        let id = subaccount_id(address, 0);
+        assert_eq!(id.len(), 2 + 64);
+        assert!(id.ends_with(&"0".repeat(24)));
+        assert_eq!(&subaccount_id(address, 1)[42..], "000000000000000000000001");
+    }
+
+    #[test]
+    fn limit_order_encoding() {
+        let address: Address = "inj15sws48vv977kmgawqfegptw0pqs7cfeq7mpr4c"
+            .parse()
+            .unwrap();
+        let msg = MsgCreateSpotLimitOrder::new(
+            address,
+            "0xmarket",
+            subaccount_id(address, 0),
+            OrderType::Buy,
+            "1.5".parse().unwrap(),
+            Decimal256::one(),
+        );
+        let info = msg.order.as_ref().unwrap().order_info.as_ref().unwrap();
+        assert_eq!(info.price, "1500000000000000000");
+        assert_eq!(info.quantity, "1000000000000000000");
+
+        // 1000 INJ in base units, beyond the range of a Decimal
+        let msg = MsgCreateSpotLimitOrder::new(
+            address,
+            "0xmarket",
+            subaccount_id(address, 0),
+            OrderType::Sell,
+            "0.000000000025".parse().unwrap(),
+            "1000000000000000000000".parse().unwrap(),
+        );
+        let info = msg.order.as_ref().unwrap().order_info.as_ref().unwrap();
+        assert_eq!(info.price, "25000000");
+        assert_eq!(info.quantity, "1000000000000000000000000000000000000000");
+        assert_eq!(
+            TxMessage::from(msg).type_url(),
+            "/injective.exchange.v1beta1.MsgCreateSpotLimitOrder"
+        );
+    }
+}
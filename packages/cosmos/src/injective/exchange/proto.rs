@@ -0,0 +1,384 @@
+#![allow(missing_docs)]
+//////////// GENERATED, COPY/PASTED, AND PATCHED FROM PROST-BUILD ////////////////
+// Source: InjectiveLabs/injective-core v1.13.0, proto/injective/exchange/v1beta1
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Deposit {
+    #[prost(string, tag = "1")]
+    pub available_balance: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub total_balance: ::prost::alloc::string::String,
+}
+/// An object describing trade pair of two assets.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpotMarket {
+    /// A name of the pair in format AAA/BBB, where AAA is base asset, BBB is quote
+    /// asset.
+    #[prost(string, tag = "1")]
+    pub ticker: ::prost::alloc::string::String,
+    /// Coin denom used for the base asset
+    #[prost(string, tag = "2")]
+    pub base_denom: ::prost::alloc::string::String,
+    /// Coin used for the quote asset
+    #[prost(string, tag = "3")]
+    pub quote_denom: ::prost::alloc::string::String,
+    /// maker_fee_rate defines the fee percentage makers pay when trading
+    #[prost(string, tag = "4")]
+    pub maker_fee_rate: ::prost::alloc::string::String,
+    /// taker_fee_rate defines the fee percentage takers pay when trading
+    #[prost(string, tag = "5")]
+    pub taker_fee_rate: ::prost::alloc::string::String,
+    /// relayer_fee_share_rate defines the percentage of the transaction fee shared
+    /// with the relayer in a derivative market
+    #[prost(string, tag = "6")]
+    pub relayer_fee_share_rate: ::prost::alloc::string::String,
+    /// Unique market ID.
+    #[prost(string, tag = "7")]
+    pub market_id: ::prost::alloc::string::String,
+    /// Status of the market
+    #[prost(enumeration = "MarketStatus", tag = "8")]
+    pub status: i32,
+    /// min_price_tick_size defines the minimum tick size that the price required
+    /// for orders in the market
+    #[prost(string, tag = "9")]
+    pub min_price_tick_size: ::prost::alloc::string::String,
+    /// min_quantity_tick_size defines the minimum tick size of the quantity
+    /// required for orders in the market
+    #[prost(string, tag = "10")]
+    pub min_quantity_tick_size: ::prost::alloc::string::String,
+    /// min_notional defines the minimum notional (in quote asset) required for
+    /// orders in the market
+    #[prost(string, tag = "11")]
+    pub min_notional: ::prost::alloc::string::String,
+}
+/// An object describing a derivative market in the Injective Futures Protocol.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DerivativeMarket {
+    /// Ticker for the derivative contract.
+    #[prost(string, tag = "1")]
+    pub ticker: ::prost::alloc::string::String,
+    /// Oracle base currency
+    #[prost(string, tag = "2")]
+    pub oracle_base: ::prost::alloc::string::String,
+    /// Oracle quote currency
+    #[prost(string, tag = "3")]
+    pub oracle_quote: ::prost::alloc::string::String,
+    /// Oracle type
+    #[prost(int32, tag = "4")]
+    pub oracle_type: i32,
+    /// Scale factor for oracle prices.
+    #[prost(uint32, tag = "5")]
+    pub oracle_scale_factor: u32,
+    /// Address of the quote currency denomination for the derivative contract
+    #[prost(string, tag = "6")]
+    pub quote_denom: ::prost::alloc::string::String,
+    /// Unique market ID.
+    #[prost(string, tag = "7")]
+    pub market_id: ::prost::alloc::string::String,
+    /// initial_margin_ratio defines the initial margin ratio of a derivative
+    /// market
+    #[prost(string, tag = "8")]
+    pub initial_margin_ratio: ::prost::alloc::string::String,
+    /// maintenance_margin_ratio defines the maintenance margin ratio of a
+    /// derivative market
+    #[prost(string, tag = "9")]
+    pub maintenance_margin_ratio: ::prost::alloc::string::String,
+    /// maker_fee_rate defines the maker fee rate of a derivative market
+    #[prost(string, tag = "10")]
+    pub maker_fee_rate: ::prost::alloc::string::String,
+    /// taker_fee_rate defines the taker fee rate of a derivative market
+    #[prost(string, tag = "11")]
+    pub taker_fee_rate: ::prost::alloc::string::String,
+    /// relayer_fee_share_rate defines the percentage of the transaction fee shared
+    /// with the relayer in a derivative market
+    #[prost(string, tag = "12")]
+    pub relayer_fee_share_rate: ::prost::alloc::string::String,
+    /// true if the market is a perpetual market. false if the market is an expiry
+    /// futures market
+    #[prost(bool, tag = "13")]
+    pub is_perpetual: bool,
+    /// Status of the market
+    #[prost(enumeration = "MarketStatus", tag = "14")]
+    pub status: i32,
+    /// min_price_tick_size defines the minimum tick size that the price and margin
+    /// required for orders in the market
+    #[prost(string, tag = "15")]
+    pub min_price_tick_size: ::prost::alloc::string::String,
+    /// min_quantity_tick_size defines the minimum tick size of the quantity
+    /// required for orders in the market
+    #[prost(string, tag = "16")]
+    pub min_quantity_tick_size: ::prost::alloc::string::String,
+    /// min_notional defines the minimum notional (in quote asset) required for
+    /// orders in the market
+    #[prost(string, tag = "17")]
+    pub min_notional: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FullDerivativeMarket {
+    #[prost(message, optional, tag = "1")]
+    pub market: ::core::option::Option<DerivativeMarket>,
+    #[prost(string, tag = "4")]
+    pub mark_price: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderInfo {
+    /// bytes32 subaccount ID that created the order
+    #[prost(string, tag = "1")]
+    pub subaccount_id: ::prost::alloc::string::String,
+    /// address fee_recipient address that will receive fees for the order
+    #[prost(string, tag = "2")]
+    pub fee_recipient: ::prost::alloc::string::String,
+    /// price of the order
+    #[prost(string, tag = "3")]
+    pub price: ::prost::alloc::string::String,
+    /// quantity of the order
+    #[prost(string, tag = "4")]
+    pub quantity: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub cid: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpotOrder {
+    /// market_id represents the unique ID of the market
+    #[prost(string, tag = "1")]
+    pub market_id: ::prost::alloc::string::String,
+    /// order_info contains the information of the order
+    #[prost(message, optional, tag = "2")]
+    pub order_info: ::core::option::Option<OrderInfo>,
+    /// order types
+    #[prost(enumeration = "OrderType", tag = "3")]
+    pub order_type: i32,
+    /// trigger_price is the trigger price used by stop/take orders
+    #[prost(string, tag = "4")]
+    pub trigger_price: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MarketStatus {
+    Unspecified = 0,
+    Active = 1,
+    Paused = 2,
+    Demolished = 3,
+    Expired = 4,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OrderType {
+    Unspecified = 0,
+    Buy = 1,
+    Sell = 2,
+    StopBuy = 3,
+    StopSell = 4,
+    TakeBuy = 5,
+    TakeSell = 6,
+    BuyPo = 7,
+    SellPo = 8,
+    BuyAtomic = 9,
+    SellAtomic = 10,
+}
+/// MsgCreateSpotLimitOrder defines a SDK message for creating a new spot limit
+/// order.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgCreateSpotLimitOrder {
+    #[prost(string, tag = "1")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub order: ::core::option::Option<SpotOrder>,
+}
+/// MsgCreateSpotLimitOrderResponse defines the Msg/CreateSpotOrder response
+/// type.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgCreateSpotLimitOrderResponse {
+    #[prost(string, tag = "1")]
+    pub order_hash: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub cid: ::prost::alloc::string::String,
+}
+/// MsgCancelSpotOrder defines the Msg/CancelSpotOrder response type.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgCancelSpotOrder {
+    #[prost(string, tag = "1")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub market_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub subaccount_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub order_hash: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub cid: ::prost::alloc::string::String,
+}
+/// MsgCancelSpotOrderResponse defines the Msg/CancelSpotOrder response type.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgCancelSpotOrderResponse {}
+/// QuerySpotMarketsRequest is the request type for the Query/SpotMarkets RPC
+/// method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuerySpotMarketsRequest {
+    /// Status of the market, for convenience it is set to string - not enum
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    /// Filter by market IDs
+    #[prost(string, repeated, tag = "2")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// QuerySpotMarketsResponse is the response type for the Query/SpotMarkets RPC
+/// method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuerySpotMarketsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub markets: ::prost::alloc::vec::Vec<SpotMarket>,
+}
+/// QueryDerivativeMarketsRequest is the request type for the
+/// Query/DerivativeMarkets RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryDerivativeMarketsRequest {
+    /// Status of the market, for convenience it is set to string - not enum
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    /// Filter by market IDs
+    #[prost(string, repeated, tag = "2")]
+    pub market_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Flag to return the markets mid price and top of the book
+    #[prost(bool, tag = "3")]
+    pub with_mid_price_and_tob: bool,
+}
+/// QueryDerivativeMarketsResponse is the response type for the
+/// Query/DerivativeMarkets RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryDerivativeMarketsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub markets: ::prost::alloc::vec::Vec<FullDerivativeMarket>,
+}
+/// QuerySubaccountDepositsRequest is the request type for the
+/// Query/SubaccountDeposits RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuerySubaccountDepositsRequest {
+    #[prost(string, tag = "1")]
+    pub subaccount_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuerySubaccountDepositsResponse {
+    #[prost(map = "string, message", tag = "1")]
+    pub deposits: ::std::collections::HashMap<::prost::alloc::string::String, Deposit>,
+}
+/// Generated client implementations.
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Retrieves a list of spot markets.
+        pub async fn spot_markets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QuerySpotMarketsRequest>,
+        ) -> Result<tonic::Response<super::QuerySpotMarketsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/injective.exchange.v1beta1.Query/SpotMarkets",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Retrieves a list of derivative markets.
+        pub async fn derivative_markets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryDerivativeMarketsRequest>,
+        ) -> Result<tonic::Response<super::QueryDerivativeMarketsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/injective.exchange.v1beta1.Query/DerivativeMarkets",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Retrieves a subaccount's deposits
+        pub async fn subaccount_deposits(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QuerySubaccountDepositsRequest>,
+        ) -> Result<tonic::Response<super::QuerySubaccountDepositsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/injective.exchange.v1beta1.Query/SubaccountDeposits",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Price feeds from the Injective oracle module.
+//!
+//! These are the same prices the exchange module uses for derivative markets,
+//! see [super::exchange::DerivativeMarket::oracle_type].
+use cosmwasm_std::Decimal;
+use tonic::async_trait;
+
+use crate::{
+    client::{node::Node, query::GrpcRequest},
+    error::Action,
+    osmosis::parse_osmosis_dec,
+    Cosmos, Error,
+};
+
+mod proto;
+
+pub(crate) use proto::{query_client, QueryOraclePriceRequest, QueryOraclePriceResponse};
+pub use proto::{OracleType, PricePairState};
+
+impl Cosmos {
+    /// Get the full price state for a base/quote pair from the given oracle.
+    ///
+    /// Prices in the returned state are 18 decimal fixed point integers.
+    ///
+    /// Note that this query will fail if called on chains besides Injective.
+    pub async fn get_injective_oracle_price_state(
+        &self,
+        oracle_type: OracleType,
+        base: impl Into<String>,
+        quote: impl Into<String>,
+    ) -> Result<PricePairState, Error> {
+        let base = base.into();
+        let quote = quote.into();
+        let action = Action::InjectiveOraclePrice {
+            oracle_type: format!("{oracle_type:?}"),
+            base: base.clone(),
+            quote: quote.clone(),
+        };
+        self.perform_query(
+            QueryOraclePriceRequest {
+                oracle_type: oracle_type.into(),
+                base,
+                quote,
+            },
+            action.clone(),
+        )
+        .run()
+        .await?
+        .into_inner()
+        .price_pair_state
+        .ok_or_else(|| Error::InvalidChainResponse {
+            message: "Missing price_pair_state field".to_owned(),
+            action: action.into(),
+        })
+    }
+
+    /// Get the price of base in terms of quote from the given oracle.
+    ///
+    /// Note that this query will fail if called on chains besides Injective.
+    pub async fn get_injective_oracle_price(
+        &self,
+        oracle_type: OracleType,
+        base: impl Into<String>,
+        quote: impl Into<String>,
+    ) -> Result<Decimal, Error> {
+        let base = base.into();
+        let quote = quote.into();
+        let state = self
+            .get_injective_oracle_price_state(oracle_type, base.clone(), quote.clone())
+            .await?;
+        parse_osmosis_dec(
+            &state.pair_price,
+            Action::InjectiveOraclePrice {
+                oracle_type: format!("{oracle_type:?}"),
+                base,
+                quote,
+            },
+        )
+    }
+}
+
+#[async_trait]
+impl GrpcRequest for QueryOraclePriceRequest {
+    type Response = QueryOraclePriceResponse;
+
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner
+            .injective_oracle_query_client()
+            .oracle_price(req)
+            .await
+    }
+}
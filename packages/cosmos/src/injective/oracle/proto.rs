@@ -0,0 +1,127 @@
+#![allow(missing_docs)]
+//////////// GENERATED, COPY/PASTED, AND PATCHED FROM PROST-BUILD ////////////////
+// Source: InjectiveLabs/injective-core v1.13.0, proto/injective/oracle/v1beta1
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PricePairState {
+    #[prost(string, tag = "1")]
+    pub pair_price: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub base_price: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub quote_price: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub base_cumulative_price: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub quote_cumulative_price: ::prost::alloc::string::String,
+    #[prost(int64, tag = "6")]
+    pub base_timestamp: i64,
+    #[prost(int64, tag = "7")]
+    pub quote_timestamp: i64,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OracleType {
+    Unspecified = 0,
+    Band = 1,
+    PriceFeed = 2,
+    Coinbase = 3,
+    Chainlink = 4,
+    Razor = 5,
+    Dia = 6,
+    Api3 = 7,
+    Uma = 8,
+    Pyth = 9,
+    BandIbc = 10,
+    Provider = 11,
+    Stork = 12,
+}
+/// QueryOraclePriceRequest defines the request type for the Query/OraclePrice
+/// RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryOraclePriceRequest {
+    #[prost(enumeration = "OracleType", tag = "1")]
+    pub oracle_type: i32,
+    #[prost(string, tag = "2")]
+    pub base: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub quote: ::prost::alloc::string::String,
+}
+/// QueryOraclePriceResponse defines the response type for the Query/OraclePrice
+/// RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryOraclePriceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub price_pair_state: ::core::option::Option<PricePairState>,
+}
+/// Generated client implementations.
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Retrieves the oracle price for a given base and quote
+        pub async fn oracle_price(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryOraclePriceRequest>,
+        ) -> Result<tonic::Response<super::QueryOraclePriceResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/injective.oracle.v1beta1.Query/OraclePrice");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
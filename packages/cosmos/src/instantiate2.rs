@@ -0,0 +1,123 @@
+use cosmos_sdk_proto::cosmwasm::wasm::v1::MsgInstantiateContract2;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    address::HasAddressHrp, error::Action, Address, CodeId, Coin, Contract, ContractAdmin,
+    HasAddress, RawAddress, TxBuilder, TxResponseExt, Wallet,
+};
+
+/// Predict the address of a contract instantiated with `MsgInstantiateContract2`.
+///
+/// This implements the wasmd instantiate2 algorithm for the default case
+/// where `fix_msg` is false. `checksum` is the SHA-256 hash of the WASM code,
+/// and the returned address uses the creator's HRP.
+pub fn predict_address(checksum: &[u8], creator: impl HasAddress, salt: &[u8]) -> Address {
+    let creator = creator.get_address();
+    RawAddress::from(instantiate2_raw(
+        checksum,
+        creator.raw().as_ref(),
+        salt,
+        &[],
+    ))
+    .with_hrp(creator.get_address_hrp())
+}
+
+fn instantiate2_raw(checksum: &[u8], creator: &[u8], salt: &[u8], msg: &[u8]) -> [u8; 32] {
+    // ADR-028 module address: sha256(sha256("module") || "wasm\0" || key),
+    // where key is each component prefixed with its big-endian u64 length.
+    let mut hasher = Sha256::new();
+    hasher.update(Sha256::digest(b"module"));
+    hasher.update(b"wasm\0");
+    for component in [checksum, creator, salt, msg] {
+        hasher.update((component.len() as u64).to_be_bytes());
+        hasher.update(component);
+    }
+    hasher.finalize().into()
+}
+
+impl CodeId {
+    /// Get the SHA-256 checksum of this code, as used for instantiate2 addresses.
+    pub async fn checksum(&self) -> Result<Vec<u8>, crate::Error> {
+        self.info().await.map(|info| info.data_hash)
+    }
+
+    /// Predict the address [Self::instantiate2] will produce for the given creator and salt.
+    pub async fn predict_address(
+        &self,
+        creator: impl HasAddress,
+        salt: &[u8],
+    ) -> Result<Address, crate::Error> {
+        Ok(predict_address(&self.checksum().await?, creator, salt))
+    }
+
+    /// Instantiate a new contract at a predictable address.
+    ///
+    /// The salt must be between 1 and 64 bytes. Use [Self::predict_address]
+    /// to find the resulting address before broadcasting.
+    pub async fn instantiate2(
+        &self,
+        wallet: &Wallet,
+        label: impl Into<String>,
+        funds: Vec<Coin>,
+        msg: impl serde::Serialize,
+        admin: ContractAdmin,
+        salt: impl Into<Vec<u8>>,
+    ) -> Result<Contract, crate::Error> {
+        let msg = MsgInstantiateContract2 {
+            sender: wallet.get_address_string(),
+            admin: match admin {
+                ContractAdmin::NoAdmin => "".to_owned(),
+                ContractAdmin::Sender => wallet.get_address_string(),
+                ContractAdmin::Addr(addr) => addr.get_address_string(),
+            },
+            code_id: self.code_id,
+            label: label.into(),
+            msg: serde_json::to_vec(&msg)?,
+            funds,
+            salt: salt.into(),
+            fix_msg: false,
+        };
+        let mut txbuilder = TxBuilder::default();
+        txbuilder.add_message(msg);
+        let res = txbuilder.sign_and_broadcast(&self.client, wallet).await?;
+        let addr =
+            res.parse_first_instantiated_contract()
+                .map_err(|source| crate::Error::ChainParse {
+                    source: source.into(),
+                    action: Action::InstantiateContract {
+                        txbuilder,
+                        txhash: res.txhash.clone(),
+                    }
+                    .into(),
+                })?;
+        Ok(self.client.make_contract(addr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::CanonicalAddr;
+
+    use super::*;
+
+    #[test]
+    fn matches_cosmwasm_std() {
+        let checksum =
+            hex::decode("13a1fc994cc6d1c81b746ee0c0ff6f90043875e0bf1d9be6b7d779fc978dc2a5")
+                .unwrap();
+        let creator: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        for salt in [&b"a"[..], &b"deployment-1"[..], &[0xff; 64][..]] {
+            let expected = cosmwasm_std::instantiate2_address(
+                &checksum,
+                &CanonicalAddr::from(creator.raw().as_ref()),
+                salt,
+            )
+            .unwrap();
+            let actual = predict_address(&checksum, creator, salt);
+            assert_eq!(actual.raw().as_ref(), expected.as_slice());
+            assert_eq!(actual.get_address_hrp(), creator.get_address_hrp());
+        }
+    }
+}
@@ -1,40 +1,98 @@
 #![deny(missing_docs)]
 //! Library for communicating with Cosmos blockchains over gRPC
 pub use address::{Address, AddressHrp, HasAddress, HasAddressHrp, PublicKeyMethod, RawAddress};
-pub use client::{BlockInfo, Cosmos, CosmosTxResponse, HasCosmos};
-pub use codeid::CodeId;
+pub use block_results::BlockResults;
+pub use build_info::{build_info, BuildInfo};
+pub use client::{
+    BlockInfo, BroadcastRawOptions, Cosmos, CosmosTxResponse, FullSimulateResponse, HasCosmos,
+    NodeInfo, RebroadcastTarget, TransactionsPage,
+};
+pub use codeid::{CodeId, InstantiatePermission, WasmVerification};
 #[cfg(feature = "config")]
-pub use config::{CosmosConfig, CosmosConfigError};
-pub use contract::{Contract, ContractAdmin, HasContract};
+pub use config::{
+    CosmosConfig, CosmosConfigError, WalletConfig, CONFIG_ENV_PREFIX, LOCAL_CONFIG_FILE,
+};
+pub use contract::{
+    Contract, ContractAdmin, ContractVersion, HasContract, InstantiatePlan, InstantiateWarning,
+    UpgradeProgress,
+};
+pub use contract_history::{code_changes, AdminChange, CodeChange, HistoryEntry};
 pub use cosmos_builder::CosmosBuilder;
 pub use cosmos_network::CosmosNetwork;
 pub use cosmos_sdk_proto as proto;
 pub use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+pub use cw20::{Cw20Contract, Cw20TokenInfo};
+pub use cw721::Cw721Contract;
+pub use eip712::{Eip712Config, Eip712Flavor, TypedData, TypedDataField};
 pub use error::Error;
-pub use ext::TxResponseExt;
-pub use gas_multiplier::DynamicGasMultiplier;
+pub use ext::{normalize_event_attributes, CustomEvent, NormalizedEvent, TxResponseExt};
+pub use fee_budget::FeeBudget;
+pub use gas_multiplier::{DynamicGasMultiplier, DynamicGasState, GasOutcome};
+pub use gas_statistics::GasStatistics;
+pub use instantiate2::predict_address;
 pub use parsed_coin::ParsedCoin;
-pub use tokenfactory::TokenFactory;
-pub use txbuilder::{TxBuilder, TxMessage};
-pub use wallet::{SeedPhrase, Wallet};
+#[cfg(feature = "deterministic-tests")]
+pub use rng::{clear_rng_seed, seed_rng};
+pub use sequence_store::{InMemorySequenceStore, SequenceKind, SequenceRecord, SequenceStore};
+pub use signer::Signer;
+pub use state_snapshot::{ContractStateSnapshot, StateEntry};
+pub use tokenfactory::{DenomAuthorityMetadata, TokenFactory};
+pub use tx_events::{
+    InstantiateEvent, MessageEvent, TransferEvent, WasmEvent, WriteAcknowledgementEvent,
+};
+pub use tx_pipeline::TxPipeline;
+pub use txbuilder::{BroadcastMode, TxBuilder, TxMessage};
+pub use validator_set::ValidatorSet;
+pub use wallet::{
+    verify_arbitrary, verify_signature, ArbitrarySignature, DiscoveredAccount, MultiChainWallet,
+    SeedPhrase, Wallet, WalletPublicKey, WatchWallet,
+};
+pub use wasm_authority::{module_address, WasmAuthority};
 
 mod address;
 mod authz;
+mod bank;
+mod block_results;
+mod build_info;
 mod client;
 mod codeid;
 #[cfg(feature = "config")]
 mod config;
 mod contract;
+mod contract_history;
 mod cosmos_builder;
 mod cosmos_network;
+mod cw20;
+mod cw721;
+mod distribution;
+mod eip712;
 mod ext;
+mod fee_budget;
+mod feegrant;
+mod feemarket;
 mod gas_multiplier;
-mod injective;
+mod gas_statistics;
+mod gov;
+mod ica;
+mod instantiate2;
+mod mempool;
+#[cfg(feature = "keyring")]
+mod os_keyring;
+mod params;
 mod parsed_coin;
-mod rujira;
+mod rng;
+mod sequence_store;
+mod signer;
+mod simulation_cache;
+mod staking;
+mod state_snapshot;
 mod tokenfactory;
+mod tx_events;
+mod tx_pipeline;
 mod txbuilder;
+mod validator_set;
 mod wallet;
+mod wasm_authority;
 
 #[cfg(feature = "clap")]
 pub mod clap;
@@ -42,8 +100,12 @@ pub mod clap;
 pub mod error;
 
 pub mod gas_price;
+pub mod ibc;
+pub mod injective;
 pub mod messages;
 pub mod osmosis;
+pub mod remote_signer;
+pub mod rujira;
 
 /// A result type with our error type provided as the default.
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -0,0 +1,122 @@
+use base64::Engine;
+use cosmos_sdk_proto::cosmos::tx::v1beta1::Tx;
+use prost::Message;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use crate::{error::Action, Cosmos};
+
+/// How many mempool entries to request from the `unconfirmed_txs` endpoint.
+///
+/// CometBFT caps this at 100 by default.
+const UNCONFIRMED_TXS_LIMIT: u32 = 100;
+
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: RpcUnconfirmedTxs,
+}
+
+#[derive(Deserialize)]
+struct RpcUnconfirmedTxs {
+    txs: Option<Vec<String>>,
+}
+
+impl RpcUnconfirmedTxs {
+    /// Find the transaction with the given hash, comparing case-insensitively.
+    fn find(self, txhash: &str, action: &Action) -> Result<Option<Tx>, crate::Error> {
+        for encoded in self.txs.unwrap_or_default() {
+            let invalid = |message: String| crate::Error::InvalidChainResponse {
+                message,
+                action: action.clone().into(),
+            };
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(&encoded)
+                .map_err(|e| invalid(format!("Invalid base64 mempool transaction: {e}")))?;
+            if !hex::encode_upper(Sha256::digest(&bytes)).eq_ignore_ascii_case(txhash) {
+                continue;
+            }
+            // TxRaw and Tx share field numbers, so the raw bytes decode as a Tx
+            return Tx::decode(bytes.as_slice())
+                .map(Some)
+                .map_err(|e| invalid(format!("Invalid mempool transaction {txhash}: {e}")));
+        }
+        Ok(None)
+    }
+}
+
+impl Cosmos {
+    /// Look up a transaction which is waiting in the mempool.
+    ///
+    /// Returns [None] if the transaction is not among the first 100 entries of
+    /// the mempool, e.g. because it already landed. This is not available
+    /// over gRPC, so it requires a CometBFT RPC endpoint to be configured with
+    /// [crate::CosmosBuilder::set_rpc_url].
+    pub async fn get_mempool_transaction(
+        &self,
+        txhash: impl Into<String>,
+    ) -> Result<Option<Tx>, crate::Error> {
+        let txhash = txhash.into();
+        let action = Action::GetMempoolTransaction(txhash.clone());
+        let rpc_url =
+            self.get_cosmos_builder()
+                .rpc_url()
+                .ok_or_else(|| crate::Error::NoRpcUrl {
+                    action: action.clone().into(),
+                })?;
+        let url = format!(
+            "{}/unconfirmed_txs?limit={UNCONFIRMED_TXS_LIMIT}",
+            rpc_url.trim_end_matches('/')
+        );
+        let make_error = |source| crate::Error::RpcRequest {
+            url: url.clone(),
+            source,
+            action: action.clone().into(),
+        };
+        let res: RpcResponse = reqwest::Client::new()
+            .get(&url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(make_error)?
+            .json()
+            .await
+            .map_err(make_error)?;
+        res.result.find(&txhash, &action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmos_sdk_proto::cosmos::tx::v1beta1::{TxBody, TxRaw};
+
+    use super::*;
+
+    #[test]
+    fn find_by_hash() {
+        let tx_bytes = TxRaw {
+            body_bytes: TxBody {
+                memo: "stuck".to_owned(),
+                ..Default::default()
+            }
+            .encode_to_vec(),
+            auth_info_bytes: vec![],
+            signatures: vec![vec![1, 2, 3]],
+        }
+        .encode_to_vec();
+        let txhash = hex::encode(Sha256::digest(&tx_bytes));
+        let json = format!(
+            r#"{{"result": {{"n_txs": "2", "txs": ["{}", "{}"]}}}}"#,
+            base64::engine::general_purpose::STANDARD.encode(b"other"),
+            base64::engine::general_purpose::STANDARD.encode(&tx_bytes)
+        );
+        let action = Action::GetMempoolTransaction(txhash.clone());
+
+        let res: RpcResponse = serde_json::from_str(&json).unwrap();
+        let tx = res.result.find(&txhash, &action).unwrap().unwrap();
+        assert_eq!(tx.body.unwrap().memo, "stuck");
+        assert_eq!(tx.signatures, vec![vec![1, 2, 3]]);
+
+        let res: RpcResponse = serde_json::from_str(r#"{"result": {"txs": null}}"#).unwrap();
+        assert!(res.result.find(&txhash, &action).unwrap().is_none());
+    }
+}
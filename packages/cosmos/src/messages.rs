@@ -1,22 +1,42 @@
 //! Message types provided directly by this library (instead of from the protobufs).
 
-use std::{fmt::Display, path::PathBuf};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    path::PathBuf,
+    sync::Arc,
+};
 
 use chrono::{DateTime, Utc};
 use cosmos_sdk_proto::{
     cosmos::{
-        authz::v1beta1::{GenericAuthorization, Grant, MsgExec, MsgGrant},
-        bank::v1beta1::MsgSend,
+        authz::v1beta1::{GenericAuthorization, Grant, MsgExec, MsgGrant, MsgRevoke},
+        bank::v1beta1::{Input, MsgMultiSend, MsgSend, Output, SendAuthorization},
         base::v1beta1::Coin,
+        distribution::v1beta1::MsgWithdrawDelegatorReward,
+        feegrant::v1beta1::{BasicAllowance, MsgGrantAllowance, PeriodicAllowance},
+        staking::v1beta1::{
+            stake_authorization, AuthorizationType, MsgBeginRedelegate, MsgDelegate, MsgUndelegate,
+            StakeAuthorization,
+        },
+        tx::v1beta1::TxBody,
     },
     cosmwasm::wasm::v1::{
-        MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract, MsgStoreCode,
-        MsgUpdateAdmin,
+        AccessType, MsgClearAdmin, MsgExecuteContract, MsgInstantiateContract,
+        MsgInstantiateContract2, MsgMigrateContract, MsgStoreAndInstantiateContract, MsgStoreCode,
+        MsgSudoContract, MsgUpdateAdmin, MsgUpdateInstantiateConfig,
     },
     traits::Message,
 };
 
-use crate::{error::StringOrBytes, Address, HasAddress, TxMessage};
+use crate::{
+    error::{ParsedCoinError, StringOrBytes},
+    Address, HasAddress, TxMessage,
+};
+
+pub mod ibc;
+
+pub use ibc::{IbcHeight, MsgTransfer};
 
 /// A local version of [MsgExec] with extra information for nice error messages.
 pub struct MsgExecHelper {
@@ -55,11 +75,77 @@ pub struct MsgGrantHelper {
     /// Address receiving permissions
     pub grantee: Address,
     /// Which features are being authorized
-    pub authorization: String,
+    pub authorization: Authorization,
     /// When the authorization expires
     pub expiration: Option<DateTime<Utc>>,
 }
 
+impl MsgGrantHelper {
+    /// Grant unrestricted use of a single message type, e.g. `/cosmos.bank.v1beta1.MsgSend`.
+    ///
+    /// The grant does not expire unless [Self::with_expiration] or [Self::expires_in] is used.
+    pub fn generic(
+        granter: impl HasAddress,
+        grantee: impl HasAddress,
+        msg_type_url: impl Into<String>,
+    ) -> Self {
+        MsgGrantHelper {
+            granter: granter.get_address(),
+            grantee: grantee.get_address(),
+            authorization: Authorization::Generic {
+                msg_type_url: msg_type_url.into(),
+            },
+            expiration: None,
+        }
+    }
+
+    /// Set the time at which the grant expires.
+    pub fn with_expiration(mut self, expiration: DateTime<Utc>) -> Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    /// Expire the grant after the given duration, starting from now.
+    pub fn expires_in(self, duration: chrono::Duration) -> Self {
+        self.with_expiration(Utc::now() + duration)
+    }
+}
+
+/// What a grantee may do with a grant from [MsgGrantHelper].
+#[derive(Clone, Debug)]
+pub enum Authorization {
+    /// `/cosmos.authz.v1beta1.GenericAuthorization`
+    Generic {
+        /// Type URL of the message the grantee may execute
+        msg_type_url: String,
+    },
+    /// `/cosmos.bank.v1beta1.SendAuthorization`
+    Send {
+        /// Total amount which may be sent
+        spend_limit: Vec<Coin>,
+        /// Only allow sending to these addresses, or any address if empty
+        allow_list: Vec<Address>,
+    },
+    /// `/cosmos.staking.v1beta1.StakeAuthorization`
+    Stake {
+        /// Which staking message is being authorized
+        authorization_type: AuthorizationType,
+        /// Maximum amount which may be staked, or unlimited if [None]
+        max_tokens: Option<Coin>,
+        /// Restrictions on which validators may be used, if any
+        validators: Option<StakeValidators>,
+    },
+}
+
+/// Validator restrictions for [Authorization::Stake].
+#[derive(Clone, Debug)]
+pub enum StakeValidators {
+    /// Only these validators may be used
+    AllowList(Vec<Address>),
+    /// Any validator except these may be used
+    DenyList(Vec<Address>),
+}
+
 impl From<MsgGrantHelper> for TxMessage {
     fn from(
         MsgGrantHelper {
@@ -69,13 +155,72 @@ impl From<MsgGrantHelper> for TxMessage {
             expiration,
         }: MsgGrantHelper,
     ) -> Self {
-        let desc = format!(
-            "{granter} grants {grantee} authorization for {authorization} until {expiration:?}"
-        );
-        let authorization = GenericAuthorization { msg: authorization };
-        let authorization = cosmos_sdk_proto::Any {
-            type_url: "/cosmos.authz.v1beta1.GenericAuthorization".to_owned(),
-            value: authorization.encode_to_vec(),
+        let (authorization, desc) = match authorization {
+            Authorization::Generic { msg_type_url } => {
+                let desc = format!(
+                    "{granter} grants {grantee} authorization for {msg_type_url} until {expiration:?}"
+                );
+                let authorization = cosmos_sdk_proto::Any {
+                    type_url: "/cosmos.authz.v1beta1.GenericAuthorization".to_owned(),
+                    value: GenericAuthorization { msg: msg_type_url }.encode_to_vec(),
+                };
+                (authorization, desc)
+            }
+            Authorization::Send {
+                spend_limit,
+                allow_list,
+            } => {
+                let desc = format!(
+                    "{granter} grants {grantee} authorization to send {} until {expiration:?}",
+                    PrettyCoins(&spend_limit)
+                );
+                let authorization = cosmos_sdk_proto::Any {
+                    type_url: "/cosmos.bank.v1beta1.SendAuthorization".to_owned(),
+                    value: SendAuthorization {
+                        spend_limit,
+                        allow_list: allow_list
+                            .iter()
+                            .map(HasAddress::get_address_string)
+                            .collect(),
+                    }
+                    .encode_to_vec(),
+                };
+                (authorization, desc)
+            }
+            Authorization::Stake {
+                authorization_type,
+                max_tokens,
+                validators,
+            } => {
+                let desc = format!(
+                    "{granter} grants {grantee} authorization for {} up to {} until {expiration:?}",
+                    authorization_type.as_str_name(),
+                    SpendLimit(max_tokens.as_slice())
+                );
+                let to_strings = |addresses: Vec<Address>| stake_authorization::Validators {
+                    address: addresses
+                        .iter()
+                        .map(HasAddress::get_address_string)
+                        .collect(),
+                };
+                let authorization = cosmos_sdk_proto::Any {
+                    type_url: "/cosmos.staking.v1beta1.StakeAuthorization".to_owned(),
+                    value: StakeAuthorization {
+                        max_tokens,
+                        authorization_type: authorization_type.into(),
+                        validators: validators.map(|validators| match validators {
+                            StakeValidators::AllowList(addresses) => {
+                                stake_authorization::Policy::AllowList(to_strings(addresses))
+                            }
+                            StakeValidators::DenyList(addresses) => {
+                                stake_authorization::Policy::DenyList(to_strings(addresses))
+                            }
+                        }),
+                    }
+                    .encode_to_vec(),
+                };
+                (authorization, desc)
+            }
         };
         let msg_grant = MsgGrant {
             granter: granter.get_address_string(),
@@ -86,14 +231,128 @@ impl From<MsgGrantHelper> for TxMessage {
             }),
         };
         TxMessage::new(
-            "/cosmos.authz.v1beta1.GenericAuthorization",
+            "/cosmos.authz.v1beta1.MsgGrant",
             msg_grant.encode_to_vec(),
             desc,
         )
     }
 }
 
-fn datetime_to_timestamp(x: DateTime<Utc>) -> cosmos_sdk_proto::Timestamp {
+/// A message for paying another address's transaction fees, using x/feegrant.
+pub struct MsgGrantAllowanceHelper {
+    /// Address paying the fees
+    pub granter: Address,
+    /// Address whose fees are paid, by setting [crate::TxBuilder::set_fee_granter]
+    pub grantee: Address,
+    /// How much may be spent on fees
+    pub allowance: FeeAllowance,
+    /// When the allowance expires
+    pub expiration: Option<DateTime<Utc>>,
+}
+
+/// Limits on a fee allowance granted with [MsgGrantAllowanceHelper].
+#[derive(Clone, Debug)]
+pub enum FeeAllowance {
+    /// `/cosmos.feegrant.v1beta1.BasicAllowance`
+    Basic {
+        /// Total amount which may be spent, or unlimited if empty
+        spend_limit: Vec<Coin>,
+    },
+    /// `/cosmos.feegrant.v1beta1.PeriodicAllowance`
+    Periodic {
+        /// Total amount which may be spent, or unlimited if empty
+        spend_limit: Vec<Coin>,
+        /// Length of each period
+        period: std::time::Duration,
+        /// Amount which may be spent within each period
+        period_spend_limit: Vec<Coin>,
+    },
+}
+
+impl From<MsgGrantAllowanceHelper> for TxMessage {
+    fn from(
+        MsgGrantAllowanceHelper {
+            granter,
+            grantee,
+            allowance,
+            expiration,
+        }: MsgGrantAllowanceHelper,
+    ) -> Self {
+        let expiration = expiration.map(datetime_to_timestamp);
+        let (allowance, desc) = match allowance {
+            FeeAllowance::Basic { spend_limit } => {
+                let desc = format!(
+                    "{granter} grants {grantee} a fee allowance of {} until {expiration:?}",
+                    SpendLimit(&spend_limit)
+                );
+                let allowance = cosmos_sdk_proto::Any {
+                    type_url: "/cosmos.feegrant.v1beta1.BasicAllowance".to_owned(),
+                    value: BasicAllowance {
+                        spend_limit,
+                        expiration,
+                    }
+                    .encode_to_vec(),
+                };
+                (allowance, desc)
+            }
+            FeeAllowance::Periodic {
+                spend_limit,
+                period,
+                period_spend_limit,
+            } => {
+                let desc = format!(
+                    "{granter} grants {grantee} a fee allowance of {} every {period:?}, {} in total, until {expiration:?}",
+                    PrettyCoins(&period_spend_limit),
+                    SpendLimit(&spend_limit)
+                );
+                let allowance = cosmos_sdk_proto::Any {
+                    type_url: "/cosmos.feegrant.v1beta1.PeriodicAllowance".to_owned(),
+                    value: PeriodicAllowance {
+                        basic: Some(BasicAllowance {
+                            spend_limit,
+                            expiration,
+                        }),
+                        period: Some(tendermint_proto::google::protobuf::Duration {
+                            seconds: period.as_secs().try_into().unwrap_or(i64::MAX),
+                            nanos: period
+                                .subsec_nanos()
+                                .try_into()
+                                .expect("Duration's nanos is too large"),
+                        }),
+                        period_can_spend: period_spend_limit.clone(),
+                        period_spend_limit,
+                        period_reset: None,
+                    }
+                    .encode_to_vec(),
+                };
+                (allowance, desc)
+            }
+        };
+        TxMessage::new(
+            "/cosmos.feegrant.v1beta1.MsgGrantAllowance",
+            MsgGrantAllowance {
+                granter: granter.get_address_string(),
+                grantee: grantee.get_address_string(),
+                allowance: Some(allowance),
+            }
+            .encode_to_vec(),
+            desc,
+        )
+    }
+}
+
+struct SpendLimit<'a>(&'a [Coin]);
+impl Display for SpendLimit<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.0.is_empty() {
+            f.write_str("unlimited")
+        } else {
+            PrettyCoins(self.0).fmt(f)
+        }
+    }
+}
+
+pub(crate) fn datetime_to_timestamp(x: DateTime<Utc>) -> cosmos_sdk_proto::Timestamp {
     cosmos_sdk_proto::Timestamp {
         seconds: x.timestamp(),
         nanos: x
@@ -137,6 +396,21 @@ impl From<MsgStoreCodeHelper> for TxMessage {
     }
 }
 
+impl From<MsgStoreAndInstantiateContract> for TxMessage {
+    fn from(msg: MsgStoreAndInstantiateContract) -> Self {
+        TxMessage::new(
+            "/cosmwasm.wasm.v1.MsgStoreAndInstantiateContract",
+            msg.encode_to_vec(),
+            format!(
+                "{} storing and instantiating WASM code with label {} and message: {}",
+                msg.authority,
+                msg.label,
+                StringOrBytes(msg.msg)
+            ),
+        )
+    }
+}
+
 impl From<MsgInstantiateContract> for TxMessage {
     fn from(msg: MsgInstantiateContract) -> Self {
         TxMessage::new(
@@ -153,6 +427,45 @@ impl From<MsgInstantiateContract> for TxMessage {
     }
 }
 
+impl From<MsgInstantiateContract2> for TxMessage {
+    fn from(msg: MsgInstantiateContract2) -> Self {
+        TxMessage::new(
+            "/cosmwasm.wasm.v1.MsgInstantiateContract2",
+            msg.encode_to_vec(),
+            format!(
+                "{} instantiating code ID {} with label {}, salt {} and message: {}",
+                msg.sender,
+                msg.code_id,
+                msg.label,
+                hex::encode(&msg.salt),
+                StringOrBytes(msg.msg)
+            ),
+        )
+    }
+}
+
+impl From<MsgUpdateInstantiateConfig> for TxMessage {
+    fn from(msg: MsgUpdateInstantiateConfig) -> Self {
+        let permission = msg
+            .new_instantiate_permission
+            .as_ref()
+            .map(|config| {
+                let access_type = AccessType::try_from(config.permission)
+                    .map_or("unknown", |access_type| access_type.as_str_name());
+                format!("{access_type} {:?}", config.addresses)
+            })
+            .unwrap_or_else(|| "unchanged".to_owned());
+        TxMessage::new(
+            "/cosmwasm.wasm.v1.MsgUpdateInstantiateConfig",
+            msg.encode_to_vec(),
+            format!(
+                "{} updating instantiate permission of code ID {} to {permission}",
+                msg.sender, msg.code_id
+            ),
+        )
+    }
+}
+
 impl From<MsgMigrateContract> for TxMessage {
     fn from(msg: MsgMigrateContract) -> Self {
         TxMessage::new(
@@ -169,6 +482,21 @@ impl From<MsgMigrateContract> for TxMessage {
     }
 }
 
+impl From<MsgSudoContract> for TxMessage {
+    fn from(msg: MsgSudoContract) -> Self {
+        TxMessage::new(
+            "/cosmwasm.wasm.v1.MsgSudoContract",
+            msg.encode_to_vec(),
+            format!(
+                "{} calling sudo on contract {} with message: {}",
+                msg.authority,
+                msg.contract,
+                StringOrBytes(msg.msg)
+            ),
+        )
+    }
+}
+
 impl From<MsgExecuteContract> for TxMessage {
     fn from(msg: MsgExecuteContract) -> Self {
         TxMessage::new(
@@ -252,7 +580,94 @@ impl From<MsgSend> for TxMessage {
     }
 }
 
-struct PrettyCoins<'a>(&'a [Coin]);
+impl From<MsgMultiSend> for TxMessage {
+    fn from(msg: MsgMultiSend) -> Self {
+        let description = format!(
+            "{} sending to {} recipient(s): {}",
+            msg.inputs
+                .iter()
+                .map(|input| input.address.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            msg.outputs.len(),
+            msg.outputs
+                .iter()
+                .map(|output| format!("{} to {}", PrettyCoins(&output.coins), output.address))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+        TxMessage::new(
+            "/cosmos.bank.v1beta1.MsgMultiSend",
+            msg.encode_to_vec(),
+            description,
+        )
+    }
+}
+
+/// A [MsgMultiSend] paying many recipients from a single sender.
+///
+/// Recent Cosmos SDK versions only allow a single input, which must equal the
+/// sum of all outputs. This helper calculates that input for you.
+pub struct MsgMultiSendHelper {
+    /// Address the funds are sent from
+    pub sender: Address,
+    /// Recipients and the coins each receives
+    pub outputs: Vec<(Address, Vec<Coin>)>,
+}
+
+impl TryFrom<MsgMultiSendHelper> for MsgMultiSend {
+    type Error = ParsedCoinError;
+
+    fn try_from(
+        MsgMultiSendHelper { sender, outputs }: MsgMultiSendHelper,
+    ) -> Result<Self, Self::Error> {
+        let mut total = BTreeMap::<String, u128>::new();
+        for Coin { denom, amount } in outputs.iter().flat_map(|(_, coins)| coins) {
+            let amount =
+                amount
+                    .parse::<u128>()
+                    .map_err(|source| ParsedCoinError::InvalidAmount {
+                        input: format!("{amount}{denom}"),
+                        source,
+                    })?;
+            let entry = total.entry(denom.clone()).or_default();
+            *entry = entry
+                .checked_add(amount)
+                .ok_or_else(|| ParsedCoinError::AmountOverflow {
+                    denom: denom.clone(),
+                })?;
+        }
+        Ok(MsgMultiSend {
+            inputs: vec![Input {
+                address: sender.get_address_string(),
+                coins: total
+                    .into_iter()
+                    .map(|(denom, amount)| Coin {
+                        denom,
+                        amount: amount.to_string(),
+                    })
+                    .collect(),
+            }],
+            outputs: outputs
+                .into_iter()
+                .map(|(address, coins)| Output {
+                    address: address.get_address_string(),
+                    coins,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<MsgMultiSendHelper> for TxMessage {
+    type Error = ParsedCoinError;
+
+    fn try_from(helper: MsgMultiSendHelper) -> Result<Self, Self::Error> {
+        MsgMultiSend::try_from(helper).map(TxMessage::from)
+    }
+}
+
+pub(crate) struct PrettyCoins<'a>(pub(crate) &'a [Coin]);
 impl Display for PrettyCoins<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         for (idx, Coin { denom, amount }) in self.0.iter().enumerate() {
@@ -264,3 +679,449 @@ impl Display for PrettyCoins<'_> {
         Ok(())
     }
 }
+
+/// A message from a transaction body, decoded into its concrete type.
+///
+/// See [decode_any].
+#[derive(Clone, Debug)]
+pub enum DecodedMessage {
+    /// `/cosmos.bank.v1beta1.MsgSend`
+    Send(MsgSend),
+    /// `/cosmos.bank.v1beta1.MsgMultiSend`
+    MultiSend(MsgMultiSend),
+    /// `/cosmwasm.wasm.v1.MsgStoreCode`
+    StoreCode(MsgStoreCode),
+    /// `/cosmwasm.wasm.v1.MsgInstantiateContract`
+    InstantiateContract(MsgInstantiateContract),
+    /// `/cosmwasm.wasm.v1.MsgInstantiateContract2`
+    InstantiateContract2(MsgInstantiateContract2),
+    /// `/cosmwasm.wasm.v1.MsgExecuteContract`
+    ExecuteContract(MsgExecuteContract),
+    /// `/cosmwasm.wasm.v1.MsgMigrateContract`
+    MigrateContract(MsgMigrateContract),
+    /// `/cosmwasm.wasm.v1.MsgUpdateAdmin`
+    UpdateAdmin(MsgUpdateAdmin),
+    /// `/cosmwasm.wasm.v1.MsgClearAdmin`
+    ClearAdmin(MsgClearAdmin),
+    /// `/cosmos.staking.v1beta1.MsgDelegate`
+    Delegate(MsgDelegate),
+    /// `/cosmos.staking.v1beta1.MsgUndelegate`
+    Undelegate(MsgUndelegate),
+    /// `/cosmos.staking.v1beta1.MsgBeginRedelegate`
+    BeginRedelegate(MsgBeginRedelegate),
+    /// `/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward`
+    WithdrawDelegatorReward(MsgWithdrawDelegatorReward),
+    /// `/cosmos.gov.v1beta1.MsgVote`
+    VoteV1Beta1(cosmos_sdk_proto::cosmos::gov::v1beta1::MsgVote),
+    /// `/cosmos.gov.v1.MsgVote`
+    Vote(cosmos_sdk_proto::cosmos::gov::v1::MsgVote),
+    /// `/cosmos.authz.v1beta1.MsgExec`
+    Exec(MsgExec),
+    /// `/cosmos.authz.v1beta1.MsgGrant`
+    Grant(MsgGrant),
+    /// `/cosmos.authz.v1beta1.MsgRevoke`
+    Revoke(MsgRevoke),
+    /// `/ibc.applications.transfer.v1.MsgTransfer`
+    IbcTransfer(MsgTransfer),
+    /// A message type registered in a [MessageRegistry]
+    Custom(DecodedCustomMessage),
+    /// Any message type not known to this library
+    Raw(cosmos_sdk_proto::Any),
+}
+
+/// Decode a protobuf [cosmos_sdk_proto::Any] into a [DecodedMessage].
+///
+/// Unrecognized type URLs are returned as [DecodedMessage::Raw]. An error is
+/// only returned when a recognized type fails to decode.
+pub fn decode_any(any: &cosmos_sdk_proto::Any) -> Result<DecodedMessage, prost::DecodeError> {
+    let value = any.value.as_slice();
+    Ok(match any.type_url.as_str() {
+        "/cosmos.bank.v1beta1.MsgSend" => DecodedMessage::Send(MsgSend::decode(value)?),
+        "/cosmos.bank.v1beta1.MsgMultiSend" => {
+            DecodedMessage::MultiSend(MsgMultiSend::decode(value)?)
+        }
+        "/cosmwasm.wasm.v1.MsgStoreCode" => DecodedMessage::StoreCode(MsgStoreCode::decode(value)?),
+        "/cosmwasm.wasm.v1.MsgInstantiateContract" => {
+            DecodedMessage::InstantiateContract(MsgInstantiateContract::decode(value)?)
+        }
+        "/cosmwasm.wasm.v1.MsgInstantiateContract2" => {
+            DecodedMessage::InstantiateContract2(MsgInstantiateContract2::decode(value)?)
+        }
+        "/cosmwasm.wasm.v1.MsgExecuteContract" => {
+            DecodedMessage::ExecuteContract(MsgExecuteContract::decode(value)?)
+        }
+        "/cosmwasm.wasm.v1.MsgMigrateContract" => {
+            DecodedMessage::MigrateContract(MsgMigrateContract::decode(value)?)
+        }
+        "/cosmwasm.wasm.v1.MsgUpdateAdmin" => {
+            DecodedMessage::UpdateAdmin(MsgUpdateAdmin::decode(value)?)
+        }
+        "/cosmwasm.wasm.v1.MsgClearAdmin" => {
+            DecodedMessage::ClearAdmin(MsgClearAdmin::decode(value)?)
+        }
+        "/cosmos.staking.v1beta1.MsgDelegate" => {
+            DecodedMessage::Delegate(MsgDelegate::decode(value)?)
+        }
+        "/cosmos.staking.v1beta1.MsgUndelegate" => {
+            DecodedMessage::Undelegate(MsgUndelegate::decode(value)?)
+        }
+        "/cosmos.staking.v1beta1.MsgBeginRedelegate" => {
+            DecodedMessage::BeginRedelegate(MsgBeginRedelegate::decode(value)?)
+        }
+        "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward" => {
+            DecodedMessage::WithdrawDelegatorReward(MsgWithdrawDelegatorReward::decode(value)?)
+        }
+        "/cosmos.gov.v1beta1.MsgVote" => DecodedMessage::VoteV1Beta1(
+            cosmos_sdk_proto::cosmos::gov::v1beta1::MsgVote::decode(value)?,
+        ),
+        "/cosmos.gov.v1.MsgVote" => {
+            DecodedMessage::Vote(cosmos_sdk_proto::cosmos::gov::v1::MsgVote::decode(value)?)
+        }
+        "/cosmos.authz.v1beta1.MsgExec" => DecodedMessage::Exec(MsgExec::decode(value)?),
+        "/cosmos.authz.v1beta1.MsgGrant" => DecodedMessage::Grant(MsgGrant::decode(value)?),
+        "/cosmos.authz.v1beta1.MsgRevoke" => DecodedMessage::Revoke(MsgRevoke::decode(value)?),
+        "/ibc.applications.transfer.v1.MsgTransfer" => {
+            DecodedMessage::IbcTransfer(MsgTransfer::decode(value)?)
+        }
+        _ => DecodedMessage::Raw(any.clone()),
+    })
+}
+
+/// Decode all messages in a [TxBody], see [decode_any].
+pub fn decode_tx_body(body: &TxBody) -> Result<Vec<DecodedMessage>, prost::DecodeError> {
+    body.messages.iter().map(decode_any).collect()
+}
+
+/// Generate the [TxMessage] description for an encoded message.
+///
+/// Uses the same descriptions as the `From` impls where one exists, so that a
+/// description can be rebuilt from the protobuf value alone.
+pub(crate) fn describe_any(any: &cosmos_sdk_proto::Any) -> Result<String, prost::DecodeError> {
+    Ok(match decode_any(any)? {
+        DecodedMessage::Send(msg) => format!(
+            "{} sending {} to {}",
+            msg.from_address,
+            PrettyCoins(msg.amount.as_slice()),
+            msg.to_address,
+        ),
+        DecodedMessage::MultiSend(msg) => TxMessage::from(msg).into_protobuf().1,
+        DecodedMessage::InstantiateContract(msg) => TxMessage::from(msg).into_protobuf().1,
+        DecodedMessage::InstantiateContract2(msg) => TxMessage::from(msg).into_protobuf().1,
+        DecodedMessage::ExecuteContract(msg) => TxMessage::from(msg).into_protobuf().1,
+        DecodedMessage::MigrateContract(msg) => TxMessage::from(msg).into_protobuf().1,
+        DecodedMessage::UpdateAdmin(msg) => TxMessage::from(msg).into_protobuf().1,
+        DecodedMessage::Raw(any) => format!("{} ({} bytes)", any.type_url, any.value.len()),
+        decoded => format!("{}: {decoded:?}", any.type_url),
+    })
+}
+
+/// A chain-specific protobuf message not covered by [cosmos_sdk_proto].
+///
+/// Implement this for your own prost-generated types to include them in
+/// transactions with [TxMessage::from_custom] and decode them with a
+/// [MessageRegistry].
+pub trait CustomMessage: Message + Default + std::fmt::Debug + Send + Sync + 'static {
+    /// Protobuf type URL, e.g. `/mychain.mymodule.v1.MsgDoThing`.
+    const TYPE_URL: &'static str;
+
+    /// Human readable description used in error messages.
+    fn description(&self) -> String {
+        format!("{}: {self:?}", Self::TYPE_URL)
+    }
+}
+
+impl TxMessage {
+    /// Generate a [TxMessage] from a [CustomMessage].
+    pub fn from_custom<T: CustomMessage>(msg: &T) -> Self {
+        TxMessage::new(T::TYPE_URL, msg.encode_to_vec(), msg.description())
+    }
+}
+
+/// A [CustomMessage] decoded via a [MessageRegistry].
+#[derive(Clone)]
+pub struct DecodedCustomMessage {
+    type_url: &'static str,
+    description: String,
+    value: Arc<dyn std::any::Any + Send + Sync>,
+}
+
+impl DecodedCustomMessage {
+    /// The protobuf type URL of this message.
+    pub fn type_url(&self) -> &'static str {
+        self.type_url
+    }
+
+    /// Human readable description of this message.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Get the concrete message, if it is of type `T`.
+    pub fn downcast_ref<T: CustomMessage>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+}
+
+impl std::fmt::Debug for DecodedCustomMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.description)
+    }
+}
+
+type CustomDecoder =
+    fn(&[u8]) -> Result<(String, Arc<dyn std::any::Any + Send + Sync>), prost::DecodeError>;
+
+/// A registry of [CustomMessage] types, used for decoding messages beyond those known to [decode_any].
+///
+/// Cloning is cheap, registrations are shared between clones.
+#[derive(Clone, Default)]
+pub struct MessageRegistry {
+    decoders: Arc<parking_lot::RwLock<HashMap<&'static str, CustomDecoder>>>,
+}
+
+impl MessageRegistry {
+    /// Register the given message type.
+    ///
+    /// Registering a type URL already known to [decode_any] takes precedence
+    /// over the built-in decoding.
+    pub fn register<T: CustomMessage>(&self) -> &Self {
+        fn decode<T: CustomMessage>(
+            value: &[u8],
+        ) -> Result<(String, Arc<dyn std::any::Any + Send + Sync>), prost::DecodeError> {
+            let msg = T::decode(value)?;
+            Ok((msg.description(), Arc::new(msg)))
+        }
+        self.decoders.write().insert(T::TYPE_URL, decode::<T>);
+        self
+    }
+
+    /// Is the given type URL registered?
+    pub fn contains(&self, type_url: &str) -> bool {
+        self.decoders.read().contains_key(type_url)
+    }
+
+    /// Same as [decode_any], but checks the registered types first.
+    pub fn decode_any(
+        &self,
+        any: &cosmos_sdk_proto::Any,
+    ) -> Result<DecodedMessage, prost::DecodeError> {
+        let found = self
+            .decoders
+            .read()
+            .get_key_value(any.type_url.as_str())
+            .map(|(type_url, decoder)| (*type_url, *decoder));
+        match found {
+            Some((type_url, decoder)) => {
+                let (description, value) = decoder(&any.value)?;
+                Ok(DecodedMessage::Custom(DecodedCustomMessage {
+                    type_url,
+                    description,
+                    value,
+                }))
+            }
+            None => decode_any(any),
+        }
+    }
+
+    /// Same as [decode_tx_body], but checks the registered types first.
+    pub fn decode_tx_body(&self, body: &TxBody) -> Result<Vec<DecodedMessage>, prost::DecodeError> {
+        body.messages
+            .iter()
+            .map(|any| self.decode_any(any))
+            .collect()
+    }
+}
+
+impl std::fmt::Debug for MessageRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.decoders.read().keys()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_known_and_unknown() {
+        let send = MsgSend {
+            from_address: "osmo1from".to_owned(),
+            to_address: "osmo1to".to_owned(),
+            amount: vec![Coin {
+                denom: "uosmo".to_owned(),
+                amount: "5".to_owned(),
+            }],
+        };
+        let body = TxBody {
+            messages: vec![
+                cosmos_sdk_proto::Any {
+                    type_url: "/cosmos.bank.v1beta1.MsgSend".to_owned(),
+                    value: send.encode_to_vec(),
+                },
+                cosmos_sdk_proto::Any {
+                    type_url: "/some.custom.MsgFoo".to_owned(),
+                    value: vec![1, 2, 3],
+                },
+            ],
+            ..Default::default()
+        };
+        let decoded = decode_tx_body(&body).unwrap();
+        match &decoded[0] {
+            DecodedMessage::Send(x) => assert_eq!(x, &send),
+            x => panic!("Unexpected decoded message: {x:?}"),
+        }
+        match &decoded[1] {
+            DecodedMessage::Raw(any) => assert_eq!(any.type_url, "/some.custom.MsgFoo"),
+            x => panic!("Unexpected decoded message: {x:?}"),
+        }
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    struct MsgCustom {
+        #[prost(string, tag = "1")]
+        sender: String,
+        #[prost(uint64, tag = "2")]
+        amount: u64,
+    }
+
+    impl CustomMessage for MsgCustom {
+        const TYPE_URL: &'static str = "/mychain.custom.v1.MsgCustom";
+    }
+
+    #[test]
+    fn decode_custom() {
+        let msg = MsgCustom {
+            sender: "osmo1sender".to_owned(),
+            amount: 42,
+        };
+        let any = TxMessage::from_custom(&msg).get_protobuf();
+
+        assert!(matches!(decode_any(&any).unwrap(), DecodedMessage::Raw(_)));
+
+        let registry = MessageRegistry::default();
+        registry.register::<MsgCustom>();
+        match registry.decode_any(&any).unwrap() {
+            DecodedMessage::Custom(custom) => {
+                assert_eq!(custom.type_url(), MsgCustom::TYPE_URL);
+                assert_eq!(custom.downcast_ref::<MsgCustom>(), Some(&msg));
+            }
+            x => panic!("Unexpected decoded message: {x:?}"),
+        }
+    }
+
+    #[test]
+    fn decode_failure() {
+        let any = cosmos_sdk_proto::Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_owned(),
+            value: vec![0xff, 0xff, 0xff],
+        };
+        assert!(decode_any(&any).is_err());
+    }
+
+    #[test]
+    fn periodic_fee_allowance() {
+        let hrp = crate::AddressHrp::from_static("osmo");
+        let granter = crate::Wallet::generate(hrp).unwrap().get_address();
+        let grantee = crate::Wallet::generate(hrp).unwrap().get_address();
+        let limit = vec![Coin {
+            denom: "uosmo".to_owned(),
+            amount: "1000".to_owned(),
+        }];
+        let any = TxMessage::from(MsgGrantAllowanceHelper {
+            granter,
+            grantee,
+            allowance: FeeAllowance::Periodic {
+                spend_limit: vec![],
+                period: std::time::Duration::from_secs(86400),
+                period_spend_limit: limit.clone(),
+            },
+            expiration: None,
+        })
+        .get_protobuf();
+        let msg = MsgGrantAllowance::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.grantee, grantee.get_address_string());
+        let allowance = msg.allowance.unwrap();
+        assert_eq!(
+            allowance.type_url,
+            "/cosmos.feegrant.v1beta1.PeriodicAllowance"
+        );
+        let periodic = PeriodicAllowance::decode(allowance.value.as_slice()).unwrap();
+        assert_eq!(periodic.period.unwrap().seconds, 86400);
+        assert_eq!(periodic.period_spend_limit, limit);
+        assert_eq!(periodic.period_can_spend, limit);
+    }
+
+    #[test]
+    fn stake_authorization_grant() {
+        let hrp = crate::AddressHrp::from_static("osmo");
+        let granter = crate::Wallet::generate(hrp).unwrap().get_address();
+        let grantee = crate::Wallet::generate(hrp).unwrap().get_address();
+        let validator = crate::Wallet::generate(hrp).unwrap().get_address();
+        let tx_msg = TxMessage::from(MsgGrantHelper {
+            granter,
+            grantee,
+            authorization: Authorization::Stake {
+                authorization_type: AuthorizationType::Delegate,
+                max_tokens: None,
+                validators: Some(StakeValidators::AllowList(vec![validator])),
+            },
+            expiration: None,
+        });
+        let any = tx_msg.get_protobuf();
+        assert_eq!(any.type_url, "/cosmos.authz.v1beta1.MsgGrant");
+        let authorization = MsgGrant::decode(any.value.as_slice())
+            .unwrap()
+            .grant
+            .unwrap()
+            .authorization
+            .unwrap();
+        assert_eq!(
+            authorization.type_url,
+            "/cosmos.staking.v1beta1.StakeAuthorization"
+        );
+        let stake = StakeAuthorization::decode(authorization.value.as_slice()).unwrap();
+        assert_eq!(stake.authorization_type(), AuthorizationType::Delegate);
+        assert_eq!(
+            stake.validators,
+            Some(stake_authorization::Policy::AllowList(
+                stake_authorization::Validators {
+                    address: vec![validator.get_address_string()]
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn multi_send_totals() {
+        let hrp = crate::AddressHrp::from_static("osmo");
+        let sender = crate::Wallet::generate(hrp).unwrap().get_address();
+        let coin = |amount: &str, denom: &str| Coin {
+            denom: denom.to_owned(),
+            amount: amount.to_owned(),
+        };
+        let outputs = (0..3)
+            .map(|i| {
+                let recipient = crate::Wallet::generate(hrp).unwrap().get_address();
+                let mut coins = vec![coin("100", "uosmo")];
+                if i == 0 {
+                    coins.push(coin("5", "uatom"));
+                }
+                (recipient, coins)
+            })
+            .collect();
+        let msg = MsgMultiSend::try_from(MsgMultiSendHelper { sender, outputs }).unwrap();
+        assert_eq!(msg.inputs.len(), 1);
+        assert_eq!(msg.inputs[0].address, sender.get_address_string());
+        assert_eq!(
+            msg.inputs[0].coins,
+            vec![coin("5", "uatom"), coin("300", "uosmo")]
+        );
+        assert_eq!(msg.outputs.len(), 3);
+
+        let invalid = MsgMultiSendHelper {
+            sender,
+            outputs: vec![(sender, vec![coin("abc", "uosmo")])],
+        };
+        assert!(MsgMultiSend::try_from(invalid).is_err());
+    }
+}
@@ -0,0 +1,160 @@
+//! IBC message types, defined locally since the IBC protobufs are not included in [cosmos_sdk_proto].
+
+pub use crate::ibc::Height as IbcHeight;
+
+use chrono::{DateTime, Utc};
+use cosmos_sdk_proto::{cosmos::base::v1beta1::Coin, traits::Message};
+
+use crate::{HasAddress, TxMessage};
+
+use super::PrettyCoins;
+
+/// IBC fungible token transfer, `/ibc.applications.transfer.v1.MsgTransfer`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgTransfer {
+    /// The port on which the packet will be sent
+    #[prost(string, tag = "1")]
+    pub source_port: String,
+    /// The channel by which the packet will be sent
+    #[prost(string, tag = "2")]
+    pub source_channel: String,
+    /// The tokens to be transferred
+    #[prost(message, optional, tag = "3")]
+    pub token: Option<Coin>,
+    /// The sender address
+    #[prost(string, tag = "4")]
+    pub sender: String,
+    /// The recipient address on the destination chain
+    #[prost(string, tag = "5")]
+    pub receiver: String,
+    /// Timeout height relative to the current block height, 0 to disable
+    #[prost(message, optional, tag = "6")]
+    pub timeout_height: Option<IbcHeight>,
+    /// Timeout timestamp in absolute nanoseconds since unix epoch, 0 to disable
+    #[prost(uint64, tag = "7")]
+    pub timeout_timestamp: u64,
+    /// Optional memo
+    #[prost(string, tag = "8")]
+    pub memo: String,
+}
+
+impl MsgTransfer {
+    /// Transfer `token` over the `transfer` port of the given channel.
+    ///
+    /// No timeout is set, but the chain requires either a timeout height or
+    /// timestamp. Use [Self::with_timeout_after] for a timeout relative to now.
+    pub fn new(
+        source_channel: impl Into<String>,
+        token: Coin,
+        sender: impl HasAddress,
+        receiver: impl Into<String>,
+    ) -> Self {
+        MsgTransfer {
+            source_port: "transfer".to_owned(),
+            source_channel: source_channel.into(),
+            token: Some(token),
+            sender: sender.get_address_string(),
+            receiver: receiver.into(),
+            timeout_height: None,
+            timeout_timestamp: 0,
+            memo: String::new(),
+        }
+    }
+
+    /// Time out if the packet is not received before the destination chain reaches this height.
+    pub fn with_timeout_height(mut self, height: IbcHeight) -> Self {
+        self.timeout_height = Some(height);
+        self
+    }
+
+    /// Time out if the packet is not received before this time on the destination chain.
+    pub fn with_timeout_timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.timeout_timestamp = timestamp
+            .timestamp_nanos_opt()
+            .and_then(|nanos| nanos.try_into().ok())
+            .unwrap_or_default();
+        self
+    }
+
+    /// Time out if the packet is not received within the given duration from now.
+    pub fn with_timeout_after(self, duration: std::time::Duration) -> Self {
+        let timeout = chrono::Duration::from_std(duration)
+            .ok()
+            .and_then(|duration| Utc::now().checked_add_signed(duration))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        self.with_timeout_timestamp(timeout)
+    }
+
+    /// Set the memo, used for example by packet-forward-middleware and IBC hooks.
+    pub fn with_memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = memo.into();
+        self
+    }
+}
+
+impl From<MsgTransfer> for TxMessage {
+    fn from(msg: MsgTransfer) -> Self {
+        TxMessage::new(
+            "/ibc.applications.transfer.v1.MsgTransfer",
+            msg.encode_to_vec(),
+            format!(
+                "{} transferring {} to {} via {}/{}",
+                msg.sender,
+                PrettyCoins(msg.token.as_slice()),
+                msg.receiver,
+                msg.source_port,
+                msg.source_channel
+            ),
+        )
+    }
+}
+
+impl IbcHeight {
+    /// Construct a height from its revision number and height.
+    pub fn new(revision_number: u64, revision_height: u64) -> Self {
+        IbcHeight {
+            revision_number,
+            revision_height,
+        }
+    }
+
+    /// Construct a height on the chain with the given chain ID.
+    ///
+    /// The revision number is parsed from the chain ID following the IBC
+    /// convention, e.g. `osmosis-1` has revision number 1. Chain IDs without
+    /// a revision suffix use revision 0.
+    pub fn for_chain(chain_id: &str, revision_height: u64) -> Self {
+        IbcHeight::new(revision_number_from_chain_id(chain_id), revision_height)
+    }
+}
+
+fn revision_number_from_chain_id(chain_id: &str) -> u64 {
+    match chain_id.rsplit_once('-') {
+        Some((name, revision))
+            if !name.is_empty()
+                && !name.ends_with('-')
+                && !revision.starts_with('0')
+                && revision.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            revision.parse().unwrap_or_default()
+        }
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revision_numbers() {
+        assert_eq!(revision_number_from_chain_id("osmosis-1"), 1);
+        assert_eq!(revision_number_from_chain_id("cosmoshub-4"), 4);
+        assert_eq!(revision_number_from_chain_id("pacific-1"), 1);
+        assert_eq!(revision_number_from_chain_id("evmos_9001-2"), 2);
+        assert_eq!(revision_number_from_chain_id("localnet"), 0);
+        assert_eq!(revision_number_from_chain_id("chain-01"), 0);
+        assert_eq!(revision_number_from_chain_id("chain--1"), 0);
+        assert_eq!(revision_number_from_chain_id("chain-"), 0);
+    }
+}
@@ -0,0 +1,50 @@
+//! Storage of seed phrases in the operating system's keyring.
+//!
+//! Backed by the macOS Keychain, the Secret Service API on Linux, or the
+//! Windows Credential Manager.
+use std::sync::Arc;
+
+use crate::{error::WalletError, SeedPhrase};
+
+/// Service name used for all keyring entries created by this crate.
+const KEYRING_SERVICE: &str = "cosmos-rs";
+
+fn keyring_entry(name: &str) -> Result<keyring::Entry, WalletError> {
+    keyring::Entry::new(KEYRING_SERVICE, name).map_err(|source| keyring_error(name, source))
+}
+
+fn keyring_error(name: &str, source: keyring::Error) -> WalletError {
+    WalletError::Keyring {
+        name: name.to_owned(),
+        source: Arc::new(source),
+    }
+}
+
+impl SeedPhrase {
+    /// Store this seed phrase in the OS keyring under the given name.
+    ///
+    /// Any override derivation path is stored together with the phrase.
+    /// An existing entry with the same name is replaced.
+    pub fn store_in_keyring(&self, name: &str) -> Result<(), WalletError> {
+        keyring_entry(name)?
+            .set_password(&self.storage_string())
+            .map_err(|source| keyring_error(name, source))
+    }
+
+    /// Load a seed phrase previously stored with [SeedPhrase::store_in_keyring].
+    pub fn load_from_keyring(name: &str) -> Result<SeedPhrase, WalletError> {
+        let phrase = zeroize::Zeroizing::new(
+            keyring_entry(name)?
+                .get_password()
+                .map_err(|source| keyring_error(name, source))?,
+        );
+        phrase.parse()
+    }
+
+    /// Remove the named seed phrase from the OS keyring.
+    pub fn delete_from_keyring(name: &str) -> Result<(), WalletError> {
+        keyring_entry(name)?
+            .delete_credential()
+            .map_err(|source| keyring_error(name, source))
+    }
+}
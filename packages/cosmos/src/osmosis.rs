@@ -5,10 +5,13 @@ use crate::{
     client::WeakCosmos,
     cosmos_builder::ChainPausedMethod,
     error::{Action, ChainParseError, QueryError},
-    Cosmos, Error,
+    Coin, Cosmos, Error,
 };
 
+pub mod concentrated_liquidity;
 pub(crate) mod epochs;
+pub mod poolmanager;
+pub(crate) mod twap;
 pub(crate) mod txfees;
 
 use chrono::{DateTime, Utc};
@@ -16,7 +19,25 @@ use cosmwasm_std::Decimal;
 pub use epochs::EpochInfo;
 use parking_lot::RwLock;
 use prost_types::Timestamp;
-pub use txfees::QueryEipBaseFeeResponse;
+pub use txfees::{FeeToken, QueryEipBaseFeeResponse};
+
+/// Osmosis versions of the generated protobuf code in this module, see [crate::build_info()].
+///
+/// Keep in sync with the prost-build package.
+pub(crate) const PROTO_VERSIONS: &[(&str, &str)] = &[
+    ("osmosis-epochs", "5494ad8992810c7385ec8a63e5e9476adf332d4c"),
+    ("osmosis-txfees", "v22.0.0"),
+    ("osmosis-poolmanager", "v25.0.0"),
+    ("osmosis-twap", "v25.0.0"),
+    ("osmosis-concentrated-liquidity", "v25.0.0"),
+];
+
+/// Extra amount added when paying fees in a non-native denom, to cover spot
+/// price movement between the price query and the fee check.
+const FEE_TOKEN_PRICE_BUFFER_PERCENT: u64 = 105;
+
+/// How often to check whether a running epoch has finished.
+const EPOCH_END_POLL_INTERVAL: tokio::time::Duration = tokio::time::Duration::from_secs(5);
 
 impl Cosmos {
     /// Get the Osmosis epoch information.
@@ -30,6 +51,25 @@ impl Cosmos {
                 epochs: res.into_inner().epochs,
             })
     }
+
+    /// Wait until no Osmosis epoch is running.
+    ///
+    /// Returns immediately if there is no epoch in progress. Otherwise, polls
+    /// the epoch information until the next epoch start moves back into the
+    /// future, meaning the chain has finished processing the epoch and is
+    /// accepting transactions again.
+    ///
+    /// Note that this query will fail if called on chains besides Osmosis.
+    pub async fn wait_for_epoch_end(&self) -> Result<(), QueryError> {
+        loop {
+            match self.get_osmosis_epoch_info().await?.summarize().current {
+                CurrentEpochStatus::Active { .. } => {
+                    tokio::time::sleep(EPOCH_END_POLL_INTERVAL).await
+                }
+                CurrentEpochStatus::NoEpochs | CurrentEpochStatus::Inactive { .. } => break Ok(()),
+            }
+        }
+    }
     /// Get the Osmosis txfees information.
     ///
     /// Note that this query will fail if called on chains besides Osmosis Mainnet.
@@ -40,45 +80,227 @@ impl Cosmos {
             .await
             .map(|res| res.into_inner())?;
 
-        // The result is a string representation of the Dec type in Osmosis (well, LegacyDec which is an alias for cosmos-sdk Decimal)
-        // but while the string over REST queries to the LCD has the decimal point in the string, over gRPC it currently does not
-        // so we have to parse it as an integer and then convert it to a Decimal (18 decimal places)
-        // as a safety measure, also handle it if there is a decimal place, just in case that changes
-        let eip_base_fee = match eip_base_fee.base_fee.contains('.') {
-            true => eip_base_fee
-                .base_fee
-                .parse::<Decimal>()
-                .map_err(|err| Error::ChainParse {
-                    source: Box::new(ChainParseError::TxFees {
-                        err: err.to_string(),
-                    }),
-                    action: Action::OsmosisTxFeesInfo.into(),
-                })?,
-            false => {
-                let eip_base_fee =
-                    eip_base_fee
-                        .base_fee
-                        .parse::<u128>()
-                        .map_err(|err| Error::ChainParse {
-                            source: Box::new(ChainParseError::TxFees {
-                                err: err.to_string(),
-                            }),
-                            action: Action::OsmosisTxFeesInfo.into(),
-                        })?;
-
-                Decimal::from_atomics(eip_base_fee, 18).map_err(|err| Error::ChainParse {
-                    source: Box::new(ChainParseError::TxFees {
-                        err: err.to_string(),
-                    }),
-                    action: Action::OsmosisTxFeesInfo.into(),
-                })?
+        let eip_base_fee = parse_osmosis_dec(&eip_base_fee.base_fee, Action::OsmosisTxFeesInfo)?;
+
+        Ok(TxFeesInfo { eip_base_fee })
+    }
+
+    /// Get the tokens whitelisted for paying fees on Osmosis, besides the base denom.
+    ///
+    /// Note that this query will fail if called on chains besides Osmosis.
+    pub async fn get_osmosis_fee_tokens(&self) -> Result<Vec<FeeToken>, QueryError> {
+        self.perform_query(txfees::QueryFeeTokensRequest {}, Action::OsmosisFeeTokens)
+            .run()
+            .await
+            .map(|res| res.into_inner().fee_tokens)
+    }
+
+    /// Get the spot price of a whitelisted fee token, in units of the base denom.
+    ///
+    /// Note that this query will fail if called on chains besides Osmosis.
+    pub async fn get_osmosis_fee_token_price(&self, denom: &str) -> Result<Decimal, Error> {
+        let action = Action::OsmosisFeeTokenPrice(denom.to_owned());
+        let res = self
+            .perform_query(
+                txfees::QueryDenomSpotPriceRequest {
+                    denom: denom.to_owned(),
+                },
+                action.clone(),
+            )
+            .run()
+            .await?
+            .into_inner();
+        parse_osmosis_dec(&res.spot_price, action)
+    }
+
+    /// Convert a fee amount in the gas coin to the given fee denom.
+    ///
+    /// If the fee denom is not the gas coin, this uses the Osmosis txfees
+    /// module to convert the amount at the current spot price.
+    pub(crate) async fn make_fee_coin(
+        &self,
+        fee_denom: Option<&str>,
+        amount: u64,
+    ) -> Result<Coin, Error> {
+        let gas_coin = self.get_cosmos_builder().gas_coin();
+        let denom = match fee_denom {
+            Some(denom) if denom != gas_coin => denom,
+            _ => {
+                return Ok(Coin {
+                    denom: gas_coin.to_owned(),
+                    amount: amount.to_string(),
+                })
             }
         };
+        let price = self.get_osmosis_fee_token_price(denom).await?;
+        let converted = Decimal::from_ratio(amount, 1u8)
+            .checked_div(price)
+            .ok()
+            .and_then(|converted| {
+                converted
+                    .checked_mul(Decimal::percent(FEE_TOKEN_PRICE_BUFFER_PERCENT))
+                    .ok()
+            })
+            .ok_or_else(|| Error::ChainParse {
+                source: Box::new(ChainParseError::TxFees {
+                    err: format!(
+                        "Unable to convert {amount}{gas_coin} to {denom} at price {price}"
+                    ),
+                }),
+                action: Action::OsmosisFeeTokenPrice(denom.to_owned()).into(),
+            })?;
+        Ok(Coin {
+            denom: denom.to_owned(),
+            amount: converted.to_uint_ceil().to_string(),
+        })
+    }
 
-        Ok(TxFeesInfo { eip_base_fee })
+    /// Get the arithmetic time-weighted average price of `base_asset` in units of `quote_asset`.
+    ///
+    /// Averages over the given pool from `start` until `end`, or until the
+    /// latest block if `end` is [None].
+    ///
+    /// Note that this query will fail if called on chains besides Osmosis.
+    pub async fn get_osmosis_arithmetic_twap(
+        &self,
+        pool_id: u64,
+        base_asset: impl Into<String>,
+        quote_asset: impl Into<String>,
+        start: DateTime<Utc>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Decimal, Error> {
+        let base_asset = base_asset.into();
+        let quote_asset = quote_asset.into();
+        let action = Action::OsmosisTwap {
+            pool_id,
+            base: base_asset.clone(),
+            quote: quote_asset.clone(),
+            geometric: false,
+        };
+        let start_time = Some(twap_timestamp(start));
+        let twap = match end {
+            Some(end) => {
+                self.perform_query(
+                    twap::ArithmeticTwapRequest {
+                        pool_id,
+                        base_asset,
+                        quote_asset,
+                        start_time,
+                        end_time: Some(twap_timestamp(end)),
+                    },
+                    action.clone(),
+                )
+                .run()
+                .await?
+                .into_inner()
+                .arithmetic_twap
+            }
+            None => {
+                self.perform_query(
+                    twap::ArithmeticTwapToNowRequest {
+                        pool_id,
+                        base_asset,
+                        quote_asset,
+                        start_time,
+                    },
+                    action.clone(),
+                )
+                .run()
+                .await?
+                .into_inner()
+                .arithmetic_twap
+            }
+        };
+        parse_osmosis_dec(&twap, action)
+    }
+
+    /// Get the geometric time-weighted average price of `base_asset` in units of `quote_asset`.
+    ///
+    /// Same as [Self::get_osmosis_arithmetic_twap], but less sensitive to
+    /// short-lived price spikes.
+    ///
+    /// Note that this query will fail if called on chains besides Osmosis.
+    pub async fn get_osmosis_geometric_twap(
+        &self,
+        pool_id: u64,
+        base_asset: impl Into<String>,
+        quote_asset: impl Into<String>,
+        start: DateTime<Utc>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Decimal, Error> {
+        let base_asset = base_asset.into();
+        let quote_asset = quote_asset.into();
+        let action = Action::OsmosisTwap {
+            pool_id,
+            base: base_asset.clone(),
+            quote: quote_asset.clone(),
+            geometric: true,
+        };
+        let start_time = Some(twap_timestamp(start));
+        let twap = match end {
+            Some(end) => {
+                self.perform_query(
+                    twap::GeometricTwapRequest {
+                        pool_id,
+                        base_asset,
+                        quote_asset,
+                        start_time,
+                        end_time: Some(twap_timestamp(end)),
+                    },
+                    action.clone(),
+                )
+                .run()
+                .await?
+                .into_inner()
+                .geometric_twap
+            }
+            None => {
+                self.perform_query(
+                    twap::GeometricTwapToNowRequest {
+                        pool_id,
+                        base_asset,
+                        quote_asset,
+                        start_time,
+                    },
+                    action.clone(),
+                )
+                .run()
+                .await?
+                .into_inner()
+                .geometric_twap
+            }
+        };
+        parse_osmosis_dec(&twap, action)
     }
 }
 
+/// The generated twap code uses prost's [Timestamp] rather than the Cosmos SDK one.
+fn twap_timestamp(x: DateTime<Utc>) -> Timestamp {
+    let cosmos_sdk_proto::Timestamp { seconds, nanos } = crate::messages::datetime_to_timestamp(x);
+    Timestamp { seconds, nanos }
+}
+
+/// Parse an Osmosis Dec value.
+///
+/// The result is a string representation of the Dec type in Osmosis (well, LegacyDec which is an alias for cosmos-sdk Decimal)
+/// but while the string over REST queries to the LCD has the decimal point in the string, over gRPC it currently does not
+/// so we have to parse it as an integer and then convert it to a Decimal (18 decimal places)
+/// as a safety measure, also handle it if there is a decimal place, just in case that changes
+pub(crate) fn parse_osmosis_dec(value: &str, action: Action) -> Result<Decimal, Error> {
+    let res = if value.contains('.') {
+        value.parse::<Decimal>().map_err(|err| err.to_string())
+    } else {
+        value
+            .parse::<u128>()
+            .map_err(|err| err.to_string())
+            .and_then(|value| Decimal::from_atomics(value, 18).map_err(|err| err.to_string()))
+    };
+    res.map_err(|err| Error::ChainParse {
+        source: Box::new(ChainParseError::TxFees { err }),
+        action: action.into(),
+    })
+}
+
 /// Information from the txfees module for an Osmosis chain.
 #[derive(Debug)]
 pub struct TxFeesInfo {
@@ -164,6 +386,45 @@ pub enum CurrentEpochStatus {
     },
 }
 
+/// A change in Osmosis epoch status, see [EpochListener].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EpochEvent {
+    /// An epoch has started and the chain is paused.
+    Started {
+        /// When the epoch was scheduled to start
+        scheduled: DateTime<Utc>,
+    },
+    /// The epoch has finished and the chain is processing transactions again.
+    Ended {
+        /// When the next epoch is scheduled to start
+        next_start: Option<DateTime<Utc>>,
+    },
+}
+
+/// Receives notifications when Osmosis epochs start and end.
+///
+/// Set with [crate::CosmosBuilder::set_epoch_listener]. Notifications come
+/// from the background task which tracks [Cosmos::is_chain_paused], so they
+/// may arrive a few seconds after the actual transition.
+pub trait EpochListener: std::fmt::Debug + Send + Sync {
+    /// Called on each epoch transition.
+    fn on_epoch_event(&self, event: EpochEvent);
+}
+
+/// Determine which event, if any, to fire after updating the next epoch start.
+fn epoch_transition(
+    was_paused: bool,
+    next_start: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Option<EpochEvent> {
+    match next_start {
+        Some(scheduled) if scheduled <= now => {
+            (!was_paused).then_some(EpochEvent::Started { scheduled })
+        }
+        _ => was_paused.then_some(EpochEvent::Ended { next_start }),
+    }
+}
+
 #[derive(Clone)]
 pub(crate) enum ChainPausedStatus {
     NoPauseSupport,
@@ -235,9 +496,17 @@ impl Cosmos {
 
 impl WeakCosmos {
     async fn update_osmosis_paused(self, next_start: Arc<RwLock<Option<DateTime<Utc>>>>) {
+        let mut was_paused = false;
         while let Some(cosmos) = self.upgrade() {
             match cosmos.single_osmosis_update(&next_start).await {
                 Ok(to_sleep) => {
+                    let event = epoch_transition(was_paused, *next_start.read(), Utc::now());
+                    if let Some(event) = event {
+                        was_paused = matches!(event, EpochEvent::Started { .. });
+                        if let Some(listener) = cosmos.get_cosmos_builder().get_epoch_listener() {
+                            listener.on_epoch_event(event);
+                        }
+                    }
                     tokio::time::sleep(to_sleep).await;
                 }
                 Err(err) => {
@@ -248,3 +517,28 @@ impl WeakCosmos {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn epoch_transitions() {
+        let now = Utc::now();
+        let past = now - chrono::Duration::seconds(30);
+        let future = now + chrono::Duration::hours(1);
+        assert_eq!(epoch_transition(false, Some(future), now), None);
+        assert_eq!(
+            epoch_transition(false, Some(past), now),
+            Some(EpochEvent::Started { scheduled: past })
+        );
+        assert_eq!(epoch_transition(true, Some(past), now), None);
+        assert_eq!(
+            epoch_transition(true, Some(future), now),
+            Some(EpochEvent::Ended {
+                next_start: Some(future)
+            })
+        );
+        assert_eq!(epoch_transition(false, None, now), None);
+    }
+}
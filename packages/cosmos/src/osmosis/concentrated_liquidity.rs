@@ -0,0 +1,194 @@
+//! Positions and incentives in Osmosis concentrated liquidity pools.
+use cosmos_sdk_proto::traits::Message;
+use cosmwasm_std::{Decimal, Uint128};
+
+use crate::{client::next_page_request, error::Action, Coin, Cosmos, Error, HasAddress, TxMessage};
+
+mod proto;
+
+pub(crate) use proto::{
+    query_client, IncentiveRecordsRequest, IncentiveRecordsResponse, UserPositionsRequest,
+    UserPositionsResponse,
+};
+pub use proto::{
+    FullPositionBreakdown, IncentiveRecord, IncentiveRecordBody, MsgCreatePosition,
+    MsgCreatePositionResponse, MsgWithdrawPosition, MsgWithdrawPositionResponse, Position,
+};
+
+impl MsgCreatePosition {
+    /// Open a position between `lower_tick` and `upper_tick`.
+    ///
+    /// `tokens_provided` holds one coin for a single sided position, or both
+    /// pool assets for a position straddling the current tick. The minimum
+    /// amounts protect against price movement before the transaction lands.
+    pub fn new(
+        sender: impl HasAddress,
+        pool_id: u64,
+        lower_tick: i64,
+        upper_tick: i64,
+        tokens_provided: Vec<Coin>,
+        token_min_amount0: Uint128,
+        token_min_amount1: Uint128,
+    ) -> Self {
+        MsgCreatePosition {
+            pool_id,
+            sender: sender.get_address_string(),
+            lower_tick,
+            upper_tick,
+            tokens_provided,
+            token_min_amount0: token_min_amount0.to_string(),
+            token_min_amount1: token_min_amount1.to_string(),
+        }
+    }
+}
+
+impl MsgWithdrawPosition {
+    /// Withdraw `liquidity_amount` of liquidity from a position.
+    ///
+    /// Withdrawing all of a position's liquidity closes it, see the `liquidity` field of [Position].
+    pub fn new(sender: impl HasAddress, position_id: u64, liquidity_amount: Decimal) -> Self {
+        MsgWithdrawPosition {
+            position_id,
+            sender: sender.get_address_string(),
+            // Dec values are encoded as their atomics in protobuf
+            liquidity_amount: liquidity_amount.atomics().to_string(),
+        }
+    }
+}
+
+impl From<MsgCreatePosition> for TxMessage {
+    fn from(msg: MsgCreatePosition) -> Self {
+        TxMessage::new(
+            "/osmosis.concentratedliquidity.v1beta1.MsgCreatePosition",
+            msg.encode_to_vec(),
+            format!(
+                "{} creating position in pool {} between ticks {} and {} with {}",
+                msg.sender,
+                msg.pool_id,
+                msg.lower_tick,
+                msg.upper_tick,
+                msg.tokens_provided
+                    .iter()
+                    .map(|coin| format!("{}{}", coin.amount, coin.denom))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        )
+    }
+}
+
+impl From<MsgWithdrawPosition> for TxMessage {
+    fn from(msg: MsgWithdrawPosition) -> Self {
+        TxMessage::new(
+            "/osmosis.concentratedliquidity.v1beta1.MsgWithdrawPosition",
+            msg.encode_to_vec(),
+            format!(
+                "{} withdrawing liquidity from position {}",
+                msg.sender, msg.position_id
+            ),
+        )
+    }
+}
+
+impl Cosmos {
+    /// Get all concentrated liquidity positions owned by the given address.
+    ///
+    /// Pass a pool ID to only return positions in that pool.
+    ///
+    /// Note that this query will fail if called on chains besides Osmosis.
+    pub async fn get_osmosis_cl_positions(
+        &self,
+        owner: impl HasAddress,
+        pool_id: Option<u64>,
+    ) -> Result<Vec<FullPositionBreakdown>, Error> {
+        let owner = owner.get_address();
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let page = self
+                .perform_query(
+                    UserPositionsRequest {
+                        address: owner.get_address_string(),
+                        pool_id: pool_id.unwrap_or_default(),
+                        pagination: pagination.take(),
+                    },
+                    Action::OsmosisClPositions(owner),
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.extend(page.positions);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get the incentives currently being distributed in a concentrated liquidity pool.
+    ///
+    /// Note that this query will fail if called on chains besides Osmosis.
+    pub async fn get_osmosis_cl_incentive_records(
+        &self,
+        pool_id: u64,
+    ) -> Result<Vec<IncentiveRecord>, Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let page = self
+                .perform_query(
+                    IncentiveRecordsRequest {
+                        pool_id,
+                        pagination: pagination.take(),
+                    },
+                    Action::OsmosisClIncentiveRecords(pool_id),
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.extend(page.incentive_records);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+
+    #[test]
+    fn position_messages() {
+        let sender: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        let msg = MsgCreatePosition::new(
+            sender,
+            1066,
+            -100,
+            100,
+            vec![Coin {
+                denom: "uosmo".to_owned(),
+                amount: "1000".to_owned(),
+            }],
+            Uint128::new(900),
+            Uint128::zero(),
+        );
+        let decoded = MsgCreatePosition::decode(msg.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, msg);
+        assert_eq!(
+            TxMessage::from(msg).type_url(),
+            "/osmosis.concentratedliquidity.v1beta1.MsgCreatePosition"
+        );
+
+        let msg = MsgWithdrawPosition::new(sender, 7, "1.5".parse().unwrap());
+        assert_eq!(msg.liquidity_amount, "1500000000000000000");
+        assert_eq!(
+            TxMessage::from(msg).type_url(),
+            "/osmosis.concentratedliquidity.v1beta1.MsgWithdrawPosition"
+        );
+    }
+}
@@ -0,0 +1,259 @@
+#![allow(missing_docs)]
+///////////// AUTOGENERATED FILE VIA TONIC-BUILD (DO NOT MODIFY) //////////////////////////////
+
+/// Position contains position's id, address, pool id, lower tick, upper tick
+/// join time, and liquidity.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Position {
+    #[prost(uint64, tag = "1")]
+    pub position_id: u64,
+    #[prost(string, tag = "2")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub pool_id: u64,
+    #[prost(int64, tag = "4")]
+    pub lower_tick: i64,
+    #[prost(int64, tag = "5")]
+    pub upper_tick: i64,
+    #[prost(message, optional, tag = "6")]
+    pub join_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "7")]
+    pub liquidity: ::prost::alloc::string::String,
+}
+/// FullPositionBreakdown returns:
+/// - the position itself
+/// - the amount the position translates in terms of asset0 and asset1
+/// - the amount of claimable fees
+/// - the amount of claimable incentives
+/// - the amount of incentives that would be forfeited if the position was closed
+///   now
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FullPositionBreakdown {
+    #[prost(message, optional, tag = "1")]
+    pub position: ::core::option::Option<Position>,
+    #[prost(message, optional, tag = "2")]
+    pub asset0: ::core::option::Option<::cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
+    #[prost(message, optional, tag = "3")]
+    pub asset1: ::core::option::Option<::cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
+    #[prost(message, repeated, tag = "4")]
+    pub claimable_spread_rewards:
+        ::prost::alloc::vec::Vec<::cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
+    #[prost(message, repeated, tag = "5")]
+    pub claimable_incentives:
+        ::prost::alloc::vec::Vec<::cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
+    #[prost(message, repeated, tag = "6")]
+    pub forfeited_incentives:
+        ::prost::alloc::vec::Vec<::cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
+}
+/// IncentiveRecord is the high-level struct we use to deal with an independent
+/// incentive being distributed on a pool. Note that PoolId, Denom, and MinUptime
+/// are included in the key so we avoid storing them in state, hence the
+/// distinction between IncentiveRecord and IncentiveRecordBody.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IncentiveRecord {
+    /// incentive_id is the id uniquely identifying this incentive record.
+    #[prost(uint64, tag = "1")]
+    pub incentive_id: u64,
+    #[prost(uint64, tag = "2")]
+    pub pool_id: u64,
+    /// incentive record body holds necessary
+    #[prost(message, optional, tag = "4")]
+    pub incentive_record_body: ::core::option::Option<IncentiveRecordBody>,
+    /// min_uptime is the minimum uptime required for liquidity to qualify for this
+    /// incentive. It should be always be one of the supported uptimes in
+    /// types.SupportedUptimes
+    #[prost(message, optional, tag = "5")]
+    pub min_uptime: ::core::option::Option<::prost_types::Duration>,
+}
+/// IncentiveRecordBody represents the body stored in state for each individual
+/// record.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IncentiveRecordBody {
+    /// remaining_coin is the total amount of incentives to be distributed
+    #[prost(message, optional, tag = "1")]
+    pub remaining_coin: ::core::option::Option<::cosmos_sdk_proto::cosmos::base::v1beta1::DecCoin>,
+    /// emission_rate is the incentive emission rate per second
+    #[prost(string, tag = "2")]
+    pub emission_rate: ::prost::alloc::string::String,
+    /// start_time is the time when the incentive starts distributing
+    #[prost(message, optional, tag = "3")]
+    pub start_time: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// ===================== MsgCreatePosition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgCreatePosition {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+    #[prost(string, tag = "2")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub lower_tick: i64,
+    #[prost(int64, tag = "4")]
+    pub upper_tick: i64,
+    /// tokens_provided is the amount of tokens provided for the position.
+    /// It must at a minimum be of length 1 (for a single sided position)
+    /// and at a maximum be of length 2 (for a position that straddles the current
+    /// tick).
+    #[prost(message, repeated, tag = "5")]
+    pub tokens_provided: ::prost::alloc::vec::Vec<::cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
+    #[prost(string, tag = "6")]
+    pub token_min_amount0: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub token_min_amount1: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgCreatePositionResponse {
+    #[prost(uint64, tag = "1")]
+    pub position_id: u64,
+    #[prost(string, tag = "2")]
+    pub amount0: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub amount1: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub liquidity_created: ::prost::alloc::string::String,
+    /// the lower and upper tick are in the response because there are
+    /// instances in which multiple ticks represent the same price, so
+    /// we may move their provided tick to the canonical tick that represents
+    /// the same price.
+    #[prost(int64, tag = "6")]
+    pub lower_tick: i64,
+    #[prost(int64, tag = "7")]
+    pub upper_tick: i64,
+}
+/// ===================== MsgWithdrawPosition
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgWithdrawPosition {
+    #[prost(uint64, tag = "1")]
+    pub position_id: u64,
+    #[prost(string, tag = "2")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub liquidity_amount: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgWithdrawPositionResponse {
+    #[prost(string, tag = "1")]
+    pub amount0: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub amount1: ::prost::alloc::string::String,
+}
+/// =============================== UserPositions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UserPositionsRequest {
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub pool_id: u64,
+    #[prost(message, optional, tag = "3")]
+    pub pagination:
+        ::core::option::Option<::cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UserPositionsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub positions: ::prost::alloc::vec::Vec<FullPositionBreakdown>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination:
+        ::core::option::Option<::cosmos_sdk_proto::cosmos::base::query::v1beta1::PageResponse>,
+}
+/// =============================== IncentiveRecords
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IncentiveRecordsRequest {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+    #[prost(message, optional, tag = "2")]
+    pub pagination:
+        ::core::option::Option<::cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IncentiveRecordsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub incentive_records: ::prost::alloc::vec::Vec<IncentiveRecord>,
+    /// pagination defines the pagination in the response.
+    #[prost(message, optional, tag = "2")]
+    pub pagination:
+        ::core::option::Option<::cosmos_sdk_proto::cosmos::base::query::v1beta1::PageResponse>,
+}
+/// Generated client implementations.
+pub mod query_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct QueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl QueryClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> QueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        /// UserPositions returns all concentrated positions of some address.
+        pub async fn user_positions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UserPositionsRequest>,
+        ) -> Result<tonic::Response<super::UserPositionsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.concentratedliquidity.v1beta1.Query/UserPositions",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// IncentiveRecords returns the incentive records for a given poolId
+        pub async fn incentive_records(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IncentiveRecordsRequest>,
+        ) -> Result<tonic::Response<super::IncentiveRecordsResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/osmosis.concentratedliquidity.v1beta1.Query/IncentiveRecords",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
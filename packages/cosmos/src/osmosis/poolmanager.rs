@@ -0,0 +1,224 @@
+//! Swaps and price queries using the Osmosis poolmanager module.
+use cosmos_sdk_proto::traits::Message;
+use cosmwasm_std::{Decimal, Uint128};
+
+use crate::{error::Action, Coin, Cosmos, Error, HasAddress, TxMessage};
+
+mod proto;
+
+pub(crate) use proto::{
+    query_client, EstimateSwapExactAmountInRequest, EstimateSwapExactAmountInResponse,
+    EstimateSwapExactAmountOutRequest, EstimateSwapExactAmountOutResponse, SpotPriceRequest,
+    SpotPriceResponse,
+};
+pub use proto::{
+    MsgSwapExactAmountIn, MsgSwapExactAmountInResponse, MsgSwapExactAmountOut,
+    MsgSwapExactAmountOutResponse, SwapAmountInRoute, SwapAmountOutRoute,
+};
+
+impl SwapAmountInRoute {
+    /// Swap through the given pool, receiving `token_out_denom`.
+    pub fn new(pool_id: u64, token_out_denom: impl Into<String>) -> Self {
+        SwapAmountInRoute {
+            pool_id,
+            token_out_denom: token_out_denom.into(),
+        }
+    }
+}
+
+impl SwapAmountOutRoute {
+    /// Swap through the given pool, paying with `token_in_denom`.
+    pub fn new(pool_id: u64, token_in_denom: impl Into<String>) -> Self {
+        SwapAmountOutRoute {
+            pool_id,
+            token_in_denom: token_in_denom.into(),
+        }
+    }
+}
+
+impl MsgSwapExactAmountIn {
+    /// Swap exactly `token_in` along `routes`, failing if less than `token_out_min_amount` is received.
+    pub fn new(
+        sender: impl HasAddress,
+        routes: Vec<SwapAmountInRoute>,
+        token_in: Coin,
+        token_out_min_amount: Uint128,
+    ) -> Self {
+        MsgSwapExactAmountIn {
+            sender: sender.get_address_string(),
+            routes,
+            token_in: Some(token_in),
+            token_out_min_amount: token_out_min_amount.to_string(),
+        }
+    }
+}
+
+impl MsgSwapExactAmountOut {
+    /// Receive exactly `token_out` via `routes`, failing if more than `token_in_max_amount` is needed.
+    pub fn new(
+        sender: impl HasAddress,
+        routes: Vec<SwapAmountOutRoute>,
+        token_in_max_amount: Uint128,
+        token_out: Coin,
+    ) -> Self {
+        MsgSwapExactAmountOut {
+            sender: sender.get_address_string(),
+            routes,
+            token_in_max_amount: token_in_max_amount.to_string(),
+            token_out: Some(token_out),
+        }
+    }
+}
+
+impl From<MsgSwapExactAmountIn> for TxMessage {
+    fn from(msg: MsgSwapExactAmountIn) -> Self {
+        TxMessage::new(
+            "/osmosis.poolmanager.v1beta1.MsgSwapExactAmountIn",
+            msg.encode_to_vec(),
+            format!(
+                "{} swapping exactly {} via pools {:?} for at least {}",
+                msg.sender,
+                msg.token_in
+                    .as_ref()
+                    .map_or_else(String::new, |coin| format!("{}{}", coin.amount, coin.denom)),
+                msg.routes
+                    .iter()
+                    .map(|route| route.pool_id)
+                    .collect::<Vec<_>>(),
+                msg.token_out_min_amount
+            ),
+        )
+    }
+}
+
+impl From<MsgSwapExactAmountOut> for TxMessage {
+    fn from(msg: MsgSwapExactAmountOut) -> Self {
+        TxMessage::new(
+            "/osmosis.poolmanager.v1beta1.MsgSwapExactAmountOut",
+            msg.encode_to_vec(),
+            format!(
+                "{} swapping at most {} via pools {:?} for exactly {}",
+                msg.sender,
+                msg.token_in_max_amount,
+                msg.routes
+                    .iter()
+                    .map(|route| route.pool_id)
+                    .collect::<Vec<_>>(),
+                msg.token_out
+                    .as_ref()
+                    .map_or_else(String::new, |coin| format!("{}{}", coin.amount, coin.denom)),
+            ),
+        )
+    }
+}
+
+impl Cosmos {
+    /// Get the spot price of `base_asset_denom` in terms of `quote_asset_denom` in the given pool.
+    ///
+    /// Note that this query will fail if called on chains besides Osmosis.
+    pub async fn get_osmosis_spot_price(
+        &self,
+        pool_id: u64,
+        base_asset_denom: impl Into<String>,
+        quote_asset_denom: impl Into<String>,
+    ) -> Result<Decimal, Error> {
+        let req = SpotPriceRequest {
+            pool_id,
+            base_asset_denom: base_asset_denom.into(),
+            quote_asset_denom: quote_asset_denom.into(),
+        };
+        let action = Action::OsmosisSpotPrice {
+            pool_id,
+            base: req.base_asset_denom.clone(),
+            quote: req.quote_asset_denom.clone(),
+        };
+        let res = self
+            .perform_query(req, action.clone())
+            .run()
+            .await?
+            .into_inner();
+        super::parse_osmosis_dec(&res.spot_price, action)
+    }
+
+    /// Estimate the amount received when swapping exactly `token_in` along `routes`.
+    ///
+    /// Note that this query will fail if called on chains besides Osmosis.
+    pub async fn estimate_osmosis_swap_exact_amount_in(
+        &self,
+        token_in: &Coin,
+        routes: Vec<SwapAmountInRoute>,
+    ) -> Result<Uint128, Error> {
+        let token_in = format!("{}{}", token_in.amount, token_in.denom);
+        let action = Action::OsmosisEstimateSwap(format!("exactly {token_in} in"));
+        let req = EstimateSwapExactAmountInRequest {
+            pool_id: 0,
+            token_in,
+            routes,
+        };
+        let res = self
+            .perform_query(req, action.clone())
+            .run()
+            .await?
+            .into_inner();
+        parse_amount(&res.token_out_amount, action)
+    }
+
+    /// Estimate the amount needed to receive exactly `token_out` along `routes`.
+    ///
+    /// Note that this query will fail if called on chains besides Osmosis.
+    pub async fn estimate_osmosis_swap_exact_amount_out(
+        &self,
+        routes: Vec<SwapAmountOutRoute>,
+        token_out: &Coin,
+    ) -> Result<Uint128, Error> {
+        let token_out = format!("{}{}", token_out.amount, token_out.denom);
+        let action = Action::OsmosisEstimateSwap(format!("exactly {token_out} out"));
+        let req = EstimateSwapExactAmountOutRequest {
+            pool_id: 0,
+            routes,
+            token_out,
+        };
+        let res = self
+            .perform_query(req, action.clone())
+            .run()
+            .await?
+            .into_inner();
+        parse_amount(&res.token_in_amount, action)
+    }
+}
+
+fn parse_amount(amount: &str, action: Action) -> Result<Uint128, Error> {
+    amount.parse().map_err(|_| Error::InvalidChainResponse {
+        message: format!("Invalid swap amount {amount:?}"),
+        action: action.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+
+    #[test]
+    fn swap_message() {
+        let sender: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        let msg = MsgSwapExactAmountIn::new(
+            sender,
+            vec![SwapAmountInRoute::new(1, "uion")],
+            Coin {
+                denom: "uosmo".to_owned(),
+                amount: "1000".to_owned(),
+            },
+            Uint128::new(5),
+        );
+        let decoded = MsgSwapExactAmountIn::decode(msg.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, msg);
+        let msg = TxMessage::from(msg);
+        assert_eq!(
+            msg.type_url(),
+            "/osmosis.poolmanager.v1beta1.MsgSwapExactAmountIn"
+        );
+    }
+}
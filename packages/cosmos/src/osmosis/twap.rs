@@ -0,0 +1,9 @@
+//! Time-weighted average prices from the Osmosis twap module.
+
+mod proto;
+
+pub(crate) use proto::{
+    query_client, ArithmeticTwapRequest, ArithmeticTwapResponse, ArithmeticTwapToNowRequest,
+    ArithmeticTwapToNowResponse, GeometricTwapRequest, GeometricTwapResponse,
+    GeometricTwapToNowRequest, GeometricTwapToNowResponse,
+};
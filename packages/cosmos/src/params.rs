@@ -0,0 +1,87 @@
+use cosmos_sdk_proto::cosmos::{auth, bank, gov, staking};
+
+use crate::{error::Action, Cosmos};
+
+impl Cosmos {
+    /// Get the auth module parameters, such as the maximum memo length.
+    pub async fn auth_params(&self) -> Result<auth::v1beta1::Params, crate::Error> {
+        let action = Action::QueryModuleParams("auth");
+        self.perform_query(auth::v1beta1::QueryParamsRequest {}, action.clone())
+            .run()
+            .await?
+            .into_inner()
+            .params
+            .ok_or_else(|| crate::Error::InvalidChainResponse {
+                message: "Missing params field".to_owned(),
+                action: action.into(),
+            })
+    }
+
+    /// Get the bank module parameters.
+    pub async fn bank_params(&self) -> Result<bank::v1beta1::Params, crate::Error> {
+        let action = Action::QueryModuleParams("bank");
+        self.perform_query(bank::v1beta1::QueryParamsRequest {}, action.clone())
+            .run()
+            .await?
+            .into_inner()
+            .params
+            .ok_or_else(|| crate::Error::InvalidChainResponse {
+                message: "Missing params field".to_owned(),
+                action: action.into(),
+            })
+    }
+
+    /// Get the staking module parameters, such as the unbonding time.
+    pub async fn staking_params(&self) -> Result<staking::v1beta1::Params, crate::Error> {
+        let action = Action::QueryModuleParams("staking");
+        self.perform_query(staking::v1beta1::QueryParamsRequest {}, action.clone())
+            .run()
+            .await?
+            .into_inner()
+            .params
+            .ok_or_else(|| crate::Error::InvalidChainResponse {
+                message: "Missing params field".to_owned(),
+                action: action.into(),
+            })
+    }
+
+    /// Get the governance module parameters, such as the minimum deposit and voting period.
+    ///
+    /// Chains on Cosmos SDK 0.46 only report parameters split into deposit,
+    /// voting, and tallying groups. Those are combined into a single
+    /// [gov::v1::Params], leaving fields added in later versions at their defaults.
+    pub async fn gov_params(&self) -> Result<gov::v1::Params, crate::Error> {
+        let action = Action::QueryModuleParams("gov");
+        let query = |params_type: &str| {
+            self.perform_query(
+                gov::v1::QueryParamsRequest {
+                    params_type: params_type.to_owned(),
+                },
+                action.clone(),
+            )
+            .run()
+        };
+        let voting = query("voting").await?.into_inner();
+        if let Some(params) = voting.params {
+            return Ok(params);
+        }
+
+        let deposit = query("deposit").await?.into_inner();
+        let tallying = query("tallying").await?.into_inner();
+        #[allow(deprecated)]
+        let (voting, deposit, tallying) = (
+            voting.voting_params.unwrap_or_default(),
+            deposit.deposit_params.unwrap_or_default(),
+            tallying.tally_params.unwrap_or_default(),
+        );
+        Ok(gov::v1::Params {
+            min_deposit: deposit.min_deposit,
+            max_deposit_period: deposit.max_deposit_period,
+            voting_period: voting.voting_period,
+            quorum: tallying.quorum,
+            threshold: tallying.threshold,
+            veto_threshold: tallying.veto_threshold,
+            ..Default::default()
+        })
+    }
+}
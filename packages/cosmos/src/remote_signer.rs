@@ -0,0 +1,240 @@
+//! Signing with keys held by a remote service, such as a KMS, HSM, or signing sidecar.
+//!
+//! The private key never enters this process. A [RemoteSigner] computes the
+//! digest to sign, asks a [RemoteSigningBackend] to sign it, and verifies the
+//! result before handing it to the transaction builder.
+use std::sync::Arc;
+
+use bitcoin::secp256k1::{ecdsa::Signature, Message, PublicKey};
+use tonic::{
+    async_trait,
+    transport::{ClientTlsConfig, Endpoint},
+};
+
+use crate::{
+    error::AddressError,
+    wallet::{global_secp, signing_digest, WalletPublicKey},
+    Address, AddressHrp, HasAddress, HasAddressHrp, PublicKeyMethod, Signer,
+};
+
+/// Error type returned by [RemoteSigningBackend] implementations.
+pub type BackendError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A remote service capable of signing digests with a secp256k1 key it holds.
+///
+/// Implement this to integrate AWS KMS, GCP KMS, an HSM, or any other
+/// custody solution. [GrpcSigningBackend] is provided for signing sidecars.
+#[async_trait]
+pub trait RemoteSigningBackend: Send + Sync {
+    /// Sign the given 32-byte digest.
+    ///
+    /// May return either a 64-byte compact signature or a DER-encoded
+    /// signature, which is what AWS and GCP KMS produce.
+    async fn sign_digest(&self, digest: [u8; 32]) -> Result<Vec<u8>, BackendError>;
+}
+
+/// A [Signer] which delegates signing to a [RemoteSigningBackend].
+///
+/// Signatures from the backend are normalized to low-S form, as required by
+/// Cosmos chains, and verified against the public key before being used.
+#[derive(Clone)]
+pub struct RemoteSigner {
+    address: Address,
+    public_key: WalletPublicKey,
+    backend: Arc<dyn RemoteSigningBackend>,
+}
+
+impl RemoteSigner {
+    /// Create a signer for the key with the given public key.
+    ///
+    /// The public key may be compressed or uncompressed. The address is
+    /// derived from it using the given [PublicKeyMethod]. Only secp256k1
+    /// keys are supported.
+    pub fn new(
+        public_key: &[u8],
+        method: PublicKeyMethod,
+        hrp: AddressHrp,
+        backend: impl RemoteSigningBackend + 'static,
+    ) -> Result<Self, AddressError> {
+        if method == PublicKeyMethod::Ed25519 {
+            return Err(AddressError::UnsupportedPublicKeyMethod { method });
+        }
+        let address = Address::from_public_key(public_key, method, hrp)?;
+        let parsed = PublicKey::from_slice(public_key)
+            .map_err(|source| AddressError::InvalidPublicKey { source })?;
+        let public_key = match method {
+            PublicKeyMethod::Cosmos => WalletPublicKey::Cosmos(parsed.serialize()),
+            PublicKeyMethod::Ethereum => WalletPublicKey::Ethereum(parsed.serialize_uncompressed()),
+            PublicKeyMethod::Ed25519 => unreachable!("Rejected above"),
+        };
+        Ok(RemoteSigner {
+            address,
+            public_key,
+            backend: Arc::new(backend),
+        })
+    }
+}
+
+impl std::fmt::Debug for RemoteSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteSigner")
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HasAddressHrp for RemoteSigner {
+    fn get_address_hrp(&self) -> AddressHrp {
+        self.address.get_address_hrp()
+    }
+}
+
+impl HasAddress for RemoteSigner {
+    fn get_address(&self) -> Address {
+        self.address
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn public_key(&self) -> WalletPublicKey {
+        self.public_key.clone()
+    }
+
+    async fn sign(&self, sign_doc: &[u8]) -> Result<Vec<u8>, crate::Error> {
+        let failed = |source: BackendError| crate::Error::SignerFailed {
+            address: self.address,
+            source,
+        };
+        let digest = signing_digest(sign_doc, self.public_key.method());
+        let raw = self.backend.sign_digest(digest).await.map_err(failed)?;
+        let mut signature = match raw.len() {
+            64 => Signature::from_compact(&raw),
+            _ => Signature::from_der(&raw),
+        }
+        .map_err(|e| failed(e.into()))?;
+        signature.normalize_s();
+        let public_key =
+            PublicKey::from_slice(self.public_key.as_bytes()).map_err(|e| failed(e.into()))?;
+        global_secp()
+            .verify_ecdsa(&Message::from_digest(digest), &signature, &public_key)
+            .map_err(|e| {
+                failed(format!("Remote signature does not match the public key: {e}").into())
+            })?;
+        Ok(signature.serialize_compact().to_vec())
+    }
+}
+
+/// Request sent by [GrpcSigningBackend].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignDigestRequest {
+    /// Identifier of the key to sign with, interpreted by the sidecar.
+    #[prost(string, tag = "1")]
+    pub key_id: String,
+    /// 32-byte digest to sign.
+    #[prost(bytes = "vec", tag = "2")]
+    pub digest: Vec<u8>,
+}
+
+/// Response expected by [GrpcSigningBackend].
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignDigestResponse {
+    /// Compact or DER-encoded secp256k1 signature.
+    #[prost(bytes = "vec", tag = "1")]
+    pub signature: Vec<u8>,
+}
+
+/// A [RemoteSigningBackend] calling a signing sidecar over gRPC.
+///
+/// The sidecar must implement the unary method
+/// `/cosmos_rs.signer.v1.RemoteSigner/SignDigest`, taking a
+/// [SignDigestRequest] and returning a [SignDigestResponse].
+#[derive(Clone, Debug)]
+pub struct GrpcSigningBackend {
+    channel: tonic::transport::Channel,
+    key_id: String,
+}
+
+impl GrpcSigningBackend {
+    /// Create a backend for the sidecar at the given URL.
+    ///
+    /// The connection is established lazily on the first signing request.
+    /// TLS is used for `https` URLs.
+    pub fn new(url: &str, key_id: impl Into<String>) -> Result<Self, tonic::transport::Error> {
+        let mut endpoint = Endpoint::from_shared(url.to_owned())?;
+        if url.starts_with("https://") {
+            endpoint = endpoint.tls_config(ClientTlsConfig::new().with_native_roots())?;
+        }
+        Ok(GrpcSigningBackend {
+            channel: endpoint.connect_lazy(),
+            key_id: key_id.into(),
+        })
+    }
+}
+
+#[async_trait]
+impl RemoteSigningBackend for GrpcSigningBackend {
+    async fn sign_digest(&self, digest: [u8; 32]) -> Result<Vec<u8>, BackendError> {
+        let mut grpc = tonic::client::Grpc::new(self.channel.clone());
+        grpc.ready().await?;
+        let path =
+            http::uri::PathAndQuery::from_static("/cosmos_rs.signer.v1.RemoteSigner/SignDigest");
+        let res: tonic::Response<SignDigestResponse> = grpc
+            .unary(
+                tonic::Request::new(SignDigestRequest {
+                    key_id: self.key_id.clone(),
+                    digest: digest.to_vec(),
+                }),
+                path,
+                tonic::codec::ProstCodec::default(),
+            )
+            .await?;
+        Ok(res.into_inner().signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Wallet;
+
+    /// Signs with a local key, returning high-S DER signatures like a KMS might.
+    struct LocalBackend(Wallet);
+
+    #[async_trait]
+    impl RemoteSigningBackend for LocalBackend {
+        async fn sign_digest(&self, digest: [u8; 32]) -> Result<Vec<u8>, BackendError> {
+            let signature = global_secp().sign_ecdsa(
+                &Message::from_digest(digest),
+                &self.0.get_privkey().private_key,
+            );
+            let compact = signature.serialize_compact();
+            // Flip to the high-S form: s' = n - s
+            let s = bitcoin::secp256k1::SecretKey::from_slice(&compact[32..])?.negate();
+            let mut high = compact;
+            high[32..].copy_from_slice(&s.secret_bytes());
+            Ok(Signature::from_compact(&high)?.serialize_der().to_vec())
+        }
+    }
+
+    #[tokio::test]
+    async fn normalizes_remote_signatures() {
+        let hrp = AddressHrp::from_static("osmo");
+        let wallet = Wallet::generate(hrp).unwrap();
+        let signer = RemoteSigner::new(
+            wallet.public_key_bytes(),
+            PublicKeyMethod::Cosmos,
+            hrp,
+            LocalBackend(wallet.clone()),
+        )
+        .unwrap();
+        assert_eq!(Signer::address(&signer), wallet.get_address());
+        let remote = signer.sign(b"sign doc").await.unwrap();
+        let local = Signer::sign(&wallet, b"sign doc").await.unwrap();
+        assert_eq!(remote, local);
+    }
+}
@@ -0,0 +1,58 @@
+use rand::RngCore;
+
+#[cfg(feature = "deterministic-tests")]
+thread_local! {
+    static SEEDED_RNG: std::cell::RefCell<Option<rand::rngs::StdRng>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Run the given function with the crate's source of randomness.
+///
+/// This is the thread-local RNG, unless the `deterministic-tests` feature is
+/// enabled and [seed_rng] has been called on the current thread.
+pub(crate) fn with_rng<T>(f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+    #[cfg(feature = "deterministic-tests")]
+    {
+        let mut seeded = SEEDED_RNG.with(|cell| cell.borrow_mut().take());
+        if let Some(rng) = seeded.as_mut() {
+            let res = f(rng);
+            SEEDED_RNG.with(|cell| *cell.borrow_mut() = seeded);
+            return res;
+        }
+    }
+    f(&mut rand::thread_rng())
+}
+
+/// Make all randomness in this crate (e.g. [crate::SeedPhrase::random]) deterministic on the current thread.
+///
+/// Only intended for tests and simulations which need reproducible wallets.
+/// The seed only applies to the calling thread, so tests running in
+/// parallel don't interfere with each other.
+#[cfg(feature = "deterministic-tests")]
+pub fn seed_rng(seed: u64) {
+    use rand::SeedableRng;
+    SEEDED_RNG.with(|cell| *cell.borrow_mut() = Some(rand::rngs::StdRng::seed_from_u64(seed)));
+}
+
+/// Undo the effect of [seed_rng] on the current thread.
+#[cfg(feature = "deterministic-tests")]
+pub fn clear_rng_seed() {
+    SEEDED_RNG.with(|cell| *cell.borrow_mut() = None);
+}
+
+#[cfg(all(test, feature = "deterministic-tests"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_seed_phrases_match() {
+        seed_rng(42);
+        let first = crate::SeedPhrase::random().phrase();
+        seed_rng(42);
+        let second = crate::SeedPhrase::random().phrase();
+        clear_rng_seed();
+        let third = crate::SeedPhrase::random().phrase();
+        assert_eq!(first, second);
+        assert_ne!(first, third);
+    }
+}
@@ -1,10 +1,214 @@
+//! Messages and queries for THORChain, as used by Rujira.
+//!
+//! THORChain handles most user actions (swaps, liquidity, THORNames) through a
+//! single [MsgDeposit] whose memo describes the action. The constructors on
+//! [MsgDeposit] build the memo for you.
+use std::{fmt::Display, str::FromStr};
+
+use cosmos_sdk_proto::traits::Message;
 use tonic::{async_trait, GrpcMethod};
 
 use crate::{
     client::{node::Node, query::GrpcRequest},
-    error::Action,
-    Cosmos,
+    error::{Action, RujiraError},
+    Cosmos, HasAddress, TxMessage,
+};
+
+mod proto;
+
+/// Upstream version of the protobuf code vendored in this module, see [crate::build_info()].
+///
+/// Copied from `gitlab.com/thorchain/thornode` and patched by hand rather than
+/// generated, keep in sync with the header of `proto.rs`.
+pub(crate) const PROTO_VERSIONS: &[(&str, &str)] = &[("thornode", "v3.2.0")];
+
+pub use proto::{
+    Asset, Coin, MsgDeposit, QueryPoolResponse, QueryPoolsResponse, QueryQuoteSwapRequest,
+    QueryQuoteSwapResponse, QuoteFees,
 };
+pub(crate) use proto::{QueryPoolRequest, QueryPoolsRequest};
+
+impl Asset {
+    /// The native RUNE asset.
+    pub fn rune() -> Self {
+        Asset {
+            chain: "THOR".to_owned(),
+            symbol: "RUNE".to_owned(),
+            ticker: "RUNE".to_owned(),
+            synth: false,
+            trade: false,
+            secured: false,
+        }
+    }
+}
+
+impl FromStr for Asset {
+    type Err = RujiraError;
+
+    /// Parse an asset string such as `BTC.BTC`, `ETH.USDC-0XA0B8...`, `BTC/BTC`
+    /// (synth), `BTC~BTC` (trade) or `BTC-BTC` (secured).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RujiraError::InvalidAsset {
+            asset: s.to_owned(),
+        };
+        let idx = s.find(['.', '/', '~', '-']).ok_or_else(invalid)?;
+        let (chain, symbol) = (&s[..idx], &s[idx + 1..]);
+        if chain.is_empty() || symbol.is_empty() {
+            return Err(invalid());
+        }
+        let separator = s.as_bytes()[idx];
+        Ok(Asset {
+            chain: chain.to_uppercase(),
+            symbol: symbol.to_uppercase(),
+            ticker: symbol.split('-').next().unwrap_or(symbol).to_uppercase(),
+            synth: separator == b'/',
+            trade: separator == b'~',
+            secured: separator == b'-',
+        })
+    }
+}
+
+impl Display for Asset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let separator = if self.synth {
+            '/'
+        } else if self.trade {
+            '~'
+        } else if self.secured {
+            '-'
+        } else {
+            '.'
+        };
+        write!(f, "{}{separator}{}", self.chain, self.symbol)
+    }
+}
+
+impl Coin {
+    /// Construct a coin with an amount in THORChain's 1e8 base units.
+    pub fn new(asset: Asset, amount: u128) -> Self {
+        Coin {
+            asset: Some(asset),
+            amount: amount.to_string(),
+            decimals: 0,
+        }
+    }
+}
+
+/// An affiliate to credit with a fee on swaps and liquidity deposits.
+#[derive(Clone, Debug)]
+pub struct Affiliate {
+    /// THORName or address of the affiliate
+    pub name: String,
+    /// Fee in basis points
+    pub bps: u16,
+}
+
+impl MsgDeposit {
+    /// Deposit the given coins with an arbitrary memo.
+    pub fn new(signer: impl HasAddress, coins: Vec<Coin>, memo: impl Into<String>) -> Self {
+        MsgDeposit {
+            coins,
+            memo: memo.into(),
+            signer: signer.get_address().raw().as_ref().to_vec(),
+        }
+    }
+
+    /// Swap the coin for `to_asset`, sending the output to `destination`.
+    ///
+    /// `limit` is the minimum output amount, in 1e8 base units.
+    pub fn swap(
+        signer: impl HasAddress,
+        coin: Coin,
+        to_asset: &Asset,
+        destination: &str,
+        limit: Option<u128>,
+        affiliate: Option<&Affiliate>,
+    ) -> Self {
+        let mut memo = format!("=:{to_asset}:{destination}");
+        if limit.is_some() || affiliate.is_some() {
+            memo.push(':');
+            if let Some(limit) = limit {
+                memo += &limit.to_string();
+            }
+        }
+        if let Some(Affiliate { name, bps }) = affiliate {
+            memo += &format!(":{name}:{bps}");
+        }
+        Self::new(signer, vec![coin], memo)
+    }
+
+    /// Add the coin as liquidity to `pool`.
+    pub fn add_liquidity(
+        signer: impl HasAddress,
+        coin: Coin,
+        pool: &Asset,
+        affiliate: Option<&Affiliate>,
+    ) -> Self {
+        let memo = match affiliate {
+            Some(Affiliate { name, bps }) => format!("+:{pool}::{name}:{bps}"),
+            None => format!("+:{pool}"),
+        };
+        Self::new(signer, vec![coin], memo)
+    }
+
+    /// Withdraw a share of the signer's liquidity from `pool`.
+    ///
+    /// `basis_points` ranges from 1 to 10000, where 10000 withdraws everything.
+    pub fn withdraw_liquidity(signer: impl HasAddress, pool: &Asset, basis_points: u16) -> Self {
+        Self::new(
+            signer,
+            vec![Coin::new(Asset::rune(), 0)],
+            format!("-:{pool}:{basis_points}"),
+        )
+    }
+
+    /// Register or update a THORName, which can then be used as an [Affiliate].
+    ///
+    /// `fee` is the RUNE amount paid for registration, in 1e8 base units.
+    pub fn register_thorname(
+        signer: impl HasAddress,
+        name: &str,
+        chain: &str,
+        address: &str,
+        fee: u128,
+    ) -> Self {
+        Self::new(
+            signer,
+            vec![Coin::new(Asset::rune(), fee)],
+            format!("~:{name}:{chain}:{address}"),
+        )
+    }
+}
+
+impl From<MsgDeposit> for TxMessage {
+    fn from(msg: MsgDeposit) -> Self {
+        let coins = msg
+            .coins
+            .iter()
+            .map(|coin| match &coin.asset {
+                Some(asset) => format!("{}{asset}", coin.amount),
+                None => coin.amount.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let description = format!("Deposit of {coins} with memo {}", msg.memo);
+        TxMessage::new("/types.MsgDeposit", msg.encode_to_vec(), description)
+    }
+}
+
+impl QueryQuoteSwapRequest {
+    /// Quote a swap of `amount` (in 1e8 base units) from one asset to another.
+    ///
+    /// Remaining fields, such as the destination and affiliates, can be set directly.
+    pub fn new(from_asset: &Asset, to_asset: &Asset, amount: u128) -> Self {
+        QueryQuoteSwapRequest {
+            from_asset: from_asset.to_string(),
+            to_asset: to_asset.to_string(),
+            amount: amount.to_string(),
+            ..Default::default()
+        }
+    }
+}
 
 impl Cosmos {
     /// Query information on a Rujira pool
@@ -12,13 +216,14 @@ impl Cosmos {
         &self,
         asset: impl Into<String>,
     ) -> Result<QueryPoolResponse, crate::Error> {
+        let asset = asset.into();
         Ok(self
             .perform_query(
                 QueryPoolRequest {
-                    asset: asset.into(),
+                    asset: asset.clone(),
                     height: "".to_owned(),
                 },
-                Action::GetLatestBlock,
+                Action::RujiraPool(asset),
             )
             .run()
             .await?
@@ -32,12 +237,24 @@ impl Cosmos {
                 QueryPoolsRequest {
                     height: "".to_owned(),
                 },
-                Action::GetLatestBlock,
+                Action::RujiraPools,
             )
             .run()
             .await?
             .into_inner())
     }
+
+    /// Get a quote for a swap, including expected output and fees.
+    pub async fn rujira_quote_swap(
+        &self,
+        req: QueryQuoteSwapRequest,
+    ) -> Result<QueryQuoteSwapResponse, crate::Error> {
+        let action = Action::RujiraQuoteSwap {
+            from_asset: req.from_asset.clone(),
+            to_asset: req.to_asset.clone(),
+        };
+        Ok(self.perform_query(req, action).run().await?.into_inner())
+    }
 }
 
 pub(crate) struct RujiraQueryClient<T> {
@@ -90,6 +307,24 @@ where
             .insert(GrpcMethod::new("types.Query", "Pools"));
         self.inner.unary(req, path, codec).await
     }
+
+    async fn quote_swap(
+        &mut self,
+        request: impl tonic::IntoRequest<QueryQuoteSwapRequest>,
+    ) -> Result<tonic::Response<QueryQuoteSwapResponse>, tonic::Status> {
+        self.inner.ready().await.map_err(|e| {
+            tonic::Status::new(
+                tonic::Code::Unknown,
+                format!("Service was not ready: {}", e.into()),
+            )
+        })?;
+        let codec = tonic::codec::ProstCodec::default();
+        let path = http::uri::PathAndQuery::from_static("/types.Query/QuoteSwap");
+        let mut req = request.into_request();
+        req.extensions_mut()
+            .insert(GrpcMethod::new("types.Query", "QuoteSwap"));
+        self.inner.unary(req, path, codec).await
+    }
 }
 
 #[async_trait]
@@ -116,84 +351,67 @@ impl GrpcRequest for QueryPoolsRequest {
     }
 }
 
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct QueryPoolRequest {
-    #[prost(string, tag = "1")]
-    pub asset: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub height: ::prost::alloc::string::String,
-}
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct QueryPoolResponse {
-    #[prost(string, tag = "1")]
-    pub asset: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub short_code: ::prost::alloc::string::String,
-    #[prost(string, tag = "3")]
-    pub status: ::prost::alloc::string::String,
-    #[prost(int64, tag = "4")]
-    pub decimals: i64,
-    #[prost(string, tag = "5")]
-    pub pending_inbound_asset: ::prost::alloc::string::String,
-    #[prost(string, tag = "6")]
-    pub pending_inbound_rune: ::prost::alloc::string::String,
-    #[prost(string, tag = "7")]
-    pub balance_asset: ::prost::alloc::string::String,
-    #[prost(string, tag = "8")]
-    pub balance_rune: ::prost::alloc::string::String,
-    /// the USD (TOR) price of the asset in 1e8
-    #[prost(string, tag = "9")]
-    pub asset_tor_price: ::prost::alloc::string::String,
-    /// the total pool units, this is the sum of LP and synth units
-    #[prost(string, tag = "10")]
-    pub pool_units: ::prost::alloc::string::String,
-    /// the total pool liquidity provider units
-    #[prost(string, tag = "11")]
-    pub lp_units: ::prost::alloc::string::String,
-    /// the total synth units in the pool
-    #[prost(string, tag = "12")]
-    pub synth_units: ::prost::alloc::string::String,
-    /// the total supply of synths for the asset
-    #[prost(string, tag = "13")]
-    pub synth_supply: ::prost::alloc::string::String,
-    /// the balance of L1 asset deposited into the Savers Vault
-    #[prost(string, tag = "14")]
-    pub savers_depth: ::prost::alloc::string::String,
-    /// the number of units owned by Savers
-    #[prost(string, tag = "15")]
-    pub savers_units: ::prost::alloc::string::String,
-    /// the filled savers capacity in basis points, 4500/10000 = 45%
-    #[prost(string, tag = "16")]
-    pub savers_fill_bps: ::prost::alloc::string::String,
-    /// amount of remaining capacity in asset
-    #[prost(string, tag = "17")]
-    pub savers_capacity_remaining: ::prost::alloc::string::String,
-    /// whether additional synths cannot be minted
-    #[prost(bool, tag = "18")]
-    pub synth_mint_paused: bool,
-    /// the amount of synth supply remaining before the current max supply is reached
-    #[prost(string, tag = "19")]
-    pub synth_supply_remaining: ::prost::alloc::string::String,
-    /// the amount of collateral collects for loans
-    #[prost(string, tag = "20")]
-    pub loan_collateral: ::prost::alloc::string::String,
-    /// the amount of remaining collateral collects for loans
-    #[prost(string, tag = "21")]
-    pub loan_collateral_remaining: ::prost::alloc::string::String,
-    /// the current loan collateralization ratio
-    #[prost(string, tag = "22")]
-    pub loan_cr: ::prost::alloc::string::String,
-    /// the depth of the derived virtual pool relative to L1 pool (in basis points)
-    #[prost(string, tag = "23")]
-    pub derived_depth_bps: ::prost::alloc::string::String,
-}
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct QueryPoolsRequest {
-    #[prost(string, tag = "1")]
-    pub height: ::prost::alloc::string::String,
+#[async_trait]
+impl GrpcRequest for QueryQuoteSwapRequest {
+    type Response = QueryQuoteSwapResponse;
+
+    async fn perform(
+        req: tonic::Request<Self>,
+        inner: &Node,
+    ) -> Result<tonic::Response<Self::Response>, tonic::Status> {
+        inner.rujira_query_client().quote_swap(req).await
+    }
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct QueryPoolsResponse {
-    #[prost(message, repeated, tag = "1")]
-    pub pools: ::prost::alloc::vec::Vec<QueryPoolResponse>,
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_assets() {
+        let usdc: Asset = "ETH.USDC-0XA0B86991C6218B36C1D19D4A2E9EB0CE3606EB48"
+            .parse()
+            .unwrap();
+        assert_eq!(usdc.chain, "ETH");
+        assert_eq!(usdc.ticker, "USDC");
+        assert!(!usdc.synth && !usdc.trade && !usdc.secured);
+        assert_eq!(
+            usdc.to_string(),
+            "ETH.USDC-0XA0B86991C6218B36C1D19D4A2E9EB0CE3606EB48"
+        );
+
+        let secured: Asset = "btc-btc".parse().unwrap();
+        assert!(secured.secured);
+        assert_eq!(secured.to_string(), "BTC-BTC");
+        assert!("BTC~BTC".parse::<Asset>().unwrap().trade);
+        assert!("BTC/BTC".parse::<Asset>().unwrap().synth);
+        assert_eq!("THOR.RUNE".parse::<Asset>().unwrap(), Asset::rune());
+        assert!("RUNE".parse::<Asset>().is_err());
+    }
+
+    #[test]
+    fn deposit_memos() {
+        let signer = crate::Wallet::generate(crate::AddressHrp::from_static("thor"))
+            .unwrap()
+            .get_address();
+        let btc: Asset = "BTC.BTC".parse().unwrap();
+        let affiliate = Affiliate {
+            name: "rj".to_owned(),
+            bps: 10,
+        };
+        let swap = MsgDeposit::swap(
+            signer,
+            Coin::new(Asset::rune(), 100),
+            &btc,
+            "bc1qdest",
+            None,
+            Some(&affiliate),
+        );
+        assert_eq!(swap.memo, "=:BTC.BTC:bc1qdest::rj:10");
+        assert_eq!(swap.signer, signer.raw().as_ref());
+        let add = MsgDeposit::add_liquidity(signer, Coin::new(Asset::rune(), 100), &btc, None);
+        assert_eq!(add.memo, "+:BTC.BTC");
+        let withdraw = MsgDeposit::withdraw_liquidity(signer, &btc, 5000);
+        assert_eq!(withdraw.memo, "-:BTC.BTC:5000");
+    }
 }
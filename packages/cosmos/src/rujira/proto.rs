@@ -0,0 +1,224 @@
+#![allow(missing_docs)]
+//////////// GENERATED, COPY/PASTED, AND PATCHED FROM PROST-BUILD ////////////////
+// Source: thorchain/thornode v3.2.0, proto/thorchain/v1
+
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Asset {
+    #[prost(string, tag = "1")]
+    pub chain: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub ticker: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub synth: bool,
+    #[prost(bool, tag = "5")]
+    pub trade: bool,
+    #[prost(bool, tag = "6")]
+    pub secured: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Coin {
+    #[prost(message, optional, tag = "1")]
+    pub asset: ::core::option::Option<Asset>,
+    #[prost(string, tag = "2")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub decimals: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgDeposit {
+    #[prost(message, repeated, tag = "1")]
+    pub coins: ::prost::alloc::vec::Vec<Coin>,
+    #[prost(string, tag = "2")]
+    pub memo: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryPoolRequest {
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub height: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryPoolResponse {
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub short_code: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub decimals: i64,
+    #[prost(string, tag = "5")]
+    pub pending_inbound_asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub pending_inbound_rune: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub balance_asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub balance_rune: ::prost::alloc::string::String,
+    /// the USD (TOR) price of the asset in 1e8
+    #[prost(string, tag = "9")]
+    pub asset_tor_price: ::prost::alloc::string::String,
+    /// the total pool units, this is the sum of LP and synth units
+    #[prost(string, tag = "10")]
+    pub pool_units: ::prost::alloc::string::String,
+    /// the total pool liquidity provider units
+    #[prost(string, tag = "11")]
+    pub lp_units: ::prost::alloc::string::String,
+    /// the total synth units in the pool
+    #[prost(string, tag = "12")]
+    pub synth_units: ::prost::alloc::string::String,
+    /// the total supply of synths for the asset
+    #[prost(string, tag = "13")]
+    pub synth_supply: ::prost::alloc::string::String,
+    /// the balance of L1 asset deposited into the Savers Vault
+    #[prost(string, tag = "14")]
+    pub savers_depth: ::prost::alloc::string::String,
+    /// the number of units owned by Savers
+    #[prost(string, tag = "15")]
+    pub savers_units: ::prost::alloc::string::String,
+    /// the filled savers capacity in basis points, 4500/10000 = 45%
+    #[prost(string, tag = "16")]
+    pub savers_fill_bps: ::prost::alloc::string::String,
+    /// amount of remaining capacity in asset
+    #[prost(string, tag = "17")]
+    pub savers_capacity_remaining: ::prost::alloc::string::String,
+    /// whether additional synths cannot be minted
+    #[prost(bool, tag = "18")]
+    pub synth_mint_paused: bool,
+    /// the amount of synth supply remaining before the current max supply is reached
+    #[prost(string, tag = "19")]
+    pub synth_supply_remaining: ::prost::alloc::string::String,
+    /// the amount of collateral collects for loans
+    #[prost(string, tag = "20")]
+    pub loan_collateral: ::prost::alloc::string::String,
+    /// the amount of remaining collateral collects for loans
+    #[prost(string, tag = "21")]
+    pub loan_collateral_remaining: ::prost::alloc::string::String,
+    /// the current loan collateralization ratio
+    #[prost(string, tag = "22")]
+    pub loan_cr: ::prost::alloc::string::String,
+    /// the depth of the derived virtual pool relative to L1 pool (in basis points)
+    #[prost(string, tag = "23")]
+    pub derived_depth_bps: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryPoolsRequest {
+    #[prost(string, tag = "1")]
+    pub height: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryPoolsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub pools: ::prost::alloc::vec::Vec<QueryPoolResponse>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryQuoteSwapRequest {
+    #[prost(string, tag = "1")]
+    pub from_asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub to_asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub streaming_interval: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub streaming_quantity: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub tolerance_bps: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub refund_address: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "9")]
+    pub affiliate: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "10")]
+    pub affiliate_bps: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "11")]
+    pub height: ::prost::alloc::string::String,
+    #[prost(string, tag = "12")]
+    pub liquidity_tolerance_bps: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuoteFees {
+    #[prost(string, tag = "1")]
+    pub asset: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub outbound: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub total: ::prost::alloc::string::String,
+    #[prost(int64, tag = "5")]
+    pub slippage_bps: i64,
+    #[prost(int64, tag = "6")]
+    pub total_bps: i64,
+    #[prost(string, tag = "7")]
+    pub affiliate: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryQuoteSwapResponse {
+    /// the inbound address for the transaction on the source chain
+    #[prost(string, tag = "1")]
+    pub inbound_address: ::prost::alloc::string::String,
+    /// the approximate number of source chain blocks required before processing
+    #[prost(int64, tag = "2")]
+    pub inbound_confirmation_blocks: i64,
+    /// the approximate seconds for block confirmations required before processing
+    #[prost(int64, tag = "3")]
+    pub inbound_confirmation_seconds: i64,
+    /// the number of thorchain blocks the outbound will be delayed
+    #[prost(int64, tag = "4")]
+    pub outbound_delay_blocks: i64,
+    /// the approximate seconds for the outbound delay before it will be sent
+    #[prost(int64, tag = "5")]
+    pub outbound_delay_seconds: i64,
+    #[prost(message, optional, tag = "6")]
+    pub fees: ::core::option::Option<QuoteFees>,
+    /// the EVM chain router contract address
+    #[prost(string, tag = "7")]
+    pub router: ::prost::alloc::string::String,
+    /// expiration timestamp in unix seconds
+    #[prost(int64, tag = "8")]
+    pub expiry: i64,
+    /// static warning message
+    #[prost(string, tag = "9")]
+    pub warning: ::prost::alloc::string::String,
+    /// chain specific quote notes
+    #[prost(string, tag = "10")]
+    pub notes: ::prost::alloc::string::String,
+    /// Defines the minimum transaction size for the chain in base units (sats, wei, uatom). Transactions with asset amounts lower than the dust_threshold are ignored.
+    #[prost(string, tag = "11")]
+    pub dust_threshold: ::prost::alloc::string::String,
+    /// The recommended minimum inbound amount for this transaction type & inbound asset. Sending less than this amount could result in failed refunds.
+    #[prost(string, tag = "12")]
+    pub recommended_min_amount_in: ::prost::alloc::string::String,
+    /// the recommended gas rate to use for the inbound to ensure timely confirmation
+    #[prost(string, tag = "13")]
+    pub recommended_gas_rate: ::prost::alloc::string::String,
+    /// the units of the recommended gas rate
+    #[prost(string, tag = "14")]
+    pub gas_rate_units: ::prost::alloc::string::String,
+    /// generated memo for the swap
+    #[prost(string, tag = "15")]
+    pub memo: ::prost::alloc::string::String,
+    /// the amount of the target asset the user can expect to receive after fees
+    #[prost(string, tag = "16")]
+    pub expected_amount_out: ::prost::alloc::string::String,
+    /// the maximum amount of trades a streaming swap can do for a trade
+    #[prost(int64, tag = "17")]
+    pub max_streaming_quantity: i64,
+    /// the number of blocks the streaming swap will execute over
+    #[prost(int64, tag = "18")]
+    pub streaming_swap_blocks: i64,
+    /// approx the number of seconds the streaming swap will execute over
+    #[prost(int64, tag = "19")]
+    pub streaming_swap_seconds: i64,
+    /// total number of seconds a swap is expected to take (inbound conf + streaming swap + outbound delay)
+    #[prost(int64, tag = "20")]
+    pub total_swap_seconds: i64,
+}
@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use tonic::async_trait;
+
+use crate::Address;
+
+/// Which sequence number is being tracked.
+///
+/// Simulation and broadcast sequence numbers are tracked separately, since
+/// simulations never increase the on-chain sequence number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SequenceKind {
+    /// Sequence numbers used for simulating transactions
+    Simulate,
+    /// Sequence numbers used for broadcasting transactions
+    Broadcast,
+}
+
+/// A tracked sequence number for a single address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceRecord {
+    /// The next sequence number to hand out
+    pub sequence: u64,
+    /// When this record was last written
+    ///
+    /// Records older than 30 seconds are ignored in favor of the on-chain value.
+    pub updated: DateTime<Utc>,
+}
+
+impl SequenceRecord {
+    /// Decide which sequence number to hand out, and the record to store in place of `existing`.
+    ///
+    /// Uses the larger of the on-chain sequence and the existing record, ignoring
+    /// records older than 30 seconds. Broadcast records are moved past the
+    /// returned sequence number so that no other caller receives it, while
+    /// simulations never consume a sequence number.
+    ///
+    /// [SequenceStore::reserve] implementations should call this while holding
+    /// their lock or transaction.
+    pub fn reserve(
+        existing: Option<SequenceRecord>,
+        kind: SequenceKind,
+        on_chain: u64,
+        now: DateTime<Utc>,
+    ) -> (u64, SequenceRecord) {
+        let tracked = existing
+            .filter(|record| now.signed_duration_since(record.updated).num_seconds() <= 30)
+            .map_or(on_chain, |record| record.sequence);
+        let sequence = tracked.max(on_chain);
+        let next = match kind {
+            SequenceKind::Simulate => sequence,
+            SequenceKind::Broadcast => sequence + 1,
+        };
+        (
+            sequence,
+            SequenceRecord {
+                sequence: next,
+                updated: now,
+            },
+        )
+    }
+
+    /// Decide what to store in place of `existing` when giving back `sequence`.
+    ///
+    /// Used when a transaction using a reserved sequence number definitely
+    /// never reached the chain. If `sequence` was the most recent reservation,
+    /// the record moves back to it. If later sequence numbers were already
+    /// handed out, those transactions will fail on the gap as well, so the
+    /// record is dropped (returning [None]) and the next reservation falls
+    /// back to the on-chain value. Simulation records are never changed.
+    pub fn release(
+        existing: Option<SequenceRecord>,
+        kind: SequenceKind,
+        sequence: u64,
+    ) -> Option<SequenceRecord> {
+        match (kind, existing) {
+            (SequenceKind::Broadcast, Some(record)) if record.sequence == sequence + 1 => {
+                Some(SequenceRecord {
+                    sequence,
+                    updated: record.updated,
+                })
+            }
+            (SequenceKind::Broadcast, Some(record)) if record.sequence > sequence + 1 => None,
+            (_, existing) => existing,
+        }
+    }
+
+    /// Decide what to store in place of `existing` after a broadcast using `sequence` was accepted.
+    ///
+    /// Used when a transaction was broadcast with a different sequence number
+    /// than the one reserved, e.g. after retrying a sequence mismatch. The
+    /// record moves past `sequence`, unless other reservations already took
+    /// it further. Simulation records are never changed.
+    pub fn record_used(
+        existing: Option<SequenceRecord>,
+        kind: SequenceKind,
+        sequence: u64,
+        now: DateTime<Utc>,
+    ) -> Option<SequenceRecord> {
+        match kind {
+            SequenceKind::Simulate => existing,
+            SequenceKind::Broadcast => {
+                let next = existing
+                    .filter(|record| now.signed_duration_since(record.updated).num_seconds() <= 30)
+                    .map_or(sequence + 1, |record| record.sequence.max(sequence + 1));
+                Some(SequenceRecord {
+                    sequence: next,
+                    updated: now,
+                })
+            }
+        }
+    }
+}
+
+/// Storage backend for locally tracked sequence numbers.
+///
+/// By default, [crate::Cosmos] keeps sequence numbers in memory, which only
+/// helps when a single process is using a wallet. Providing a shared
+/// implementation (e.g. backed by Redis or a database) via
+/// [crate::CosmosBuilder::set_sequence_store] allows multiple processes
+/// using the same wallet to avoid sequence collisions.
+#[async_trait]
+pub trait SequenceStore: std::fmt::Debug + Send + Sync {
+    /// Get the tracked sequence for the given address, if present.
+    async fn get(
+        &self,
+        kind: SequenceKind,
+        address: Address,
+    ) -> Result<Option<SequenceRecord>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Atomically reserve a sequence number for the given address.
+    ///
+    /// Implementations must read the existing record, pass it to
+    /// [SequenceRecord::reserve] and store the resulting record as a single
+    /// atomic operation (e.g. a database transaction or a compare-and-swap
+    /// loop), returning the reserved sequence number. Otherwise processes
+    /// sharing the store can be handed the same sequence number.
+    async fn reserve(
+        &self,
+        kind: SequenceKind,
+        address: Address,
+        on_chain: u64,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Give back a sequence number from [SequenceStore::reserve] whose transaction never reached the chain.
+    ///
+    /// Like [SequenceStore::reserve], implementations must atomically pass the
+    /// existing record to [SequenceRecord::release] and store the result,
+    /// removing the record if it returns [None].
+    async fn release(
+        &self,
+        kind: SequenceKind,
+        address: Address,
+        sequence: u64,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Record that a broadcast using `sequence` was accepted by the chain.
+    ///
+    /// Like [SequenceStore::reserve], implementations must atomically pass the
+    /// existing record to [SequenceRecord::record_used] and store the result,
+    /// removing the record if it returns [None].
+    async fn record_used(
+        &self,
+        kind: SequenceKind,
+        address: Address,
+        sequence: u64,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// The default, in-process [SequenceStore].
+#[derive(Debug, Default)]
+pub struct InMemorySequenceStore {
+    sequences: RwLock<HashMap<(SequenceKind, Address), SequenceRecord>>,
+}
+
+#[async_trait]
+impl SequenceStore for InMemorySequenceStore {
+    async fn get(
+        &self,
+        kind: SequenceKind,
+        address: Address,
+    ) -> Result<Option<SequenceRecord>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(self.sequences.read().get(&(kind, address)).copied())
+    }
+
+    async fn reserve(
+        &self,
+        kind: SequenceKind,
+        address: Address,
+        on_chain: u64,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        let mut sequences = self.sequences.write();
+        let existing = sequences.get(&(kind, address)).copied();
+        let (sequence, record) = SequenceRecord::reserve(existing, kind, on_chain, Utc::now());
+        sequences.insert((kind, address), record);
+        Ok(sequence)
+    }
+
+    async fn release(
+        &self,
+        kind: SequenceKind,
+        address: Address,
+        sequence: u64,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut sequences = self.sequences.write();
+        let existing = sequences.get(&(kind, address)).copied();
+        match SequenceRecord::release(existing, kind, sequence) {
+            Some(record) => sequences.insert((kind, address), record),
+            None => sequences.remove(&(kind, address)),
+        };
+        Ok(())
+    }
+
+    async fn record_used(
+        &self,
+        kind: SequenceKind,
+        address: Address,
+        sequence: u64,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut sequences = self.sequences.write();
+        let existing = sequences.get(&(kind, address)).copied();
+        match SequenceRecord::record_used(existing, kind, sequence, Utc::now()) {
+            Some(record) => sequences.insert((kind, address), record),
+            None => sequences.remove(&(kind, address)),
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reserve_hands_out_unique_sequences() {
+        let store = InMemorySequenceStore::default();
+        let address: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        let kind = SequenceKind::Broadcast;
+        assert_eq!(store.reserve(kind, address, 5).await.unwrap(), 5);
+        assert_eq!(store.reserve(kind, address, 5).await.unwrap(), 6);
+        assert_eq!(store.reserve(kind, address, 10).await.unwrap(), 10);
+        assert_eq!(
+            store
+                .reserve(SequenceKind::Simulate, address, 3)
+                .await
+                .unwrap(),
+            3
+        );
+        assert_eq!(
+            store
+                .reserve(SequenceKind::Simulate, address, 3)
+                .await
+                .unwrap(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn release_closes_the_gap() {
+        let store = InMemorySequenceStore::default();
+        let address: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        let kind = SequenceKind::Broadcast;
+        assert_eq!(store.reserve(kind, address, 5).await.unwrap(), 5);
+        store.release(kind, address, 5).await.unwrap();
+        assert_eq!(store.reserve(kind, address, 5).await.unwrap(), 5);
+
+        // A later reservation is outstanding, fall back to the chain
+        assert_eq!(store.reserve(kind, address, 5).await.unwrap(), 6);
+        store.release(kind, address, 5).await.unwrap();
+        assert_eq!(store.get(kind, address).await.unwrap(), None);
+        assert_eq!(store.reserve(kind, address, 5).await.unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn record_used_moves_past_corrected_sequence() {
+        let store = InMemorySequenceStore::default();
+        let address: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        let kind = SequenceKind::Broadcast;
+        assert_eq!(store.reserve(kind, address, 5).await.unwrap(), 5);
+
+        // The chain expected 8, and the retry with 8 went through
+        store.record_used(kind, address, 8).await.unwrap();
+        assert_eq!(store.reserve(kind, address, 5).await.unwrap(), 9);
+
+        // Never moves the record backwards
+        store.record_used(kind, address, 3).await.unwrap();
+        assert_eq!(store.reserve(kind, address, 5).await.unwrap(), 10);
+
+        store
+            .record_used(SequenceKind::Simulate, address, 20)
+            .await
+            .unwrap();
+        assert_eq!(
+            store.get(SequenceKind::Simulate, address).await.unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn reserve_ignores_stale_records() {
+        let now = Utc::now();
+        let stale = SequenceRecord {
+            sequence: 50,
+            updated: now - chrono::Duration::seconds(31),
+        };
+        let (sequence, record) =
+            SequenceRecord::reserve(Some(stale), SequenceKind::Broadcast, 7, now);
+        assert_eq!(sequence, 7);
+        assert_eq!(record.sequence, 8);
+    }
+}
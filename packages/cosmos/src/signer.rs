@@ -0,0 +1,52 @@
+use tonic::async_trait;
+
+use crate::{wallet::WalletPublicKey, Address, HasAddress, Wallet};
+
+/// Anything capable of signing transactions on behalf of an address.
+///
+/// [Wallet] is the standard implementation, holding the private key in
+/// memory. Alternative implementations can delegate signing to hardware
+/// wallets, KMS/HSM services, or remote signing services.
+#[async_trait]
+pub trait Signer: Send + Sync {
+    /// The address which will sign transactions.
+    fn address(&self) -> Address;
+
+    /// The public key used to verify signatures from this signer.
+    fn public_key(&self) -> WalletPublicKey;
+
+    /// Sign the given serialized `SignDoc`.
+    ///
+    /// Must return the 64-byte compact signature expected by the chain.
+    async fn sign(&self, sign_doc: &[u8]) -> Result<Vec<u8>, crate::Error>;
+}
+
+#[async_trait]
+impl Signer for Wallet {
+    fn address(&self) -> Address {
+        self.get_address()
+    }
+
+    fn public_key(&self) -> WalletPublicKey {
+        self.public_key.clone()
+    }
+
+    async fn sign(&self, sign_doc: &[u8]) -> Result<Vec<u8>, crate::Error> {
+        Ok(self.sign_raw(sign_doc).to_vec())
+    }
+}
+
+#[async_trait]
+impl<T: Signer + ?Sized> Signer for &T {
+    fn address(&self) -> Address {
+        (**self).address()
+    }
+
+    fn public_key(&self) -> WalletPublicKey {
+        (**self).public_key()
+    }
+
+    async fn sign(&self, sign_doc: &[u8]) -> Result<Vec<u8>, crate::Error> {
+        (**self).sign(sign_doc).await
+    }
+}
@@ -0,0 +1,93 @@
+use std::{collections::HashMap, time::Duration};
+
+use sha2::{Digest, Sha256};
+use tokio::time::Instant;
+
+use crate::{Address, HasAddress, TxBuilder};
+
+/// Number of entries above which expired entries are pruned on insert.
+const PRUNE_THRESHOLD: usize = 1024;
+
+/// Identifies a set of messages signed by a set of signers.
+pub(crate) type SimulationKey = [u8; 32];
+
+/// Remembers simulated gas for repeated transactions.
+///
+/// See [crate::CosmosBuilder::set_simulation_cache_duration].
+#[derive(Default)]
+pub(crate) struct SimulationCache {
+    entries: HashMap<SimulationKey, (Instant, u64)>,
+}
+
+impl SimulationCache {
+    pub(crate) fn get(&self, key: &SimulationKey, duration: Duration) -> Option<u64> {
+        self.entries
+            .get(key)
+            .filter(|(when, _)| when.elapsed() < duration)
+            .map(|(_, gas_used)| *gas_used)
+    }
+
+    pub(crate) fn insert(&mut self, key: SimulationKey, gas_used: u64, duration: Duration) {
+        if self.entries.len() >= PRUNE_THRESHOLD {
+            self.entries
+                .retain(|_, (when, _)| when.elapsed() < duration);
+        }
+        self.entries.insert(key, (Instant::now(), gas_used));
+    }
+
+    pub(crate) fn remove(&mut self, key: &SimulationKey) {
+        self.entries.remove(key);
+    }
+}
+
+impl TxBuilder {
+    /// Hash of the messages, memo and signers, used as the simulation cache key.
+    pub(crate) fn simulation_key(&self, signers: &[Address]) -> SimulationKey {
+        let mut hasher = Sha256::new();
+        for msg in &self.messages {
+            let msg = msg.get_protobuf();
+            hasher.update((msg.type_url.len() as u64).to_be_bytes());
+            hasher.update(msg.type_url.as_bytes());
+            hasher.update((msg.value.len() as u64).to_be_bytes());
+            hasher.update(&msg.value);
+        }
+        hasher.update(self.memo.as_deref().unwrap_or_default().as_bytes());
+        for signer in signers {
+            hasher.update(signer.get_address_string().as_bytes());
+            hasher.update([0]);
+        }
+        hasher.finalize().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TxMessage;
+
+    use super::*;
+
+    #[test]
+    fn key_depends_on_messages_and_signers() {
+        let signer: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        let mut builder = TxBuilder::default();
+        builder.add_message(TxMessage::new("/a", vec![1, 2], "a"));
+        let key = builder.simulation_key(&[signer]);
+        assert_eq!(key, builder.clone().simulation_key(&[signer]));
+        assert_ne!(key, builder.simulation_key(&[]));
+        builder.add_message(TxMessage::new("/a", vec![1, 2], "a"));
+        assert_ne!(key, builder.simulation_key(&[signer]));
+    }
+
+    #[test]
+    fn expiry() {
+        let duration = Duration::from_secs(60);
+        let mut cache = SimulationCache::default();
+        cache.insert([0; 32], 100_000, duration);
+        assert_eq!(cache.get(&[0; 32], duration), Some(100_000));
+        assert_eq!(cache.get(&[0; 32], Duration::ZERO), None);
+        cache.remove(&[0; 32]);
+        assert_eq!(cache.get(&[0; 32], duration), None);
+    }
+}
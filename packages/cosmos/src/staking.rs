@@ -0,0 +1,185 @@
+use cosmos_sdk_proto::{
+    cosmos::{
+        distribution::v1beta1::{
+            QueryDelegationTotalRewardsRequest, QueryDelegationTotalRewardsResponse,
+        },
+        staking::v1beta1::{
+            BondStatus, DelegationResponse, MsgBeginRedelegate, MsgDelegate, MsgUndelegate,
+            QueryDelegatorDelegationsRequest, QueryDelegatorUnbondingDelegationsRequest,
+            QueryValidatorRequest, QueryValidatorsRequest, UnbondingDelegation, Validator,
+        },
+    },
+    traits::Message,
+};
+
+use crate::{
+    client::next_page_request, error::Action, messages::PrettyCoins, Cosmos, HasAddress, TxMessage,
+};
+
+impl From<MsgDelegate> for TxMessage {
+    fn from(msg: MsgDelegate) -> Self {
+        TxMessage::new(
+            "/cosmos.staking.v1beta1.MsgDelegate",
+            msg.encode_to_vec(),
+            format!(
+                "{} delegating {} to {}",
+                msg.delegator_address,
+                PrettyCoins(msg.amount.as_slice()),
+                msg.validator_address
+            ),
+        )
+    }
+}
+
+impl From<MsgUndelegate> for TxMessage {
+    fn from(msg: MsgUndelegate) -> Self {
+        TxMessage::new(
+            "/cosmos.staking.v1beta1.MsgUndelegate",
+            msg.encode_to_vec(),
+            format!(
+                "{} undelegating {} from {}",
+                msg.delegator_address,
+                PrettyCoins(msg.amount.as_slice()),
+                msg.validator_address
+            ),
+        )
+    }
+}
+
+impl From<MsgBeginRedelegate> for TxMessage {
+    fn from(msg: MsgBeginRedelegate) -> Self {
+        TxMessage::new(
+            "/cosmos.staking.v1beta1.MsgBeginRedelegate",
+            msg.encode_to_vec(),
+            format!(
+                "{} redelegating {} from {} to {}",
+                msg.delegator_address,
+                PrettyCoins(msg.amount.as_slice()),
+                msg.validator_src_address,
+                msg.validator_dst_address
+            ),
+        )
+    }
+}
+
+impl Cosmos {
+    /// Get all delegations made by the given delegator.
+    pub async fn delegations(
+        &self,
+        delegator: impl HasAddress,
+    ) -> Result<Vec<DelegationResponse>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    QueryDelegatorDelegationsRequest {
+                        delegator_addr: delegator.get_address_string(),
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryDelegations(delegator.get_address()),
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.delegation_responses);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get all in-progress unbonding delegations for the given delegator.
+    pub async fn unbonding_delegations(
+        &self,
+        delegator: impl HasAddress,
+    ) -> Result<Vec<UnbondingDelegation>, crate::Error> {
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    QueryDelegatorUnbondingDelegationsRequest {
+                        delegator_addr: delegator.get_address_string(),
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryUnbondingDelegations(delegator.get_address()),
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.unbonding_responses);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get the pending staking rewards for the given delegator, per validator and in total.
+    pub async fn delegation_rewards(
+        &self,
+        delegator: impl HasAddress,
+    ) -> Result<QueryDelegationTotalRewardsResponse, crate::Error> {
+        Ok(self
+            .perform_query(
+                QueryDelegationTotalRewardsRequest {
+                    delegator_address: delegator.get_address_string(),
+                },
+                Action::QueryDelegationRewards(delegator.get_address()),
+            )
+            .run()
+            .await?
+            .into_inner())
+    }
+
+    /// List validators, optionally filtered by bond status.
+    pub async fn validators(
+        &self,
+        status: Option<BondStatus>,
+    ) -> Result<Vec<Validator>, crate::Error> {
+        let status = status
+            .map(|status| status.as_str_name().to_owned())
+            .unwrap_or_default();
+        let mut res = vec![];
+        let mut pagination = None;
+        loop {
+            let mut page = self
+                .perform_query(
+                    QueryValidatorsRequest {
+                        status: status.clone(),
+                        pagination: pagination.take(),
+                    },
+                    Action::QueryValidators,
+                )
+                .run()
+                .await?
+                .into_inner();
+            res.append(&mut page.validators);
+            match next_page_request(page.pagination) {
+                Some(next) => pagination = Some(next),
+                None => break Ok(res),
+            }
+        }
+    }
+
+    /// Get information on a single validator by its operator address.
+    pub async fn validator(&self, validator: impl HasAddress) -> Result<Validator, crate::Error> {
+        let action = Action::QueryValidator(validator.get_address());
+        self.perform_query(
+            QueryValidatorRequest {
+                validator_addr: validator.get_address_string(),
+            },
+            action.clone(),
+        )
+        .run()
+        .await?
+        .into_inner()
+        .validator
+        .ok_or_else(|| crate::Error::InvalidChainResponse {
+            message: "No validator returned".to_owned(),
+            action: action.into(),
+        })
+    }
+}
@@ -0,0 +1,207 @@
+use std::path::Path;
+
+use cosmos_sdk_proto::cosmwasm::wasm::v1::{MsgExecuteContract, MsgMigrateContract};
+use cosmwasm_std::Binary;
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{Address, Contract, HasAddress, TxMessage};
+
+/// A dump of all raw storage belonging to a contract.
+///
+/// Produced by [Contract::export_state] and stored on disk as JSON, with keys
+/// and values base64-encoded. Use [ContractStateSnapshot::restore_messages] to
+/// load the state into a contract on another (usually local) chain.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ContractStateSnapshot {
+    /// Address of the contract the state was exported from.
+    pub address: Address,
+    /// Code ID the contract was running when exported.
+    pub code_id: u64,
+    /// Raw key/value pairs, in storage order.
+    pub entries: Vec<StateEntry>,
+}
+
+/// A single raw key/value pair from contract storage.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StateEntry {
+    /// Raw storage key
+    pub key: Binary,
+    /// Raw storage value
+    pub value: Binary,
+}
+
+/// Message understood by the state loader contract, see [ContractStateSnapshot::restore_messages].
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum LoaderMsg<'a> {
+    ImportState { entries: &'a [StateEntry] },
+}
+
+impl Contract {
+    /// Export all raw contract storage to the given file as JSON.
+    ///
+    /// The full state is held in memory before being written, so this is
+    /// best suited to contracts with a modest amount of storage.
+    pub async fn export_state(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<ContractStateSnapshot, crate::Error> {
+        let info = self.info().await?;
+        let entries = self
+            .all_state(None)
+            .map_ok(|model| StateEntry {
+                key: model.key.into(),
+                value: model.value.into(),
+            })
+            .try_collect()
+            .await?;
+        let snapshot = ContractStateSnapshot {
+            address: self.get_address(),
+            code_id: info.code_id,
+            entries,
+        };
+        snapshot.save(path)?;
+        Ok(snapshot)
+    }
+}
+
+impl ContractStateSnapshot {
+    /// Load a snapshot previously written by [Contract::export_state].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, crate::Error> {
+        let path = path.as_ref();
+        let bytes = fs_err::read(path).map_err(|source| crate::Error::StateSnapshotIo {
+            path: path.to_owned(),
+            source,
+        })?;
+        serde_json::from_slice(&bytes).map_err(|source| crate::Error::StateSnapshotParse {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    /// Write the snapshot to the given file as JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), crate::Error> {
+        let path = path.as_ref();
+        let bytes = serde_json::to_vec_pretty(self)?;
+        fs_err::write(path, bytes).map_err(|source| crate::Error::StateSnapshotIo {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    /// Generate the messages needed to restore this state into `contract`.
+    ///
+    /// Raw storage can only be written by contract code, so restoring relies
+    /// on a state loader contract uploaded as `loader_code_id`. The loader
+    /// must accept `{"import_state":{"entries":[{"key":..,"value":..}]}}`
+    /// (base64 keys and values) both as its migrate and execute message,
+    /// writing each entry verbatim to storage.
+    ///
+    /// The generated messages are, in order:
+    ///
+    /// 1. A migration to the loader carrying the first chunk of entries.
+    /// 2. One execute message per remaining chunk of `chunk_size` entries.
+    /// 3. A migration back to `restore_code_id` with `migrate_msg`.
+    ///
+    /// `sender` must be the admin of `contract`. The messages can be split
+    /// across multiple transactions if they do not fit in a single one.
+    pub fn restore_messages(
+        &self,
+        sender: impl HasAddress,
+        contract: impl HasAddress,
+        loader_code_id: u64,
+        restore_code_id: u64,
+        migrate_msg: impl Serialize,
+        chunk_size: usize,
+    ) -> Result<Vec<TxMessage>, crate::Error> {
+        let sender = sender.get_address_string();
+        let contract = contract.get_address_string();
+        let mut chunks = self.entries.chunks(chunk_size.max(1));
+        let first = chunks.next().unwrap_or_default();
+
+        let mut msgs = vec![MsgMigrateContract {
+            sender: sender.clone(),
+            contract: contract.clone(),
+            code_id: loader_code_id,
+            msg: serde_json::to_vec(&LoaderMsg::ImportState { entries: first })?,
+        }
+        .into()];
+        for entries in chunks {
+            msgs.push(
+                MsgExecuteContract {
+                    sender: sender.clone(),
+                    contract: contract.clone(),
+                    msg: serde_json::to_vec(&LoaderMsg::ImportState { entries })?,
+                    funds: vec![],
+                }
+                .into(),
+            );
+        }
+        msgs.push(
+            MsgMigrateContract {
+                sender,
+                contract,
+                code_id: restore_code_id,
+                msg: serde_json::to_vec(&migrate_msg)?,
+            }
+            .into(),
+        );
+        Ok(msgs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restore_messages_chunking() {
+        let address: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        let snapshot = ContractStateSnapshot {
+            address,
+            code_id: 5,
+            entries: (0u8..5)
+                .map(|i| StateEntry {
+                    key: vec![i].into(),
+                    value: vec![i, i].into(),
+                })
+                .collect(),
+        };
+        let msgs = snapshot
+            .restore_messages(address, address, 7, 5, serde_json::json!({}), 2)
+            .unwrap();
+        // Migrate with 2 entries, execute 2, execute 1, migrate back
+        assert_eq!(msgs.len(), 4);
+
+        let empty = ContractStateSnapshot {
+            entries: vec![],
+            ..snapshot
+        };
+        let msgs = empty
+            .restore_messages(address, address, 7, 5, serde_json::json!({}), 2)
+            .unwrap();
+        assert_eq!(msgs.len(), 2);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let snapshot = ContractStateSnapshot {
+            address: "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+                .parse()
+                .unwrap(),
+            code_id: 1,
+            entries: vec![StateEntry {
+                key: b"config".to_vec().into(),
+                value: b"{}".to_vec().into(),
+            }],
+        };
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert!(json.contains("\"Y29uZmln\""));
+        let parsed: ContractStateSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, snapshot);
+    }
+}
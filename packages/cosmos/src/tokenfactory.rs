@@ -1,10 +1,10 @@
 use crate::{
     address::{AddressHrp, HasAddressHrp},
     error::{Action, TokenFactoryError},
-    Cosmos, HasAddress, TxBuilder, TxMessage, Wallet,
+    Address, Cosmos, HasAddress, TxBuilder, TxMessage, TxResponseExt, Wallet,
 };
 use cosmos_sdk_proto::cosmos::{
-    bank::v1beta1::Metadata,
+    bank::v1beta1::{DenomUnit, Metadata},
     base::{abci::v1beta1::TxResponse, v1beta1::Coin},
 };
 
@@ -15,18 +15,37 @@ pub struct TokenFactory {
     kind: TokenFactoryKind,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum TokenFactoryKind {
+    /// The Osmosis module, also used by Neutron and many other chains.
     Osmosis,
+    Injective,
     Sei,
 }
 
+impl TokenFactoryKind {
+    const ALL: [TokenFactoryKind; 3] = [
+        TokenFactoryKind::Osmosis,
+        TokenFactoryKind::Injective,
+        TokenFactoryKind::Sei,
+    ];
+
+    /// Find the tokenfactory variant whose messages are registered on the chain.
+    fn detect(type_urls: &[String]) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| {
+            let create = type_url(*kind, "MsgCreateDenom");
+            type_urls.iter().any(|registered| *registered == create)
+        })
+    }
+}
+
 impl TryFrom<AddressHrp> for TokenFactoryKind {
     type Error = TokenFactoryError;
 
     fn try_from(hrp: AddressHrp) -> Result<Self, TokenFactoryError> {
         match hrp.as_str() {
-            "osmo" => Ok(TokenFactoryKind::Osmosis),
+            "osmo" | "neutron" => Ok(TokenFactoryKind::Osmosis),
+            "inj" => Ok(TokenFactoryKind::Injective),
             "sei" => Ok(TokenFactoryKind::Sei),
             _ => Err(TokenFactoryError::Unsupported { hrp }),
         }
@@ -35,11 +54,29 @@ impl TryFrom<AddressHrp> for TokenFactoryKind {
 
 impl Cosmos {
     /// Generate a new [TokenFactory] for this connection, if supported for this chain.
+    ///
+    /// The tokenfactory variant is chosen from the chain's address HRP. Use
+    /// [Self::detect_token_factory] for chains not known here.
     pub fn token_factory(self) -> Result<TokenFactory, TokenFactoryError> {
         self.get_address_hrp()
             .try_into()
             .map(|kind| TokenFactory { client: self, kind })
     }
+
+    /// Like [Self::token_factory], but probes the chain for a supported tokenfactory module.
+    ///
+    /// The tokenfactory variant is detected by checking which `MsgCreateDenom`
+    /// the chain has registered, see [Cosmos::get_message_type_urls].
+    pub async fn detect_token_factory(self) -> Result<TokenFactory, crate::Error> {
+        let type_urls = self.get_message_type_urls().await?;
+        match TokenFactoryKind::detect(&type_urls) {
+            Some(kind) => Ok(TokenFactory { client: self, kind }),
+            None => Err(TokenFactoryError::Unsupported {
+                hrp: self.get_address_hrp(),
+            }
+            .into()),
+        }
+    }
 }
 
 impl TokenFactory {
@@ -60,20 +97,15 @@ impl TokenFactory {
         let res = txbuilder.sign_and_broadcast(&self.client, wallet).await?;
 
         let denom = res
-            .events
-            .iter()
-            .find_map(|evt| {
-                if evt.r#type == "create_denom" {
-                    evt.attributes.iter().find_map(|attr| {
-                        if attr.key == "new_token_denom" {
-                            Some(attr.value.clone())
-                        } else {
-                            None
-                        }
-                    })
-                } else {
-                    None
-                }
+            .normalized_events()
+            .into_iter()
+            .find_map(|evt| match evt.r#type.as_str() {
+                "create_denom" => evt.get("new_token_denom").map(ToOwned::to_owned),
+                // Typed event, attribute values are JSON encoded
+                "injective.tokenfactory.v1beta1.EventCreateTFDenom" => evt
+                    .get("denom")
+                    .map(|denom| denom.trim_matches('"').to_owned()),
+                _ => None,
             })
             .ok_or_else(|| crate::Error::InvalidChainResponse {
                 message: "Failed to get denom from tx events".to_owned(),
@@ -114,16 +146,168 @@ impl TokenFactory {
     ) -> Result<TxResponse, crate::Error> {
         let msg = MsgBurn {
             sender: wallet.get_address_string(),
-            burn_from_address: wallet.get_address_string(),
+            burn_from_address: if self.supports_burn_from() {
+                wallet.get_address_string()
+            } else {
+                // Leave unset so it isn't rejected as an unknown field
+                String::new()
+            },
+            amount: Some(Coin {
+                denom,
+                amount: amount.to_string(),
+            }),
+        }
+        .into_typed_message(self.kind);
+        wallet.broadcast_message(&self.client, msg).await
+    }
+
+    /// Does this chain allow burning tokens from addresses other than the admin?
+    pub fn supports_burn_from(&self) -> bool {
+        match self.kind {
+            TokenFactoryKind::Osmosis => true,
+            // MsgBurn on these chains has no burn_from_address field
+            TokenFactoryKind::Injective | TokenFactoryKind::Sei => false,
+        }
+    }
+
+    /// Burn tokens for the given denom from another address.
+    ///
+    /// Only the token admin can do this. Fails without broadcasting if
+    /// [Self::supports_burn_from] is false.
+    pub async fn burn_from(
+        &self,
+        wallet: &Wallet,
+        denom: String,
+        amount: u128,
+        burn_from: Address,
+    ) -> Result<TxResponse, crate::Error> {
+        if !self.supports_burn_from() {
+            return Err(self.unsupported("burning from other addresses").into());
+        }
+        let msg = MsgBurn {
+            sender: wallet.get_address_string(),
+            burn_from_address: burn_from.get_address_string(),
+            amount: Some(Coin {
+                denom,
+                amount: amount.to_string(),
+            }),
+        }
+        .into_typed_message(self.kind);
+        wallet.broadcast_message(&self.client, msg).await
+    }
+
+    /// Does this chain allow the token admin to force transfers?
+    ///
+    /// Checks that the chain has `MsgForceTransfer` registered. Chains may
+    /// still reject the message if force transfers are disabled by governance.
+    pub async fn supports_force_transfer(&self) -> Result<bool, crate::Error> {
+        self.client
+            .supports_message_type(&type_url(self.kind, "MsgForceTransfer"))
+            .await
+    }
+
+    /// Move tokens for the given denom between two addresses, without the owner's signature.
+    ///
+    /// Only the token admin can do this. Fails without broadcasting if
+    /// [Self::supports_force_transfer] is false.
+    pub async fn force_transfer(
+        &self,
+        wallet: &Wallet,
+        denom: String,
+        amount: u128,
+        from: Address,
+        to: Address,
+    ) -> Result<TxResponse, crate::Error> {
+        if !self.supports_force_transfer().await? {
+            return Err(self.unsupported("force transfers").into());
+        }
+        let msg = MsgForceTransfer {
+            sender: wallet.get_address_string(),
             amount: Some(Coin {
                 denom,
                 amount: amount.to_string(),
             }),
+            transfer_from_address: from.get_address_string(),
+            transfer_to_address: to.get_address_string(),
         }
         .into_typed_message(self.kind);
         wallet.broadcast_message(&self.client, msg).await
     }
 
+    fn unsupported(&self, operation: &'static str) -> TokenFactoryError {
+        TokenFactoryError::UnsupportedOperation {
+            hrp: self.client.get_address_hrp(),
+            operation,
+        }
+    }
+
+    /// Get all denoms created by the given address.
+    pub async fn denoms_by_creator(
+        &self,
+        creator: impl HasAddress,
+    ) -> Result<Vec<String>, crate::Error> {
+        self.query::<_, QueryDenomsFromCreatorResponse>(
+            "DenomsFromCreator",
+            QueryDenomsFromCreatorRequest {
+                creator: creator.get_address_string(),
+            },
+        )
+        .await
+        .map(|res| res.denoms)
+    }
+
+    /// Get the authority metadata, i.e. the current admin, for the given denom.
+    pub async fn denom_authority_metadata(
+        &self,
+        denom: impl Into<String>,
+    ) -> Result<DenomAuthorityMetadata, crate::Error> {
+        let denom = denom.into();
+        let res = match self.kind {
+            // Injective identifies the denom by its creator and subdenom
+            TokenFactoryKind::Injective => {
+                let (creator, sub_denom) = denom
+                    .strip_prefix("factory/")
+                    .and_then(|rest| rest.split_once('/'))
+                    .ok_or_else(|| TokenFactoryError::InvalidDenom {
+                        denom: denom.clone(),
+                    })?;
+                self.query::<_, QueryDenomAuthorityMetadataResponse>(
+                    "DenomAuthorityMetadata",
+                    InjectiveQueryDenomAuthorityMetadataRequest {
+                        creator: creator.to_owned(),
+                        sub_denom: sub_denom.to_owned(),
+                    },
+                )
+                .await?
+            }
+            TokenFactoryKind::Osmosis | TokenFactoryKind::Sei => {
+                self.query::<_, QueryDenomAuthorityMetadataResponse>(
+                    "DenomAuthorityMetadata",
+                    QueryDenomAuthorityMetadataRequest { denom },
+                )
+                .await?
+            }
+        };
+        Ok(res.authority_metadata.unwrap_or_default())
+    }
+
+    /// Query the tokenfactory module by ABCI, since the gRPC service name differs between chains.
+    async fn query<Req: prost::Message, Res: prost::Message + Default>(
+        &self,
+        method: &str,
+        req: Req,
+    ) -> Result<Res, crate::Error> {
+        let path = type_url(self.kind, &format!("Query/{method}"));
+        let res = self
+            .client
+            .abci_query(path.clone(), req.encode_to_vec(), 0, false)
+            .await?;
+        Res::decode(res.value.as_slice()).map_err(|err| crate::Error::InvalidChainResponse {
+            message: format!("Unable to decode {method} response: {err}"),
+            action: Action::AbciQuery { path, height: 0 }.into(),
+        })
+    }
+
     /// Change the admin for the given token.
     pub async fn change_admin(
         &self,
@@ -139,11 +323,68 @@ impl TokenFactory {
         .into_typed_message(self.kind);
         wallet.broadcast_message(&self.client, msg).await
     }
+
+    /// Set the bank metadata for the given token, so wallets can display it properly.
+    ///
+    /// The metadata's base denom must be a token administered by the wallet.
+    /// See [Self::denom_metadata] for building simple metadata.
+    pub async fn set_denom_metadata(
+        &self,
+        wallet: &Wallet,
+        metadata: Metadata,
+    ) -> Result<TxResponse, crate::Error> {
+        let msg = MsgSetDenomMetadata {
+            sender: wallet.get_address_string(),
+            metadata: Some(metadata),
+        }
+        .into_typed_message(self.kind);
+        wallet.broadcast_message(&self.client, msg).await
+    }
+
+    /// Build metadata for a token with a single display unit.
+    ///
+    /// `exponent` is the number of decimal places between the base denom and
+    /// the display unit, e.g. 6 if one `display` is 1,000,000 `base`. The name
+    /// defaults to the symbol; modify the returned value for anything more
+    /// elaborate.
+    pub fn denom_metadata(
+        base: impl Into<String>,
+        display: impl Into<String>,
+        symbol: impl Into<String>,
+        exponent: u32,
+    ) -> Metadata {
+        let base = base.into();
+        let display = display.into();
+        let symbol = symbol.into();
+        let mut denom_units = vec![DenomUnit {
+            denom: base.clone(),
+            exponent: 0,
+            aliases: vec![],
+        }];
+        if display != base {
+            denom_units.push(DenomUnit {
+                denom: display.clone(),
+                exponent,
+                aliases: vec![],
+            });
+        }
+        Metadata {
+            description: String::new(),
+            denom_units,
+            base,
+            display,
+            name: symbol.clone(),
+            symbol,
+            uri: String::new(),
+            uri_hash: String::new(),
+        }
+    }
 }
 
 fn type_url(kind: TokenFactoryKind, s: &str) -> String {
     match kind {
         TokenFactoryKind::Osmosis => format!("/osmosis.tokenfactory.v1beta1.{s}"),
+        TokenFactoryKind::Injective => format!("/injective.tokenfactory.v1beta1.{s}"),
         TokenFactoryKind::Sei => format!("/seiprotocol.seichain.tokenfactory.{s}"),
     }
 }
@@ -187,7 +428,24 @@ impl MsgBurn {
         into_typed_message(
             kind,
             "MsgBurn",
-            format!("tokenfactory: {} burning {:?}", self.sender, self.amount),
+            format!(
+                "tokenfactory: {} burning {:?} from {}",
+                self.sender, self.amount, self.burn_from_address
+            ),
+            self,
+        )
+    }
+}
+
+impl MsgForceTransfer {
+    fn into_typed_message(self, kind: TokenFactoryKind) -> TxMessage {
+        into_typed_message(
+            kind,
+            "MsgForceTransfer",
+            format!(
+                "tokenfactory: {} force transferring {:?} from {} to {}",
+                self.sender, self.amount, self.transfer_from_address, self.transfer_to_address
+            ),
             self,
         )
     }
@@ -207,6 +465,23 @@ impl MsgChangeAdmin {
     }
 }
 
+impl MsgSetDenomMetadata {
+    fn into_typed_message(self, kind: TokenFactoryKind) -> TxMessage {
+        into_typed_message(
+            kind,
+            "MsgSetDenomMetadata",
+            format!(
+                "tokenfactory: {} setting metadata on {}",
+                self.sender,
+                self.metadata
+                    .as_ref()
+                    .map_or("", |metadata| metadata.base.as_str())
+            ),
+            self,
+        )
+    }
+}
+
 //////////// GENERATED, COPY/PASTED, AND PATCHED FROM PROST-BUILD ////////////////
 
 /// MsgCreateDenom defines the message structure for the CreateDenom gRPC service
@@ -330,3 +605,116 @@ pub struct MsgForceTransfer {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgForceTransferResponse {}
+/// DenomAuthorityMetadata specifies metadata for addresses that have specific
+/// capabilities over a token factory denom. Right now there is only one Admin
+/// permission, but is planned to be extended to the future.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DenomAuthorityMetadata {
+    /// Can be empty for no admin, or a valid osmosis address
+    #[prost(string, tag = "1")]
+    pub admin: ::prost::alloc::string::String,
+}
+/// QueryDenomAuthorityMetadataRequest defines the request structure for the
+/// DenomAuthorityMetadata gRPC query.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryDenomAuthorityMetadataRequest {
+    #[prost(string, tag = "1")]
+    pub denom: ::prost::alloc::string::String,
+}
+/// Injective's variant of QueryDenomAuthorityMetadataRequest, which takes
+/// the creator and subdenom separately.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InjectiveQueryDenomAuthorityMetadataRequest {
+    #[prost(string, tag = "1")]
+    pub creator: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub sub_denom: ::prost::alloc::string::String,
+}
+/// QueryDenomAuthorityMetadataResponse defines the response structure for the
+/// DenomAuthorityMetadata gRPC query.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryDenomAuthorityMetadataResponse {
+    #[prost(message, optional, tag = "1")]
+    pub authority_metadata: ::core::option::Option<DenomAuthorityMetadata>,
+}
+/// QueryDenomsFromCreatorRequest defines the request structure for the
+/// DenomsFromCreator gRPC query.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryDenomsFromCreatorRequest {
+    #[prost(string, tag = "1")]
+    pub creator: ::prost::alloc::string::String,
+}
+/// QueryDenomsFromCreatorRequest defines the response structure for the
+/// DenomsFromCreator gRPC query.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryDenomsFromCreatorResponse {
+    #[prost(string, repeated, tag = "1")]
+    pub denoms: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_kind() {
+        let type_urls = |urls: &[&str]| urls.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            TokenFactoryKind::detect(&type_urls(&[
+                "/cosmos.bank.v1beta1.MsgSend",
+                "/osmosis.tokenfactory.v1beta1.MsgCreateDenom"
+            ])),
+            Some(TokenFactoryKind::Osmosis)
+        );
+        assert_eq!(
+            TokenFactoryKind::detect(&type_urls(&[
+                "/injective.tokenfactory.v1beta1.MsgCreateDenom"
+            ])),
+            Some(TokenFactoryKind::Injective)
+        );
+        assert_eq!(
+            TokenFactoryKind::detect(&type_urls(&[
+                "/seiprotocol.seichain.tokenfactory.MsgCreateDenom"
+            ])),
+            Some(TokenFactoryKind::Sei)
+        );
+        assert_eq!(
+            TokenFactoryKind::detect(&type_urls(&["/cosmos.bank.v1beta1.MsgSend"])),
+            None
+        );
+    }
+
+    #[test]
+    fn kind_from_hrp() {
+        let kind = |hrp| TokenFactoryKind::try_from(AddressHrp::from_static(hrp)).ok();
+        assert_eq!(kind("osmo"), Some(TokenFactoryKind::Osmosis));
+        assert_eq!(kind("neutron"), Some(TokenFactoryKind::Osmosis));
+        assert_eq!(kind("inj"), Some(TokenFactoryKind::Injective));
+        assert_eq!(kind("sei"), Some(TokenFactoryKind::Sei));
+        assert_eq!(kind("cosmos"), None);
+    }
+
+    #[test]
+    fn simple_denom_metadata() {
+        let metadata = TokenFactory::denom_metadata("factory/osmo1abc/utoken", "token", "TKN", 6);
+        assert_eq!(metadata.display, "token");
+        assert_eq!(metadata.name, "TKN");
+        assert_eq!(
+            metadata
+                .denom_units
+                .iter()
+                .map(|unit| (unit.denom.as_str(), unit.exponent))
+                .collect::<Vec<_>>(),
+            [("factory/osmo1abc/utoken", 0), ("token", 6)]
+        );
+
+        let metadata = TokenFactory::denom_metadata("utoken", "utoken", "TKN", 0);
+        assert_eq!(metadata.denom_units.len(), 1);
+    }
+}
@@ -0,0 +1,299 @@
+//! Typed representations of the standard events emitted by the Cosmos SDK,
+//! CosmWasm, and IBC modules.
+use crate::{error::ChainParseError, Address, Coin, NormalizedEvent};
+
+/// A `transfer` event from the bank module.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferEvent {
+    /// Address sending the funds
+    pub sender: Address,
+    /// Address receiving the funds
+    pub recipient: Address,
+    /// Coins transferred
+    pub amount: Vec<Coin>,
+}
+
+/// A `wasm` or `wasm-<custom>` event emitted by a contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmEvent {
+    /// Full event type, e.g. `wasm` or `wasm-swap`
+    pub r#type: String,
+    /// Contract which emitted the event
+    pub contract_address: Address,
+    /// Remaining attributes, in the order they were emitted
+    pub attributes: Vec<(String, String)>,
+}
+
+impl WasmEvent {
+    /// Find the first attribute value with the given key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A `message` event, describing the message being processed.
+///
+/// Different modules emit different subsets of these attributes, so all of them are optional.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct MessageEvent {
+    /// Message type URL or legacy action name
+    pub action: Option<String>,
+    /// Signer of the message
+    pub sender: Option<String>,
+    /// Module handling the message
+    pub module: Option<String>,
+    /// Index of the message within the transaction
+    pub msg_index: Option<u32>,
+}
+
+/// An `instantiate` event from the wasm module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstantiateEvent {
+    /// Address of the new contract
+    pub contract_address: Address,
+    /// Code ID the contract was instantiated from
+    pub code_id: u64,
+}
+
+/// A `write_acknowledgement` event from IBC core, emitted when receiving a packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WriteAcknowledgementEvent {
+    /// Packet sequence number
+    pub sequence: u64,
+    /// Port on the sending chain
+    pub src_port: String,
+    /// Channel on the sending chain
+    pub src_channel: String,
+    /// Port on this chain
+    pub dst_port: String,
+    /// Channel on this chain
+    pub dst_channel: String,
+    /// Packet data, usually JSON
+    pub data: String,
+    /// Acknowledgement written for the packet, usually JSON
+    pub ack: String,
+    /// Connection the channel runs over, if reported
+    pub connection: Option<String>,
+}
+
+struct EventParser<'a> {
+    event: &'a NormalizedEvent,
+    txhash: &'a str,
+}
+
+impl EventParser<'_> {
+    fn error(&self, message: String) -> ChainParseError {
+        ChainParseError::InvalidStandardEvent {
+            event_type: self.event.r#type.clone(),
+            txhash: self.txhash.to_owned(),
+            message,
+        }
+    }
+
+    fn require(&self, key: &str) -> Result<&str, ChainParseError> {
+        self.event
+            .get(key)
+            .ok_or_else(|| self.error(format!("missing attribute {key}")))
+    }
+
+    fn address(&self, key: &str, value: &str) -> Result<Address, ChainParseError> {
+        value
+            .parse()
+            .map_err(|e| self.error(format!("invalid address {value:?} for {key}: {e}")))
+    }
+
+    fn number<T: std::str::FromStr>(&self, key: &str, value: &str) -> Result<T, ChainParseError>
+    where
+        T::Err: std::fmt::Display,
+    {
+        value
+            .parse()
+            .map_err(|e| self.error(format!("invalid number {value:?} for {key}: {e}")))
+    }
+
+    fn coins(&self, value: &str) -> Result<Vec<Coin>, ChainParseError> {
+        parse_coins(value).ok_or_else(|| self.error(format!("invalid coins {value:?}")))
+    }
+}
+
+/// Parse a comma-separated list of coins, e.g. `5uosmo,3ibc/ABCD`.
+///
+/// Denoms are not validated, since chains are far more liberal than [crate::ParsedCoin].
+fn parse_coins(s: &str) -> Option<Vec<Coin>> {
+    if s.is_empty() {
+        return Some(vec![]);
+    }
+    s.split(',')
+        .map(|coin| {
+            let coin = coin.trim();
+            let idx = coin.find(|c: char| !c.is_ascii_digit())?;
+            if idx == 0 {
+                return None;
+            }
+            let (amount, denom) = coin.split_at(idx);
+            Some(Coin {
+                denom: denom.to_owned(),
+                amount: amount.to_owned(),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn transfer_events(
+    event: &NormalizedEvent,
+    txhash: &str,
+) -> Result<Vec<TransferEvent>, ChainParseError> {
+    let parser = EventParser { event, txhash };
+    // Older SDK versions flatten multiple transfers into a single event with repeated keys.
+    let senders = event.get_all("sender").collect::<Vec<_>>();
+    let recipients = event.get_all("recipient").collect::<Vec<_>>();
+    let amounts = event.get_all("amount").collect::<Vec<_>>();
+    if senders.len() != recipients.len() || senders.len() != amounts.len() {
+        return Err(parser.error(format!(
+            "mismatched attribute counts: {} senders, {} recipients, {} amounts",
+            senders.len(),
+            recipients.len(),
+            amounts.len()
+        )));
+    }
+    senders
+        .into_iter()
+        .zip(recipients)
+        .zip(amounts)
+        .map(|((sender, recipient), amount)| {
+            Ok(TransferEvent {
+                sender: parser.address("sender", sender)?,
+                recipient: parser.address("recipient", recipient)?,
+                amount: parser.coins(amount)?,
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn wasm_event(
+    event: &NormalizedEvent,
+    txhash: &str,
+) -> Result<WasmEvent, ChainParseError> {
+    let parser = EventParser { event, txhash };
+    let contract_address =
+        parser.address("_contract_address", parser.require("_contract_address")?)?;
+    Ok(WasmEvent {
+        r#type: event.r#type.clone(),
+        contract_address,
+        attributes: event
+            .attributes
+            .iter()
+            .filter(|(key, _)| key != "_contract_address")
+            .cloned()
+            .collect(),
+    })
+}
+
+pub(crate) fn message_event(
+    event: &NormalizedEvent,
+    txhash: &str,
+) -> Result<MessageEvent, ChainParseError> {
+    let parser = EventParser { event, txhash };
+    Ok(MessageEvent {
+        action: event.get("action").map(ToOwned::to_owned),
+        sender: event.get("sender").map(ToOwned::to_owned),
+        module: event.get("module").map(ToOwned::to_owned),
+        msg_index: event
+            .get("msg_index")
+            .map(|value| parser.number("msg_index", value))
+            .transpose()?,
+    })
+}
+
+pub(crate) fn instantiate_event(
+    event: &NormalizedEvent,
+    txhash: &str,
+) -> Result<InstantiateEvent, ChainParseError> {
+    let parser = EventParser { event, txhash };
+    Ok(InstantiateEvent {
+        contract_address: parser
+            .address("_contract_address", parser.require("_contract_address")?)?,
+        code_id: parser.number("code_id", parser.require("code_id")?)?,
+    })
+}
+
+pub(crate) fn write_acknowledgement_event(
+    event: &NormalizedEvent,
+    txhash: &str,
+) -> Result<WriteAcknowledgementEvent, ChainParseError> {
+    let parser = EventParser { event, txhash };
+    Ok(WriteAcknowledgementEvent {
+        sequence: parser.number("packet_sequence", parser.require("packet_sequence")?)?,
+        src_port: parser.require("packet_src_port")?.to_owned(),
+        src_channel: parser.require("packet_src_channel")?.to_owned(),
+        dst_port: parser.require("packet_dst_port")?.to_owned(),
+        dst_channel: parser.require("packet_dst_channel")?.to_owned(),
+        data: parser.require("packet_data")?.to_owned(),
+        ack: parser.require("packet_ack")?.to_owned(),
+        connection: event.get("packet_connection").map(ToOwned::to_owned),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(r#type: &str, attrs: &[(&str, &str)]) -> NormalizedEvent {
+        NormalizedEvent {
+            r#type: r#type.to_owned(),
+            attributes: attrs
+                .iter()
+                .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+                .collect(),
+        }
+    }
+
+    const ADDR1: &str = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5";
+
+    #[test]
+    fn flattened_transfers() {
+        let event = event(
+            "transfer",
+            &[
+                ("recipient", ADDR1),
+                ("sender", ADDR1),
+                ("amount", "5uosmo,3ibc/ABCD"),
+                ("recipient", ADDR1),
+                ("sender", ADDR1),
+                ("amount", "7uosmo"),
+            ],
+        );
+        let transfers = transfer_events(&event, "HASH").unwrap();
+        assert_eq!(transfers.len(), 2);
+        assert_eq!(transfers[0].amount.len(), 2);
+        assert_eq!(transfers[0].amount[1].denom, "ibc/ABCD");
+        assert_eq!(transfers[1].amount[0].amount, "7");
+    }
+
+    #[test]
+    fn missing_attribute() {
+        let event = event("instantiate", &[("_contract_address", ADDR1)]);
+        assert!(instantiate_event(&event, "HASH").is_err());
+    }
+
+    #[test]
+    fn wasm_attributes() {
+        let event = event(
+            "wasm-swap",
+            &[("_contract_address", ADDR1), ("offer", "5uosmo")],
+        );
+        let wasm = wasm_event(&event, "HASH").unwrap();
+        assert_eq!(wasm.get("offer"), Some("5uosmo"));
+        assert_eq!(wasm.get("_contract_address"), None);
+    }
+
+    #[test]
+    fn coins() {
+        assert_eq!(parse_coins("").unwrap(), vec![]);
+        assert!(parse_coins("uosmo").is_none());
+        assert!(parse_coins("5").is_none());
+    }
+}
@@ -0,0 +1,341 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::{sync::Mutex, task::JoinSet};
+
+use crate::{
+    client::{get_expected_sequence_inner, SignerAccount},
+    error::{Action, CosmosSdkError, TransactionStage},
+    Address, Cosmos, CosmosTxResponse, Error, Signer, TxBuilder,
+};
+
+/// How many sequence mismatches a single transaction will recover from
+/// before giving up.
+const SEQUENCE_RECOVERY_ATTEMPTS: usize = 10;
+
+/// How long to wait before rebroadcasting a transaction which arrived at a
+/// node ahead of transactions with earlier sequence numbers.
+const SEQUENCE_RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// Broadcast many transactions from a single wallet with consecutive sequence numbers.
+///
+/// The pipeline owns the sequence counter for its wallet. Each transaction
+/// reserves the next sequence number, after which signing, broadcasting and
+/// waiting all happen concurrently. This allows many transactions from the
+/// same account to land in a single block.
+///
+/// Concurrent broadcasts can reach a node out of order. A transaction which
+/// arrives ahead of its predecessors is rebroadcast after a short delay. If a
+/// transaction fails without reaching the chain, its sequence number is handed
+/// to the next submission, and in-flight transactions that were counting on it
+/// reserve a new one.
+///
+/// Cloning a [TxPipeline] is cheap and shares the same sequence counter.
+/// Avoid using the same wallet outside of the pipeline while it is active.
+#[derive(Clone)]
+pub struct TxPipeline {
+    inner: Arc<TxPipelineInner>,
+}
+
+struct TxPipelineInner {
+    cosmos: Cosmos,
+    signer: Box<dyn Signer>,
+    sequence: Mutex<SequenceState>,
+}
+
+#[derive(Default)]
+struct SequenceState {
+    /// Account number and next sequence number, loaded from the chain if [None].
+    next: Option<(u64, u64)>,
+    /// Incremented every time the counter is reset, so that stale
+    /// reservations do not reset it again.
+    generation: u64,
+}
+
+/// A sequence number handed out by [SequenceState::reserve].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Reservation {
+    account_number: u64,
+    sequence: u64,
+    generation: u64,
+}
+
+impl SequenceState {
+    /// Take the next sequence number, if it is known.
+    fn reserve(&mut self) -> Option<Reservation> {
+        let (account_number, sequence) = self.next?;
+        self.next = Some((account_number, sequence + 1));
+        Some(Reservation {
+            account_number,
+            sequence,
+            generation: self.generation,
+        })
+    }
+
+    /// Set the next sequence number loaded from the chain.
+    fn load(&mut self, account_number: u64, sequence: u64) {
+        self.next = Some((account_number, sequence));
+    }
+
+    /// Reset the counter after a broadcast with the given reservation failed.
+    ///
+    /// The counter continues from `next_sequence` if given, or is reloaded
+    /// from the chain otherwise. Does nothing if the counter has already been
+    /// reset since the reservation was made.
+    fn reset(&mut self, reservation: &Reservation, next_sequence: Option<u64>) {
+        if reservation.generation != self.generation {
+            return;
+        }
+        self.generation += 1;
+        self.next = next_sequence.map(|sequence| (reservation.account_number, sequence));
+    }
+
+    fn is_current(&self, reservation: &Reservation) -> bool {
+        reservation.generation == self.generation
+    }
+}
+
+impl TxPipeline {
+    /// Create a new pipeline for the given signer.
+    pub fn new(cosmos: Cosmos, signer: impl Signer + 'static) -> Self {
+        TxPipeline {
+            inner: Arc::new(TxPipelineInner {
+                cosmos,
+                signer: Box::new(signer),
+                sequence: Mutex::default(),
+            }),
+        }
+    }
+
+    /// Address of the wallet used by this pipeline.
+    pub fn address(&self) -> Address {
+        self.inner.signer.address()
+    }
+
+    /// Simulate, sign, broadcast, and wait for a single transaction.
+    ///
+    /// Multiple calls may be run concurrently, each transaction will be
+    /// assigned the next available sequence number.
+    pub async fn submit(&self, txbuilder: TxBuilder) -> Result<CosmosTxResponse, Error> {
+        let cosmos = &self.inner.cosmos;
+        let simres = txbuilder.simulate(cosmos, &[self.address()]).await?;
+        let gas_to_request = (simres.gas_used as f64 * cosmos.gas_multiplier.get_current()) as u64;
+
+        let (grpc_url, tx, res) = self
+            .broadcast_next(&txbuilder, &simres.body, gas_to_request)
+            .await?;
+
+        let action = Action::WaitForBroadcast {
+            txbuilder: txbuilder.clone(),
+            txhash: res.txhash.clone(),
+        };
+        let res = cosmos
+            .wait_for_transaction_with_action(res.txhash, Some(action.clone()))
+            .await
+            .and_then(|(_, _, res)| {
+                if !txbuilder.skip_code_check && res.code != 0 {
+                    Err(Error::TransactionFailed {
+                        code: CosmosSdkError::from_code(res.code, &res.codespace),
+                        txhash: res.txhash.clone(),
+                        raw_log: res.raw_log,
+                        action: action.into(),
+                        grpc_url,
+                        stage: TransactionStage::Wait,
+                    })
+                } else {
+                    Ok(CosmosTxResponse { response: res, tx })
+                }
+            });
+        cosmos.gas_multiplier.update(&res);
+        res
+    }
+
+    /// Submit all of the given transactions concurrently.
+    ///
+    /// Results are returned in the same order as the inputs.
+    pub async fn submit_all(
+        &self,
+        txbuilders: impl IntoIterator<Item = TxBuilder>,
+    ) -> Vec<Result<CosmosTxResponse, Error>> {
+        let mut set = JoinSet::new();
+        let mut count = 0;
+        for (idx, txbuilder) in txbuilders.into_iter().enumerate() {
+            let pipeline = self.clone();
+            set.spawn(async move { (idx, pipeline.submit(txbuilder).await) });
+            count += 1;
+        }
+
+        let mut results = Vec::with_capacity(count);
+        while let Some(res) = set.join_next().await {
+            match res {
+                Ok(pair) => results.push(pair),
+                Err(e) => std::panic::resume_unwind(e.into_panic()),
+            }
+        }
+        results.sort_by_key(|(idx, _)| *idx);
+        results.into_iter().map(|(_, res)| res).collect()
+    }
+
+    /// Forget the current sequence number, forcing a refresh from the chain
+    /// on the next submission.
+    pub async fn reset_sequence(&self) {
+        let mut state = self.inner.sequence.lock().await;
+        state.generation += 1;
+        state.next = None;
+    }
+
+    /// Reserve the next sequence number, loading it from the chain if needed.
+    async fn reserve_sequence(&self) -> Result<Reservation, Error> {
+        let mut state = self.inner.sequence.lock().await;
+        if state.next.is_none() {
+            let base_account = self.inner.cosmos.get_base_account(self.address()).await?;
+            state.load(base_account.account_number, base_account.sequence);
+        }
+        Ok(state
+            .reserve()
+            .expect("reserve_sequence: sequence was just loaded"))
+    }
+
+    /// Sign and broadcast with a newly reserved sequence number.
+    ///
+    /// The sequence lock is only held while reserving, so broadcasts from
+    /// concurrent submissions run in parallel.
+    async fn broadcast_next(
+        &self,
+        txbuilder: &TxBuilder,
+        body: &cosmos_sdk_proto::cosmos::tx::v1beta1::TxBody,
+        gas_to_request: u64,
+    ) -> Result<
+        (
+            Arc<String>,
+            cosmos_sdk_proto::cosmos::tx::v1beta1::Tx,
+            cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse,
+        ),
+        Error,
+    > {
+        let cosmos = &self.inner.cosmos;
+        let price_attempts = cosmos.get_cosmos_builder().gas_price_retry_attempts();
+        let mut sequence_attempts = 0;
+        let mut price_attempt = 0;
+        let mut reservation = self.reserve_sequence().await?;
+        loop {
+            let amount = cosmos.gas_to_coins(gas_to_request, price_attempt).await;
+            let res = txbuilder
+                .sign_and_broadcast_no_wait(
+                    cosmos,
+                    &[SignerAccount {
+                        signer: &*self.inner.signer,
+                        account_number: reservation.account_number,
+                        sequence: reservation.sequence,
+                    }],
+                    body,
+                    gas_to_request,
+                    amount,
+                )
+                .await;
+            match res {
+                Ok(res) => break Ok(res),
+                Err(Error::TransactionFailed {
+                    code: CosmosSdkError::InsufficientFee,
+                    ..
+                }) if price_attempt < price_attempts => {
+                    price_attempt += 1;
+                }
+                Err(e)
+                    if is_sequence_mismatch(&e)
+                        && sequence_attempts < SEQUENCE_RECOVERY_ATTEMPTS =>
+                {
+                    sequence_attempts += 1;
+                    let expected = expected_sequence(&e);
+                    let mut state = self.inner.sequence.lock().await;
+                    match expected {
+                        // An earlier transaction from this pipeline hasn't
+                        // reached the node yet, give it a moment.
+                        Some(expected)
+                            if expected < reservation.sequence
+                                && state.is_current(&reservation) =>
+                        {
+                            drop(state);
+                            tracing::debug!(
+                                "Sequence {} from {} arrived early, node expects {expected}",
+                                reservation.sequence,
+                                self.address()
+                            );
+                            tokio::time::sleep(SEQUENCE_RETRY_DELAY).await;
+                        }
+                        _ => {
+                            tracing::warn!(
+                                "Sequence mismatch in transaction pipeline for {}, reserving a new sequence: {e}",
+                                self.address()
+                            );
+                            state.reset(&reservation, expected);
+                            drop(state);
+                            reservation = self.reserve_sequence().await?;
+                        }
+                    }
+                }
+                Err(e) => {
+                    if e.is_definite_tx_failure() {
+                        // Our sequence number was never used, let the next
+                        // submission take it.
+                        self.inner
+                            .sequence
+                            .lock()
+                            .await
+                            .reset(&reservation, Some(reservation.sequence));
+                    }
+                    break Err(e);
+                }
+            }
+        }
+    }
+}
+
+fn is_sequence_mismatch(e: &Error) -> bool {
+    matches!(
+        e,
+        Error::TransactionFailed {
+            code: CosmosSdkError::IncorrectAccountSequence,
+            ..
+        }
+    ) || e.get_sequence_mismatch_status().is_some()
+}
+
+/// The sequence number the node expected, if the error reports it.
+fn expected_sequence(e: &Error) -> Option<u64> {
+    match e {
+        Error::TransactionFailed { raw_log, .. } => get_expected_sequence_inner(raw_log),
+        _ => e
+            .get_sequence_mismatch_status()
+            .and_then(|status| get_expected_sequence_inner(status.message())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserve_and_reset() {
+        let mut state = SequenceState::default();
+        assert_eq!(state.reserve(), None);
+
+        state.load(7, 10);
+        let first = state.reserve().unwrap();
+        let second = state.reserve().unwrap();
+        assert_eq!((first.account_number, first.sequence), (7, 10));
+        assert_eq!(second.sequence, 11);
+
+        // The first transaction failed, the next submission reuses its sequence
+        state.reset(&first, Some(first.sequence));
+        assert!(!state.is_current(&second));
+        // A stale reservation does not reset the counter again
+        state.reset(&second, Some(20));
+        let third = state.reserve().unwrap();
+        assert_eq!(third.sequence, 10);
+        assert!(state.is_current(&third));
+
+        // Without an expected sequence the counter is reloaded from the chain
+        state.reset(&third, None);
+        assert_eq!(state.reserve(), None);
+    }
+}
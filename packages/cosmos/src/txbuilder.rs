@@ -1,20 +1,117 @@
 use std::{fmt::Display, sync::Arc};
 
 use cosmos_sdk_proto::{
-    cosmos::base::v1beta1::Coin,
+    cosmos::{
+        base::v1beta1::Coin,
+        distribution::v1beta1::{MsgSetWithdrawAddress, MsgWithdrawDelegatorReward},
+        gov::v1::{MsgDeposit, MsgSubmitProposal, MsgVote, VoteOption},
+    },
     cosmwasm::wasm::v1::{MsgExecuteContract, MsgMigrateContract, MsgUpdateAdmin},
 };
 
-use crate::HasAddress;
+use crate::{error::TxBuilderError, messages::MsgExecHelper, Address, HasAddress};
 
 /// Transaction builder
 ///
 /// This is the core interface for producing, simulating, and broadcasting transactions.
-#[derive(Default, Clone, Debug)]
+///
+/// Can be serialized to and from JSON, e.g. to review a transaction before signing it.
+#[derive(Default, Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct TxBuilder {
+    #[serde(
+        serialize_with = "serialize_messages",
+        deserialize_with = "deserialize_messages"
+    )]
     pub(crate) messages: Vec<Arc<TxMessage>>,
     pub(crate) memo: Option<String>,
     pub(crate) skip_code_check: bool,
+    pub(crate) broadcast_mode: BroadcastMode,
+    pub(crate) fee_denom: Option<String>,
+    pub(crate) fee_granter: Option<Address>,
+    pub(crate) tip: Option<TxTip>,
+    pub(crate) priority_fee_multiplier: Option<f64>,
+    pub(crate) unordered_timeout: Option<std::time::Duration>,
+    pub(crate) timeout_height: Option<u64>,
+    #[serde(with = "coins_serde::option")]
+    pub(crate) max_fee: Option<Coin>,
+}
+
+fn serialize_messages<S: serde::Serializer>(
+    messages: &[Arc<TxMessage>],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(messages.iter().map(|msg| &**msg))
+}
+
+fn deserialize_messages<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Arc<TxMessage>>, D::Error> {
+    let messages: Vec<TxMessage> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(messages.into_iter().map(Arc::new).collect())
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct TxTip {
+    #[serde(with = "coins_serde")]
+    pub(crate) amount: Vec<Coin>,
+    pub(crate) tipper: Address,
+}
+
+mod coins_serde {
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct CoinJson {
+        denom: String,
+        amount: String,
+    }
+
+    pub(super) fn serialize<S: serde::Serializer>(
+        coins: &[Coin],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(coins.iter().map(|Coin { denom, amount }| CoinJson {
+            denom: denom.clone(),
+            amount: amount.clone(),
+        }))
+    }
+
+    pub(super) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Coin>, D::Error> {
+        let coins: Vec<CoinJson> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(coins
+            .into_iter()
+            .map(|CoinJson { denom, amount }| Coin { denom, amount })
+            .collect())
+    }
+
+    pub(super) mod option {
+        use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+
+        use super::CoinJson;
+
+        pub(crate) fn serialize<S: serde::Serializer>(
+            coin: &Option<Coin>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serde::Serialize::serialize(
+                &coin.as_ref().map(|Coin { denom, amount }| CoinJson {
+                    denom: denom.clone(),
+                    amount: amount.clone(),
+                }),
+                serializer,
+            )
+        }
+
+        pub(crate) fn deserialize<'de, D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Coin>, D::Error> {
+            let coin: Option<CoinJson> = serde::Deserialize::deserialize(deserializer)?;
+            Ok(coin.map(|CoinJson { denom, amount }| Coin { denom, amount }))
+        }
+    }
 }
 
 impl Display for TxBuilder {
@@ -113,6 +210,111 @@ impl TxBuilder {
         }))
     }
 
+    /// Add a message to withdraw the staking rewards from a single validator.
+    pub fn add_withdraw_delegator_reward(
+        &mut self,
+        delegator: impl HasAddress,
+        validator: impl HasAddress,
+    ) -> &mut Self {
+        self.add_message(MsgWithdrawDelegatorReward {
+            delegator_address: delegator.get_address_string(),
+            validator_address: validator.get_address_string(),
+        })
+    }
+
+    /// Add a message to change the address which receives staking rewards.
+    pub fn add_set_withdraw_address(
+        &mut self,
+        delegator: impl HasAddress,
+        withdraw_address: impl HasAddress,
+    ) -> &mut Self {
+        self.add_message(MsgSetWithdrawAddress {
+            delegator_address: delegator.get_address_string(),
+            withdraw_address: withdraw_address.get_address_string(),
+        })
+    }
+
+    /// Add a governance vote.
+    ///
+    /// Uses the `cosmos.gov.v1` message, available since Cosmos SDK 0.46. For
+    /// older chains, add a `cosmos.gov.v1beta1` message directly.
+    pub fn add_vote(
+        &mut self,
+        voter: impl HasAddress,
+        proposal_id: u64,
+        option: VoteOption,
+    ) -> &mut Self {
+        self.add_message(MsgVote {
+            proposal_id,
+            voter: voter.get_address_string(),
+            option: option as i32,
+            metadata: String::new(),
+        })
+    }
+
+    /// Add a deposit on a governance proposal.
+    pub fn add_deposit(
+        &mut self,
+        depositor: impl HasAddress,
+        proposal_id: u64,
+        amount: Vec<Coin>,
+    ) -> &mut Self {
+        self.add_message(MsgDeposit {
+            proposal_id,
+            depositor: depositor.get_address_string(),
+            amount,
+        })
+    }
+
+    /// Add a governance proposal which executes the given messages if it passes.
+    ///
+    /// The messages are typically signed by the governance module account,
+    /// e.g. parameter updates or community pool spends.
+    pub fn add_submit_proposal(
+        &mut self,
+        proposer: impl HasAddress,
+        messages: Vec<TxMessage>,
+        initial_deposit: Vec<Coin>,
+        title: impl Into<String>,
+        summary: impl Into<String>,
+    ) -> &mut Self {
+        self.add_message(MsgSubmitProposal {
+            messages: messages
+                .into_iter()
+                .map(|msg| msg.into_protobuf().0)
+                .collect(),
+            initial_deposit,
+            proposer: proposer.get_address_string(),
+            metadata: String::new(),
+            title: title.into(),
+            summary: summary.into(),
+            expedited: false,
+        })
+    }
+
+    /// Wrap all messages added so far in a single authz `MsgExec`.
+    ///
+    /// The wrapped messages will be executed by `grantee` on behalf of the
+    /// granter, which must be the signer of the original messages. Messages
+    /// added after this call are not wrapped.
+    pub fn wrap_authz_exec(&mut self, grantee: impl HasAddress) -> &mut Self {
+        let msgs = std::mem::take(&mut self.messages)
+            .into_iter()
+            .map(|msg| match Arc::try_unwrap(msg) {
+                Ok(msg) => msg,
+                Err(msg) => TxMessage::new(
+                    msg.type_url.clone(),
+                    msg.value.clone(),
+                    msg.description.clone(),
+                ),
+            })
+            .collect();
+        self.add_message(MsgExecHelper {
+            grantee: grantee.get_address(),
+            msgs,
+        })
+    }
+
     /// Set the memo field.
     pub fn set_memo(&mut self, memo: impl Into<String>) -> &mut Self {
         self.memo = Some(memo.into());
@@ -136,16 +338,360 @@ impl TxBuilder {
         self.skip_code_check = skip_code_check;
         self
     }
+
+    /// Set the [BroadcastMode] used when submitting this transaction.
+    ///
+    /// Regardless of the mode, [TxBuilder::sign_and_broadcast] waits for the
+    /// transaction to land in a block before returning.
+    pub fn set_broadcast_mode(&mut self, broadcast_mode: BroadcastMode) -> &mut Self {
+        self.broadcast_mode = broadcast_mode;
+        self
+    }
+
+    /// Get the [BroadcastMode] used when submitting this transaction.
+    ///
+    /// Default: [BroadcastMode::Sync]
+    pub fn get_broadcast_mode(&self) -> BroadcastMode {
+        self.broadcast_mode
+    }
+
+    /// Pay fees for this transaction in the given denom.
+    ///
+    /// Overrides [crate::CosmosBuilder::set_fee_denom]. See that method for details.
+    pub fn set_fee_denom(&mut self, fee_denom: impl Into<Option<String>>) -> &mut Self {
+        self.fee_denom = fee_denom.into();
+        self
+    }
+
+    /// Get the denom fees will be paid in, if overridden for this transaction.
+    ///
+    /// Default: [None], which uses [crate::CosmosBuilder::get_fee_denom]
+    pub fn get_fee_denom(&self) -> Option<&str> {
+        self.fee_denom.as_deref()
+    }
+
+    /// Pay fees for this transaction from an x/feegrant allowance granted by `granter`.
+    ///
+    /// The granter must have granted an allowance to the fee payer, normally
+    /// the first signer. Not supported for EIP-712 signed transactions.
+    pub fn set_fee_granter(&mut self, granter: impl Into<Option<Address>>) -> &mut Self {
+        self.fee_granter = granter.into();
+        self
+    }
+
+    /// Get the fee granter for this transaction, if any.
+    pub fn get_fee_granter(&self) -> Option<Address> {
+        self.fee_granter
+    }
+
+    pub(crate) fn fee_granter_string(&self) -> String {
+        self.fee_granter
+            .map(|granter| granter.get_address_string())
+            .unwrap_or_default()
+    }
+
+    /// Set the tip paid by `tipper` with this transaction.
+    ///
+    /// This uses the `tip` field of `AuthInfo`, which is only supported by
+    /// Cosmos SDK 0.46 and was removed in later versions. For chains which
+    /// order transactions by fee (such as those using Skip's block-sdk), use
+    /// [Self::set_priority_fee_multiplier] instead.
+    ///
+    /// Returns an error if any coin has an empty denom or an amount which is
+    /// not a non-negative integer.
+    pub fn set_tip(
+        &mut self,
+        amount: Vec<Coin>,
+        tipper: impl HasAddress,
+    ) -> Result<&mut Self, TxBuilderError> {
+        for coin in &amount {
+            if coin.denom.is_empty() {
+                return Err(TxBuilderError::EmptyTipDenom {
+                    amount: coin.amount.clone(),
+                });
+            }
+            coin.amount
+                .parse::<u128>()
+                .map_err(|source| TxBuilderError::InvalidTipAmount {
+                    denom: coin.denom.clone(),
+                    amount: coin.amount.clone(),
+                    source,
+                })?;
+        }
+        self.tip = Some(TxTip {
+            amount,
+            tipper: tipper.get_address(),
+        });
+        Ok(self)
+    }
+
+    /// Remove the tip set by [Self::set_tip].
+    pub fn clear_tip(&mut self) -> &mut Self {
+        self.tip = None;
+        self
+    }
+
+    /// Multiply the fee paid for this transaction to increase its priority.
+    ///
+    /// Chains with fee-based priority (such as those using Skip's block-sdk)
+    /// order transactions in the mempool by gas price, so paying more than
+    /// the minimum moves a transaction ahead of others.
+    ///
+    /// Returns an error unless `multiplier` is a positive finite number.
+    pub fn set_priority_fee_multiplier(
+        &mut self,
+        multiplier: f64,
+    ) -> Result<&mut Self, TxBuilderError> {
+        if !multiplier.is_finite() || multiplier <= 0.0 {
+            return Err(TxBuilderError::InvalidPriorityFeeMultiplier { multiplier });
+        }
+        self.priority_fee_multiplier = Some(multiplier);
+        Ok(self)
+    }
+
+    /// Get the fee multiplier used for transaction priority.
+    ///
+    /// Default: 1.0
+    pub fn get_priority_fee_multiplier(&self) -> f64 {
+        self.priority_fee_multiplier.unwrap_or(1.0)
+    }
+
+    pub(crate) fn apply_priority_fee(&self, amount: u64) -> u64 {
+        (amount as f64 * self.get_priority_fee_multiplier()).ceil() as u64
+    }
+
+    /// Submit this as an unordered transaction.
+    ///
+    /// Unordered transactions don't use sequence numbers, so many can be
+    /// submitted concurrently from the same wallet without contention.
+    /// Instead, they are valid until `timeout` after signing, which must be
+    /// below the chain's configured maximum (10 minutes by default). Only
+    /// supported on chains running Cosmos SDK 0.53 or later, see
+    /// [crate::Cosmos::supports_unordered_transactions].
+    ///
+    /// Pass [None] to go back to a regular, sequenced transaction.
+    pub fn set_unordered(&mut self, timeout: Option<std::time::Duration>) -> &mut Self {
+        self.unordered_timeout = timeout;
+        self
+    }
+
+    /// Get the unordered transaction timeout, see [Self::set_unordered].
+    ///
+    /// Default: [None]
+    pub fn get_unordered(&self) -> Option<std::time::Duration> {
+        self.unordered_timeout
+    }
+
+    /// Reject this transaction if it is not included in a block by the given height.
+    ///
+    /// Pass [None] to remove the timeout.
+    pub fn set_timeout_height(&mut self, height: impl Into<Option<u64>>) -> &mut Self {
+        self.timeout_height = height.into();
+        self
+    }
+
+    /// Get the timeout height set by [Self::set_timeout_height].
+    ///
+    /// Default: [None]
+    pub fn get_timeout_height(&self) -> Option<u64> {
+        self.timeout_height
+    }
+
+    /// Refuse to sign this transaction if its fee would exceed `max_fee`.
+    ///
+    /// The fee is checked every time it is computed, so this also caps gas
+    /// price retries. A fee in a different denom than `max_fee` cannot be
+    /// compared and is always refused. Overrides
+    /// [crate::CosmosBuilder::set_max_fee], pass [None] to use that default.
+    pub fn set_max_fee(&mut self, max_fee: impl Into<Option<Coin>>) -> &mut Self {
+        self.max_fee = max_fee.into();
+        self
+    }
+
+    /// Get the maximum fee set by [Self::set_max_fee].
+    pub fn get_max_fee(&self) -> Option<&Coin> {
+        self.max_fee.as_ref()
+    }
+
+    /// Check a computed fee against the transaction or builder-level maximum.
+    pub(crate) fn check_max_fee(
+        &self,
+        cosmos: &crate::Cosmos,
+        fee: &Coin,
+        action: impl FnOnce() -> crate::error::Action,
+    ) -> Result<(), crate::Error> {
+        let Some(max_fee) = self
+            .max_fee
+            .as_ref()
+            .or_else(|| cosmos.get_cosmos_builder().get_max_fee())
+        else {
+            return Ok(());
+        };
+        if fee_within_max(fee, max_fee) {
+            Ok(())
+        } else {
+            Err(crate::Error::FeeExceedsMax {
+                fee: fee.clone(),
+                max_fee: max_fee.clone(),
+                action: action().into(),
+            })
+        }
+    }
+
+    /// Which tracked sequence to use when signing.
+    ///
+    /// Unordered transactions don't consume a sequence number, so they must
+    /// not reserve one.
+    pub(crate) fn sequence_kind(&self) -> crate::SequenceKind {
+        if self.unordered_timeout.is_some() {
+            crate::SequenceKind::Simulate
+        } else {
+            crate::SequenceKind::Broadcast
+        }
+    }
+
+    /// Sequence number to sign with, unordered transactions always use 0.
+    pub(crate) fn tx_sequence(&self, sequence: u64) -> u64 {
+        if self.unordered_timeout.is_some() {
+            0
+        } else {
+            sequence
+        }
+    }
+
+    /// Encode the transaction body, including unordered transaction fields.
+    ///
+    /// The timeout timestamp is calculated from the current time.
+    pub(crate) fn encode_tx_body(
+        &self,
+        body: &cosmos_sdk_proto::cosmos::tx::v1beta1::TxBody,
+    ) -> Vec<u8> {
+        use prost::Message;
+
+        let mut bytes = body.encode_to_vec();
+        if let Some(timeout) = self.unordered_timeout {
+            let timeout = chrono::Duration::from_std(timeout)
+                .ok()
+                .and_then(|timeout| chrono::Utc::now().checked_add_signed(timeout))
+                .unwrap_or(chrono::DateTime::<chrono::Utc>::MAX_UTC);
+            UnorderedTxBodyFields {
+                unordered: true,
+                timeout_timestamp: Some(prost_types::Timestamp {
+                    seconds: timeout.timestamp(),
+                    nanos: timeout.timestamp_subsec_nanos() as i32,
+                }),
+            }
+            .encode(&mut bytes)
+            .expect("Vec<u8> has unlimited capacity");
+        }
+        bytes
+    }
+
+    #[allow(deprecated)]
+    pub(crate) fn make_tip(&self) -> Option<cosmos_sdk_proto::cosmos::tx::v1beta1::Tip> {
+        self.tip.as_ref().map(|TxTip { amount, tipper }| {
+            cosmos_sdk_proto::cosmos::tx::v1beta1::Tip {
+                amount: amount.clone(),
+                tipper: tipper.get_address_string(),
+            }
+        })
+    }
+}
+
+/// `TxBody` fields added in Cosmos SDK 0.53, which aren't available in our protobuf definitions.
+///
+/// Protobuf messages can be concatenated, so these are appended to the encoded `TxBody`.
+#[derive(Clone, PartialEq, prost::Message)]
+struct UnorderedTxBodyFields {
+    #[prost(bool, tag = "4")]
+    unordered: bool,
+    #[prost(message, optional, tag = "5")]
+    timeout_timestamp: Option<prost_types::Timestamp>,
+}
+
+/// How a transaction is submitted to a node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BroadcastMode {
+    /// Return after the transaction passes `CheckTx`.
+    #[default]
+    Sync,
+    /// Return immediately, without waiting for `CheckTx`.
+    Async,
+    /// Return after the transaction is included in a block.
+    ///
+    /// Removed in Cosmos SDK 0.47, only use this with chains on older versions.
+    Block,
+}
+
+impl From<BroadcastMode> for cosmos_sdk_proto::cosmos::tx::v1beta1::BroadcastMode {
+    #[allow(deprecated)]
+    fn from(mode: BroadcastMode) -> Self {
+        match mode {
+            BroadcastMode::Sync => Self::Sync,
+            BroadcastMode::Async => Self::Async,
+            BroadcastMode::Block => Self::Block,
+        }
+    }
 }
 
 /// A message to include in a transaction.
-#[derive(Debug)]
+///
+/// When serialized, the protobuf-encoded value is represented as base64. The
+/// description is included for readability, but is regenerated from the
+/// decoded value on deserialization so it always reflects what will be signed.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "TxMessageRepr")]
 pub struct TxMessage {
     type_url: String,
+    #[serde(serialize_with = "base64_bytes::serialize")]
     value: Vec<u8>,
     description: String,
 }
 
+/// Deserialized form of [TxMessage], any provided description is ignored.
+#[derive(serde::Deserialize)]
+struct TxMessageRepr {
+    type_url: String,
+    #[serde(deserialize_with = "base64_bytes::deserialize")]
+    value: Vec<u8>,
+}
+
+impl TryFrom<TxMessageRepr> for TxMessage {
+    type Error = String;
+
+    fn try_from(TxMessageRepr { type_url, value }: TxMessageRepr) -> Result<Self, String> {
+        let any = cosmos_sdk_proto::Any { type_url, value };
+        let description = crate::messages::describe_any(&any)
+            .map_err(|e| format!("Invalid protobuf value for {}: {e}", any.type_url))?;
+        Ok(TxMessage {
+            type_url: any.type_url,
+            value: any.value,
+            description,
+        })
+    }
+}
+
+mod base64_bytes {
+    use base64::Engine;
+
+    pub(super) fn serialize<S: serde::Serializer>(
+        value: &[u8],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&base64::engine::general_purpose::STANDARD.encode(value))
+    }
+
+    pub(super) fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        let value: String = serde::Deserialize::deserialize(deserializer)?;
+        base64::engine::general_purpose::STANDARD
+            .decode(value)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl TxMessage {
     /// Generate a new [TxMessage].
     pub fn new(
@@ -181,8 +727,121 @@ impl TxMessage {
         )
     }
 
+    /// Get the protobuf type URL of this message.
+    pub fn type_url(&self) -> &str {
+        &self.type_url
+    }
+
     /// Set the description, useful if the raw message is very large and makes error messages hard to parse.
     pub fn set_description(&mut self, desc: impl Into<String>) {
         self.description = desc.into();
     }
 }
+
+/// Is the fee no more than the maximum? Different denoms cannot be compared.
+fn fee_within_max(fee: &Coin, max_fee: &Coin) -> bool {
+    fee.denom == max_fee.denom
+        && matches!(
+            (fee.amount.parse::<u128>(), max_fee.amount.parse::<u128>()),
+            (Ok(fee), Ok(max_fee)) if fee <= max_fee
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use cosmos_sdk_proto::cosmos::bank::v1beta1::MsgSend;
+
+    use super::*;
+
+    #[test]
+    fn json_roundtrip() {
+        let mut builder = TxBuilder::default();
+        builder
+            .add_message(MsgSend {
+                from_address: "osmo1from".to_owned(),
+                to_address: "osmo1to".to_owned(),
+                amount: vec![Coin {
+                    denom: "uosmo".to_owned(),
+                    amount: "100".to_owned(),
+                }],
+            })
+            .set_memo("audit me")
+            .set_broadcast_mode(BroadcastMode::Async);
+
+        let json = serde_json::to_string(&builder).unwrap();
+        let parsed: TxBuilder = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.memo.as_deref(), Some("audit me"));
+        assert_eq!(parsed.broadcast_mode, BroadcastMode::Async);
+        assert_eq!(parsed.messages.len(), 1);
+        assert_eq!(
+            parsed.messages[0].get_protobuf(),
+            builder.messages[0].get_protobuf()
+        );
+        assert_eq!(parsed.to_string(), builder.to_string());
+    }
+
+    #[test]
+    fn json_description_regenerated() {
+        let msg = TxMessage::from(MsgSend {
+            from_address: "osmo1from".to_owned(),
+            to_address: "osmo1to".to_owned(),
+            amount: vec![],
+        });
+        let expected = msg.description.clone();
+        let mut json = serde_json::to_value(&msg).unwrap();
+        json["description"] = "harmless no-op".into();
+        let parsed: TxMessage = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(parsed.description, expected);
+
+        json["value"] = base64::engine::general_purpose::STANDARD
+            .encode([0xff, 0xff])
+            .into();
+        serde_json::from_value::<TxMessage>(json).unwrap_err();
+    }
+
+    #[test]
+    fn max_fee() {
+        let coin = |amount: &str, denom: &str| Coin {
+            denom: denom.to_owned(),
+            amount: amount.to_owned(),
+        };
+        let max_fee = coin("1000", "uosmo");
+        assert!(fee_within_max(&coin("999", "uosmo"), &max_fee));
+        assert!(fee_within_max(&coin("1000", "uosmo"), &max_fee));
+        assert!(!fee_within_max(&coin("1001", "uosmo"), &max_fee));
+        assert!(!fee_within_max(&coin("1", "uatom"), &max_fee));
+
+        let mut builder = TxBuilder::default();
+        builder.set_max_fee(max_fee.clone());
+        let parsed: TxBuilder =
+            serde_json::from_str(&serde_json::to_string(&builder).unwrap()).unwrap();
+        assert_eq!(parsed.get_max_fee(), Some(&max_fee));
+    }
+
+    #[test]
+    fn priority_options_validated() {
+        let mut builder = TxBuilder::default();
+        for multiplier in [f64::NAN, f64::INFINITY, -1.5, 0.0] {
+            builder.set_priority_fee_multiplier(multiplier).unwrap_err();
+        }
+        assert_eq!(builder.get_priority_fee_multiplier(), 1.0);
+        builder.set_priority_fee_multiplier(1.5).unwrap();
+        assert_eq!(builder.apply_priority_fee(101), 152);
+
+        let tipper: Address = "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+            .parse()
+            .unwrap();
+        let coin = |amount: &str, denom: &str| Coin {
+            denom: denom.to_owned(),
+            amount: amount.to_owned(),
+        };
+        builder
+            .set_tip(vec![coin("-5", "uosmo")], tipper)
+            .unwrap_err();
+        builder.set_tip(vec![coin("5", "")], tipper).unwrap_err();
+        assert!(builder.tip.is_none());
+        builder.set_tip(vec![coin("5", "uosmo")], tipper).unwrap();
+        assert!(builder.tip.is_some());
+    }
+}
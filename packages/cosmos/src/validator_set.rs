@@ -0,0 +1,100 @@
+use cosmos_sdk_proto::cosmos::base::{
+    query::v1beta1::PageRequest,
+    tendermint::v1beta1::{
+        GetLatestValidatorSetRequest, GetValidatorSetByHeightRequest, Validator,
+    },
+};
+
+use crate::{error::Action, Cosmos};
+
+/// Page size used when loading validator sets.
+///
+/// These endpoints paginate by offset and never return a next key.
+const PAGE_SIZE: u64 = 100;
+
+/// The CometBFT validator set at a specific height.
+#[derive(Clone, Debug)]
+pub struct ValidatorSet {
+    /// Height the validator set applies to
+    pub height: i64,
+    /// Validators, ordered by voting power
+    pub validators: Vec<Validator>,
+}
+
+impl ValidatorSet {
+    /// Sum of the voting power of all validators.
+    pub fn total_voting_power(&self) -> i64 {
+        self.validators.iter().map(|v| v.voting_power).sum()
+    }
+}
+
+fn page_request(offset: u64) -> Option<PageRequest> {
+    Some(PageRequest {
+        key: vec![],
+        offset,
+        limit: PAGE_SIZE,
+        count_total: true,
+        reverse: false,
+    })
+}
+
+impl Cosmos {
+    /// Get the validator set at the given height.
+    pub async fn get_validator_set(&self, height: i64) -> Result<ValidatorSet, crate::Error> {
+        self.load_validator_set(height, vec![], None).await
+    }
+
+    /// Get the validator set for the latest block.
+    ///
+    /// Only the first page is requested against the latest block; the
+    /// remainder is loaded at that same height so all pages are consistent.
+    pub async fn get_latest_validator_set(&self) -> Result<ValidatorSet, crate::Error> {
+        let res = self
+            .perform_query(
+                GetLatestValidatorSetRequest {
+                    pagination: page_request(0),
+                },
+                Action::GetLatestValidatorSet,
+            )
+            .run()
+            .await?
+            .into_inner();
+        let total = res.pagination.map(|page| page.total);
+        self.load_validator_set(res.block_height, res.validators, total)
+            .await
+    }
+
+    async fn load_validator_set(
+        &self,
+        height: i64,
+        mut validators: Vec<Validator>,
+        mut total: Option<u64>,
+    ) -> Result<ValidatorSet, crate::Error> {
+        loop {
+            if let Some(total) = total {
+                if validators.len() as u64 >= total {
+                    break;
+                }
+            }
+            let res = self
+                .perform_query(
+                    GetValidatorSetByHeightRequest {
+                        height,
+                        pagination: page_request(validators.len() as u64),
+                    },
+                    Action::GetValidatorSet(height),
+                )
+                .run()
+                .await?
+                .into_inner();
+            let count = res.validators.len() as u64;
+            validators.extend(res.validators);
+            total = res.pagination.map(|page| page.total);
+            // Without a total, the only signal of completion is a short page.
+            if count == 0 || (total.is_none() && count < PAGE_SIZE) {
+                break;
+            }
+        }
+        Ok(ValidatorSet { height, validators })
+    }
+}
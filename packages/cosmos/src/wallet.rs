@@ -1,9 +1,13 @@
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
 
-use bitcoin::bip32::{DerivationPath, Xpriv, Xpub};
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::Engine;
+use bitcoin::bip32::{ChildNumber, DerivationPath, Xpriv, Xpub};
 use bitcoin::hashes::{ripemd160, sha256, Hash};
 use bitcoin::secp256k1::ecdsa::Signature;
 use bitcoin::secp256k1::{All, Message, Secp256k1};
@@ -14,14 +18,19 @@ use once_cell::sync::{Lazy, OnceCell};
 use parking_lot::Mutex;
 use rand::Rng;
 use tiny_keccak::{Hasher, Keccak};
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::address::{AddressHrp, HasAddressHrp, PublicKeyMethod, RawAddress};
-use crate::error::WalletError;
-use crate::{Address, Cosmos, HasAddress, TxBuilder, TxMessage};
+use crate::eip712::TypedData;
+use crate::error::{AddressError, Eip712Error, QueryError, QueryErrorDetails, WalletError};
+use crate::messages::MsgMultiSendHelper;
+use crate::{Address, Cosmos, HasAddress, Signer, TxBuilder, TxMessage};
 
 /// A seed phrase for a wallet, together with an optional derivation path.
 ///
 /// The derivation path can be provided before the seed phrase to override the default derivation path.
+///
+/// The mnemonic and passphrase are zeroed out when this value is dropped.
 #[derive(Clone)]
 pub struct SeedPhrase {
     /// The mnemonic seed phrase itself, used for deriving private keys.
@@ -30,26 +39,33 @@ pub struct SeedPhrase {
     pub derivation_path: Option<Arc<DerivationPath>>,
     /// The override method for converting the public key into bytes.
     pub public_key_method: Option<PublicKeyMethod>,
+    /// Optional BIP39 passphrase (sometimes called the 25th word), used for hidden wallets.
+    ///
+    /// This is never included when storing the seed phrase in a keyring or keystore file.
+    pub bip39_passphrase: Option<String>,
 }
 
 impl SeedPhrase {
     /// Generate a random [SeedPhrase].
     pub fn random() -> SeedPhrase {
-        let mut rng = rand::thread_rng();
-        let mut entropy: [u8; 32] = [0; 32];
-        for b in &mut entropy {
-            *b = rng.gen();
-        }
+        let mut entropy = Zeroizing::new([0u8; 32]);
+        crate::rng::with_rng(|rng| {
+            for b in entropy.iter_mut() {
+                *b = rng.gen();
+            }
+        });
         SeedPhrase {
-            mnemonic: bip39::Mnemonic::from_entropy(&entropy).unwrap(),
+            mnemonic: bip39::Mnemonic::from_entropy(&*entropy).unwrap(),
             derivation_path: None,
             public_key_method: None,
+            bip39_passphrase: None,
         }
     }
 
     /// Generate the seed phrase itself.
     ///
-    /// Note that this should be considered security-sensitive content.
+    /// Note that this should be considered security-sensitive content. The
+    /// returned value is not zeroed on drop, prefer to keep it short-lived.
     pub fn phrase(&self) -> String {
         let mut phrase = String::new();
         for (idx, word) in self.mnemonic.words().enumerate() {
@@ -61,12 +77,185 @@ impl SeedPhrase {
         phrase
     }
 
+    /// The phrase prefixed with any override derivation path, as accepted by [FromStr].
+    ///
+    /// Note that this should be considered security-sensitive content.
+    pub(crate) fn storage_string(&self) -> Zeroizing<String> {
+        let phrase = Zeroizing::new(self.phrase());
+        match &self.derivation_path {
+            Some(path) => Zeroizing::new(format!("{path} {}", *phrase)),
+            None => phrase,
+        }
+    }
+
+    /// Generate a random seed phrase whose address starts and ends with the given patterns.
+    ///
+    /// The prefix is matched after the HRP and separator, e.g. a prefix of
+    /// `abc` matches `osmo1abc...`. Both patterns must only contain bech32
+    /// characters. Each additional character makes the search 32 times
+    /// slower on average. The search runs on `parallelism` threads and blocks
+    /// until a match is found.
+    pub fn generate_vanity(
+        hrp: AddressHrp,
+        prefix: &str,
+        suffix: &str,
+        parallelism: usize,
+    ) -> Result<SeedPhrase, WalletError> {
+        const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+        for pattern in [prefix, suffix] {
+            if !pattern.chars().all(|c| BECH32_CHARSET.contains(c)) {
+                return Err(WalletError::InvalidVanityPattern {
+                    pattern: pattern.to_owned(),
+                });
+            }
+        }
+
+        let prefix = format!("{hrp}1{prefix}");
+        let done = std::sync::atomic::AtomicBool::new(false);
+        let result = Mutex::new(None);
+        std::thread::scope(|scope| {
+            for _ in 0..parallelism.max(1) {
+                scope.spawn(|| {
+                    while !done.load(std::sync::atomic::Ordering::Relaxed) {
+                        let phrase = SeedPhrase::random();
+                        let res = phrase.with_hrp(hrp).map(|wallet| {
+                            let address = wallet.get_address().to_string();
+                            address.starts_with(&prefix) && address.ends_with(suffix)
+                        });
+                        let res = match res {
+                            Ok(false) => continue,
+                            Ok(true) => Ok(phrase),
+                            Err(e) => Err(e),
+                        };
+                        done.store(true, std::sync::atomic::Ordering::Relaxed);
+                        result.lock().get_or_insert(res);
+                    }
+                });
+            }
+        });
+        result
+            .into_inner()
+            .expect("Vanity search finished without a result")
+    }
+
+    /// Find funded accounts derived from this seed phrase.
+    ///
+    /// Derives addresses for indices `0..=max_index` using both the Cosmos
+    /// (coin type 118) and Ethereum (coin type 60) derivation paths, with the
+    /// default public key method for the chain. Returns the accounts which
+    /// either hold a balance or already exist on chain.
+    pub async fn scan_accounts(
+        &self,
+        cosmos: &Cosmos,
+        max_index: u64,
+    ) -> Result<Vec<DiscoveredAccount>, crate::Error> {
+        let hrp = cosmos.get_address_hrp();
+        let mut found = vec![];
+        for index in 0..=max_index {
+            for path in [
+                DerivationPathConfig::cosmos_numbered(index),
+                DerivationPathConfig::ethereum_numbered(index),
+            ] {
+                let derivation_path = path.as_derivation_path();
+                let wallet = self.derive_wallet(
+                    hrp,
+                    derivation_path.clone(),
+                    hrp.default_public_key_method(),
+                )?;
+                let address = wallet.get_address();
+                let balances = cosmos.all_balances(address).await?;
+                let exists_on_chain = match cosmos.get_base_account(address).await {
+                    Ok(_) => true,
+                    Err(crate::Error::Query(QueryError {
+                        query: QueryErrorDetails::NotFound(_),
+                        ..
+                    })) => false,
+                    Err(e) => return Err(e),
+                };
+                if exists_on_chain || !balances.is_empty() {
+                    found.push(DiscoveredAccount {
+                        index,
+                        derivation_path,
+                        address,
+                        balances,
+                        exists_on_chain,
+                    });
+                }
+            }
+        }
+        Ok(found)
+    }
+
+    /// Save this seed phrase to an encrypted keystore file.
+    ///
+    /// The encryption key is derived from the passphrase using scrypt, and
+    /// the phrase is encrypted with AES-256-GCM. Any override derivation path
+    /// is stored together with the phrase. On Unix, the file is only readable
+    /// by the current user.
+    ///
+    /// Fails if the file already exists, see [SeedPhrase::overwrite_encrypted].
+    pub fn save_encrypted(
+        &self,
+        path: impl AsRef<Path>,
+        passphrase: &str,
+    ) -> Result<(), WalletError> {
+        self.write_encrypted(path.as_ref(), passphrase, false)
+    }
+
+    /// Same as [SeedPhrase::save_encrypted], but replaces an existing file.
+    ///
+    /// On Unix, the permissions of an existing file are reset so that it is
+    /// only readable by the current user.
+    pub fn overwrite_encrypted(
+        &self,
+        path: impl AsRef<Path>,
+        passphrase: &str,
+    ) -> Result<(), WalletError> {
+        self.write_encrypted(path.as_ref(), passphrase, true)
+    }
+
+    fn write_encrypted(
+        &self,
+        path: &Path,
+        passphrase: &str,
+        overwrite: bool,
+    ) -> Result<(), WalletError> {
+        let keystore =
+            EncryptedKeystore::encrypt(&self.storage_string(), passphrase, KEYSTORE_SCRYPT_LOG_N);
+        let json = serde_json::to_vec_pretty(&keystore).expect("EncryptedKeystore is serializable");
+        write_keystore_file(path, &json, overwrite).map_err(|source| WalletError::KeystoreIo {
+            path: path.to_owned(),
+            source: Arc::new(source),
+        })
+    }
+
+    /// Load a seed phrase from a keystore file written by [SeedPhrase::save_encrypted].
+    pub fn load_encrypted(path: impl AsRef<Path>, passphrase: &str) -> Result<Self, WalletError> {
+        let path = path.as_ref();
+        let json = fs_err::read(path).map_err(|source| WalletError::KeystoreIo {
+            path: path.to_owned(),
+            source: Arc::new(source),
+        })?;
+        let keystore: EncryptedKeystore =
+            serde_json::from_slice(&json).map_err(|source| WalletError::InvalidKeystore {
+                path: path.to_owned(),
+                message: source.to_string(),
+            })?;
+        keystore.decrypt(path, passphrase)?.parse()
+    }
+
     /// Make a new [SeedPhrase] using the given derivation path.
     pub fn with_derivation_path(mut self, derivation_path: Option<Arc<DerivationPath>>) -> Self {
         self.derivation_path = derivation_path;
         self
     }
 
+    /// Make a new [SeedPhrase] using the given BIP39 passphrase.
+    pub fn with_bip39_passphrase(mut self, passphrase: Option<String>) -> Self {
+        self.bip39_passphrase = passphrase;
+        self
+    }
+
     /// Make a new [SeedPhrase] using a Cosmos derivation path and the given index.
     pub fn with_cosmos_numbered(self, index: u64) -> Self {
         self.with_derivation_path(Some(
@@ -87,56 +276,101 @@ impl SeedPhrase {
     /// used. Similarly, if `self` does not include a derivation path, the
     /// default for the HRP is used.
     pub fn with_hrp(&self, hrp: AddressHrp) -> Result<Wallet, WalletError> {
-        let root_private_key = bitcoin::bip32::Xpriv::new_master(
-            bitcoin::Network::Bitcoin,
-            &self.mnemonic.to_seed(""),
-        )
-        .map_err(|source| WalletError::CouldNotGetRootPrivateKey { source })?;
-
         let derivation_path = self
             .derivation_path
             .clone()
             .unwrap_or_else(|| hrp.default_derivation_path());
-        let secp = global_secp();
-        let privkey = root_private_key
-            .derive_priv(secp, &*derivation_path)
-            .map_err(|source| WalletError::CouldNotDerivePrivateKey {
-                derivation_path,
-                source,
-            })?;
-        let public_key = Xpub::from_priv(secp, &privkey);
-        let public_key_bytes = public_key.public_key.serialize();
-        let public_key_bytes_uncompressed = public_key.public_key.serialize_uncompressed();
-
         let public_key_method = self
             .public_key_method
             .unwrap_or_else(|| hrp.default_public_key_method());
-        let (raw_address, public_key) = match public_key_method {
-            crate::address::PublicKeyMethod::Cosmos => (
-                cosmos_address_from_public_key(&public_key_bytes),
-                WalletPublicKey::Cosmos(public_key_bytes),
-            ),
-            crate::address::PublicKeyMethod::Ethereum => (
-                eth_address_from_public_key(&public_key_bytes_uncompressed),
-                WalletPublicKey::Ethereum(public_key_bytes_uncompressed),
-            ),
-        };
-        let address = RawAddress::from(raw_address).with_hrp(hrp);
+        self.derive_wallet(hrp, derivation_path, public_key_method)
+    }
 
-        Ok(Wallet {
-            address,
+    /// Derive a wallet using exactly the given settings, ignoring overrides on `self`.
+    fn derive_wallet(
+        &self,
+        hrp: AddressHrp,
+        derivation_path: Arc<DerivationPath>,
+        public_key_method: PublicKeyMethod,
+    ) -> Result<Wallet, WalletError> {
+        let seed = Zeroizing::new(
+            self.mnemonic
+                .to_seed(self.bip39_passphrase.as_deref().unwrap_or_default()),
+        );
+        if public_key_method == PublicKeyMethod::Ed25519 {
+            let secret = slip10_ed25519(&*seed, &derivation_path)?;
+            return Ok(Wallet::from_ed25519_secret(&secret, hrp));
+        }
+        let mut root_private_key =
+            bitcoin::bip32::Xpriv::new_master(bitcoin::Network::Bitcoin, &*seed)
+                .map_err(|source| WalletError::CouldNotGetRootPrivateKey { source })?;
+
+        let secp = global_secp();
+        let privkey = root_private_key.derive_priv(secp, &*derivation_path);
+        erase_xpriv(&mut root_private_key);
+        let privkey = privkey.map_err(|source| WalletError::CouldNotDerivePrivateKey {
+            derivation_path,
+            source,
+        })?;
+        Ok(Wallet::from_privkey_with_method(
             privkey,
-            public_key,
-        })
+            hrp,
+            public_key_method,
+        ))
+    }
+}
+
+/// Derive an ed25519 secret key following SLIP-10.
+///
+/// SLIP-10 only supports hardened derivation for ed25519.
+fn slip10_ed25519(
+    seed: &[u8],
+    derivation_path: &Arc<DerivationPath>,
+) -> Result<Zeroizing<[u8; 32]>, WalletError> {
+    use bitcoin::hashes::{hmac, sha512, HashEngine};
+
+    let mut engine = hmac::HmacEngine::<sha512::Hash>::new(b"ed25519 seed");
+    engine.input(seed);
+    let mut node = Zeroizing::new(hmac::Hmac::from_engine(engine).to_byte_array());
+    let components: &[ChildNumber] = (**derivation_path).as_ref();
+    for child in components {
+        let ChildNumber::Hardened { index } = child else {
+            return Err(WalletError::Ed25519NonHardenedPath {
+                derivation_path: derivation_path.clone(),
+            });
+        };
+        let mut engine = hmac::HmacEngine::<sha512::Hash>::new(&node[32..]);
+        engine.input(&[0]);
+        engine.input(&node[..32]);
+        engine.input(&(index | 0x8000_0000).to_be_bytes());
+        *node = hmac::Hmac::from_engine(engine).to_byte_array();
+    }
+    let mut secret = Zeroizing::new([0u8; 32]);
+    secret.copy_from_slice(&node[..32]);
+    Ok(secret)
+}
+
+impl Drop for SeedPhrase {
+    fn drop(&mut self) {
+        self.mnemonic.zeroize();
+        self.bip39_passphrase.zeroize();
     }
 }
 
+/// Overwrite the secret parts of an extended private key.
+fn erase_xpriv(xpriv: &mut Xpriv) {
+    xpriv.private_key.non_secure_erase();
+    xpriv.chain_code = bitcoin::bip32::ChainCode::from([0; 32]);
+    std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+}
+
 impl From<bip39::Mnemonic> for SeedPhrase {
     fn from(mnemonic: bip39::Mnemonic) -> Self {
         SeedPhrase {
             mnemonic,
             derivation_path: None,
             public_key_method: None,
+            bip39_passphrase: None,
         }
     }
 }
@@ -176,10 +410,150 @@ impl FromStr for SeedPhrase {
             derivation_path,
             mnemonic,
             public_key_method: None,
+            bip39_passphrase: None,
         })
     }
 }
 
+/// An account found by [SeedPhrase::scan_accounts].
+#[derive(Clone, Debug)]
+pub struct DiscoveredAccount {
+    /// Account index within the derivation path.
+    pub index: u64,
+    /// Full derivation path used for this account.
+    pub derivation_path: Arc<DerivationPath>,
+    /// Address of the account.
+    pub address: Address,
+    /// Balances held by the account.
+    pub balances: Vec<Coin>,
+    /// Whether the account exists in the auth module, i.e. has received funds or sent transactions.
+    pub exists_on_chain: bool,
+}
+
+/// Cost parameter for scrypt when creating keystore files, matching geth's "light" setting.
+const KEYSTORE_SCRYPT_LOG_N: u8 = 15;
+const KEYSTORE_SCRYPT_R: u32 = 8;
+const KEYSTORE_SCRYPT_P: u32 = 1;
+/// Largest scrypt parameters accepted when loading keystore files.
+///
+/// The parameters come from the file itself, so without a cap a crafted file
+/// could make loading it allocate and compute arbitrarily much. These allow
+/// geth's "standard" setting (256 MiB) but nothing beyond it.
+const KEYSTORE_MAX_SCRYPT_LOG_N: u8 = 18;
+const KEYSTORE_MAX_SCRYPT_R: u32 = 8;
+const KEYSTORE_MAX_SCRYPT_P: u32 = 4;
+
+/// On-disk format of an encrypted keystore file.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct EncryptedKeystore {
+    version: u32,
+    scrypt_log_n: u8,
+    scrypt_r: u32,
+    scrypt_p: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl EncryptedKeystore {
+    fn encrypt(plaintext: &str, passphrase: &str, scrypt_log_n: u8) -> Self {
+        let mut salt = [0u8; 32];
+        let mut nonce = [0u8; 12];
+        crate::rng::with_rng(|rng| {
+            rng.fill_bytes(&mut salt);
+            rng.fill_bytes(&mut nonce);
+        });
+        let params = scrypt::Params::new(scrypt_log_n, KEYSTORE_SCRYPT_R, KEYSTORE_SCRYPT_P, 32)
+            .expect("Keystore scrypt parameters are valid");
+        let cipher = keystore_cipher(passphrase, &salt, &params);
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_bytes())
+            .expect("AES-GCM encryption of a seed phrase cannot fail");
+        EncryptedKeystore {
+            version: 1,
+            scrypt_log_n,
+            scrypt_r: KEYSTORE_SCRYPT_R,
+            scrypt_p: KEYSTORE_SCRYPT_P,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        }
+    }
+
+    fn decrypt(&self, path: &Path, passphrase: &str) -> Result<Zeroizing<String>, WalletError> {
+        let invalid = |message: String| WalletError::InvalidKeystore {
+            path: path.to_owned(),
+            message,
+        };
+        if self.version != 1 {
+            return Err(invalid(format!(
+                "Unsupported keystore version {}",
+                self.version
+            )));
+        }
+        let salt = hex::decode(&self.salt).map_err(|e| invalid(format!("Invalid salt: {e}")))?;
+        let nonce = hex::decode(&self.nonce).map_err(|e| invalid(format!("Invalid nonce: {e}")))?;
+        if nonce.len() != 12 {
+            return Err(invalid(format!("Invalid nonce length {}", nonce.len())));
+        }
+        let ciphertext = hex::decode(&self.ciphertext)
+            .map_err(|e| invalid(format!("Invalid ciphertext: {e}")))?;
+        if self.scrypt_log_n > KEYSTORE_MAX_SCRYPT_LOG_N
+            || self.scrypt_r > KEYSTORE_MAX_SCRYPT_R
+            || self.scrypt_p > KEYSTORE_MAX_SCRYPT_P
+        {
+            return Err(invalid(format!(
+                "scrypt parameters log_n={}, r={}, p={} exceed the maximum of log_n={KEYSTORE_MAX_SCRYPT_LOG_N}, r={KEYSTORE_MAX_SCRYPT_R}, p={KEYSTORE_MAX_SCRYPT_P}",
+                self.scrypt_log_n, self.scrypt_r, self.scrypt_p
+            )));
+        }
+        let params = scrypt::Params::new(self.scrypt_log_n, self.scrypt_r, self.scrypt_p, 32)
+            .map_err(|e| invalid(format!("Invalid scrypt parameters: {e}")))?;
+        let plaintext = keystore_cipher(passphrase, &salt, &params)
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| WalletError::KeystoreDecrypt {
+                path: path.to_owned(),
+            })?;
+        String::from_utf8(plaintext)
+            .map(Zeroizing::new)
+            .map_err(|_| invalid("Decrypted phrase is not UTF-8".to_owned()))
+    }
+}
+
+fn keystore_cipher(passphrase: &str, salt: &[u8], params: &scrypt::Params) -> Aes256Gcm {
+    let mut key = Zeroizing::new([0u8; 32]);
+    scrypt::scrypt(passphrase.as_bytes(), salt, params, key.as_mut_slice())
+        .expect("32 bytes is a valid scrypt output length");
+    Aes256Gcm::new_from_slice(key.as_slice()).expect("32 bytes is a valid AES-256 key length")
+}
+
+fn write_keystore_file(path: &Path, contents: &[u8], overwrite: bool) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut options = fs_err::OpenOptions::new();
+    options.write(true);
+    if overwrite {
+        options.create(true).truncate(true);
+    } else {
+        options.create_new(true);
+    }
+    #[cfg(unix)]
+    {
+        use fs_err::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    // The mode above only applies to newly created files, so tighten the
+    // permissions of an existing file before writing the secret into it.
+    #[cfg(unix)]
+    if overwrite {
+        use std::os::unix::fs::PermissionsExt;
+        file.file()
+            .set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(contents)
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum DerivationPathConfig {
     Three([DerivationPathComponent; 3]),
@@ -283,6 +657,52 @@ impl Display for DerivationPathComponent {
     }
 }
 
+/// Wallets for many chains derived from a single [SeedPhrase].
+///
+/// Each chain's [Wallet] is derived the first time it's requested, using
+/// the default derivation path and public key method for that chain's HRP
+/// unless overridden on the [SeedPhrase]. Cloning is cheap and shares the
+/// cache of derived wallets.
+#[derive(Clone)]
+pub struct MultiChainWallet {
+    seed_phrase: Arc<SeedPhrase>,
+    wallets: Arc<Mutex<HashMap<AddressHrp, Wallet>>>,
+}
+
+impl MultiChainWallet {
+    /// Create a new [MultiChainWallet] from the given seed phrase.
+    pub fn new(seed_phrase: SeedPhrase) -> Self {
+        MultiChainWallet {
+            seed_phrase: Arc::new(seed_phrase),
+            wallets: Default::default(),
+        }
+    }
+
+    /// Get the [Wallet] for the given HRP, deriving it if necessary.
+    pub fn for_hrp(&self, hrp: AddressHrp) -> Result<Wallet, WalletError> {
+        if let Some(wallet) = self.wallets.lock().get(&hrp) {
+            return Ok(wallet.clone());
+        }
+        let wallet = self.seed_phrase.with_hrp(hrp)?;
+        self.wallets.lock().insert(hrp, wallet.clone());
+        Ok(wallet)
+    }
+
+    /// Get the [Wallet] for the chain the given value is connected to.
+    pub fn for_chain(&self, chain: &impl HasAddressHrp) -> Result<Wallet, WalletError> {
+        self.for_hrp(chain.get_address_hrp())
+    }
+}
+
+impl std::fmt::Debug for MultiChainWallet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the seed phrase
+        f.debug_struct("MultiChainWallet")
+            .field("hrps", &self.wallets.lock().keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
 const JUNO_LOCAL_PHRASE: &str = "clip hire initial neck maid actor venue client foam budget lock catalog sweet steak waste crater broccoli pipe steak sister coyote moment obvious choose";
 const OSMO_LOCAL_PHRASE: &str = "notice oak worry limit wrap speak medal online prefer cluster roof addict wrist behave treat actual wasp year salad speed social layer crew genius";
 
@@ -291,17 +711,48 @@ const OSMO_LOCAL_PHRASE: &str = "notice oak worry limit wrap speak medal online
 // Not deriving Copy since this is a pretty large data structure.
 pub struct Wallet {
     address: Address,
-    privkey: Xpriv,
+    privkey: WalletPrivateKey,
     pub(crate) public_key: WalletPublicKey,
 }
 
 #[derive(Clone)]
-pub(crate) enum WalletPublicKey {
+enum WalletPrivateKey {
+    Secp256k1(Xpriv),
+    Ed25519(ed25519_dalek::SigningKey),
+}
+
+/// The public key of a signer, tagged by the signing method.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WalletPublicKey {
+    /// Compressed secp256k1 public key, used by most Cosmos chains
     Cosmos([u8; 33]),
+    /// Uncompressed secp256k1 public key, used by Ethereum-style chains like Injective
     Ethereum([u8; 65]),
+    /// ed25519 public key
+    Ed25519([u8; 32]),
 }
 
-fn global_secp() -> &'static Secp256k1<All> {
+impl WalletPublicKey {
+    /// The [PublicKeyMethod] this public key is used with.
+    pub fn method(&self) -> PublicKeyMethod {
+        match self {
+            WalletPublicKey::Cosmos(_) => PublicKeyMethod::Cosmos,
+            WalletPublicKey::Ethereum(_) => PublicKeyMethod::Ethereum,
+            WalletPublicKey::Ed25519(_) => PublicKeyMethod::Ed25519,
+        }
+    }
+
+    /// Get the byte representation of the public key.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            WalletPublicKey::Cosmos(public_key) => public_key,
+            WalletPublicKey::Ethereum(public_key) => public_key,
+            WalletPublicKey::Ed25519(public_key) => public_key,
+        }
+    }
+}
+
+pub(crate) fn global_secp() -> &'static Secp256k1<All> {
     static CELL: OnceCell<Secp256k1<All>> = OnceCell::new();
     CELL.get_or_init(Secp256k1::new)
 }
@@ -314,25 +765,198 @@ impl Wallet {
         SeedPhrase::random().with_hrp(hrp)
     }
 
+    /// Create a wallet from a hex-encoded secp256k1 private key, as exported by Keplr or MetaMask.
+    ///
+    /// A leading `0x` is accepted. The public key method is the default for the given HRP.
+    pub fn from_privkey_hex(privkey: &str, hrp: AddressHrp) -> Result<Self, WalletError> {
+        let invalid = |reason: String| WalletError::InvalidPrivateKey { reason };
+        let bytes = hex::decode(privkey.strip_prefix("0x").unwrap_or(privkey))
+            .map_err(|e| invalid(format!("Invalid hex: {e}")))?;
+        let private_key = bitcoin::secp256k1::SecretKey::from_slice(&bytes)
+            .map_err(|e| invalid(format!("Invalid secp256k1 key: {e}")))?;
+        let privkey = Xpriv {
+            network: bitcoin::NetworkKind::Main,
+            depth: 0,
+            parent_fingerprint: Default::default(),
+            child_number: bitcoin::bip32::ChildNumber::Normal { index: 0 },
+            private_key,
+            chain_code: bitcoin::bip32::ChainCode::from([0; 32]),
+        };
+        Ok(Wallet::from_privkey_with_method(
+            privkey,
+            hrp,
+            hrp.default_public_key_method(),
+        ))
+    }
+
+    /// Generate a random ed25519 wallet.
+    pub fn generate_ed25519(hrp: AddressHrp) -> Self {
+        let mut secret = Zeroizing::new([0u8; 32]);
+        crate::rng::with_rng(|rng| rng.fill_bytes(&mut *secret));
+        Wallet::from_ed25519_secret(&secret, hrp)
+    }
+
+    /// Create an ed25519 wallet from the 32-byte secret key.
+    ///
+    /// The address is the first 20 bytes of the SHA-256 hash of the public key,
+    /// matching the Cosmos SDK.
+    pub fn from_ed25519_secret(secret: &[u8; 32], hrp: AddressHrp) -> Self {
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(secret);
+        let public_key = signing_key.verifying_key().to_bytes();
+        Wallet {
+            address: RawAddress::from(ed25519_address_from_public_key(&public_key)).with_hrp(hrp),
+            privkey: WalletPrivateKey::Ed25519(signing_key),
+            public_key: WalletPublicKey::Ed25519(public_key),
+        }
+    }
+
+    fn from_privkey_with_method(
+        privkey: Xpriv,
+        hrp: AddressHrp,
+        public_key_method: PublicKeyMethod,
+    ) -> Self {
+        let public_key = Xpub::from_priv(global_secp(), &privkey);
+        let public_key_bytes = public_key.public_key.serialize();
+        let public_key_bytes_uncompressed = public_key.public_key.serialize_uncompressed();
+
+        let (raw_address, public_key) = match public_key_method {
+            PublicKeyMethod::Cosmos => (
+                cosmos_address_from_public_key(&public_key_bytes),
+                WalletPublicKey::Cosmos(public_key_bytes),
+            ),
+            PublicKeyMethod::Ethereum => (
+                eth_address_from_public_key(&public_key_bytes_uncompressed),
+                WalletPublicKey::Ethereum(public_key_bytes_uncompressed),
+            ),
+            PublicKeyMethod::Ed25519 => {
+                unreachable!("ed25519 wallets are never created from a secp256k1 key")
+            }
+        };
+        let address = RawAddress::from(raw_address).with_hrp(hrp);
+
+        Wallet {
+            address,
+            privkey: WalletPrivateKey::Secp256k1(privkey),
+            public_key,
+        }
+    }
+
+    /// Use the same private key on a chain with a different HRP.
+    ///
+    /// The public key method is the default for the new HRP, so rebinding
+    /// between Cosmos-style and Ethereum-style chains produces an unrelated
+    /// address. The key is not re-derived: chains with a different default
+    /// derivation path (e.g. Injective) will not get the address a wallet app
+    /// would show for the same mnemonic. Use [MultiChainWallet] for that.
+    ///
+    /// ed25519 wallets keep using the same ed25519 key.
+    pub fn rebind_hrp(&self, hrp: AddressHrp) -> Wallet {
+        match &self.privkey {
+            WalletPrivateKey::Secp256k1(privkey) => {
+                Wallet::from_privkey_with_method(*privkey, hrp, hrp.default_public_key_method())
+            }
+            WalletPrivateKey::Ed25519(signing_key) => {
+                Wallet::from_ed25519_secret(&signing_key.to_bytes(), hrp)
+            }
+        }
+    }
+
     /// Get the byte representation of the public key used on chain.
     pub fn public_key_bytes(&self) -> &[u8] {
-        match &self.public_key {
-            WalletPublicKey::Cosmos(public_key) => public_key,
-            WalletPublicKey::Ethereum(public_key) => public_key,
-        }
+        self.public_key.as_bytes()
     }
 
     /// Sign the given bytes with this wallet
     ///
     /// Note that the signature will depend on the [PublicKeyMethod] used when
     /// deriving this wallet.
+    ///
+    /// # Panics
+    ///
+    /// Panics for ed25519 wallets, use [Self::sign_raw] instead.
     pub fn sign_bytes(&self, msg: &[u8]) -> Signature {
-        let msg = match self.public_key {
-            WalletPublicKey::Cosmos(_) => *sha256::Hash::hash(msg).as_ref(),
-            WalletPublicKey::Ethereum(_) => keccak(msg),
+        let privkey = self.secp256k1_key().unwrap_or_else(|e| panic!("{e}"));
+        sign_secp256k1(privkey, msg, self.public_key.method())
+    }
+
+    /// Sign the given bytes with this wallet, returning the 64-byte signature used on chain.
+    ///
+    /// This is the compact ECDSA signature for secp256k1 wallets, and the
+    /// standard signature for ed25519 wallets.
+    pub fn sign_raw(&self, msg: &[u8]) -> [u8; 64] {
+        match &self.privkey {
+            WalletPrivateKey::Secp256k1(privkey) => {
+                sign_secp256k1(privkey, msg, self.public_key.method()).serialize_compact()
+            }
+            WalletPrivateKey::Ed25519(signing_key) => {
+                ed25519_dalek::Signer::sign(signing_key, msg).to_bytes()
+            }
+        }
+    }
+
+    fn secp256k1_key(&self) -> Result<&Xpriv, WalletError> {
+        match &self.privkey {
+            WalletPrivateKey::Secp256k1(privkey) => Ok(privkey),
+            WalletPrivateKey::Ed25519(_) => Err(WalletError::Secp256k1KeyRequired {
+                address: self.address,
+            }),
+        }
+    }
+
+    /// Sign EIP-712 typed data with this wallet.
+    ///
+    /// Returns the 65-byte `r ‖ s ‖ v` signature used by Ethereum, with `v`
+    /// being 27 or 28.
+    pub fn sign_eip712(&self, typed_data: &TypedData) -> Result<Vec<u8>, Eip712Error> {
+        let WalletPrivateKey::Secp256k1(privkey) = &self.privkey else {
+            return Err(Eip712Error::UnsupportedKeyType);
         };
-        let msg = Message::from_digest_slice(msg.as_ref()).unwrap();
-        global_secp().sign_ecdsa(&msg, &self.privkey.private_key)
+        let hash = typed_data.signing_hash()?;
+        let msg = Message::from_digest(hash);
+        let (recovery_id, compact) = global_secp()
+            .sign_ecdsa_recoverable(&msg, &privkey.private_key)
+            .serialize_compact();
+        let mut signature = compact.to_vec();
+        signature.push(27 + recovery_id.to_i32() as u8);
+        Ok(signature)
+    }
+
+    /// Sign arbitrary data following ADR-36.
+    ///
+    /// The data is wrapped in a `sign/MsgSignData` Amino JSON sign doc, making
+    /// the result compatible with Keplr's `signArbitrary` and verifiable with
+    /// [verify_arbitrary].
+    pub fn sign_arbitrary(&self, data: impl AsRef<[u8]>) -> ArbitrarySignature {
+        let sign_doc = adr36_sign_doc(self.address, data.as_ref());
+        ArbitrarySignature {
+            public_key: self.public_key.clone(),
+            signature: self.sign_raw(&sign_doc),
+        }
+    }
+
+    /// Generate the gRPC metadata used to authenticate queries.
+    ///
+    /// The signature is an ADR-36 signature over the string
+    /// `cosmos-rs-query-auth:{timestamp}`, where timestamp is in seconds since
+    /// the epoch. Servers are expected to verify the signature against the
+    /// provided public key, check that the public key matches the address,
+    /// and reject stale timestamps.
+    pub fn query_auth_headers(&self, timestamp: i64) -> Vec<(&'static str, String)> {
+        let data = format!("cosmos-rs-query-auth:{timestamp}");
+        let sign_doc = adr36_sign_doc(self.address, data.as_bytes());
+        let signature = self.sign_raw(&sign_doc);
+        vec![
+            ("x-cosmos-auth-address", self.address.to_string()),
+            (
+                "x-cosmos-auth-pubkey",
+                base64::engine::general_purpose::STANDARD.encode(self.public_key_bytes()),
+            ),
+            ("x-cosmos-auth-timestamp", timestamp.to_string()),
+            (
+                "x-cosmos-auth-signature",
+                base64::engine::general_purpose::STANDARD.encode(signature),
+            ),
+        ]
     }
 
     // Technically these functions are redundant, but keeping them as
@@ -374,6 +998,21 @@ impl Wallet {
         .await
     }
 
+    /// Send coins to many recipients in a single `MsgMultiSend` message.
+    ///
+    /// Note: this is just a helper around the more general [TxBuilder] interface.
+    pub async fn send_coins_multi(
+        &self,
+        cosmos: &Cosmos,
+        outputs: Vec<(Address, Vec<Coin>)>,
+    ) -> Result<TxResponse, crate::Error> {
+        let msg = TxMessage::try_from(MsgMultiSendHelper {
+            sender: self.get_address(),
+            outputs,
+        })?;
+        self.broadcast_message(cosmos, msg).await
+    }
+
     /// Send a given amount of gas coin
     ///
     /// Note: this is just a helper around the more general [TxBuilder] interface.
@@ -401,17 +1040,38 @@ impl Wallet {
     ///
     /// This function returns the private key (`Xpriv`) of the wallet.
     /// The private key is crucial for signing transactions and should be kept secure.
+    ///
+    /// # Panics
+    ///
+    /// Panics for ed25519 wallets, use [Self::try_get_privkey] to handle them.
     pub fn get_privkey(&self) -> Xpriv {
-        self.privkey
+        self.try_get_privkey().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Like [Self::get_privkey], but returns an error for ed25519 wallets.
+    pub fn try_get_privkey(&self) -> Result<Xpriv, WalletError> {
+        self.secp256k1_key().copied()
     }
 }
 
-fn cosmos_address_from_public_key(public_key: &[u8]) -> [u8; 20] {
+fn sign_secp256k1(privkey: &Xpriv, msg: &[u8], method: PublicKeyMethod) -> Signature {
+    let msg = Message::from_digest(signing_digest(msg, method));
+    global_secp().sign_ecdsa(&msg, &privkey.private_key)
+}
+
+pub(crate) fn cosmos_address_from_public_key(public_key: &[u8]) -> [u8; 20] {
     let sha = sha256::Hash::hash(public_key);
     *ripemd160::Hash::hash(sha.as_ref()).as_ref()
 }
 
-fn eth_address_from_public_key(public_key: &[u8; 65]) -> [u8; 20] {
+pub(crate) fn ed25519_address_from_public_key(public_key: &[u8; 32]) -> [u8; 20] {
+    let sha = sha256::Hash::hash(public_key).to_byte_array();
+    let mut output = [0u8; 20];
+    output.copy_from_slice(&sha[..20]);
+    output
+}
+
+pub(crate) fn eth_address_from_public_key(public_key: &[u8; 65]) -> [u8; 20] {
     assert_eq!(public_key[0], 4);
     let hash = keccak(&public_key[1..]);
     let mut output = [0u8; 20];
@@ -419,6 +1079,24 @@ fn eth_address_from_public_key(public_key: &[u8; 65]) -> [u8; 20] {
     output
 }
 
+impl Drop for Wallet {
+    fn drop(&mut self) {
+        // ed25519 signing keys zeroize themselves on drop
+        if let WalletPrivateKey::Secp256k1(privkey) = &mut self.privkey {
+            erase_xpriv(privkey);
+        }
+    }
+}
+
+impl std::fmt::Debug for Wallet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the private key
+        f.debug_struct("Wallet")
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
+
 impl Display for Wallet {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", self.address)
@@ -437,7 +1115,273 @@ impl HasAddress for Wallet {
     }
 }
 
-fn keccak(input: &[u8]) -> [u8; 32] {
+/// A watch-only wallet: an address, and optionally its public key, without any private key.
+///
+/// Used to simulate transactions and estimate fees for accounts we cannot
+/// sign for, such as a CW3 multisig treasury. Providing the public key
+/// allows the simulation to account for signature verification costs of
+/// accounts which have never signed a transaction on chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatchWallet {
+    address: Address,
+    public_key: Option<WalletPublicKey>,
+}
+
+impl WatchWallet {
+    /// Watch the given address, without a known public key.
+    pub fn new(address: Address) -> Self {
+        WatchWallet {
+            address,
+            public_key: None,
+        }
+    }
+
+    /// Watch the address derived from the given public key.
+    pub fn from_public_key(
+        public_key: WalletPublicKey,
+        hrp: AddressHrp,
+    ) -> Result<Self, AddressError> {
+        let address = Address::from_public_key(public_key.as_bytes(), public_key.method(), hrp)?;
+        Ok(WatchWallet {
+            address,
+            public_key: Some(public_key),
+        })
+    }
+
+    /// Watch the address and public key of the given signer.
+    pub fn from_signer(signer: &impl Signer) -> Self {
+        WatchWallet {
+            address: signer.address(),
+            public_key: Some(signer.public_key()),
+        }
+    }
+
+    /// The public key, if known.
+    pub fn public_key(&self) -> Option<&WalletPublicKey> {
+        self.public_key.as_ref()
+    }
+}
+
+impl From<Address> for WatchWallet {
+    fn from(address: Address) -> Self {
+        WatchWallet::new(address)
+    }
+}
+
+impl From<&Wallet> for WatchWallet {
+    fn from(wallet: &Wallet) -> Self {
+        WatchWallet::from_signer(wallet)
+    }
+}
+
+impl FromStr for WatchWallet {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(WatchWallet::new)
+    }
+}
+
+impl Display for WatchWallet {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.address)
+    }
+}
+
+impl HasAddressHrp for WatchWallet {
+    fn get_address_hrp(&self) -> AddressHrp {
+        self.address.get_address_hrp()
+    }
+}
+
+impl HasAddress for WatchWallet {
+    fn get_address(&self) -> Address {
+        self.address
+    }
+}
+
+/// An ADR-36 signature over arbitrary data, produced by [Wallet::sign_arbitrary].
+///
+/// Serializes to the `StdSignature` JSON returned by Keplr's `signArbitrary`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(into = "StdSignatureJson", try_from = "StdSignatureJson")]
+pub struct ArbitrarySignature {
+    /// Public key of the signer.
+    pub public_key: WalletPublicKey,
+    /// Compact `r ‖ s` signature.
+    pub signature: [u8; 64],
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct StdSignatureJson {
+    pub_key: StdPubKeyJson,
+    signature: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct StdPubKeyJson {
+    #[serde(rename = "type")]
+    type_: String,
+    value: String,
+}
+
+const AMINO_PUBKEY_SECP256K1: &str = "tendermint/PubKeySecp256k1";
+const AMINO_PUBKEY_ETH_SECP256K1: &str = "ethermint/PubKeyEthSecp256k1";
+const AMINO_PUBKEY_ED25519: &str = "tendermint/PubKeyEd25519";
+
+impl From<ArbitrarySignature> for StdSignatureJson {
+    fn from(
+        ArbitrarySignature {
+            public_key,
+            signature,
+        }: ArbitrarySignature,
+    ) -> Self {
+        let (type_, public_key) = match public_key {
+            WalletPublicKey::Cosmos(public_key) => (AMINO_PUBKEY_SECP256K1, public_key.to_vec()),
+            WalletPublicKey::Ed25519(public_key) => (AMINO_PUBKEY_ED25519, public_key.to_vec()),
+            WalletPublicKey::Ethereum(public_key) => (
+                AMINO_PUBKEY_ETH_SECP256K1,
+                bitcoin::secp256k1::PublicKey::from_slice(&public_key)
+                    .expect("Wallet public keys are valid")
+                    .serialize()
+                    .to_vec(),
+            ),
+        };
+        let base64 = base64::engine::general_purpose::STANDARD;
+        StdSignatureJson {
+            pub_key: StdPubKeyJson {
+                type_: type_.to_owned(),
+                value: base64.encode(public_key),
+            },
+            signature: base64.encode(signature),
+        }
+    }
+}
+
+impl TryFrom<StdSignatureJson> for ArbitrarySignature {
+    type Error = String;
+
+    fn try_from(StdSignatureJson { pub_key, signature }: StdSignatureJson) -> Result<Self, String> {
+        let base64 = base64::engine::general_purpose::STANDARD;
+        let public_key = base64
+            .decode(&pub_key.value)
+            .map_err(|e| format!("Invalid base64 public key: {e}"))?;
+        let secp256k1 = |public_key: &[u8]| {
+            bitcoin::secp256k1::PublicKey::from_slice(public_key)
+                .map_err(|e| format!("Invalid secp256k1 public key: {e}"))
+        };
+        let public_key = match pub_key.type_.as_str() {
+            AMINO_PUBKEY_SECP256K1 => WalletPublicKey::Cosmos(secp256k1(&public_key)?.serialize()),
+            AMINO_PUBKEY_ETH_SECP256K1 => {
+                WalletPublicKey::Ethereum(secp256k1(&public_key)?.serialize_uncompressed())
+            }
+            AMINO_PUBKEY_ED25519 => {
+                WalletPublicKey::Ed25519(public_key.try_into().map_err(|public_key: Vec<u8>| {
+                    format!(
+                        "Expected a 32 byte ed25519 public key, received {}",
+                        public_key.len()
+                    )
+                })?)
+            }
+            type_ => return Err(format!("Unsupported public key type {type_}")),
+        };
+        let signature = base64
+            .decode(&signature)
+            .map_err(|e| format!("Invalid base64 signature: {e}"))?
+            .try_into()
+            .map_err(|signature: Vec<u8>| {
+                format!("Expected a 64 byte signature, received {}", signature.len())
+            })?;
+        Ok(ArbitrarySignature {
+            public_key,
+            signature,
+        })
+    }
+}
+
+/// Verify an ADR-36 signature over arbitrary data, such as one produced by
+/// [Wallet::sign_arbitrary] or Keplr's `signArbitrary`.
+///
+/// Checks both that the public key belongs to the given address and that the
+/// signature is valid for the data.
+pub fn verify_arbitrary(signer: Address, data: &[u8], signature: &ArbitrarySignature) -> bool {
+    let public_key = signature.public_key.as_bytes();
+    let method = signature.public_key.method();
+    match Address::from_public_key(public_key, method, signer.hrp()) {
+        Ok(address) if address == signer => verify_signature(
+            public_key,
+            &adr36_sign_doc(signer, data),
+            &signature.signature,
+            method,
+        ),
+        _ => false,
+    }
+}
+
+/// Verify a secp256k1 signature over the given message.
+///
+/// The message is hashed according to the [PublicKeyMethod]: SHA-256 for
+/// Cosmos and Keccak-256 for Ethereum, matching [Wallet::sign_bytes]. The
+/// public key may be compressed or uncompressed. The signature is the 64-byte
+/// compact form, optionally followed by an Ethereum-style recovery byte which
+/// is ignored.
+pub fn verify_signature(
+    public_key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+    method: PublicKeyMethod,
+) -> bool {
+    if method == PublicKeyMethod::Ed25519 {
+        let (Ok(public_key), Ok(signature)) = (
+            <[u8; 32]>::try_from(public_key),
+            ed25519_dalek::Signature::from_slice(signature),
+        ) else {
+            return false;
+        };
+        return ed25519_dalek::VerifyingKey::from_bytes(&public_key)
+            .and_then(|public_key| public_key.verify_strict(message, &signature))
+            .is_ok();
+    }
+    let Ok(public_key) = bitcoin::secp256k1::PublicKey::from_slice(public_key) else {
+        return false;
+    };
+    let signature = match signature.len() {
+        65 => &signature[..64],
+        _ => signature,
+    };
+    let Ok(signature) = Signature::from_compact(signature) else {
+        return false;
+    };
+    let msg = Message::from_digest(signing_digest(message, method));
+    global_secp()
+        .verify_ecdsa(&msg, &signature, &public_key)
+        .is_ok()
+}
+
+pub(crate) fn signing_digest(msg: &[u8], method: PublicKeyMethod) -> [u8; 32] {
+    match method {
+        // ed25519 signs the message itself, the digest is only used for secp256k1
+        PublicKeyMethod::Cosmos | PublicKeyMethod::Ed25519 => {
+            sha256::Hash::hash(msg).to_byte_array()
+        }
+        PublicKeyMethod::Ethereum => keccak(msg),
+    }
+}
+
+/// Generate the Amino JSON sign doc for an ADR-36 arbitrary data signature.
+///
+/// Keys are sorted and no whitespace is used, matching Keplr's `signArbitrary`.
+pub(crate) fn adr36_sign_doc(signer: Address, data: &[u8]) -> Vec<u8> {
+    let data = base64::engine::general_purpose::STANDARD.encode(data);
+    format!(
+        r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","msgs":[{{"type":"sign/MsgSignData","value":{{"data":{},"signer":{}}}}}],"sequence":"0"}}"#,
+        serde_json::Value::String(data),
+        serde_json::Value::String(signer.to_string())
+    )
+    .into_bytes()
+}
+
+pub(crate) fn keccak(input: &[u8]) -> [u8; 32] {
     let mut sha3 = Keccak::v256();
     sha3.update(input);
     let mut output = [0; 32];
@@ -461,6 +1405,7 @@ mod tests {
         let wallet = phrase.with_hrp(AddressHrp::from_static("inj")).unwrap();
         let eth_address = eth_address_from_public_key(match &wallet.public_key {
             WalletPublicKey::Cosmos(_) => panic!("Should not be Cosmos"),
+            WalletPublicKey::Ed25519(_) => panic!("Should not be Ed25519"),
             WalletPublicKey::Ethereum(public_key) => public_key,
         });
         assert_eq!(address, hex::encode(eth_address));
@@ -554,6 +1499,284 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rebind_hrp() {
+        const PHRASE: &str =
+            "dilemma flavor noise circle voyage vacant amateur mass morning tunnel unhappy entire";
+        let seed_phrase = SeedPhrase::from_str(PHRASE).unwrap();
+        let osmosis = seed_phrase
+            .with_hrp(AddressHrp::from_static("osmo"))
+            .unwrap();
+        let juno = seed_phrase
+            .with_hrp(AddressHrp::from_static("juno"))
+            .unwrap();
+        let rebound = osmosis.rebind_hrp(AddressHrp::from_static("juno"));
+        assert_eq!(juno.get_address(), rebound.get_address());
+        assert_eq!(juno.public_key, rebound.public_key);
+    }
+
+    #[test]
+    fn test_multi_chain_wallet() {
+        const PHRASE: &str =
+            "dilemma flavor noise circle voyage vacant amateur mass morning tunnel unhappy entire";
+        let multi = MultiChainWallet::new(SeedPhrase::from_str(PHRASE).unwrap());
+        assert_eq!(
+            multi
+                .for_hrp(AddressHrp::from_static("osmo"))
+                .unwrap()
+                .get_address()
+                .to_string(),
+            "osmo1t3mvqjxvfxlstyzfskl37zqgu5ftq0rttpqqc5"
+        );
+        assert_eq!(
+            multi
+                .for_hrp(AddressHrp::from_static("inj"))
+                .unwrap()
+                .get_address()
+                .to_string(),
+            "inj15sws48vv977kmgawqfegptw0pqs7cfeq7mpr4c"
+        );
+    }
+
+    #[test]
+    fn test_encrypted_keystore_roundtrip() {
+        const PHRASE: &str =
+            "dilemma flavor noise circle voyage vacant amateur mass morning tunnel unhappy entire";
+        let seed_phrase = SeedPhrase::from_str(PHRASE)
+            .unwrap()
+            .with_cosmos_numbered(3);
+        // Use a low scrypt cost to keep the test fast
+        let keystore = EncryptedKeystore::encrypt(&seed_phrase.storage_string(), "hunter2", 4);
+        let path = Path::new("keystore.json");
+        let loaded: SeedPhrase = keystore.decrypt(path, "hunter2").unwrap().parse().unwrap();
+        assert_eq!(loaded.phrase(), PHRASE);
+        assert_eq!(loaded.derivation_path, seed_phrase.derivation_path);
+        assert!(matches!(
+            keystore.decrypt(path, "wrong"),
+            Err(WalletError::KeystoreDecrypt { .. })
+        ));
+
+        let expensive = EncryptedKeystore {
+            scrypt_log_n: 40,
+            ..keystore
+        };
+        assert!(matches!(
+            expensive.decrypt(path, "hunter2"),
+            Err(WalletError::InvalidKeystore { .. })
+        ));
+    }
+
+    #[test]
+    fn test_keystore_file_overwrite() {
+        let path =
+            std::env::temp_dir().join(format!("cosmos-keystore-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        write_keystore_file(&path, b"first", false).unwrap();
+        let err = write_keystore_file(&path, b"second", false).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+        assert_eq!(std::fs::read(&path).unwrap(), b"first");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        }
+        write_keystore_file(&path, b"second", true).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"second");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn sign_arbitrary_known_answer() {
+        // Computed with an independent implementation of Keplr's
+        // signArbitrary: sorted-key Amino JSON, SHA-256 and RFC 6979 signing.
+        const PRIVKEY: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        const DATA: &[u8] = b"cosmos-rs ownership proof";
+        const SIGN_DOC: &str = r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"Y29zbW9zLXJzIG93bmVyc2hpcCBwcm9vZg==","signer":"cosmos15428vq2uzwhm3taey9sr9x5vm6tk78ewe54lwe"}}],"sequence":"0"}"#;
+        const SIGNATURE: &str = r#"{"pub_key":{"type":"tendermint/PubKeySecp256k1","value":"A4MYU1tUEF1Keq5gwI/EX5aHGBtP38YlvRp1P6c5f+11"},"signature":"kG6rDoYh9TnDCsQVp6dTHPDfEjuHvbT8BskieQC3qIsEXQr6IPwiWk9afb3r0xgS/NNO+ALhRgzNldwEnl0BpA=="}"#;
+
+        let wallet = Wallet::from_privkey_hex(PRIVKEY, AddressHrp::from_static("cosmos")).unwrap();
+        assert_eq!(
+            wallet.get_address_string(),
+            "cosmos15428vq2uzwhm3taey9sr9x5vm6tk78ewe54lwe"
+        );
+        assert_eq!(
+            String::from_utf8(adr36_sign_doc(wallet.get_address(), DATA)).unwrap(),
+            SIGN_DOC
+        );
+        let signature = wallet.sign_arbitrary(DATA);
+        assert_eq!(serde_json::to_string(&signature).unwrap(), SIGNATURE);
+
+        let expected: ArbitrarySignature = serde_json::from_str(SIGNATURE).unwrap();
+        assert!(verify_arbitrary(wallet.get_address(), DATA, &expected));
+    }
+
+    #[test]
+    fn test_sign_arbitrary() {
+        const PHRASE: &str =
+            "dilemma flavor noise circle voyage vacant amateur mass morning tunnel unhappy entire";
+        let seed_phrase = SeedPhrase::from_str(PHRASE).unwrap();
+        for hrp in ["osmo", "inj"] {
+            let wallet = seed_phrase.with_hrp(AddressHrp::from_static(hrp)).unwrap();
+            let other = Wallet::generate(AddressHrp::from_static(hrp)).unwrap();
+            let signature = wallet.sign_arbitrary(b"hello world");
+            assert!(verify_arbitrary(
+                wallet.get_address(),
+                b"hello world",
+                &signature
+            ));
+            assert!(!verify_arbitrary(
+                wallet.get_address(),
+                b"goodbye",
+                &signature
+            ));
+            assert!(!verify_arbitrary(
+                other.get_address(),
+                b"hello world",
+                &signature
+            ));
+
+            let json = serde_json::to_string(&signature).unwrap();
+            let parsed: ArbitrarySignature = serde_json::from_str(&json).unwrap();
+            assert_eq!(signature, parsed);
+        }
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let wallet = Wallet::generate(AddressHrp::from_static("osmo")).unwrap();
+        let signature = wallet.sign_bytes(b"message").serialize_compact();
+        let public_key = wallet.public_key_bytes();
+        assert!(verify_signature(
+            public_key,
+            b"message",
+            &signature,
+            PublicKeyMethod::Cosmos
+        ));
+        assert!(!verify_signature(
+            public_key,
+            b"other message",
+            &signature,
+            PublicKeyMethod::Cosmos
+        ));
+        assert!(!verify_signature(
+            public_key,
+            b"message",
+            &signature,
+            PublicKeyMethod::Ethereum
+        ));
+        assert_eq!(
+            Address::from_public_key(
+                public_key,
+                PublicKeyMethod::Cosmos,
+                AddressHrp::from_static("osmo")
+            )
+            .unwrap(),
+            wallet.get_address()
+        );
+    }
+
+    #[test]
+    fn test_from_privkey_hex() {
+        let wallet = Wallet::generate(AddressHrp::from_static("osmo")).unwrap();
+        let hex = wallet
+            .get_privkey()
+            .private_key
+            .display_secret()
+            .to_string();
+        for input in [hex.clone(), format!("0x{hex}")] {
+            let imported =
+                Wallet::from_privkey_hex(&input, AddressHrp::from_static("osmo")).unwrap();
+            assert_eq!(imported.get_address(), wallet.get_address());
+        }
+        Wallet::from_privkey_hex("not hex", AddressHrp::from_static("osmo")).unwrap_err();
+        Wallet::from_privkey_hex("abcd", AddressHrp::from_static("osmo")).unwrap_err();
+    }
+
+    #[test]
+    fn test_bip39_passphrase() {
+        const PHRASE: &str =
+            "dilemma flavor noise circle voyage vacant amateur mass morning tunnel unhappy entire";
+        let hrp = AddressHrp::from_static("osmo");
+        let plain = SeedPhrase::from_str(PHRASE).unwrap();
+        let empty = plain.clone().with_bip39_passphrase(Some(String::new()));
+        let hidden = plain
+            .clone()
+            .with_bip39_passphrase(Some("hidden".to_owned()));
+        let plain = plain.with_hrp(hrp).unwrap().get_address();
+        assert_eq!(plain, empty.with_hrp(hrp).unwrap().get_address());
+        assert_ne!(plain, hidden.with_hrp(hrp).unwrap().get_address());
+    }
+
+    #[test]
+    fn test_slip10_ed25519() {
+        // Test vector 1 from SLIP-0010
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let secret = slip10_ed25519(&seed, &Arc::new("m/0'".parse().unwrap())).unwrap();
+        assert_eq!(
+            hex::encode(*secret),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+        slip10_ed25519(&seed, &Arc::new("m/0'/1".parse().unwrap())).unwrap_err();
+    }
+
+    #[test]
+    fn test_ed25519_wallet() {
+        let hrp = AddressHrp::from_static("cosmos");
+        let wallet = Wallet::generate_ed25519(hrp);
+        assert_eq!(wallet.public_key.method(), PublicKeyMethod::Ed25519);
+        assert_eq!(
+            Address::from_public_key(wallet.public_key_bytes(), PublicKeyMethod::Ed25519, hrp)
+                .unwrap(),
+            wallet.get_address()
+        );
+        assert!(matches!(
+            wallet.try_get_privkey(),
+            Err(WalletError::Secp256k1KeyRequired { .. })
+        ));
+        let signature = wallet.sign_raw(b"message");
+        assert!(verify_signature(
+            wallet.public_key_bytes(),
+            b"message",
+            &signature,
+            PublicKeyMethod::Ed25519
+        ));
+        let arbitrary = wallet.sign_arbitrary(b"hello");
+        assert!(verify_arbitrary(wallet.get_address(), b"hello", &arbitrary));
+        let json = serde_json::to_string(&arbitrary).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ArbitrarySignature>(&json).unwrap(),
+            arbitrary
+        );
+    }
+
+    #[test]
+    fn test_generate_vanity() {
+        let hrp = AddressHrp::from_static("osmo");
+        let phrase = SeedPhrase::generate_vanity(hrp, "q", "", 2).unwrap();
+        let address = phrase.with_hrp(hrp).unwrap().get_address().to_string();
+        assert!(address.starts_with("osmo1q"), "{address}");
+        SeedPhrase::generate_vanity(hrp, "b", "", 1).unwrap_err();
+    }
+
+    #[test]
+    fn test_watch_wallet() {
+        let hrp = AddressHrp::from_static("osmo");
+        let wallet = Wallet::generate(hrp).unwrap();
+        let watch = WatchWallet::from_public_key(wallet.public_key.clone(), hrp).unwrap();
+        assert_eq!(watch, WatchWallet::from(&wallet));
+        let parsed: WatchWallet = wallet.get_address().to_string().parse().unwrap();
+        assert_eq!(parsed.get_address(), wallet.get_address());
+        assert_eq!(parsed.public_key(), None);
+    }
+
     #[test]
     fn test_gen_key_pair() {
         let address_hrp = AddressHrp::from_static("cosmos");
@@ -0,0 +1,124 @@
+use cosmos_sdk_proto::cosmwasm::wasm::v1::{
+    MsgMigrateContract, MsgStoreAndInstantiateContract, MsgSudoContract,
+};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    Address, AddressHrp, Coin, ContractAdmin, HasAddress, InstantiatePermission, RawAddress,
+};
+
+/// Compute the address of a module account, e.g. `gov` or `distribution`.
+pub fn module_address(name: &str, hrp: AddressHrp) -> Address {
+    let hash: [u8; 32] = Sha256::digest(name.as_bytes()).into();
+    let mut raw = [0; 20];
+    raw.copy_from_slice(&hash[..20]);
+    RawAddress::from(raw).with_hrp(hrp)
+}
+
+/// Builds wasm messages which must be signed by a privileged authority.
+///
+/// Permissioned chains such as Osmosis restrict uploading code, migrating
+/// some contracts, and sudo calls to the governance module. Wrap the
+/// resulting messages in a proposal with [crate::TxBuilder::add_submit_proposal].
+/// Chains using a different authority, like the admin module on Neutron, can
+/// provide its address with [Self::new].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WasmAuthority {
+    authority: Address,
+}
+
+impl WasmAuthority {
+    /// Use the given address as the authority.
+    pub fn new(authority: impl HasAddress) -> Self {
+        WasmAuthority {
+            authority: authority.get_address(),
+        }
+    }
+
+    /// Use the governance module account as the authority.
+    pub fn gov(hrp: AddressHrp) -> Self {
+        Self::new(module_address("gov", hrp))
+    }
+
+    /// Address of the authority.
+    pub fn authority(&self) -> Address {
+        self.authority
+    }
+
+    /// Upload and instantiate code in a single message.
+    ///
+    /// The code is gzipped before inclusion, matching [crate::Cosmos::store_code].
+    /// [ContractAdmin::Sender] makes the authority the contract admin.
+    pub fn store_and_instantiate(
+        &self,
+        wasm_byte_code: &[u8],
+        label: impl Into<String>,
+        msg: impl serde::Serialize,
+        admin: ContractAdmin,
+        funds: Vec<Coin>,
+        instantiate_permission: Option<InstantiatePermission>,
+    ) -> Result<MsgStoreAndInstantiateContract, crate::Error> {
+        Ok(MsgStoreAndInstantiateContract {
+            authority: self.authority.get_address_string(),
+            wasm_byte_code: crate::Cosmos::compress_wasm_code(wasm_byte_code)?,
+            instantiate_permission: instantiate_permission.map(Into::into),
+            unpin_code: false,
+            admin: match admin {
+                ContractAdmin::NoAdmin => String::new(),
+                ContractAdmin::Sender => self.authority.get_address_string(),
+                ContractAdmin::Addr(addr) => addr.get_address_string(),
+            },
+            label: label.into(),
+            msg: serde_json::to_vec(&msg)?,
+            funds,
+            ..Default::default()
+        })
+    }
+
+    /// Migrate a contract whose admin is the authority.
+    pub fn migrate(
+        &self,
+        contract: impl HasAddress,
+        code_id: u64,
+        msg: impl serde::Serialize,
+    ) -> Result<MsgMigrateContract, crate::Error> {
+        Ok(MsgMigrateContract {
+            sender: self.authority.get_address_string(),
+            contract: contract.get_address_string(),
+            code_id,
+            msg: serde_json::to_vec(&msg)?,
+        })
+    }
+
+    /// Call a contract's `sudo` entry point.
+    pub fn sudo(
+        &self,
+        contract: impl HasAddress,
+        msg: impl serde::Serialize,
+    ) -> Result<MsgSudoContract, crate::Error> {
+        Ok(MsgSudoContract {
+            authority: self.authority.get_address_string(),
+            contract: contract.get_address_string(),
+            msg: serde_json::to_vec(&msg)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gov_module_address() {
+        let hrp = AddressHrp::from_static("cosmos");
+        assert_eq!(
+            WasmAuthority::gov(hrp).authority().to_string(),
+            "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn"
+        );
+        let hrp = AddressHrp::from_static("osmo");
+        assert_eq!(
+            module_address("gov", hrp).to_string(),
+            "osmo10d07y265gmmuvt4z0w9aw880jnsr700jjeq4qp"
+        );
+    }
+}
@@ -77,14 +77,21 @@ const COSMOS_PROTO_VERSION: &str = "v1.0.0-beta.3";
 const OSMOSIS_VERSION: &str = "v15.0.0"; // testnet is behind master
 const OSMOSIS_VERSION_EPOCHS: &str = "5494ad8992810c7385ec8a63e5e9476adf332d4c"; // different file paths on various tags
 const OSMOSIS_VERSION_TXFEES: &str = "v22.0.0";
+const OSMOSIS_VERSION_POOLMANAGER: &str = "v25.0.0";
+const OSMOSIS_VERSION_TWAP: &str = "v25.0.0";
+const OSMOSIS_VERSION_CONCENTRATED_LIQUIDITY: &str = "v25.0.0";
 const REGEN_VERSION: &str = "v1.3.3-alpha.regen.1";
 const GOOGLE_VERSION: &str = "master";
+const IBC_GO_VERSION: &str = "v8.5.1";
 
 const COSMOS_SDK_BASE: &str = "cosmos/base/v1beta1";
 const COSMOS_SDK_QUERY: &str = "cosmos/base/query/v1beta1";
 const COSMOS_SDK_BANK: &str = "cosmos/bank/v1beta1";
 const COSMOS_SDK_AMINO: &str = "amino";
 const COSMOS_SDK_MSG: &str = "cosmos/msg/v1";
+const COSMOS_SDK_QUERY_V1: &str = "cosmos/query/v1";
+const COSMOS_SDK_UPGRADE: &str = "cosmos/upgrade/v1beta1";
+const COSMOS_SDK_ICS23: &str = "cosmos/ics23/v1";
 
 impl Proto {
     pub fn url(&self) -> String {
@@ -96,6 +103,9 @@ impl Proto {
                 ProtoCosmosSdk::Bank => format!("https://raw.githubusercontent.com/cosmos/cosmos-sdk/{COSMOS_SDK_VERSION}/proto/{COSMOS_SDK_BANK}/bank.proto"), 
                 ProtoCosmosSdk::Amino => format!("https://raw.githubusercontent.com/cosmos/cosmos-sdk/{COSMOS_SDK_VERSION}/proto/{COSMOS_SDK_AMINO}/amino.proto"), 
                 ProtoCosmosSdk::Msg => format!("https://raw.githubusercontent.com/cosmos/cosmos-sdk/{COSMOS_SDK_VERSION}/proto/{COSMOS_SDK_MSG}/msg.proto"), 
+                ProtoCosmosSdk::QueryV1 => format!("https://raw.githubusercontent.com/cosmos/cosmos-sdk/{COSMOS_SDK_VERSION}/proto/{COSMOS_SDK_QUERY_V1}/query.proto"),
+                ProtoCosmosSdk::Upgrade => format!("https://raw.githubusercontent.com/cosmos/cosmos-sdk/{COSMOS_SDK_VERSION}/proto/{COSMOS_SDK_UPGRADE}/upgrade.proto"),
+                ProtoCosmosSdk::Ics23 => format!("https://raw.githubusercontent.com/cosmos/ics23/master/proto/{COSMOS_SDK_ICS23}/proofs.proto"),
             },
             // actually download from regen, see https://github.com/cosmos/cosmos-sdk/issues/12984#issuecomment-1275674526
             Proto::Gogo => format!("https://raw.githubusercontent.com/regen-network/protobuf/{REGEN_VERSION}/gogoproto/gogo.proto"),
@@ -103,6 +113,7 @@ impl Proto {
                 ProtoGoogle::Annotations => format!("https://raw.githubusercontent.com/googleapis/googleapis/{GOOGLE_VERSION}/google/api/annotations.proto"),
                 ProtoGoogle::Http => format!("https://raw.githubusercontent.com/googleapis/googleapis/{GOOGLE_VERSION}/google/api/http.proto"),
             },
+            Proto::Ibc(p) => format!("https://raw.githubusercontent.com/cosmos/ibc-go/{IBC_GO_VERSION}/proto/{}", p.path()),
             Proto::Osmosis(p) => match p {
                 ProtoOsmosis::TokenFactory(p) => match p {
                     ProtoTokenFactory::AuthorityMetadata => format!("https://raw.githubusercontent.com/osmosis-labs/osmosis/{OSMOSIS_VERSION}/proto/osmosis/tokenfactory/v1beta1/authorityMetadata.proto"),
@@ -121,6 +132,9 @@ impl Proto {
                     ProtoTxFees::Gov => format!("https://raw.githubusercontent.com/osmosis-labs/osmosis/{OSMOSIS_VERSION_TXFEES}/proto/osmosis/txfees/v1beta1/gov.proto"),
                     ProtoTxFees::Query => format!("https://raw.githubusercontent.com/osmosis-labs/osmosis/{OSMOSIS_VERSION_TXFEES}/proto/osmosis/txfees/v1beta1/query.proto"),
                 }
+                ProtoOsmosis::PoolManager(p) => format!("https://raw.githubusercontent.com/osmosis-labs/osmosis/{OSMOSIS_VERSION_POOLMANAGER}/proto/{}", p.path()),
+                ProtoOsmosis::Twap => format!("https://raw.githubusercontent.com/osmosis-labs/osmosis/{OSMOSIS_VERSION_TWAP}/proto/osmosis/twap/v1beta1/query.proto"),
+                ProtoOsmosis::ConcentratedLiquidity(p) => format!("https://raw.githubusercontent.com/osmosis-labs/osmosis/{OSMOSIS_VERSION_CONCENTRATED_LIQUIDITY}/proto/{}", p.path()),
             }
         }
     }
@@ -134,6 +148,9 @@ impl Proto {
                 ProtoCosmosSdk::Bank => format!("{COSMOS_SDK_BANK}/bank.proto"),
                 ProtoCosmosSdk::Amino => format!("{COSMOS_SDK_AMINO}/amino.proto"),
                 ProtoCosmosSdk::Msg => format!("{COSMOS_SDK_MSG}/msg.proto"),
+                ProtoCosmosSdk::QueryV1 => format!("{COSMOS_SDK_QUERY_V1}/query.proto"),
+                ProtoCosmosSdk::Upgrade => format!("{COSMOS_SDK_UPGRADE}/upgrade.proto"),
+                ProtoCosmosSdk::Ics23 => format!("{COSMOS_SDK_ICS23}/proofs.proto"),
             },
             // actually download from regen, see https://github.com/cosmos/cosmos-sdk/issues/12984#issuecomment-1275674526
             Proto::Gogo => format!("gogoproto/gogo.proto"),
@@ -141,6 +158,7 @@ impl Proto {
                 ProtoGoogle::Annotations => format!("google/api/annotations.proto"),
                 ProtoGoogle::Http => format!("google/api/http.proto"),
             },
+            Proto::Ibc(p) => p.path().to_string(),
             Proto::Osmosis(p) => match p {
                 ProtoOsmosis::TokenFactory(p) => match p {
                     ProtoTokenFactory::AuthorityMetadata => {
@@ -165,6 +183,9 @@ impl Proto {
                     ProtoTxFees::Gov => format!("osmosis/txfees/v1beta1/gov.proto"),
                     ProtoTxFees::Query => format!("osmosis/txfees/v1beta1/query.proto"),
                 },
+                ProtoOsmosis::PoolManager(p) => p.path().to_string(),
+                ProtoOsmosis::Twap => format!("osmosis/twap/v1beta1/query.proto"),
+                ProtoOsmosis::ConcentratedLiquidity(p) => p.path().to_string(),
             },
         }
     }
@@ -177,6 +198,9 @@ impl Proto {
             Proto::CosmosSdk(ProtoCosmosSdk::Bank),
             Proto::CosmosSdk(ProtoCosmosSdk::Amino),
             Proto::CosmosSdk(ProtoCosmosSdk::Msg),
+            Proto::CosmosSdk(ProtoCosmosSdk::QueryV1),
+            Proto::CosmosSdk(ProtoCosmosSdk::Upgrade),
+            Proto::CosmosSdk(ProtoCosmosSdk::Ics23),
             Proto::Gogo,
             Proto::Google(ProtoGoogle::Annotations),
             Proto::Google(ProtoGoogle::Http),
@@ -193,6 +217,35 @@ impl Proto {
             Proto::Osmosis(ProtoOsmosis::TxFees(ProtoTxFees::Genesis)),
             Proto::Osmosis(ProtoOsmosis::TxFees(ProtoTxFees::Gov)),
             Proto::Osmosis(ProtoOsmosis::TxFees(ProtoTxFees::Query)),
+            Proto::Osmosis(ProtoOsmosis::PoolManager(ProtoPoolManager::SwapRoute)),
+            Proto::Osmosis(ProtoOsmosis::PoolManager(ProtoPoolManager::Tx)),
+            Proto::Osmosis(ProtoOsmosis::PoolManager(ProtoPoolManager::Query)),
+            Proto::Osmosis(ProtoOsmosis::Twap),
+            Proto::Osmosis(ProtoOsmosis::ConcentratedLiquidity(
+                ProtoConcentratedLiquidity::Position,
+            )),
+            Proto::Osmosis(ProtoOsmosis::ConcentratedLiquidity(
+                ProtoConcentratedLiquidity::IncentiveRecord,
+            )),
+            Proto::Osmosis(ProtoOsmosis::ConcentratedLiquidity(
+                ProtoConcentratedLiquidity::Tx,
+            )),
+            Proto::Osmosis(ProtoOsmosis::ConcentratedLiquidity(
+                ProtoConcentratedLiquidity::Query,
+            )),
+            Proto::Ibc(ProtoIbc::ClientV1Client),
+            Proto::Ibc(ProtoIbc::ClientV1Query),
+            Proto::Ibc(ProtoIbc::CommitmentV1),
+            Proto::Ibc(ProtoIbc::ConnectionV1Connection),
+            Proto::Ibc(ProtoIbc::ConnectionV1Query),
+            Proto::Ibc(ProtoIbc::ChannelV1Channel),
+            Proto::Ibc(ProtoIbc::ChannelV1Upgrade),
+            Proto::Ibc(ProtoIbc::ChannelV1Query),
+            Proto::Ibc(ProtoIbc::TransferV1Transfer),
+            Proto::Ibc(ProtoIbc::TransferV1Query),
+            Proto::Ibc(ProtoIbc::IcaV1Packet),
+            Proto::Ibc(ProtoIbc::IcaControllerV1Tx),
+            Proto::Ibc(ProtoIbc::IcaControllerV1Query),
         ]
     }
 }
@@ -203,6 +256,7 @@ enum Proto {
     Gogo,
     Google(ProtoGoogle),
     Osmosis(ProtoOsmosis),
+    Ibc(ProtoIbc),
 }
 
 enum ProtoCosmosSdk {
@@ -211,6 +265,9 @@ enum ProtoCosmosSdk {
     Bank,
     Amino,
     Msg,
+    QueryV1,
+    Upgrade,
+    Ics23,
 }
 
 enum ProtoGoogle {
@@ -222,6 +279,9 @@ enum ProtoOsmosis {
     TokenFactory(ProtoTokenFactory),
     Epochs(ProtoEpochs),
     TxFees(ProtoTxFees),
+    PoolManager(ProtoPoolManager),
+    Twap,
+    ConcentratedLiquidity(ProtoConcentratedLiquidity),
 }
 
 enum ProtoTokenFactory {
@@ -243,3 +303,83 @@ enum ProtoTxFees {
     Gov,
     Query,
 }
+
+enum ProtoPoolManager {
+    SwapRoute,
+    Tx,
+    Query,
+}
+
+impl ProtoPoolManager {
+    fn path(&self) -> &'static str {
+        match self {
+            ProtoPoolManager::SwapRoute => "osmosis/poolmanager/v1beta1/swap_route.proto",
+            ProtoPoolManager::Tx => "osmosis/poolmanager/v1beta1/tx.proto",
+            ProtoPoolManager::Query => "osmosis/poolmanager/v1beta1/query.proto",
+        }
+    }
+}
+
+enum ProtoConcentratedLiquidity {
+    Position,
+    IncentiveRecord,
+    Tx,
+    Query,
+}
+
+impl ProtoConcentratedLiquidity {
+    fn path(&self) -> &'static str {
+        match self {
+            ProtoConcentratedLiquidity::Position => {
+                "osmosis/concentratedliquidity/v1beta1/position.proto"
+            }
+            ProtoConcentratedLiquidity::IncentiveRecord => {
+                "osmosis/concentratedliquidity/v1beta1/incentive_record.proto"
+            }
+            ProtoConcentratedLiquidity::Tx => "osmosis/concentratedliquidity/v1beta1/tx.proto",
+            ProtoConcentratedLiquidity::Query => {
+                "osmosis/concentratedliquidity/v1beta1/query.proto"
+            }
+        }
+    }
+}
+
+enum ProtoIbc {
+    ClientV1Client,
+    ClientV1Query,
+    CommitmentV1,
+    ConnectionV1Connection,
+    ConnectionV1Query,
+    ChannelV1Channel,
+    ChannelV1Upgrade,
+    ChannelV1Query,
+    TransferV1Transfer,
+    TransferV1Query,
+    IcaV1Packet,
+    IcaControllerV1Tx,
+    IcaControllerV1Query,
+}
+
+impl ProtoIbc {
+    fn path(&self) -> &'static str {
+        match self {
+            ProtoIbc::ClientV1Client => "ibc/core/client/v1/client.proto",
+            ProtoIbc::ClientV1Query => "ibc/core/client/v1/query.proto",
+            ProtoIbc::CommitmentV1 => "ibc/core/commitment/v1/commitment.proto",
+            ProtoIbc::ConnectionV1Connection => "ibc/core/connection/v1/connection.proto",
+            ProtoIbc::ConnectionV1Query => "ibc/core/connection/v1/query.proto",
+            ProtoIbc::ChannelV1Channel => "ibc/core/channel/v1/channel.proto",
+            ProtoIbc::ChannelV1Upgrade => "ibc/core/channel/v1/upgrade.proto",
+            ProtoIbc::ChannelV1Query => "ibc/core/channel/v1/query.proto",
+            ProtoIbc::TransferV1Transfer => "ibc/applications/transfer/v1/transfer.proto",
+            ProtoIbc::TransferV1Query => "ibc/applications/transfer/v1/query.proto",
+            ProtoIbc::IcaV1Packet => "ibc/applications/interchain_accounts/v1/packet.proto",
+            ProtoIbc::IcaControllerV1Tx => {
+                "ibc/applications/interchain_accounts/controller/v1/tx.proto"
+            }
+            ProtoIbc::IcaControllerV1Query => {
+                "ibc/applications/interchain_accounts/controller/v1/query.proto"
+            }
+        }
+    }
+}